@@ -0,0 +1,47 @@
+// benches/moves.rs
+
+//! Move-engine benchmarks (`cargo bench`): single turns, and a 1000-move
+//! algorithm parsed-per-call, pre-parsed, and via packed move tables.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use rubics::cube::{Cube, MoveTables, PackedCube};
+use rubics::logic::alg::{apply_alg, apply_token};
+use rubics::logic::scramble::{scramble_with_seed, seeded_alg};
+
+fn single_moves(c: &mut Criterion) {
+    let mut g = c.benchmark_group("single_move");
+    for tok in ["R", "R'", "R2", "U", "F'"] {
+        g.bench_function(tok, |b| {
+            let mut cube = Cube::default();
+            b.iter(|| apply_token(black_box(&mut cube), black_box(tok)).unwrap());
+        });
+    }
+    g.finish();
+}
+
+fn long_alg(c: &mut Criterion) {
+    let alg = scramble_with_seed(1000, 42);
+    c.bench_function("apply_alg_1000", |b| {
+        b.iter(|| {
+            let mut cube = Cube::default();
+            apply_alg(&mut cube, black_box(&alg)).unwrap();
+            cube
+        });
+    });
+    let parsed = seeded_alg(1000, 42);
+    c.bench_function("apply_parsed_1000", |b| {
+        b.iter(|| {
+            let mut cube = Cube::default();
+            cube.apply_alg(black_box(&parsed));
+            cube
+        });
+    });
+    let tables = MoveTables::get();
+    c.bench_function("apply_table_1000", |b| {
+        b.iter(|| tables.apply_all(PackedCube::SOLVED, black_box(&parsed.0)));
+    });
+}
+
+criterion_group!(benches, single_moves, long_alg);
+criterion_main!(benches);
@@ -0,0 +1,163 @@
+// src/app/ab_test.rs
+
+//! A/B trainer: two algorithms for the same library case, executed in turn
+//! from the case's setup, each timed from the first turn to solved. Once
+//! both have a few times, Welch's t-test says whether one is really faster
+//! for this user or the gap is still within the noise.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Alg, Cube, FaceId, Move, Turn};
+use crate::logic::alg::parse_alg;
+use crate::logic::library::{AlgSet, NamedAlg};
+use crate::logic::stats::{welch, Welch};
+use crate::t;
+
+/// Timed executions of each algorithm in a test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trials(pub u16);
+
+impl Trials {
+    pub const ALL: [Trials; 4] = [Trials(10), Trials(20), Trials(30), Trials(50)];
+}
+
+impl Default for Trials {
+    fn default() -> Self {
+        Trials(20)
+    }
+}
+
+impl std::fmt::Display for Trials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&t!("ab-trials-value", n = self.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+}
+
+impl Side {
+    pub fn other(self) -> Side {
+        match self {
+            Side::A => Side::B,
+            Side::B => Side::A,
+        }
+    }
+}
+
+impl std::fmt::Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Side::A => "A",
+            Side::B => "B",
+        })
+    }
+}
+
+/// Library entries that are cases to solve: the orientations and
+/// permutations, not the triggers.
+pub fn cases() -> Vec<&'static NamedAlg> {
+    AlgSet::Oll.algs().chain(AlgSet::Perm.algs()).collect()
+}
+
+/// Whether `alg` solves the cube `setup` leaves, allowing a last U turn
+/// the user adds by eye.
+fn solves(setup: &Alg, alg: &Alg) -> bool {
+    let mut cube = Cube::default();
+    cube.apply_alg(setup);
+    cube.apply_alg(alg);
+    [None, Some(Turn::Cw), Some(Turn::Ccw), Some(Turn::Half)].into_iter().any(|auf| {
+        let mut c = cube.clone();
+        if let Some(turn) = auf {
+            c.apply(Move { face: FaceId::U, turn });
+        }
+        c.is_solved()
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct AbTest {
+    pub case: &'static NamedAlg,
+    pub algs: [Alg; 2],
+    /// Taken from solved to the case: the library algorithm undone.
+    pub setup: Alg,
+    pub trials: Trials,
+    /// Times so far, for A then B.
+    pub times: [Vec<Duration>; 2],
+    /// The algorithm the current trial is for.
+    pub side: Side,
+    /// Set on the trial's first turn.
+    started: Option<Instant>,
+}
+
+impl AbTest {
+    /// A test of algorithms `a` and `b` on `case`; an error, for the
+    /// status line, when one doesn't parse or doesn't solve the case.
+    pub fn new(case: &'static NamedAlg, [a, b]: [&str; 2], trials: Trials) -> Result<Self, String> {
+        let setup = parse_alg(case.moves).map_err(|e| e.to_string())?.inverse();
+        let parse = |side: Side, text: &str| {
+            let alg = parse_alg(text).map_err(|e| e.to_string())?;
+            if alg.0.is_empty() {
+                return Err(t!("ab-alg-empty", side = side.to_string()));
+            }
+            if !solves(&setup, &alg) {
+                return Err(t!("ab-alg-wrong-case", side = side.to_string(), case = case.name));
+            }
+            Ok(alg)
+        };
+        let algs = [parse(Side::A, a)?, parse(Side::B, b)?];
+        Ok(Self { case, algs, setup, trials, times: [Vec::new(), Vec::new()], side: Side::A, started: None })
+    }
+
+    pub fn alg(&self, side: Side) -> &Alg {
+        &self.algs[side as usize]
+    }
+
+    /// Trials timed so far, and in all.
+    pub fn done(&self) -> usize {
+        self.times.iter().map(Vec::len).sum()
+    }
+
+    pub fn total(&self) -> usize {
+        2 * usize::from(self.trials.0)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.done() >= self.total()
+    }
+
+    /// The cube a trial starts from.
+    pub fn setup_cube(&self) -> Cube {
+        let mut cube = Cube::default();
+        cube.apply_alg(&self.setup);
+        cube
+    }
+
+    /// Start the current trial over, untimed.
+    pub fn restart(&mut self) {
+        self.started = None;
+    }
+
+    /// Update after a user turn on `cube`: the trial's time once solved,
+    /// when the next trial is for the other algorithm.
+    pub fn after_move(&mut self, cube: &Cube) -> Option<Duration> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        if !cube.is_solved() {
+            return None;
+        }
+        let time = started.elapsed();
+        self.times[self.side as usize].push(time);
+        self.side = self.side.other();
+        self.started = None;
+        Some(time)
+    }
+
+    /// The comparison so far, A against B, in seconds.
+    pub fn result(&self) -> Option<Welch> {
+        let [a, b] = self.times.each_ref().map(|ts| ts.iter().map(Duration::as_secs_f64).collect::<Vec<_>>());
+        welch(&a, &b)
+    }
+}
@@ -0,0 +1,48 @@
+// src/app/alg_watch.rs
+
+//! Watching an algorithm file for live editing in another editor. While a
+//! file is watched it is checked a few times a second; each time it is
+//! saved, the cube goes back to where it was when watching began and the
+//! file's algorithm is applied to it afresh, so the cube always shows what
+//! the file as saved does. Moves made by hand in between are undone by the
+//! next save.
+
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::Error;
+use super::Snapshot;
+
+/// How often the file is checked.
+pub const POLL: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone)]
+pub struct AlgWatch {
+    pub path: PathBuf,
+    /// The cube and its moves when watching began.
+    pub base: Snapshot,
+    /// Modification time and length of the version last read; `None`
+    /// until the first read.
+    seen: Option<(SystemTime, u64)>,
+}
+
+impl AlgWatch {
+    /// Watch `path`, replaying its algorithm onto `base`. Fails if there is
+    /// no file there to watch.
+    pub fn new(path: PathBuf, base: Snapshot) -> Result<Self, Error> {
+        std::fs::metadata(&path).map_err(|e| Error::read(&path, e))?;
+        Ok(Self { path, base, seen: None })
+    }
+
+    /// The file's text, if it was saved since the last call. A file gone
+    /// for a moment (as editors replace it on save) counts as unchanged.
+    pub fn changed(&mut self) -> Option<Result<String, Error>> {
+        let meta = std::fs::metadata(&self.path).ok()?;
+        let stamp = (meta.modified().ok()?, meta.len());
+        if self.seen == Some(stamp) {
+            return None;
+        }
+        self.seen = Some(stamp);
+        Some(std::fs::read_to_string(&self.path).map_err(|e| Error::read(&self.path, e)))
+    }
+}
@@ -0,0 +1,60 @@
+// src/app/anki.rs
+
+//! Anki deck export: an algorithm set as flash cards, the case each
+//! algorithm solves drawn on the front (see [`crate::render::thumb`]) and
+//! its name and moves on the back. The deck is a text file Anki imports as
+//! it is (File ▸ Import): header lines name the separator, note type, and
+//! deck, and the pictures are inline SVG, so there are no media files to
+//! copy alongside.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::cube::Cube;
+use crate::logic::alg::parse_alg;
+use crate::logic::library::AlgSet;
+use crate::render::thumb;
+use crate::storage::{data_dir, save_file};
+use crate::t;
+use crate::Error;
+
+/// Sticker size in the card pictures, in pixels.
+const CARD_UNIT: f32 = 40.0;
+
+/// Where the deck goes when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("anki-deck.txt")
+}
+
+/// `s` as one quoted field: quotes doubled, and line breaks, which would
+/// end the note, dropped.
+fn field(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\"").replace(['\n', '\r'], ""))
+}
+
+/// The deck for `set`, and how many cards it holds.
+pub fn deck(set: AlgSet) -> (String, usize) {
+    let mut out = String::new();
+    let _ = writeln!(out, "#separator:tab");
+    let _ = writeln!(out, "#html:true");
+    let _ = writeln!(out, "#notetype:Basic");
+    let _ = writeln!(out, "#deck:{}", t!("anki-deck", set = set.to_string()));
+    let mut cards = 0;
+    for named in set.algs() {
+        let Ok(alg) = parse_alg(named.moves) else { continue };
+        // The case is the state the algorithm solves.
+        let mut cube = Cube::default();
+        cube.apply_alg(&alg.inverse());
+        let back = format!("<b>{}</b><br>{}", named.name, named.moves);
+        let _ = writeln!(out, "{}\t{}", field(&thumb::svg(&cube, CARD_UNIT)), field(&back));
+        cards += 1;
+    }
+    (out, cards)
+}
+
+/// Write the deck for `set`; returns how many cards it holds.
+pub fn save(set: AlgSet, path: &Path) -> Result<usize, Error> {
+    let (text, cards) = deck(set);
+    save_file(path, text).map_err(|e| Error::write(path, e))?;
+    Ok(cards)
+}
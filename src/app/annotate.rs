@@ -0,0 +1,75 @@
+// src/app/annotate.rs
+
+//! Coach mode: marks drawn over the cube view for remote lessons and video.
+//! By default the drawing is wiped by the next move; with "keep per step"
+//! each cube state keeps its own marks, so stepping back through a solve
+//! brings back what was drawn there. Snapshots save the window as a PNG.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::cube::Cube;
+use crate::render::annotate::{Ink, Mark, Tool};
+use crate::storage::data_dir;
+
+#[derive(Debug, Clone, Default)]
+pub struct Annotations {
+    /// Drawing with the mouse on the main view.
+    pub on: bool,
+    pub tool: Tool,
+    pub ink: Ink,
+    /// Keep marks with the state they were drawn on instead of clearing
+    /// them on the next move.
+    pub keep: bool,
+    /// Marks by the facelets of the state they were drawn on.
+    marks: HashMap<String, Vec<Mark>>,
+    /// State shown at the last update.
+    shown: String,
+}
+
+impl Annotations {
+    /// The pen for the canvas, while drawing.
+    pub fn pen(&self) -> Option<(Tool, Ink)> {
+        self.on.then_some((self.tool, self.ink))
+    }
+
+    /// Marks drawn on `cube`'s state.
+    pub fn marks(&self, cube: &Cube) -> &[Mark] {
+        self.marks.get(&cube.to_facelets()).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn add(&mut self, cube: &Cube, mark: Mark) {
+        self.marks.entry(cube.to_facelets()).or_default().push(mark);
+    }
+
+    /// Remove the last mark on `cube`'s state.
+    pub fn undo(&mut self, cube: &Cube) {
+        if let Some(marks) = self.marks.get_mut(&cube.to_facelets()) {
+            marks.pop();
+        }
+    }
+
+    /// Remove every mark on `cube`'s state.
+    pub fn clear(&mut self, cube: &Cube) {
+        self.marks.remove(&cube.to_facelets());
+    }
+
+    /// After each update: unless marks are kept per step, a new state
+    /// starts with a clean view.
+    pub fn follow(&mut self, cube: &Cube) {
+        let key = cube.to_facelets();
+        if key != self.shown {
+            if !self.keep {
+                self.marks.clear();
+            }
+            self.shown = key;
+        }
+    }
+}
+
+/// A new snapshot file, named by the time it was taken.
+pub fn snapshot_path() -> PathBuf {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    data_dir().join(format!("annotated-{secs}.png"))
+}
@@ -0,0 +1,92 @@
+// src/app/attract.rs
+
+//! Attract loop for booth and museum displays. Once a demo has been left
+//! alone long enough, the app plays with itself: it scrambles the cube,
+//! holds the scramble a moment, solves it a turn at a time, each turn
+//! shown turning, holds the solved cube, and starts over, the views slowly
+//! orbiting all the while. Any input ends the loop.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Alg, Cube, Move};
+use crate::logic::scramble::{seeded_alg, SCRAMBLE_LEN};
+use crate::logic::solver::DistanceTable;
+
+/// Animation frame interval while the loop runs.
+pub const FRAME: Duration = Duration::from_millis(33);
+
+/// Time between the solve's turns.
+const TURN_GAP: Duration = Duration::from_millis(700);
+
+/// How long the scrambled and the solved cube are held.
+const HOLD: Duration = Duration::from_secs(3);
+
+/// How fast the views orbit, in degrees per second.
+const ORBIT_SPEED: f32 = 8.0;
+
+#[derive(Debug, Clone)]
+enum Phase {
+    /// Solved; scrambling at the instant given.
+    Solved(Instant),
+    /// Scrambled; solving from the instant given.
+    Scrambled(Instant),
+    /// The solve's turns still to make, last first, and when the next is due.
+    Solving { left: Vec<Move>, next: Instant },
+}
+
+/// What the loop does to the cube on a frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    Scramble(Alg),
+    Turn(Move),
+}
+
+#[derive(Debug, Clone)]
+pub struct Attract {
+    phase: Phase,
+    /// Degrees added to both views' Z angle.
+    pub orbit: f32,
+    last_frame: Instant,
+}
+
+impl Attract {
+    /// A loop starting with a scramble, now.
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self { phase: Phase::Solved(now), orbit: 0.0, last_frame: now }
+    }
+
+    /// Advance the orbit to now (unless it is held still) and the loop to
+    /// its next step, if one is due. Without a solver the scramble is held
+    /// until there is one.
+    pub fn frame(&mut self, cube: &Cube, solver: Option<&DistanceTable>, orbit: bool) -> Option<Step> {
+        let now = Instant::now();
+        if orbit {
+            let dt = now.duration_since(self.last_frame).as_secs_f32();
+            self.orbit = (self.orbit + ORBIT_SPEED * dt) % 360.0;
+        }
+        self.last_frame = now;
+
+        match &mut self.phase {
+            Phase::Solved(at) if now >= *at => {
+                self.phase = Phase::Scrambled(now + HOLD);
+                Some(Step::Scramble(seeded_alg(SCRAMBLE_LEN, rand::random())))
+            }
+            Phase::Scrambled(at) if now >= *at => {
+                let mut left = solver?.solve(cube)?.0;
+                left.reverse();
+                self.phase = Phase::Solving { left, next: now };
+                None
+            }
+            Phase::Solving { left, next } if now >= *next => {
+                let step = left.pop().map(Step::Turn);
+                *next = now + TURN_GAP;
+                if left.is_empty() {
+                    self.phase = Phase::Solved(now + HOLD);
+                }
+                step
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// src/app/backup.rs
+
+//! Profile backups: every file in the active profile's data folder
+//! (settings, solve logs, the letter scheme, bindings, traces and scripts,
+//! exports) in one ZIP archive, and restoring one by writing its files back
+//! over the folder's. Archives in the folder aren't backed up, so backups
+//! don't nest; nor are the other profiles' folders under the default
+//! profile's, or the note of the profile used last, which they all share.
+
+use std::path::{Path, PathBuf};
+
+use crate::storage::{data_dir, save_file, zip};
+use crate::Error;
+
+/// Where the backup goes, and is restored from, when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("backup.zip")
+}
+
+/// Whether `name` is a plain file name a backup may hold: no folders, no
+/// way out of the data folder.
+fn plain(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\', ':'])
+}
+
+/// Write a backup of the data folder to `path`; returns the files saved.
+pub fn save(path: &Path) -> Result<usize, Error> {
+    save_from(&data_dir(), path)
+}
+
+fn save_from(dir: &Path, path: &Path) -> Result<usize, Error> {
+    let mut files = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let file = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !file.is_file()
+                || !plain(&name)
+                || name == "profile.txt"
+                || file.extension().is_some_and(|e| e == "zip")
+            {
+                continue;
+            }
+            let data = std::fs::read(&file).map_err(|e| Error::read(&file, e))?;
+            files.push((name, data));
+        }
+    }
+    files.sort();
+    save_file(path, zip::write(&files)).map_err(|e| Error::write(path, e))?;
+    Ok(files.len())
+}
+
+/// Write the files in the backup at `path` into the data folder, over
+/// those of the same name; returns the files restored. The archive is
+/// checked whole before anything is written.
+pub fn restore(path: &Path) -> Result<usize, Error> {
+    restore_to(&data_dir(), path)
+}
+
+fn restore_to(dir: &Path, path: &Path) -> Result<usize, Error> {
+    let bytes = std::fs::read(path).map_err(|e| Error::read(path, e))?;
+    let files = zip::read(&bytes)?;
+    if !files.iter().all(|(name, _)| plain(name)) {
+        return Err(Error::BadArchive);
+    }
+    for (name, data) in &files {
+        let file = dir.join(name);
+        save_file(&file, data).map_err(|e| Error::write(&file, e))?;
+    }
+    Ok(files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty folder of its own under the system temp folder.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rubics-backup-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn plain_names_only() {
+        for name in ["stats.txt", "letters.txt", "trace 1.txt"] {
+            assert!(plain(name), "{name}");
+        }
+        for name in ["", ".", "..", "../stats.txt", "a/b.txt", "/etc/passwd", "..\\x", "C:\\x.txt", "C:x.txt"] {
+            assert!(!plain(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let (from, to) = (temp_dir("from"), temp_dir("to"));
+        std::fs::write(from.join("stats.txt"), "1\n2\n").unwrap();
+        std::fs::write(from.join("empty.txt"), "").unwrap();
+        std::fs::write(from.join("profile.txt"), "other").unwrap();
+        std::fs::write(from.join("old.zip"), "PK").unwrap();
+        let zip = from.join("backup.zip");
+        assert_eq!(save_from(&from, &zip).unwrap(), 2);
+        assert_eq!(restore_to(&to, &zip).unwrap(), 2);
+        assert_eq!(std::fs::read_to_string(to.join("stats.txt")).unwrap(), "1\n2\n");
+        assert_eq!(std::fs::read_to_string(to.join("empty.txt")).unwrap(), "");
+        assert!(!to.join("profile.txt").exists());
+        let _ = std::fs::remove_dir_all(from);
+        let _ = std::fs::remove_dir_all(to);
+    }
+
+    #[test]
+    fn restore_refuses_paths_out_of_the_folder() {
+        let dir = temp_dir("evil");
+        let data = dir.join("data");
+        for name in ["../escaped.txt", "/tmp/escaped.txt", "sub/escaped.txt"] {
+            let zip = dir.join("evil.zip");
+            let files = [("ok.txt".to_string(), b"ok".to_vec()), (name.to_string(), b"evil".to_vec())];
+            std::fs::write(&zip, zip::write(&files)).unwrap();
+            assert!(matches!(restore_to(&data, &zip), Err(Error::BadArchive)), "{name}");
+            assert!(!data.exists(), "{name}");
+        }
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
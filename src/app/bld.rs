@@ -0,0 +1,83 @@
+// src/app/bld.rs
+
+//! BLD memo drill: the corner memo for the cube as shown is worked out up
+//! front, the clock runs while the user memorizes, and stops when they type
+//! the memo back. Also persists the user's letter scheme.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cube::{Cube, FaceId};
+use crate::logic::memo::{corner_memo, LetterScheme, Memo};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+const HEADER: &str = "rubics letter scheme v1";
+
+#[derive(Debug, Clone)]
+pub struct MemoDrill {
+    /// The cube being memorized and the memo the user should arrive at.
+    pub cube: Cube,
+    pub memo: Memo,
+    started: Instant,
+}
+
+impl MemoDrill {
+    /// Start timing a memo of `cube`; `None` for an illegal cube.
+    pub fn start(cube: &Cube, scheme: &LetterScheme) -> Option<Self> {
+        corner_memo(cube, scheme).map(|memo| Self { cube: cube.clone(), memo, started: Instant::now() })
+    }
+
+    /// Stop the clock on `typed`: whether it was right, and the time taken.
+    pub fn finish(&self, typed: &str) -> (bool, Duration) {
+        (self.memo.matches(typed), self.started.elapsed())
+    }
+}
+
+// ----- letter scheme -----
+
+/// One line per face, its letters row-major, e.g. `U=DCAB`.
+pub fn encode_scheme(scheme: &LetterScheme) -> String {
+    let pairs: Vec<(&str, String)> = FaceId::ALL
+        .iter()
+        .map(|&f| (face_key(f), scheme.face(f).iter().flatten().collect()))
+        .collect();
+    write_kv(HEADER, &pairs)
+}
+
+/// Faces missing or malformed in `text` keep their Speffz letters.
+pub fn decode_scheme(text: &str) -> LetterScheme {
+    let kv = read_kv(text);
+    let mut scheme = LetterScheme::SPEFFZ;
+    for f in FaceId::ALL {
+        let Some(v) = kv.get(face_key(f)) else { continue };
+        let letters: Vec<char> = v.chars().collect();
+        if letters.len() == 4 && letters.iter().all(|c| c.is_alphanumeric()) {
+            for (i, &letter) in letters.iter().enumerate() {
+                scheme.set((f, i / 2, i % 2), letter);
+            }
+        }
+    }
+    scheme
+}
+
+fn face_key(f: FaceId) -> &'static str {
+    match f {
+        FaceId::U => "U", FaceId::D => "D", FaceId::F => "F",
+        FaceId::B => "B", FaceId::L => "L", FaceId::R => "R",
+    }
+}
+
+/// Location of the letter scheme file.
+pub fn scheme_path() -> PathBuf {
+    data_dir().join("letters.txt")
+}
+
+pub fn load_scheme() -> LetterScheme {
+    std::fs::read_to_string(scheme_path())
+        .map(|t| decode_scheme(&t))
+        .unwrap_or_default()
+}
+
+pub fn save_scheme(scheme: &LetterScheme) -> std::io::Result<()> {
+    save_file(&scheme_path(), encode_scheme(scheme))
+}
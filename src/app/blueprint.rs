@@ -0,0 +1,21 @@
+// src/app/blueprint.rs
+
+//! Blueprint export: the cube's top, front, and right views as an SVG
+//! diagram (see [`crate::render::blueprint`]), bookmarking the scene (see
+//! [`super::bookmark`]).
+
+use std::path::{Path, PathBuf};
+
+use crate::cube::Cube;
+use crate::render::blueprint::svg;
+use crate::storage::{data_dir, save_file};
+use crate::Error;
+
+/// Where the blueprint goes when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("blueprint.svg")
+}
+
+pub fn save(cube: &Cube, bookmark: &str, path: &Path) -> Result<(), Error> {
+    save_file(path, svg(cube, bookmark)).map_err(|e| Error::write(path, e))
+}
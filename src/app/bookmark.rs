@@ -0,0 +1,42 @@
+// src/app/bookmark.rs
+
+//! Scene bookmarks in exported images. A PNG screenshot carries the scene's
+//! share link (facelets, both camera angles, and the algorithm; see
+//! [`super::share`]) in a `tEXt` chunk, and an SVG blueprint carries it as
+//! its `<desc>`. Dropping either file back onto the window restores the
+//! scene it shows.
+
+use std::path::Path;
+
+use crate::render::markup::unescape;
+use crate::Error;
+use super::share::{self, SharedState};
+
+/// `tEXt` keyword of the bookmark in a PNG.
+pub const KEYWORD: &str = "icedcube";
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// The scene bookmarked in the image at `path`; `None` if it has none.
+pub fn read(path: &Path) -> Result<Option<SharedState>, Error> {
+    let bytes = std::fs::read(path).map_err(|e| Error::read(path, e))?;
+    let link = if bytes.starts_with(PNG_SIGNATURE) {
+        png_text(&bytes)
+    } else {
+        svg_desc(&String::from_utf8_lossy(&bytes))
+    };
+    Ok(link.and_then(|l| share::decode(&l)))
+}
+
+fn png_text(bytes: &[u8]) -> Option<String> {
+    // Text chunks ahead of the image data are read with the header.
+    let reader = png::Decoder::new(bytes).read_info().ok()?;
+    let chunks = &reader.info().uncompressed_latin1_text;
+    chunks.iter().find(|c| c.keyword == KEYWORD).map(|c| c.text.clone())
+}
+
+fn svg_desc(svg: &str) -> Option<String> {
+    let start = svg.find("<desc>")? + "<desc>".len();
+    let end = start + svg[start..].find("</desc>")?;
+    Some(unescape(&svg[start..end]))
+}
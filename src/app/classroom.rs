@@ -0,0 +1,73 @@
+// src/app/classroom.rs
+
+//! Classroom mode: the window shows only a scramble in huge type and the
+//! cube it scrambles in large views, for a teacher projecting scrambles to
+//! a room. Space or → brings the next scramble, a timer can move on by
+//! itself, and Esc leaves. The cube on the bench is left as it was.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Alg, Cube};
+use crate::logic::scramble::{seeded_alg, SCRAMBLE_LEN};
+use crate::t;
+
+/// How often the countdown is checked.
+pub const TICK: Duration = Duration::from_secs(1);
+
+/// Seconds before the next scramble comes by itself; 0 waits for a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Interval(pub u16);
+
+impl Interval {
+    pub const ALL: [Interval; 5] = [Interval(0), Interval(30), Interval(60), Interval(90), Interval(120)];
+}
+
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self.0 {
+            0 => t!("classroom-on-key"),
+            secs => t!("classroom-every", secs = secs),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Classroom {
+    pub scramble: Alg,
+    /// The scramble applied to a solved cube.
+    pub state: Cube,
+    /// Scrambles shown so far, this one included.
+    pub number: u32,
+    pub interval: Interval,
+    shown: Instant,
+}
+
+impl Classroom {
+    pub fn start(interval: Interval) -> Self {
+        let mut c = Self { scramble: Alg::default(), state: Cube::default(), number: 0, interval, shown: Instant::now() };
+        c.next();
+        c
+    }
+
+    /// Show a fresh scramble.
+    pub fn next(&mut self) {
+        self.scramble = seeded_alg(SCRAMBLE_LEN, rand::random());
+        self.state = Cube::default();
+        self.state.apply_alg(&self.scramble);
+        self.number += 1;
+        self.shown = Instant::now();
+    }
+
+    /// Seconds until the timer brings the next scramble; `None` without one.
+    pub fn remaining(&self) -> Option<u64> {
+        let every = Duration::from_secs(u64::from(self.interval.0));
+        (self.interval.0 > 0).then(|| every.saturating_sub(self.shown.elapsed()).as_secs_f32().ceil() as u64)
+    }
+
+    /// Move on if the timer has run out.
+    pub fn tick(&mut self) {
+        if self.remaining() == Some(0) {
+            self.next();
+        }
+    }
+}
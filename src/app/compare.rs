@@ -0,0 +1,57 @@
+// src/app/compare.rs
+
+//! State comparison: two saved slots (A, B) or a typed pattern against the
+//! current cube, and the last comparison's result.
+
+use crate::cube::{Alg, Cube};
+use crate::logic::alg::parse_alg;
+use crate::logic::diff::StateDiff;
+use crate::t;
+
+/// Which two states to compare, first → second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffPair {
+    #[default]
+    CurrentA,
+    CurrentB,
+    AB,
+    CurrentPattern,
+}
+
+impl DiffPair {
+    pub const ALL: [DiffPair; 4] = [DiffPair::CurrentA, DiffPair::CurrentB, DiffPair::AB, DiffPair::CurrentPattern];
+}
+
+impl std::fmt::Display for DiffPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            DiffPair::CurrentA => t!("diff-current-a"),
+            DiffPair::CurrentB => t!("diff-current-b"),
+            DiffPair::AB => t!("diff-a-b"),
+            DiffPair::CurrentPattern => t!("diff-current-pattern"),
+        })
+    }
+}
+
+/// A finished comparison.
+#[derive(Debug, Clone)]
+pub struct DiffReport {
+    pub from: Cube,
+    pub to: Cube,
+    pub diff: StateDiff,
+    /// Shortest algorithm from `from` to `to`; `None` when either is illegal.
+    pub alg: Option<Alg>,
+}
+
+/// A pattern typed as 24 facelet letters (`Cube::to_facelets` order,
+/// whitespace ignored) or as an algorithm applied to a solved cube.
+pub fn parse_pattern(s: &str) -> Result<Cube, String> {
+    let letters: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(cube) = Cube::from_facelets(&letters) {
+        return Ok(cube);
+    }
+    let alg = parse_alg(s).map_err(|e| t!("diff-bad-pattern", error = e))?;
+    let mut cube = Cube::default();
+    cube.apply_alg(&alg);
+    Ok(cube)
+}
@@ -0,0 +1,196 @@
+// src/app/daily.rs
+
+//! Daily challenge: one scramble a day, drawn from the date (UTC) so that
+//! everyone gets the same one without a server. The first solve of the day
+//! records its time; the days solved make a streak and fill a calendar of
+//! the last few weeks. Times are kept in a small file per user.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::cube::{Alg, Cube};
+use crate::logic::scramble::{seeded_alg, SCRAMBLE_LEN};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+const HEADER: &str = "rubics daily challenge v1";
+
+/// Mixed into the day number so the daily seeds don't match the small
+/// seeds people type into the seed field.
+const SEED_SALT: u64 = 0x6461_696c_7900_0000;
+
+/// Weeks shown in the calendar, the current one last.
+pub const CALENDAR_WEEKS: usize = 5;
+
+/// A calendar day, counted from 1970-01-01 (UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Day(pub i64);
+
+impl Day {
+    pub fn today() -> Day {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Day((secs / 86_400) as i64)
+    }
+
+    /// The day's scramble.
+    pub fn scramble(self) -> Alg {
+        seeded_alg(SCRAMBLE_LEN, SEED_SALT ^ self.0 as u64)
+    }
+
+    /// Weekday, Monday 0 to Sunday 6. 1970-01-01 was a Thursday.
+    pub fn weekday(self) -> usize {
+        (self.0 + 3).rem_euclid(7) as usize
+    }
+
+    /// Year, month, and day of the month, in the proleptic Gregorian
+    /// calendar (Howard Hinnant's `civil_from_days`).
+    pub fn date(self) -> (i64, u32, u32) {
+        let z = self.0 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        (yoe + era * 400 + i64::from(m <= 2), m, d)
+    }
+
+    /// The day written as `YYYY-MM-DD` (`days_from_civil`).
+    pub fn parse(s: &str) -> Option<Day> {
+        let mut parts = s.trim().splitn(3, '-');
+        let y: i64 = parts.next()?.parse().ok()?;
+        let m: i64 = parts.next()?.parse().ok()?;
+        let d: i64 = parts.next()?.parse().ok()?;
+        if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+            return None;
+        }
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        Some(Day(era * 146_097 + doe - 719_468))
+    }
+}
+
+impl std::fmt::Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (y, m, d) = self.date();
+        write!(f, "{y:04}-{m:02}-{d:02}")
+    }
+}
+
+/// The day's challenge while it is being solved.
+#[derive(Debug, Clone, Copy)]
+pub struct DailyRun {
+    pub day: Day,
+    /// Set on the first turn.
+    started: Option<Instant>,
+}
+
+impl DailyRun {
+    /// Today's challenge and its scramble.
+    pub fn start() -> (DailyRun, Alg) {
+        let day = Day::today();
+        (DailyRun { day, started: None }, day.scramble())
+    }
+
+    /// Update after a user turn on `cube`; the time in milliseconds once
+    /// it is solved.
+    pub fn after_move(&mut self, cube: &Cube) -> Option<u64> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        cube.is_solved().then(|| started.elapsed().as_millis() as u64)
+    }
+}
+
+/// Recorded time per day solved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DailyLog {
+    times: BTreeMap<Day, u64>,
+}
+
+impl DailyLog {
+    pub fn time(&self, day: Day) -> Option<u64> {
+        self.times.get(&day).copied()
+    }
+
+    /// Record `millis` for `day`, unless the day already has a time.
+    /// Returns whether it was recorded.
+    pub fn record(&mut self, day: Day, millis: u64) -> bool {
+        if self.times.contains_key(&day) {
+            return false;
+        }
+        self.times.insert(day, millis);
+        true
+    }
+
+    /// Days solved in a row up to `today`, or up to yesterday while today
+    /// is still open.
+    pub fn streak(&self, today: Day) -> u32 {
+        let mut day = if self.times.contains_key(&today) { today } else { Day(today.0 - 1) };
+        let mut n = 0;
+        while self.times.contains_key(&day) {
+            n += 1;
+            day = Day(day.0 - 1);
+        }
+        n
+    }
+
+    pub fn best(&self) -> Option<u64> {
+        self.times.values().copied().min()
+    }
+
+    /// The last [`CALENDAR_WEEKS`] weeks, Monday first, ending with the
+    /// week of `today`; days after today are `None`.
+    pub fn calendar(today: Day) -> Vec<[Option<Day>; 7]> {
+        let monday = today.0 - today.weekday() as i64 - 7 * (CALENDAR_WEEKS as i64 - 1);
+        (0..CALENDAR_WEEKS as i64)
+            .map(|w| std::array::from_fn(|d| Some(Day(monday + 7 * w + d as i64)).filter(|day| *day <= today)))
+            .collect()
+    }
+
+    pub fn encode(&self) -> String {
+        let days: Vec<String> = self.times.keys().map(Day::to_string).collect();
+        let pairs: Vec<(&str, String)> =
+            days.iter().zip(self.times.values()).map(|(d, ms)| (d.as_str(), ms.to_string())).collect();
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let times = read_kv(text)
+            .into_iter()
+            .filter_map(|(k, v)| Some((Day::parse(&k)?, v.parse().ok()?)))
+            .collect();
+        Self { times }
+    }
+}
+
+/// Location of the daily challenge file.
+pub fn log_path() -> PathBuf {
+    data_dir().join("daily.txt")
+}
+
+pub fn load() -> DailyLog {
+    std::fs::read_to_string(log_path())
+        .map(|t| DailyLog::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(log: &DailyLog) -> std::io::Result<()> {
+    save_file(&log_path(), log.encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Everyone's scramble for a day must never change, whatever the
+    /// dependency versions.
+    #[test]
+    fn scramble_is_pinned_for_a_known_day() {
+        let day = Day::parse("2026-01-01").unwrap();
+        assert_eq!(day, Day(20_454));
+        assert_eq!(day.scramble().to_string(), "L' U' R D' F' R2 U2 B' F2 F2 D F2 R B L2");
+    }
+}
@@ -0,0 +1,139 @@
+// src/app/debug.rs
+
+//! Help → Diagnostics: which build this is and how it is running, for bug
+//! reports, and a debug bundle of the same plus the profile's settings
+//! files and this session's trace, copied to paste into a report. The
+//! leaderboard settings stay out of the bundle; they hold the player's
+//! name and server.
+
+use crate::app::{motion, solver_cache, window_state, bld, App};
+use crate::app::solver_cache::TableOrigin;
+use crate::storage::{data_dir, read_only, write_kv};
+use crate::t;
+
+const HEADER: &str = "rubics debug bundle v1";
+
+/// Cargo features, and whether this build has each.
+const FEATURES: [(&str, bool); 6] = [
+    ("sound", cfg!(feature = "sound")),
+    ("controllers", cfg!(feature = "controllers")),
+    ("gamepad", cfg!(feature = "gamepad")),
+    ("gpu", cfg!(feature = "gpu")),
+    ("leaderboard", cfg!(feature = "leaderboard")),
+    ("hotkey", cfg!(feature = "hotkey")),
+];
+
+/// One line of the diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Version,
+    Features,
+    Platform,
+    Backend,
+    Tables,
+    Profile,
+    DataDir,
+    ReadOnly,
+    Language,
+}
+
+impl Field {
+    pub const ALL: [Field; 9] = [
+        Field::Version,
+        Field::Features,
+        Field::Platform,
+        Field::Backend,
+        Field::Tables,
+        Field::Profile,
+        Field::DataDir,
+        Field::ReadOnly,
+        Field::Language,
+    ];
+
+    /// Key in the debug bundle, the same in every language.
+    fn key(self) -> &'static str {
+        match self {
+            Field::Version => "version",
+            Field::Features => "features",
+            Field::Platform => "platform",
+            Field::Backend => "backend",
+            Field::Tables => "tables",
+            Field::Profile => "profile",
+            Field::DataDir => "data_dir",
+            Field::ReadOnly => "read_only",
+            Field::Language => "language",
+        }
+    }
+
+    pub fn value(self, app: &App) -> String {
+        match self {
+            Field::Version => env!("CARGO_PKG_VERSION").to_string(),
+            Field::Features => {
+                let on: Vec<&str> = FEATURES.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+                if on.is_empty() { t!("debug-none") } else { on.join(", ") }
+            }
+            Field::Platform => format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            Field::Backend => app.render.backend.to_string(),
+            Field::Tables => tables(app),
+            Field::Profile => app.profile.to_string(),
+            Field::DataDir => data_dir().display().to_string(),
+            Field::ReadOnly => read_only().to_string(),
+            Field::Language => app.lang.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Field::Version => t!("debug-version"),
+            Field::Features => t!("debug-features"),
+            Field::Platform => t!("debug-platform"),
+            Field::Backend => t!("debug-backend"),
+            Field::Tables => t!("debug-tables"),
+            Field::Profile => t!("debug-profile"),
+            Field::DataDir => t!("debug-data-dir"),
+            Field::ReadOnly => t!("debug-read-only"),
+            Field::Language => t!("debug-language"),
+        })
+    }
+}
+
+/// Where the solver tables came from, and the cache file's size.
+fn tables(app: &App) -> String {
+    let origin = match (app.solver.is_some(), app.solver_origin) {
+        (false, _) | (true, None) => t!("debug-tables-loading"),
+        (true, Some(TableOrigin::Cache)) => t!("solver-loaded"),
+        (true, Some(TableOrigin::Built { millis, saved: true })) => t!("solver-built", ms = millis),
+        (true, Some(TableOrigin::Built { millis, saved: false })) => t!("solver-built-unsaved", ms = millis),
+    };
+    let path = solver_cache::table_path();
+    match std::fs::metadata(&path) {
+        Ok(meta) => format!("{origin} {} ({} KiB)", path.display(), meta.len() / 1024),
+        Err(_) => format!("{origin} {}", t!("debug-no-cache", path = path.display().to_string())),
+    }
+}
+
+/// The diagnostics, the profile's settings files, and the session trace,
+/// as one text to paste into a bug report.
+pub fn bundle(app: &App) -> String {
+    let values: Vec<String> = Field::ALL.iter().map(|f| f.value(app)).collect();
+    let pairs: Vec<(&str, String)> = Field::ALL.iter().map(|f| f.key()).zip(values).collect();
+    let mut out = write_kv(HEADER, &pairs);
+    let settings = [
+        motion::settings_path(),
+        window_state::state_path(),
+        bld::scheme_path(),
+        crate::audio::settings_path(),
+        crate::gamepad::settings_path(),
+        crate::controllers::bindings_path(),
+        crate::hotkey::settings_path(),
+    ];
+    for path in settings {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        let body = std::fs::read_to_string(&path).unwrap_or_else(|_| "(none)\n".to_string());
+        out.push_str(&format!("\n## {name}\n{body}"));
+    }
+    out.push_str(&format!("\n## session trace\n{}", app.trace.encode()));
+    out
+}
@@ -0,0 +1,64 @@
+// src/app/demo.rs
+
+//! Demo mode, for kiosks and exhibition stands. `rubics --demo` opens a
+//! profile read-only: its settings and logs load as usual, but nothing the
+//! app keeps is written, so visitors can't change what the next one sees.
+//! After a spell with no one at the controls the cube goes back to solved
+//! and the profile's settings are loaded again.
+//!
+//! `--demo=<seconds>` sets the idle timeout. The profile is the one named
+//! with `--profile=<name>`, else one called "demo" if there is one, else
+//! the default profile. `--attract[=<minutes>]` as well has the cube play
+//! the attract loop (see [`super::attract`]) once left alone that long.
+
+use std::time::{Duration, Instant};
+
+/// Idle timeout when `--demo` gives none.
+pub const DEFAULT_IDLE: Duration = Duration::from_secs(120);
+/// How often the idle time is checked.
+pub const TICK: Duration = Duration::from_secs(1);
+/// Idle time before the attract loop when `--attract` gives none.
+pub const DEFAULT_ATTRACT: Duration = Duration::from_secs(180);
+/// The profile a demo opens unless one is named.
+pub const PROFILE: &str = "demo";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Demo {
+    pub idle: Duration,
+    /// Idle time before the attract loop starts, if it plays at all.
+    pub attract: Option<Duration>,
+    last_input: Instant,
+    /// Whether the reset for the last input has been made.
+    reset: bool,
+}
+
+impl Demo {
+    /// The demo asked for on the command line, if any.
+    pub fn from_args() -> Option<Demo> {
+        // `--flag` alone, or `--flag=<n>` with `n` a positive count.
+        let flag = |name: &str| {
+            let arg = std::env::args().skip(1).find(|a| a == name || a.starts_with(&format!("{name}=")))?;
+            Some(arg.split_once('=').and_then(|(_, n)| n.parse::<u64>().ok()).filter(|&n| n > 0))
+        };
+        let idle = flag("--demo")?.map_or(DEFAULT_IDLE, Duration::from_secs);
+        let attract = flag("--attract").map(|m| m.map_or(DEFAULT_ATTRACT, |m| Duration::from_secs(m.saturating_mul(60))));
+        Some(Demo { idle, attract, last_input: Instant::now(), reset: false })
+    }
+
+    pub fn touched(&mut self, now: Instant) {
+        self.last_input = now;
+        self.reset = false;
+    }
+
+    /// Whether the idle timeout ran out by `now`; once per idle spell.
+    pub fn timed_out(&mut self, now: Instant) -> bool {
+        let out = !self.reset && now.duration_since(self.last_input) >= self.idle;
+        self.reset |= out;
+        out
+    }
+
+    /// Whether the attract loop is due by `now`.
+    pub fn attract_due(&self, now: Instant) -> bool {
+        self.attract.is_some_and(|a| now.duration_since(self.last_input) >= a)
+    }
+}
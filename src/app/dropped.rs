@@ -0,0 +1,36 @@
+// src/app/dropped.rs
+
+//! Files dropped onto the main window, or opened with the app from the
+//! command line or the desktop, sorted by extension for the importer that
+//! reads them. While a file is held over the window, the
+//! cube canvas is highlighted as the place to drop it.
+
+use std::path::Path;
+
+/// What a dropped file is opened as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropKind {
+    /// An exported PNG or SVG carrying a scene bookmark (see
+    /// [`super::bookmark`]).
+    Bookmark,
+    /// cubing.js KPuzzle JSON: a state and an algorithm.
+    KPuzzle,
+    /// Algorithm text, loaded into the algorithm box.
+    Alg,
+    /// Text: a saved session trace to replay, or else sticker letters.
+    Text,
+}
+
+impl DropKind {
+    /// The kind of `path`; `None` for files nothing opens.
+    pub fn of(path: &Path) -> Option<DropKind> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "png" | "svg" => Some(DropKind::Bookmark),
+            "json" => Some(DropKind::KPuzzle),
+            "alg" => Some(DropKind::Alg),
+            "txt" => Some(DropKind::Text),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// src/app/forum.rs
+
+//! "Copy as forum post": the scramble, the moves made since as the
+//! solution, their count, the last timer time, and an alg.cubing.net link
+//! replaying it all, laid out the way solves are posted on
+//! speedsolving.com, as BBCode for the forum itself or as Markdown for
+//! Reddit and chat.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use crate::cube::Alg;
+use crate::logic::script::fmt_time_short;
+use crate::t;
+use super::share::alg_cubing_link;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PostFormat {
+    #[default]
+    BbCode,
+    Markdown,
+}
+
+impl PostFormat {
+    pub const ALL: [PostFormat; 2] = [PostFormat::BbCode, PostFormat::Markdown];
+}
+
+impl std::fmt::Display for PostFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            PostFormat::BbCode => t!("post-bbcode"),
+            PostFormat::Markdown => t!("post-markdown"),
+        })
+    }
+}
+
+/// What goes into a post.
+#[derive(Debug, Clone)]
+pub struct Solve {
+    pub scramble: Alg,
+    pub solution: Alg,
+    pub time: Option<Duration>,
+}
+
+/// The post's text, with the labels in the interface language.
+pub fn post(solve: &Solve, format: PostFormat) -> String {
+    let moves = t!("post-moves-value", n = solve.solution.len());
+    let mut fields = vec![
+        (t!("post-scramble"), solve.scramble.to_string()),
+        (t!("post-solution"), solve.solution.to_string()),
+        (t!("post-moves"), moves),
+    ];
+    if let Some(time) = solve.time {
+        fields.push((t!("post-time"), fmt_time_short(time)));
+    }
+    let link = alg_cubing_link(&solve.scramble, &solve.solution);
+    let mut out = String::new();
+    match format {
+        PostFormat::BbCode => {
+            for (label, value) in &fields {
+                let _ = writeln!(out, "[B]{label}:[/B] {value}");
+            }
+            let _ = write!(out, "[URL={link}]{}[/URL]", t!("post-link"));
+        }
+        PostFormat::Markdown => {
+            for (label, value) in &fields {
+                // Two trailing spaces: a line break rather than a new paragraph.
+                let _ = writeln!(out, "**{label}:** {value}  ");
+            }
+            let _ = write!(out, "[{}]({link})", t!("post-link"));
+        }
+    }
+    out
+}
@@ -0,0 +1,57 @@
+// src/app/glide.rs
+
+//! Camera moves shown in motion. A preset or a camera reset glides the view
+//! to its new pose over a fraction of a second instead of jumping. The
+//! glide runs between the two orientations as quaternions, turning about a
+//! single axis, so poses near Ry = ±90° don't send the slider angles (and
+//! the cube) spinning the long way round. Reduced motion jumps.
+
+use std::time::{Duration, Instant};
+
+use crate::render::quat::Quat;
+use super::{App, ViewSide, ViewUI};
+
+/// Redraw interval while a camera glides.
+pub const FRAME: Duration = Duration::from_millis(16);
+
+/// How long a glide takes.
+const SPAN: Duration = Duration::from_millis(350);
+
+#[derive(Debug, Clone, Copy)]
+pub struct CameraGlide {
+    from: Quat,
+    /// The pose glided to; once the view's angles change otherwise, the
+    /// glide is over.
+    to: ViewUI,
+    started: Instant,
+}
+
+impl CameraGlide {
+    pub fn new(from: ViewUI, to: ViewUI) -> Self {
+        Self { from: from.orientation(), to, started: Instant::now() }
+    }
+
+    /// The pose to draw for a view now at `target`; `None` once the glide
+    /// is done, or if `target` isn't where it was going.
+    pub fn pose(&self, target: ViewUI) -> Option<ViewUI> {
+        if target != self.to {
+            return None;
+        }
+        let progress = self.started.elapsed().as_secs_f32() / SPAN.as_secs_f32();
+        if progress >= 1.0 {
+            return None;
+        }
+        // Ease in and out.
+        let t = progress * progress * (3.0 - 2.0 * progress);
+        Some(ViewUI::from_orientation(self.from.slerp(self.to.orientation(), t)))
+    }
+}
+
+/// The pose `side` is drawn at now, partway along its glide if it has one.
+pub fn drawn(app: &App, side: ViewSide) -> ViewUI {
+    let (target, glide) = match side {
+        ViewSide::Left => (app.params.left, &app.glides[0]),
+        ViewSide::Right => (app.params.right, &app.glides[1]),
+    };
+    glide.and_then(|g| g.pose(target)).unwrap_or(target)
+}
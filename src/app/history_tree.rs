@@ -0,0 +1,211 @@
+// src/app/history_tree.rs
+
+//! Branching move history. Undo steps back to the parent state; a different
+//! move from there starts a new branch instead of discarding the old line,
+//! so alternative continuations of a solve can be compared.
+//!
+//! `App::history` stays the path from the root to the current node. Code
+//! that replaces the history wholesale (a scramble, an import, a reset)
+//! doesn't touch the tree; [`HistoryTree::sync`] notices after each update
+//! and starts a new tree from that history.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Cube, Move};
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub parent: Option<usize>,
+    /// Move token that led here (empty at the root).
+    pub token: String,
+    pub cube: Cube,
+    pub depth: usize,
+    pub children: Vec<usize>,
+    /// When the move was first made, after the root; `None` for moves only
+    /// known from a replaced history.
+    pub at: Option<Duration>,
+    /// The user's note on this move (on the scramble, at the root).
+    pub comment: String,
+    /// Child last visited, followed by redo.
+    last_child: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryTree {
+    nodes: Vec<Node>,
+    current: usize,
+    started: Instant,
+}
+
+impl Default for HistoryTree {
+    fn default() -> Self {
+        Self::new(Cube::default())
+    }
+}
+
+impl HistoryTree {
+    pub fn new(root: Cube) -> Self {
+        let root = Node {
+            parent: None,
+            token: String::new(),
+            cube: root,
+            depth: 0,
+            children: Vec::new(),
+            at: None,
+            comment: String::new(),
+            last_child: None,
+        };
+        Self { nodes: vec![root], current: 0, started: Instant::now() }
+    }
+
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Record move `token` reaching `cube`; a move already tried from here
+    /// follows the existing branch.
+    pub fn push(&mut self, token: &str, cube: &Cube) {
+        let here = self.current;
+        let existing = self.nodes[here].children.iter().copied().find(|&c| self.nodes[c].token == token);
+        let child = existing.unwrap_or_else(|| {
+            let id = self.nodes.len();
+            self.nodes.push(Node {
+                parent: Some(here),
+                token: token.to_string(),
+                cube: cube.clone(),
+                depth: self.nodes[here].depth + 1,
+                children: Vec::new(),
+                at: Some(self.started.elapsed()),
+                comment: String::new(),
+                last_child: None,
+            });
+            self.nodes[here].children.push(id);
+            id
+        });
+        self.nodes[here].last_child = Some(child);
+        self.current = child;
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.nodes[self.current].parent.is_some()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.nodes[self.current].last_child.is_some()
+    }
+
+    /// Set the note on the current move.
+    pub fn set_comment(&mut self, comment: String) {
+        self.nodes[self.current].comment = comment;
+    }
+
+    /// Nodes from the root to node `i`, root first.
+    pub fn line(&self, i: usize) -> Vec<&Node> {
+        let mut out = vec![&self.nodes[i]];
+        while let Some(p) = out[out.len() - 1].parent {
+            out.push(&self.nodes[p]);
+        }
+        out.reverse();
+        out
+    }
+
+    /// Step back to the parent; the node now current.
+    pub fn undo(&mut self) -> Option<&Node> {
+        self.current = self.nodes[self.current].parent?;
+        Some(&self.nodes[self.current])
+    }
+
+    /// Step forward along the branch last visited; the node now current.
+    pub fn redo(&mut self) -> Option<&Node> {
+        self.current = self.nodes[self.current].last_child?;
+        Some(&self.nodes[self.current])
+    }
+
+    /// Make node `i` current, remembering the way there for redo.
+    pub fn jump(&mut self, i: usize) -> Option<&Node> {
+        self.nodes.get(i)?;
+        let mut n = i;
+        while let Some(p) = self.nodes[n].parent {
+            self.nodes[p].last_child = Some(n);
+            n = p;
+        }
+        self.current = i;
+        Some(&self.nodes[i])
+    }
+
+    /// Move tokens from the root to node `i`.
+    pub fn path(&self, i: usize) -> Vec<String> {
+        self.line(i).iter().skip(1).map(|n| n.token.clone()).collect()
+    }
+
+    /// Nodes with no children: the end of each line explored.
+    pub fn tips(&self) -> Vec<usize> {
+        (0..self.nodes.len()).filter(|&i| self.nodes[i].children.is_empty()).collect()
+    }
+
+    /// Follow a history replaced outside the tree: jump to it if it is a
+    /// line already in the tree (e.g. a restored checkpoint), otherwise
+    /// start over with the root being `cube` with the history undone.
+    pub fn sync(&mut self, cube: &Cube, history: &[String]) {
+        let node = &self.nodes[self.current];
+        let in_sync = node.cube == *cube
+            && node.depth == history.len()
+            && history.last().is_none_or(|t| *t == node.token);
+        if in_sync {
+            return;
+        }
+        if let Some(i) = self.find(history).filter(|&i| self.nodes[i].cube == *cube) {
+            self.jump(i);
+            return;
+        }
+        let moves: Vec<Option<Move>> = history.iter().map(|t| Move::parse(t)).collect();
+        let mut root = cube.clone();
+        for m in moves.iter().rev().flatten() {
+            root.apply(m.inverse());
+        }
+        *self = Self::new(root.clone());
+        for (tok, m) in history.iter().zip(&moves) {
+            if let Some(m) = m {
+                root.apply(*m);
+            }
+            self.push(tok, &root);
+        }
+        // Whatever was replayed, the tip is the cube shown.
+        self.nodes[self.current].cube = cube.clone();
+        for node in &mut self.nodes {
+            node.at = None;
+        }
+    }
+
+    /// The node reached from the root by `history`, if it was explored.
+    fn find(&self, history: &[String]) -> Option<usize> {
+        history.iter().try_fold(0, |n, tok| {
+            self.nodes[n].children.iter().copied().find(|&c| self.nodes[c].token == *tok)
+        })
+    }
+
+    /// Screen layout: each node's depth and lane. A node's first child
+    /// continues its lane; every other child opens a new one.
+    pub fn layout(&self) -> Vec<(usize, usize)> {
+        let mut out = vec![(0, 0); self.nodes.len()];
+        let mut next_lane = 1;
+        let mut stack = vec![(0, 0)];
+        while let Some((n, lane)) = stack.pop() {
+            out[n] = (self.nodes[n].depth, lane);
+            // Push in reverse so the first child's subtree is laid out first.
+            let children = &self.nodes[n].children;
+            let lanes: Vec<usize> = (0..children.len())
+                .map(|k| if k == 0 { lane } else { next_lane + k - 1 })
+                .collect();
+            next_lane += children.len().saturating_sub(1);
+            for (&c, &l) in children.iter().zip(&lanes).rev() {
+                stack.push((c, l));
+            }
+        }
+        out
+    }
+}
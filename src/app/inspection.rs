@@ -0,0 +1,191 @@
+// src/app/inspection.rs
+
+//! Inspection before a timed solve, under rules chosen for the session.
+//! WCA rules give 15 seconds with a judge's calls at 8 and 12, cost +2 for
+//! starting after 15 and make the attempt a DNF after 17. Relaxed rules
+//! keep the countdown and the calls without penalties, and with inspection
+//! off the clock just waits for the start. The length can be changed; the
+//! calls and the penalty thresholds move with it. The solve timer and the
+//! color drill both inspect this way.
+
+use std::time::{Duration, Instant};
+
+use crate::t;
+use super::support::fmt_secs;
+
+/// How often to check for a due call or an overrun.
+pub const TICK: Duration = Duration::from_millis(250);
+
+/// Seconds left at each of the judge's calls.
+const CALLS: [u32; 2] = [7, 3];
+/// Seconds past the inspection length before a late start is a DNF
+/// rather than +2.
+const DNF_GRACE: u32 = 2;
+/// What a +2 adds.
+const PLUS_TWO: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InspectionMode {
+    /// Timed, with calls and penalties.
+    #[default]
+    Wca,
+    /// Timed, with calls but no penalties.
+    Relaxed,
+    /// No inspection: the clock waits for the start however long it takes.
+    Off,
+}
+
+impl InspectionMode {
+    pub const ALL: [InspectionMode; 3] = [InspectionMode::Wca, InspectionMode::Relaxed, InspectionMode::Off];
+}
+
+impl std::fmt::Display for InspectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            InspectionMode::Wca => t!("inspection-wca"),
+            InspectionMode::Relaxed => t!("inspection-relaxed"),
+            InspectionMode::Off => t!("inspection-off"),
+        })
+    }
+}
+
+/// Inspection length, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InspectionSecs(pub u32);
+
+impl InspectionSecs {
+    pub const ALL: [InspectionSecs; 4] = [InspectionSecs(8), InspectionSecs(15), InspectionSecs(20), InspectionSecs(30)];
+}
+
+impl Default for InspectionSecs {
+    fn default() -> Self {
+        InspectionSecs(15)
+    }
+}
+
+impl std::fmt::Display for InspectionSecs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&t!("inspection-secs", secs = self.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InspectionRules {
+    pub mode: InspectionMode,
+    pub secs: InspectionSecs,
+}
+
+impl InspectionRules {
+    /// Whether attempts are inspected at all.
+    pub fn enforced(&self) -> bool {
+        self.mode != InspectionMode::Off
+    }
+
+    /// Seconds of inspection past which starting costs +2, and past which
+    /// the attempt is a DNF; `None` when nothing is penalized.
+    pub fn thresholds(&self) -> Option<(u32, u32)> {
+        (self.mode == InspectionMode::Wca).then_some((self.secs.0, self.secs.0 + DNF_GRACE))
+    }
+
+    /// The penalty for starting after `inspected`.
+    pub fn penalty(&self, inspected: Duration) -> Penalty {
+        match self.thresholds() {
+            Some((_, dnf)) if inspected.as_secs() >= u64::from(dnf) => Penalty::Dnf,
+            Some((plus_two, _)) if inspected.as_secs() >= u64::from(plus_two) => Penalty::PlusTwo,
+            _ => Penalty::None,
+        }
+    }
+}
+
+/// What inspection cost an attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Penalty {
+    #[default]
+    None,
+    PlusTwo,
+    Dnf,
+}
+
+impl Penalty {
+    /// The time that counts for a solve of `time`; `None` for a DNF.
+    pub fn apply(self, time: Duration) -> Option<Duration> {
+        match self {
+            Penalty::None => Some(time),
+            Penalty::PlusTwo => Some(time + PLUS_TWO),
+            Penalty::Dnf => None,
+        }
+    }
+
+    /// A result as shown: the time that counts, marked with any penalty.
+    pub fn describe(self, time: Duration) -> String {
+        match self.apply(time) {
+            None => t!("result-dnf"),
+            Some(counted) if self == Penalty::PlusTwo => t!("result-plus-two", time = fmt_secs(counted.as_millis() as u64)),
+            Some(counted) => fmt_secs(counted.as_millis() as u64),
+        }
+    }
+}
+
+/// Something due during inspection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectionTick {
+    /// A judge's call, as seconds left.
+    Call(u32),
+    /// Past the DNF threshold without starting.
+    Expired,
+}
+
+/// An inspection under way.
+#[derive(Debug, Clone, Copy)]
+pub struct Inspection {
+    rules: InspectionRules,
+    began: Instant,
+    /// Calls made so far.
+    calls: usize,
+}
+
+impl Inspection {
+    pub fn new(rules: InspectionRules) -> Self {
+        Self { rules, began: Instant::now(), calls: 0 }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.began.elapsed()
+    }
+
+    /// Whole seconds of inspection left, counting down to 0.
+    pub fn left(&self) -> u64 {
+        u64::from(self.rules.secs.0).saturating_sub(self.elapsed().as_secs())
+    }
+
+    /// Whether a call or an overrun is still to come, so it needs ticks.
+    pub fn pending(&self) -> bool {
+        self.rules.enforced() && (self.calls < self.call_count() || self.rules.thresholds().is_some())
+    }
+
+    /// Calls this inspection gets: those that fit in its length.
+    fn call_count(&self) -> usize {
+        CALLS.iter().filter(|&&left| left < self.rules.secs.0).count()
+    }
+
+    /// The next thing due, once it is.
+    pub fn tick(&mut self) -> Option<InspectionTick> {
+        if !self.rules.enforced() {
+            return None;
+        }
+        if self.rules.penalty(self.elapsed()) == Penalty::Dnf {
+            return Some(InspectionTick::Expired);
+        }
+        let secs = self.rules.secs.0;
+        let left = *CALLS.iter().filter(|&&left| left < secs).nth(self.calls)?;
+        (self.elapsed().as_secs() >= u64::from(secs - left)).then(|| {
+            self.calls += 1;
+            InspectionTick::Call(left)
+        })
+    }
+
+    /// The penalty for starting now.
+    pub fn penalty(&self) -> Penalty {
+        self.rules.penalty(self.elapsed())
+    }
+}
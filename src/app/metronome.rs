@@ -0,0 +1,102 @@
+// src/app/metronome.rs
+
+//! Metronome for turns-per-second practice: each beat flashes the canvas
+//! border (and clicks, with sound on). A beat drill scores how close each
+//! turn lands to the nearest beat, so the user can pace finger tricks.
+
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+/// Selectable tempo range, in beats per minute.
+pub const BPM_RANGE: RangeInclusive<u16> = 40..=240;
+
+/// How long the border stays lit after a beat.
+pub const FLASH: Duration = Duration::from_millis(90);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Metronome {
+    pub bpm: u16,
+    pub running: bool,
+    /// Also click on each beat (needs sound).
+    pub click: bool,
+    /// Set while a beat drill is scoring turns.
+    pub drill: Option<BeatScore>,
+    last_beat: Option<Instant>,
+}
+
+impl Default for Metronome {
+    fn default() -> Self {
+        Self { bpm: 90, running: false, click: false, drill: None, last_beat: None }
+    }
+}
+
+impl Metronome {
+    pub fn period(&self) -> Duration {
+        Duration::from_secs_f64(60.0 / f64::from(self.bpm))
+    }
+
+    pub fn start(&mut self) {
+        self.running = true;
+        self.last_beat = Some(Instant::now());
+    }
+
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.last_beat = None;
+    }
+
+    /// Mark a beat as shown now.
+    pub fn beat(&mut self) {
+        self.last_beat = Some(Instant::now());
+    }
+
+    pub fn flashing(&self) -> bool {
+        self.running && self.last_beat.is_some_and(|t| t.elapsed() < FLASH)
+    }
+
+    /// Score a turn made now against the nearest beat (running drill only).
+    pub fn record_turn(&mut self) {
+        let period = self.period().as_secs_f64() * 1000.0;
+        let (Some(beat), Some(score)) = (self.last_beat, self.drill.as_mut()) else { return };
+        let since = beat.elapsed().as_secs_f64() * 1000.0;
+        // Past the midpoint, the coming beat is nearer: the turn is early.
+        let offset = if since > period / 2.0 { since - period } else { since };
+        score.record(offset);
+    }
+}
+
+/// Running beat-drill tally. Offsets are in milliseconds, negative = early.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BeatScore {
+    turns: u32,
+    total_abs_ms: f64,
+    total_ms: f64,
+}
+
+impl BeatScore {
+    pub fn record(&mut self, offset_ms: f64) {
+        self.turns += 1;
+        self.total_abs_ms += offset_ms.abs();
+        self.total_ms += offset_ms;
+    }
+
+    pub fn turns(&self) -> u32 {
+        self.turns
+    }
+
+    /// Mean distance from the beat.
+    pub fn mean_abs_ms(&self) -> f64 {
+        if self.turns == 0 { 0.0 } else { self.total_abs_ms / f64::from(self.turns) }
+    }
+
+    /// Mean signed offset: below zero rushes the beat, above drags it.
+    pub fn bias_ms(&self) -> f64 {
+        if self.turns == 0 { 0.0 } else { self.total_ms / f64::from(self.turns) }
+    }
+
+    /// 100 % on the beat, 0 % exactly between beats (the worst possible).
+    pub fn accuracy(&self, period: Duration) -> f64 {
+        let half = period.as_secs_f64() * 500.0;
+        (100.0 * (1.0 - self.mean_abs_ms() / half)).clamp(0.0, 100.0)
+    }
+}
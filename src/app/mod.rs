@@ -1,17 +1,98 @@
 // src/app/mod.rs
 
-//! Application root: message enum, app state, and `iced::Application` impl.
+//! Application root: message enum, app state, and the multi-window
+//! `iced::multi_window::Application` impl.
 
 pub mod view;
 pub mod update;
 pub mod support;
+pub mod recovery;
+pub mod solver_cache;
+pub mod trainer;
+pub mod inspection;
+pub mod share;
+pub mod plugins;
+pub mod metronome;
+pub mod random_walk;
+pub mod notation;
+pub mod bld;
+pub mod compare;
+pub mod history_tree;
+pub mod script;
+pub mod trace;
+pub mod palette;
+pub mod screenshot;
+pub mod bookmark;
+pub mod dropped;
+pub mod alg_watch;
+pub mod report;
+pub mod annotate;
+pub mod window_state;
+pub mod motion;
+pub mod playback;
+pub mod glide;
+pub mod blueprint;
+pub mod solve_sheet;
+pub mod forum;
+pub mod classroom;
+pub mod anki;
+pub mod daily;
+pub mod profiles;
+pub mod backup;
+pub mod demo;
+pub mod attract;
+pub mod watch;
+pub mod usage;
+pub mod ab_test;
+pub mod solve_timer;
+pub mod debug;
 
-use iced::{Application, Command, Element, Theme, Settings};
-use crate::cube::Cube;
+use iced::{Command, Element, Subscription, Theme, Settings, window};
+use iced::widget::text_editor;
+use iced::multi_window::Application;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-/// Run the interactive Iced application with default settings.
+use crate::cube::{Alg, Cube, FaceId};
+use crate::events::Hooks;
+use crate::audio::{ClickSound, Cue, Player, SoundSettings};
+use crate::controllers;
+use crate::gamepad;
+use crate::leaderboard;
+use crate::obs;
+use crate::logic::first_face::FaceReport;
+use crate::logic::subgroup::SubgroupReport;
+use crate::logic::tracking::{Piece, Sticker};
+use crate::logic::console;
+use crate::logic::phases::Breakdown;
+use crate::logic::pauses::{Pause, Threshold};
+use crate::logic::library::{AlgSet, NamedAlg};
+use crate::logic::memo::{LetterScheme, Memo};
+use crate::logic::regrip::MoveCosts;
+use crate::logic::verify::Mismatch;
+use crate::logic::solver::DistanceTable;
+use crate::t;
+use crate::Error;
+use crate::i18n::Lang;
+use crate::render::{CameraModel, CameraPreset, Radians, RenderMode, RenderSettings, RotX, RotY, RotZ};
+use crate::render::quat::Quat;
+use window_state::WindowState;
+
+/// Run the interactive Iced application with [`settings`].
 pub fn run() -> iced::Result {
-    App::run(Settings::default())
+    App::run(settings())
+}
+
+/// App settings: the main window as it was last left. Close requests are
+/// handled by the app so it can drop the crash-recovery checkpoint and save
+/// the window state before exiting.
+pub fn settings() -> Settings<WindowState> {
+    let state = window_state::load();
+    Settings {
+        window: state.settings(),
+        flags: state,
+        ..Default::default()
+    }
 }
 
 /* ---------------- Messages ----------------
@@ -25,30 +106,330 @@ pub enum Msg {
     RightRzChanged(f32), RightRxChanged(f32), RightRyChanged(f32),
     SizeChanged(f32),
 
+    // typed angle entry (text box per slider) and ±nudges
+    AngleTextChanged(ViewSide, Axis, String),
+    AngleTextSubmit,
+    AngleNudge(ViewSide, Axis, f32),
+
+    // render settings
+    LightAzimuthChanged(f32),
+    LightElevationChanged(f32),
+    LightIntensityChanged(f32),
+    LeftModeChanged(RenderMode),
+    RightModeChanged(RenderMode),
+    CameraModelChanged(CameraModel),
+    /// A trackball drag step turning a view, in world space.
+    Roll(ViewSide, Quat),
+    ToggleGrid(bool),
+    ToggleAxes(bool),
+    ToggleHeat(bool),
+    ToggleLetters(bool),
+
+    // piece tracking
+    TrackPiece(Piece),
+    StopTracking,
+    ToggleTrail(bool),
+    ScaleFactorChanged(ScaleChoice),
+    ToggleProfiler,
+
     // camera helpers
     PresetLeft,
     PresetRight,
+    NamedPreset(ViewSide, CameraPreset),
+    LookAtFace(ViewSide, FaceId),
     ResetCameras,
     ToggleSnap90(bool),
+    ToggleMirrored(bool),
+    ToggleReducedMotion(bool),
+    FpsCapChanged(motion::FpsCap),
+    BackendChanged(crate::render::Backend),
 
     // algorithm panel
-    AlgChanged(String),
+    AlgEdited(text_editor::Action),
+    CompleteAlg(&'static NamedAlg),
     ApplyAlg,
+    ClearAlg,
+    ShowAlgOrder,
+    AlgWatchPathChanged(String),
+    StartAlgWatch,
+    StopAlgWatch,
+    AlgWatchTick,
     ResetCube,
+    ConfirmReset,
+    CancelReset,
+    RestoreTrash,
+
+    // named checkpoints
+    CheckpointNameChanged(String),
+    AddCheckpoint,
+    JumpToCheckpoint(usize),
+    RemoveCheckpoint(usize),
+
+    // branching history
+    Undo,
+    Redo,
+    JumpToHistory(usize),
+
+    // annotated solve scripts
+    MoveCommentChanged(String),
+    ScriptPathChanged(String),
+    ExportScript,
+    ImportScript,
+    StopScriptReplay,
+    ScriptReplayTick,
+    BreakDownSolve,
+    CloseBreakdown,
+    SyncScriptToVideo,
+    VideoTimeChanged(String),
+    ApplyVideoTime,
+    VideoScrubbed(f32),
+    VideoOffsetChanged(String),
+    CloseVideoSync,
+
+    // session traces
+    TracePathChanged(String),
+    SaveTrace,
+    ReplayTrace,
+    StopTraceReplay,
+    TraceReplayTick,
+
+    // scripting console
+    ConsoleEdited(text_editor::Action),
+    RunConsole,
+    ConsoleFinished(console::Run),
+    ClearConsole,
+
+    // command palette (Ctrl+P) and PNG export
+    TogglePalette,
+    ClosePalette,
+    PaletteInputChanged(String),
+    RunPaletteCommand,
+    ExportPng(std::path::PathBuf),
+    ScreenshotTaken(std::path::PathBuf, window::Screenshot),
+    /// A file held over the main window, then its leaving or drop.
+    FileHovered(std::path::PathBuf),
+    FilesHoveredLeft,
+    FileDropped(std::path::PathBuf),
+
+    // menu bar
+    TogglePanels,
+    ToggleAbout,
+    ToggleDebugInfo,
+    CopyDebugBundle,
+
+    AnnounceState,
+    AnalyzeFirstFaces,
+    ExploreSubgroups,
+    SolveCube,
+    CostModelChanged(String),
+    ToggleWeightedSolve(bool),
+    GeneratorsChanged(String),
+    SearchSolved((Cube, Result<Alg, Error>)),
+    WatchSolve,
+    StopWatching,
+    WatchPaceChanged(watch::Pace),
+    WatchTick,
+    CopyShareLink,
+    OpenAlgCubing,
+    PostFormatChanged(forum::PostFormat),
+    CopyForumPost,
+    ToggleQr,
+
+    // color-neutrality trainer
+    StartDrill,
+    StopDrill,
+    InspectionTick,
+    PauseThresholdChanged(Threshold),
+    FindPauses,
+    ClosePauses,
+    ToggleMoveUsage,
+    ResetMoveUsage,
+    ExportReport(std::path::PathBuf),
+    ExportBlueprint(std::path::PathBuf),
+    AnkiSetChanged(AlgSet),
+    ExportAnki(std::path::PathBuf),
+    ExportSolveSheet(std::path::PathBuf),
+    ToggleSheetSolutions(bool),
+    NewRound,
+    CloseRound,
+
+    // A/B algorithm trainer
+    AbCaseChanged(&'static NamedAlg),
+    AbAlgChanged(ab_test::Side, String),
+    AbTrialsChanged(ab_test::Trials),
+    StartAbTest,
+    RedoAbTrial,
+    StopAbTest,
+
+    // solve timer and its global hotkey
+    ToggleTimer,
+    InspectionModeChanged(inspection::InspectionMode),
+    InspectionSecsChanged(inspection::InspectionSecs),
+    Hotkey(crate::hotkey::Key),
+    ToggleHotkey(bool),
+    HotkeyChanged(crate::hotkey::Key),
+
+    // profiles
+    ProfileSelected(profiles::Profile),
+    ProfileInputChanged(String),
+    CreateProfile,
+    ExportBackup(std::path::PathBuf),
+    /// Restore asks first, since it overwrites the profile's files.
+    RestoreBackup(std::path::PathBuf),
+    ConfirmRestore,
+    CancelRestore,
+
+    // demo mode
+    /// A key, click, scroll or touch, restarting the idle time.
+    DemoActivity,
+    DemoTick,
+    AttractFrame,
+
+    // daily challenge
+    StartDaily,
+    StopDaily,
+    LeaderboardOptIn(bool),
+    LeaderboardEndpointChanged(String),
+    LeaderboardNameChanged(String),
+    SyncLeaderboard,
+    LeaderboardSynced(leaderboard::Synced),
+    ObsEnabled(bool),
+    ObsHostChanged(String),
+    ObsPortChanged(String),
+    ObsPasswordChanged(String),
+    ObsActionChanged(obs::ObsAction),
+    ObsSceneChanged(String),
+    TestObs,
+    ObsTriggered(Result<(), String>),
+
+    // classroom mode
+    StartClassroom,
+    LeaveClassroom,
+    ClassroomNext,
+    ClassroomTick,
+    ClassroomIntervalChanged(classroom::Interval),
+
+    // coach annotations
+    ToggleAnnotate(bool),
+    AnnotateToolChanged(crate::render::annotate::Tool),
+    AnnotateInkChanged(crate::render::annotate::Ink),
+    ToggleKeepMarks(bool),
+    MarkDrawn(crate::render::annotate::Mark),
+    UndoMark,
+    ClearMarks,
+
+    // metronome + beat drill
+    ToggleMetronome,
+    MetronomeBpmChanged(u16),
+    MetronomeClickToggled(bool),
+    MetronomeBeat,
+    StartBeatDrill,
+    StopBeatDrill,
+
+    // random-walk demo
+    ToggleRandomWalk,
+    RandomWalkRateChanged(u16),
+    RandomWalkStopWhenSolved(bool),
+    RandomWalkFrame,
+    TurnFrame,
+    GlideFrame,
+
+    // state diagnostics
+    ToggleDiagnostics(bool),
+    FixTwist(u8),
+
+    // state comparison
+    SaveDiffSlot(usize),
+    DiffPairChanged(compare::DiffPair),
+    DiffPatternChanged(String),
+    CompareStates,
+    ClearDiff,
+
+    // BLD memo
+    ShowMemo,
+    StartMemoDrill,
+    CancelMemoDrill,
+    MemoInputChanged(String),
+    SubmitMemo,
+    ToggleSchemeEditor(bool),
+    SchemeLetterChanged(Sticker, String),
+    ResetScheme,
+
+    // notation trainer
+    StartNotation,
+    StopNotation,
+    NotationQuizChanged(notation::Quiz),
+    NotationLimitChanged(u16),
+    NotationKey(char),
+    NotationTick,
+
+    // sound effects
+    SoundClickChanged(ClickSound),
+    SoundVolumeChanged(Cue, f32),
+    SoundMuteToggled(Cue, bool),
+
+    // controller (MIDI / Stream Deck) bindings
+    ControllerInput(controllers::Input),
+    BindingInputChanged(String),
+    BindingCommandChanged(String),
+    LearnBinding,
+    AddBinding,
+    RemoveBinding(controllers::Input),
+
+    // game controller
+    Gamepad(gamepad::Event),
+    GamepadFrame,
+    GamepadFaceChanged(usize, gamepad::Button),
+    GamepadPrimeChanged(gamepad::Button),
+    GamepadDoubleChanged(gamepad::Button),
+    GamepadSpeedChanged(f32),
+    GamepadInvertToggled(bool),
+
+    // touchscreen
+    Touch(crate::render::touch::Gesture),
 
     // seed / scramble panel
     SeedChanged(String),
-
-    // New names (used by your UI now)
     ApplySeed,
     Scramble,
-    Reset,
+    ClearSeed,
+    MinDepthChanged(u8),
+
+    // custom scramble entry, physical-cube verification, cubing.js import
+    CustomScrambleChanged(String),
+    ApplyCustomScramble,
+    VerifyInputChanged(String),
+    VerifyState,
+    KPuzzleInputChanged(String),
+    ImportKPuzzle,
 
-    // Legacy names (still referenced by some code paths)
-    SeedGenerate,
-    SeedScramble,
-    SeedClear,
-    ToggleOpposite(bool),
+    // right-view link
+    LinkModeChanged(LinkMode),
+
+    // UI language
+    LanguageChanged(Lang),
+
+    // auto-save / crash recovery
+    AutoSaveTick,
+    RestoreSession,
+    DiscardRecovery,
+    CloseRequested(window::Id),
+    /// The main window moved or was resized; a resize then reports whether
+    /// the window is maximized.
+    WindowMoved(iced::Point),
+    WindowResized(iced::Size),
+    WindowSized(iced::Size, bool),
+    /// Held modifier keys, for shortcuts inside widgets (Ctrl+Enter in the
+    /// algorithm editor).
+    ModifiersChanged(iced::keyboard::Modifiers),
+
+    // solver tables (loaded/built in the background)
+    SolverReady((Arc<DistanceTable>, solver_cache::TableOrigin)),
+    RebuildSolverTables,
+
+    // pop the right view into its own window (closing it re-docks)
+    DetachRight,
+    ReattachRight,
 
     // individual move buttons
     Move(String),
@@ -56,14 +437,107 @@ pub enum Msg {
     Noop,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Display scale option for the picker (`f32` itself can't be `Eq`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleChoice(pub u16); // percent
+impl std::fmt::Display for ScaleChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+impl ScaleChoice {
+    pub fn from_factor(s: f32) -> Self { ScaleChoice((s * 100.0).round() as u16) }
+    pub fn factor(self) -> f32 { f32::from(self.0) / 100.0 }
+}
+
+/// Which of the two cube views a control belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewSide { Left, Right }
+
+/// Camera rotation axis, in slider order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis { Rz, Rx, Ry }
+
+/// How the right view follows the left one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkMode {
+    /// Independent cameras.
+    #[default]
+    Off,
+    /// Same Rz/Ry, Rx + 180° (shows the D/L/B side).
+    Opposite,
+    /// Same Rz/Rx, Ry + 180° (flipped about Y).
+    Mirror,
+    /// Identical angles.
+    Follow,
+    /// Left plus the offset captured when the mode was selected.
+    Offset,
+}
+impl LinkMode {
+    pub const ALL: [LinkMode; 5] =
+        [LinkMode::Off, LinkMode::Opposite, LinkMode::Mirror, LinkMode::Follow, LinkMode::Offset];
+}
+impl std::fmt::Display for LinkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LinkMode::Off      => t!("link-off"),
+            LinkMode::Opposite => t!("link-opposite"),
+            LinkMode::Mirror   => t!("link-mirror"),
+            LinkMode::Follow   => t!("link-follow"),
+            LinkMode::Offset   => t!("link-offset"),
+        }.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct ViewUI { pub rz: f32, pub rx: f32, pub ry: f32 }
+impl ViewUI {
+    /// Current angle (degrees) about `axis`.
+    pub fn get(&self, axis: Axis) -> f32 {
+        match axis { Axis::Rz => self.rz, Axis::Rx => self.rx, Axis::Ry => self.ry }
+    }
+
+    /// The three angles as one rotation.
+    pub fn orientation(&self) -> Quat {
+        Quat::from_euler(
+            RotZ(Radians::from_degrees(self.rz)),
+            RotY(Radians::from_degrees(self.ry)),
+            RotX(Radians::from_degrees(self.rx)),
+        )
+    }
+
+    /// The angles nearest `q`, each in `(-180, 180]` degrees.
+    pub fn from_orientation(q: Quat) -> Self {
+        let (rz, rx, ry) = q.to_euler();
+        Self { rz: rz.0.degrees(), rx: rx.0.degrees(), ry: ry.0.degrees() }
+    }
+}
+
+/// Map a side/axis pair to the slider message that sets it, so typed and
+/// nudged angles follow exactly the same path (snap, link) as the sliders.
+pub fn angle_msg(side: ViewSide, axis: Axis, v: f32) -> Msg {
+    match (side, axis) {
+        (ViewSide::Left,  Axis::Rz) => Msg::LeftRzChanged(v),
+        (ViewSide::Left,  Axis::Rx) => Msg::LeftRxChanged(v),
+        (ViewSide::Left,  Axis::Ry) => Msg::LeftRyChanged(v),
+        (ViewSide::Right, Axis::Rz) => Msg::RightRzChanged(v),
+        (ViewSide::Right, Axis::Rx) => Msg::RightRxChanged(v),
+        (ViewSide::Right, Axis::Ry) => Msg::RightRyChanged(v),
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct Params {
     pub size: f32,
     pub left: ViewUI,
     pub right: ViewUI,
+    pub left_mode: RenderMode,
+    pub right_mode: RenderMode,
+}
+impl Params {
+    pub fn view(&self, side: ViewSide) -> ViewUI {
+        match side { ViewSide::Left => self.left, ViewSide::Right => self.right }
+    }
 }
 impl Default for Params {
     fn default() -> Self {
@@ -73,41 +547,547 @@ impl Default for Params {
             // Start both cameras at (0,0,0)
             left:  ViewUI { rz: 0.0, rx: 0.0, ry: 0.0 },
             right: ViewUI { rz: 90.0, rx: 180.0, ry: 0.0 },
+            left_mode:  RenderMode::Filled,
+            right_mode: RenderMode::Filled,
         }
     }
 }
 
+/// A cube state together with the moves that led to it.
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+    pub cube: Cube,
+    pub history: Vec<String>,
+}
+
+/// A user-named point in the session to jump back to.
+#[derive(Debug, Clone)]
+pub struct NamedCheckpoint {
+    pub name: String,
+    pub state: Snapshot,
+}
+
 #[derive(Default)]
 pub struct App {
     pub cube: Cube,
+    /// Move tokens applied since the last reset/scramble.
+    pub history: Vec<String>,
+    /// Every line of moves explored since the last reset/scramble; `history`
+    /// is the path to its current node.
+    pub history_tree: history_tree::HistoryTree,
+    /// The last scramble applied, written into exported solve scripts.
+    pub scramble: Option<Alg>,
+    /// Solve script file typed for export/import (empty = the default), and
+    /// the script being replayed.
+    pub script_path: String,
+    pub script_replay: Option<script::ScriptReplay>,
+    /// Step-by-step breakdown of the solve on the current line, on request.
+    pub breakdown: Option<Breakdown>,
+    /// A script stepped along with a video of the solve.
+    pub video_sync: Option<script::VideoSync>,
+    /// Everything done this session, the trace file typed for save/replay
+    /// (empty = the default), and the trace being replayed.
+    pub trace: trace::SessionTrace,
+    pub trace_path: String,
+    pub trace_replay: Option<trace::TraceReplay>,
+    /// Console script being edited, its output so far, and the cube a
+    /// running script started from.
+    pub console_editor: text_editor::Content,
+    pub console_output: Vec<String>,
+    pub console_run: Option<Cube>,
+    /// Command palette input, while the palette is open.
+    pub palette: Option<String>,
+    /// Panels folded away from the View menu, leaving the cube and the
+    /// status line, and Help → About open.
+    pub hide_panels: bool,
+    pub show_about: bool,
+    /// Help → Diagnostics open.
+    pub show_debug: bool,
+    /// Set after a first `ResetCube` that would discard history.
+    pub confirm_reset: bool,
+    /// State discarded by the last reset, restorable with `RestoreTrash`.
+    pub trash: Option<Snapshot>,
+    /// Checkpoints dropped this session, oldest first, and the name typed
+    /// for the next one.
+    pub checkpoints: Vec<NamedCheckpoint>,
+    pub checkpoint_name: String,
     pub alg_input: String,
+    /// Multi-line editor showing `alg_input`.
+    pub alg_editor: text_editor::Content,
+    /// Algorithm file typed for watching, and the watch while it runs.
+    pub alg_watch_path: String,
+    pub alg_watch: Option<alg_watch::AlgWatch>,
+    /// Modifier keys currently held.
+    pub modifiers: iced::keyboard::Modifiers,
     pub seed_input: String,
     pub status: String,
     pub params: Params,
     pub snap90: bool,
+    /// Left-handed layout: the views and their controls swap sides, panels
+    /// line up on the right, and camera presets are mirrored.
+    pub mirrored: bool,
+    /// Reduced motion and the frame-rate cap (persisted).
+    pub motion: motion::MotionSettings,
+    pub render: RenderSettings,
+    /// Angle text box currently being edited (its draft text), if any.
+    pub angle_edit: Option<(ViewSide, Axis, String)>,
+    /// Checkpoint found at startup, awaiting Restore/Discard.
+    pub pending_recovery: Option<recovery::Checkpoint>,
+    /// Last checkpoint text written, to skip unchanged auto-saves.
+    pub last_checkpoint: String,
+
+    pub link_mode: LinkMode,
+    /// Right − left angles, captured when `LinkMode::Offset` is selected.
+    pub link_offset: ViewUI,
 
-    pub link_opposite: bool,
+    /// UI language (mirrored into `i18n` for lookups).
+    pub lang: Lang,
+
+    /// Window showing the detached right view, if popped out.
+    pub right_window: Option<window::Id>,
+    /// Algorithm-order popup window and the algorithm it analyzes.
+    pub order_window: Option<(window::Id, Alg)>,
+
+    /// Solver distance table; `None` while loading or rebuilding.
+    pub solver: Option<Arc<DistanceTable>>,
+    /// Where the solver tables came from, for the diagnostics.
+    pub solver_origin: Option<solver_cache::TableOrigin>,
+    /// Optimal depth of the scramble shown in `alg_input`, if computed.
+    pub scramble_depth: Option<u8>,
+    /// Random scrambles shallower than this are rerolled (0 = any).
+    pub min_depth: u8,
+    /// First-face report and the cube it describes (hidden once they differ).
+    pub face_analysis: Option<(Cube, Vec<FaceReport>)>,
+    /// Subgroup report and the cube it describes (hidden once they differ).
+    pub subgroups: Option<(Cube, SubgroupReport)>,
+    /// Show the parity/twist/solvability checks for the current stickers.
+    pub show_diagnostics: bool,
+    /// Saved comparison states A and B, which pair to compare, the typed
+    /// pattern, and the last comparison (its stickers outlined on the cube
+    /// while it is one of the two states).
+    pub diff_slots: [Option<Cube>; 2],
+    pub diff_pair: compare::DiffPair,
+    pub diff_pattern: String,
+    pub state_diff: Option<compare::DiffReport>,
+    /// Optimal solution and the cube it solves (hidden once they differ).
+    pub solution: Option<(Cube, Alg)>,
+    /// Move-cost model for regrip hints, as typed and as last parsed.
+    pub cost_input: String,
+    pub costs: MoveCosts,
+    /// Solve for least total move cost instead of fewest moves.
+    pub weighted_solve: bool,
+    /// Faces the solver may turn, as typed and as last parsed (empty = all).
+    pub generators_input: String,
+    pub generators: Vec<FaceId>,
+    /// A weighted or restricted solve is running in the background.
+    pub solving: bool,
+    /// The solution being played by "Solve & watch", and its pace.
+    pub watch: Option<watch::Watch>,
+    pub watch_pace: watch::Pace,
+
+    /// Externally supplied scramble text (e.g. from a scorecard).
+    pub custom_scramble: String,
+    /// cubing.js KPuzzle JSON, or a path to a file holding it.
+    pub kpuzzle_input: String,
+    /// Facelet letters typed from a physical cube, for verification.
+    pub verify_input: String,
+    /// Result of the last verification (empty = all stickers match).
+    pub verify_result: Option<Vec<Mismatch>>,
+
+    /// Show a QR code of the cube state + algorithm.
+    pub show_qr: bool,
+    /// Markup of "Copy as forum post".
+    pub post_format: forum::PostFormat,
+
+    /// Running color-neutrality drill, if any.
+    pub drill: Option<trainer::Drill>,
+    /// Per-color drill times (persisted).
+    pub neutrality: trainer::NeutralityStats,
+    /// A/B trainer: the case and the two algorithms typed for the next
+    /// test, its length, and the test running or just finished.
+    pub ab_case: Option<&'static NamedAlg>,
+    pub ab_inputs: [String; 2],
+    pub ab_trials: ab_test::Trials,
+    pub ab_test: Option<ab_test::AbTest>,
+    /// Plain solve timer, and the hotkey driving it (persisted).
+    pub timer: solve_timer::SolveTimer,
+    pub hotkey: crate::hotkey::HotkeySettings,
+    /// The key grabbed while the hotkey is on, or why it couldn't be.
+    pub hotkey_grab: Option<crate::hotkey::Grab>,
+    pub hotkey_error: Option<String>,
+    /// Inspection rules for this session's timer and drills.
+    pub inspection: inspection::InspectionRules,
+    /// Active profile, the profiles on this computer, and the name typed
+    /// for a new one.
+    pub profile: profiles::Profile,
+    pub profiles: Vec<profiles::Profile>,
+    pub profile_input: String,
+    /// Backup waiting for the restore to be confirmed.
+    pub pending_restore: Option<std::path::PathBuf>,
+    /// Demo mode, when launched with `--demo`, and its attract loop while
+    /// it plays.
+    pub demo: Option<demo::Demo>,
+    pub attract: Option<attract::Attract>,
+    /// Today's challenge while it's being solved.
+    pub daily: Option<daily::DailyRun>,
+    /// Daily challenge times (persisted).
+    pub daily_log: daily::DailyLog,
+    /// Leaderboard settings and the times waiting to be sent (both
+    /// persisted), the last board fetched, and whether a sync is running.
+    pub leaderboard: leaderboard::LeaderboardSettings,
+    pub leaderboard_queue: leaderboard::Queue,
+    pub board: Option<leaderboard::Board>,
+    pub syncing: bool,
+    /// OBS trigger settings (persisted).
+    pub obs: obs::ObsSettings,
+    /// Drill solves of this session, for the report.
+    pub session_solves: Vec<report::Solve>,
+    /// Shortest gap counted as a pause, and the pauses found in the
+    /// session's solves, longest first.
+    pub pause_threshold: Threshold,
+    pub pauses: Option<Vec<Pause>>,
+    /// Turns made in the profile's solves, by move, and whether their
+    /// heatmap is open.
+    pub move_usage: usage::MoveUsage,
+    pub show_usage: bool,
+    /// Print the solutions upside down on solve sheets.
+    pub sheet_solutions: bool,
+    /// Algorithm set the Anki deck export covers.
+    pub anki_set: AlgSet,
+    /// A round of scrambles previewed side by side; a solve sheet prints
+    /// these while shown.
+    pub round: Option<Vec<solve_sheet::Entry>>,
+    /// Classroom mode, while on, and its timer setting for next time.
+    pub classroom: Option<classroom::Classroom>,
+    pub classroom_interval: classroom::Interval,
+
+    /// Coach mode: drawing tools and the marks drawn over the view.
+    pub annotations: annotate::Annotations,
+
+    /// Integrations notified of moves, solves, and scrambles.
+    pub hooks: Hooks,
+    /// Sound effect settings (persisted) and the audio thread, if running.
+    pub sound: SoundSettings,
+    pub player: Option<Player>,
+
+    /// Controller buttons and their commands (persisted); the binding being
+    /// typed, and whether the next press fills in its input.
+    pub bindings: controllers::Bindings,
+    pub binding_input: String,
+    pub binding_command: String,
+    pub learning_binding: bool,
+
+    /// Game controller settings (persisted), what the pad reports, when
+    /// the sticks last orbited the views, and why no pad can be read.
+    pub gamepad: gamepad::GamepadSettings,
+    pub pad: gamepad::Pad,
+    pub pad_frame: Option<Instant>,
+    pub gamepad_error: Option<String>,
+    /// Main window size, position, and maximized state, saved on exit.
+    pub window: WindowState,
+
+    /// Practice metronome (flashes the canvas border on each beat).
+    pub metronome: metronome::Metronome,
+    /// Random-walk demo (random turns while the views orbit).
+    pub random_walk: random_walk::RandomWalk,
+    /// The last turn of the walk or a script replay, drawn turning.
+    pub in_flight: Option<playback::TurnInFlight>,
+    /// Left and right cameras gliding to a preset.
+    pub glides: [Option<glide::CameraGlide>; 2],
+    /// A file that can be opened is held over the window.
+    pub drop_target: bool,
+    /// Corner piece highlighted on the render, and whether to list the
+    /// slots it visited since the last reset or scramble.
+    pub tracked: Option<Piece>,
+    pub show_trail: bool,
+    /// Running notation quiz, the quiz and time limit (ms) for the next
+    /// one, and the accuracy stats (persisted).
+    pub notation: Option<notation::NotationDrill>,
+    pub notation_quiz: notation::Quiz,
+    pub notation_limit_ms: u16,
+    pub notation_stats: notation::NotationStats,
+    /// Corner memo on display (with the cube it was traced on), the memo
+    /// drill being timed and the answer typed so far, and the session's best
+    /// correct memo time.
+    pub memo: Option<(Cube, Memo)>,
+    pub memo_drill: Option<bld::MemoDrill>,
+    pub memo_input: String,
+    pub memo_best: Option<Duration>,
+    /// Letter for each sticker position (persisted), used by the memo, the
+    /// letter overlay and state descriptions; and whether its editor is open.
+    pub scheme: LetterScheme,
+    pub scheme_editor: bool,
+}
+
+impl App {
+    /// Load everything kept per profile from the active profile's files.
+    pub fn load_profile(&mut self) {
+        self.neutrality = trainer::load();
+        self.daily_log = daily::load();
+        self.leaderboard = leaderboard::load();
+        self.leaderboard_queue = leaderboard::load_queue();
+        self.obs = obs::load();
+        self.notation_stats = notation::load();
+        self.scheme = bld::load_scheme();
+        self.sound = crate::audio::load();
+        self.bindings = crate::controllers::load();
+        self.gamepad = crate::gamepad::load();
+        self.motion = motion::load();
+        self.hotkey = crate::hotkey::load();
+        self.hotkey_error = crate::hotkey::sync(&mut self.hotkey_grab, self.hotkey).err();
+        self.move_usage = usage::load();
+    }
 }
 
 impl Application for App {
     type Executor = iced::executor::Default;
-    type Flags = ();
+    type Flags = WindowState;
     type Message = Msg;
     type Theme = Theme;
 
-    fn new(_flags: ()) -> (Self, Command<Msg>) {
-        (Self::default(), Command::none())
+    fn new(window: WindowState) -> (Self, Command<Msg>) {
+        let pending_recovery = recovery::load();
+        let status = if pending_recovery.is_some() {
+            t!("session-interrupted")
+        } else {
+            String::new()
+        };
+        let mut app = Self {
+            pending_recovery,
+            status,
+            session_solves: Vec::new(),
+            annotations: annotate::Annotations::default(),
+            notation_limit_ms: 2000,
+            window,
+            profile: profiles::Profile(crate::storage::active_profile().unwrap_or_default()),
+            profiles: profiles::list(),
+            demo: demo::Demo::from_args(),
+            ..Self::default()
+        };
+        app.load_profile();
+        app.player = Player::spawn(app.sound);
+        app.hooks = plugins::registered(app.player.as_ref());
+        if crate::audio::AVAILABLE && app.player.is_none() && app.status.is_empty() {
+            app.status = t!("sound-unavailable");
+        }
+        if let Some(demo) = app.demo {
+            app.status = t!("demo-started", secs = demo.idle.as_secs());
+        }
+        // A share link as the first argument opens that configuration; a
+        // file (as the desktop passes one to "Open with") opens like a drop,
+        // once the trace has started so a replayed trace is kept.
+        let mut file = None;
+        if let Some(arg) = std::env::args().skip(1).find(|a| !a.starts_with("--")) {
+            match share::decode(&arg) {
+                Some(state) => {
+                    state.apply_to(&mut app);
+                    app.status = t!("link-opened");
+                }
+                None if dropped::DropKind::of(std::path::Path::new(&arg)).is_some() => file = Some(std::path::PathBuf::from(arg)),
+                None => app.status = t!("link-invalid", link = arg),
+            }
+        }
+        app.trace = trace::SessionTrace::start(recovery::Checkpoint::capture(&app));
+        if let Some(path) = file {
+            update::open_file(&mut app, &path);
+        }
+        let solver = Command::perform(solver_cache::load_or_build_async(), Msg::SolverReady);
+        let maximize = if window.maximized { window::maximize(window::Id::MAIN, true) } else { Command::none() };
+        (app, Command::batch([solver, maximize]))
     }
 
-    fn title(&self) -> String {
-        "2×2 Pocket Cube — Isometric 3D".into()
+    fn title(&self, id: window::Id) -> String {
+        if Some(id) == self.right_window {
+            t!("right-window-title")
+        } else if self.order_window.as_ref().is_some_and(|(w, _)| *w == id) {
+            t!("order-window-title")
+        } else {
+            t!("app-title")
+        }
     }
 
     fn update(&mut self, msg: Msg) -> Command<Msg> {
-        update::update(self, msg)
+        let traced = trace::Traced::of(&msg);
+        let command = update::update(self, msg);
+        trace::record(self, traced);
+        self.history_tree.sync(&self.cube, &self.history);
+        self.annotations.follow(&self.cube);
+        // Code that sets `alg_input` directly (a scramble, a share link)
+        // leaves the editor behind.
+        if support::editor_text(&self.alg_editor) != self.alg_input.strip_suffix('\n').unwrap_or(&self.alg_input) {
+            self.alg_editor = text_editor::Content::with_text(&self.alg_input);
+        }
+        command
+    }
+
+    fn view(&self, id: window::Id) -> Element<Msg> {
+        if Some(id) == self.right_window {
+            view::right_window_view(self)
+        } else if let Some((_, alg)) = self.order_window.as_ref().filter(|(w, _)| *w == id) {
+            view::order_window_view(alg)
+        } else {
+            view::view(self)
+        }
     }
 
-    fn view(&self) -> Element<Msg> {
-        view::view(self)
+    fn subscription(&self) -> Subscription<Msg> {
+        Subscription::batch([
+            iced::time::every(recovery::AUTOSAVE_EVERY).map(|_| Msg::AutoSaveTick),
+            if self.metronome.running {
+                iced::time::every(self.metronome.period()).map(|_| Msg::MetronomeBeat)
+            } else {
+                Subscription::none()
+            },
+            // One more redraw to turn the beat flash off.
+            if self.metronome.flashing() && !self.motion.reduced {
+                iced::time::every(metronome::FLASH).map(|_| Msg::Noop)
+            } else {
+                Subscription::none()
+            },
+            // Held still, the walk only needs a frame per turn.
+            if self.random_walk.running {
+                let frame = if self.motion.reduced {
+                    self.random_walk.period().max(random_walk::FRAME)
+                } else {
+                    random_walk::FRAME
+                };
+                iced::time::every(self.motion.frame(frame)).map(|_| Msg::RandomWalkFrame)
+            } else {
+                Subscription::none()
+            },
+            if self.in_flight.is_some() {
+                iced::time::every(self.motion.frame(playback::FRAME)).map(|_| Msg::TurnFrame)
+            } else {
+                Subscription::none()
+            },
+            if self.glides.iter().any(Option::is_some) {
+                iced::time::every(self.motion.frame(glide::FRAME)).map(|_| Msg::GlideFrame)
+            } else {
+                Subscription::none()
+            },
+            if self.notation.is_some() {
+                Subscription::batch([
+                    iced::time::every(notation::TICK).map(|_| Msg::NotationTick),
+                    iced::keyboard::on_key_press(|key, _mods| match key {
+                        iced::keyboard::Key::Character(c) => c.chars().next().map(Msg::NotationKey),
+                        _ => None,
+                    }),
+                ])
+            } else {
+                Subscription::none()
+            },
+            if self.script_replay.is_some() {
+                iced::time::every(script::TICK).map(|_| Msg::ScriptReplayTick)
+            } else {
+                Subscription::none()
+            },
+            if self.watch.as_ref().is_some_and(|w| !w.finished()) {
+                iced::time::every(watch::TICK).map(|_| Msg::WatchTick)
+            } else {
+                Subscription::none()
+            },
+            if self.trace_replay.is_some() {
+                iced::time::every(trace::TICK).map(|_| Msg::TraceReplayTick)
+            } else {
+                Subscription::none()
+            },
+            if self.alg_watch.is_some() {
+                iced::time::every(alg_watch::POLL).map(|_| Msg::AlgWatchTick)
+            } else {
+                Subscription::none()
+            },
+            if self.leaderboard.active() && !self.leaderboard_queue.0.is_empty() {
+                iced::time::every(leaderboard::RETRY).map(|_| Msg::SyncLeaderboard)
+            } else {
+                Subscription::none()
+            },
+            if let Some(room) = &self.classroom {
+                Subscription::batch([
+                    if room.interval.0 > 0 {
+                        iced::time::every(classroom::TICK).map(|_| Msg::ClassroomTick)
+                    } else {
+                        Subscription::none()
+                    },
+                    iced::keyboard::on_key_press(|key, _mods| match key.as_ref() {
+                        iced::keyboard::Key::Named(
+                            iced::keyboard::key::Named::Space | iced::keyboard::key::Named::ArrowRight,
+                        ) => Some(Msg::ClassroomNext),
+                        iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape) => Some(Msg::LeaveClassroom),
+                        _ => None,
+                    }),
+                ])
+            } else {
+                Subscription::none()
+            },
+            if self.drill.is_some_and(|d| d.inspecting()) || self.timer.inspection().is_some_and(|i| i.pending()) {
+                iced::time::every(inspection::TICK).map(|_| Msg::InspectionTick)
+            } else {
+                Subscription::none()
+            },
+            if self.attract.is_some() {
+                iced::time::every(self.motion.frame(attract::FRAME)).map(|_| Msg::AttractFrame)
+            } else {
+                Subscription::none()
+            },
+            if self.demo.is_some() {
+                Subscription::batch([
+                    iced::time::every(demo::TICK).map(|_| Msg::DemoTick),
+                    iced::event::listen_with(|event, _status| match event {
+                        iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { .. })
+                        | iced::Event::Mouse(
+                            iced::mouse::Event::ButtonPressed(_) | iced::mouse::Event::WheelScrolled { .. },
+                        )
+                        | iced::Event::Touch(iced::touch::Event::FingerPressed { .. }) => Some(Msg::DemoActivity),
+                        _ => None,
+                    }),
+                ])
+            } else {
+                Subscription::none()
+            },
+            crate::controllers::subscription().map(Msg::ControllerInput),
+            if self.hotkey_grab.is_some() {
+                crate::hotkey::subscription().map(Msg::Hotkey)
+            } else {
+                Subscription::none()
+            },
+            if self.timer.active() {
+                iced::time::every(solve_timer::TICK).map(|_| Msg::Noop)
+            } else {
+                Subscription::none()
+            },
+            gamepad::subscription().map(Msg::Gamepad),
+            if self.pad.orbiting() {
+                iced::time::every(self.motion.frame(gamepad::FRAME)).map(|_| Msg::GamepadFrame)
+            } else {
+                Subscription::none()
+            },
+            iced::keyboard::on_key_press(|key, mods| match key.as_ref() {
+                iced::keyboard::Key::Named(iced::keyboard::key::Named::F12) => Some(Msg::ToggleProfiler),
+                iced::keyboard::Key::Character("z") if mods.command() && mods.shift() => Some(Msg::Redo),
+                iced::keyboard::Key::Character("z") if mods.command() => Some(Msg::Undo),
+                iced::keyboard::Key::Character("y") if mods.command() => Some(Msg::Redo),
+                _ => None,
+            }),
+            iced::event::listen_with(|event, _status| match event {
+                iced::Event::Window(id, window::Event::CloseRequested) => Some(Msg::CloseRequested(id)),
+                iced::Event::Window(window::Id::MAIN, window::Event::Moved { x, y }) => {
+                    Some(Msg::WindowMoved(iced::Point::new(x as f32, y as f32)))
+                }
+                iced::Event::Window(window::Id::MAIN, window::Event::Resized { width, height }) => {
+                    Some(Msg::WindowResized(iced::Size::new(width as f32, height as f32)))
+                }
+                iced::Event::Window(window::Id::MAIN, window::Event::FileHovered(path)) => Some(Msg::FileHovered(path)),
+                iced::Event::Window(window::Id::MAIN, window::Event::FilesHoveredLeft) => Some(Msg::FilesHoveredLeft),
+                iced::Event::Window(window::Id::MAIN, window::Event::FileDropped(path)) => Some(Msg::FileDropped(path)),
+                // Seen even while a text field has focus.
+                iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { key, modifiers, .. }) => match key.as_ref() {
+                    iced::keyboard::Key::Character("p") if modifiers.command() => Some(Msg::TogglePalette),
+                    iced::keyboard::Key::Named(iced::keyboard::key::Named::Escape) => Some(Msg::ClosePalette),
+                    _ => None,
+                },
+                iced::Event::Keyboard(iced::keyboard::Event::ModifiersChanged(m)) => Some(Msg::ModifiersChanged(m)),
+                _ => None,
+            }),
+        ])
     }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+// src/app/motion.rs
+
+//! Reduced motion and the frame-rate cap. Reduced motion stops the random
+//! walk's orbit and face glow, the metronome's beat flash, and playback
+//! turns turning, and has the walk redraw only when a turn is due rather
+//! than every frame. The cap
+//! spaces out every frame-driven redraw, for laptops on battery.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+const HEADER: &str = "rubics motion settings v1";
+
+/// Frame-rate cap, in frames per second; 0 is uncapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FpsCap(pub u16);
+
+impl FpsCap {
+    pub const ALL: [FpsCap; 4] = [FpsCap(0), FpsCap(60), FpsCap(30), FpsCap(15)];
+}
+
+impl std::fmt::Display for FpsCap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self.0 {
+            0 => t!("fps-uncapped"),
+            fps => t!("fps-cap-value", fps = fps),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MotionSettings {
+    pub reduced: bool,
+    pub fps_cap: FpsCap,
+}
+
+impl MotionSettings {
+    /// A frame interval, stretched to the cap.
+    pub fn frame(&self, frame: Duration) -> Duration {
+        match self.fps_cap.0 {
+            0 => frame,
+            fps => frame.max(Duration::from_secs(1) / u32::from(fps)),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        write_kv(HEADER, &[
+            ("reduced", self.reduced.to_string()),
+            ("fps_cap", self.fps_cap.0.to_string()),
+        ])
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        Self {
+            reduced: kv.get("reduced").is_some_and(|v| v == "true"),
+            fps_cap: kv
+                .get("fps_cap")
+                .and_then(|v| v.parse().ok())
+                .map(FpsCap)
+                .filter(|c| FpsCap::ALL.contains(c))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Location of the motion settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("motion.txt")
+}
+
+pub fn load() -> MotionSettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| MotionSettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &MotionSettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
@@ -0,0 +1,296 @@
+// src/app/notation.rs
+
+//! Notation trainer for the virtual-cube keyboard layout that online timers
+//! use (I/K = R/R', J/F = U/U', H/G = F/F', D/E = L/L', S/L = D/D',
+//! W/O = B/B'; a half turn is either key of that face pressed twice).
+//!
+//! Two quizzes: a move token is flashed and must be keyed in before the
+//! time limit, or a move is played on a small cube and the user keys in
+//! which move it was. Per-quiz accuracy and the most-missed moves are kept
+//! in a small stats file.
+
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::cube::{Cube, FaceId, Move, Turn};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+const HEADER: &str = "rubics notation stats v1";
+
+/// Key for each quarter turn.
+pub const KEYMAP: [(char, Move); 12] = {
+    use FaceId::*;
+    use Turn::*;
+    [
+        ('i', Move::new(R, Cw)), ('k', Move::new(R, Ccw)),
+        ('j', Move::new(U, Cw)), ('f', Move::new(U, Ccw)),
+        ('h', Move::new(F, Cw)), ('g', Move::new(F, Ccw)),
+        ('d', Move::new(L, Cw)), ('e', Move::new(L, Ccw)),
+        ('s', Move::new(D, Cw)), ('l', Move::new(D, Ccw)),
+        ('w', Move::new(B, Cw)), ('o', Move::new(B, Ccw)),
+    ]
+};
+
+/// Selectable time limit per prompt, in milliseconds.
+pub const LIMIT_RANGE: RangeInclusive<u16> = 500..=5000;
+
+/// How often to check the time limit (and step the move animation).
+pub const TICK: Duration = Duration::from_millis(100);
+
+/// The move animation shows the cube before, then after, this long each.
+const FLIP: Duration = Duration::from_millis(700);
+
+/// The quarter turn typed with `key` (case-insensitive).
+pub fn key_move(key: char) -> Option<Move> {
+    let key = key.to_ascii_lowercase();
+    KEYMAP.iter().find(|(k, _)| *k == key).map(|&(_, m)| m)
+}
+
+/// The keystrokes for `m`, e.g. `I I` for R2.
+pub fn keys_for(m: Move) -> String {
+    let key = |m: Move| KEYMAP.iter().find(|(_, x)| *x == m).map_or('?', |&(k, _)| k.to_ascii_uppercase());
+    match m.turn {
+        Turn::Half => {
+            let k = key(Move::new(m.face, Turn::Cw));
+            format!("{k} {k}")
+        }
+        _ => key(m).to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Quiz {
+    /// A token is shown; key it in.
+    #[default]
+    Keys,
+    /// A move is played; key in which one it was.
+    Read,
+}
+
+impl Quiz {
+    pub const ALL: [Quiz; 2] = [Quiz::Keys, Quiz::Read];
+
+    fn key(self) -> &'static str {
+        match self { Quiz::Keys => "keys", Quiz::Read => "read" }
+    }
+}
+
+impl std::fmt::Display for Quiz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Quiz::Keys => t!("notation-quiz-keys"),
+            Quiz::Read => t!("notation-quiz-read"),
+        })
+    }
+}
+
+/// How a prompt ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct { millis: u64 },
+    Wrong { typed: Move },
+    TimedOut,
+}
+
+/// One running quiz: the move asked for and what has been typed so far.
+#[derive(Debug, Clone)]
+pub struct NotationDrill {
+    pub quiz: Quiz,
+    pub target: Move,
+    pub limit: Duration,
+    shown: Instant,
+    /// First press of a possible half turn.
+    pending: Option<Move>,
+    /// Animation frames for the read quiz: solved, then `target` applied.
+    frames: [Cube; 2],
+}
+
+impl NotationDrill {
+    pub fn start(quiz: Quiz, limit: Duration) -> Self {
+        let mut drill = Self {
+            quiz,
+            target: random_move(None),
+            limit,
+            shown: Instant::now(),
+            pending: None,
+            frames: Default::default(),
+        };
+        drill.frames[1].apply(drill.target);
+        drill
+    }
+
+    /// Move on to a new prompt (never the same move twice running).
+    pub fn next(&mut self) {
+        self.target = random_move(Some(self.target));
+        self.shown = Instant::now();
+        self.pending = None;
+        self.frames[1] = Cube::default();
+        self.frames[1].apply(self.target);
+    }
+
+    /// Handle a key press; `None` while the prompt is still open (an
+    /// unmapped key, or the first press of a half turn).
+    pub fn key(&mut self, key: char) -> Option<Outcome> {
+        let m = key_move(key)?;
+        let typed = match self.pending.take() {
+            Some(first) if first == m => Move::new(m.face, Turn::Half),
+            Some(_) => m,
+            None if self.target.turn == Turn::Half && m.face == self.target.face => {
+                self.pending = Some(m);
+                return None;
+            }
+            None => m,
+        };
+        Some(if typed == self.target {
+            Outcome::Correct { millis: self.shown.elapsed().as_millis() as u64 }
+        } else {
+            Outcome::Wrong { typed }
+        })
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.shown.elapsed() >= self.limit
+    }
+
+    /// For the read quiz: whether the animation is showing the turn done.
+    pub fn showing_after(&self) -> bool {
+        (self.shown.elapsed().as_millis() / FLIP.as_millis()) % 2 == 1
+    }
+
+    /// The animation frame to draw now.
+    pub fn frame(&self) -> &Cube {
+        &self.frames[usize::from(self.showing_after())]
+    }
+}
+
+fn random_move(not: Option<Move>) -> Move {
+    let mut rng = thread_rng();
+    loop {
+        let m = *Move::ALL.choose(&mut rng).expect("eighteen turns");
+        if Some(m) != not {
+            return m;
+        }
+    }
+}
+
+// ----- stats -----
+
+/// Tally for one quiz.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuizStats {
+    pub correct: u32,
+    pub wrong: u32,
+    pub timed_out: u32,
+    /// Sum of response times of correct answers.
+    total_ms: u64,
+}
+
+impl QuizStats {
+    pub fn attempts(&self) -> u32 {
+        self.correct + self.wrong + self.timed_out
+    }
+
+    pub fn accuracy(&self) -> Option<f64> {
+        let n = self.attempts();
+        (n > 0).then(|| 100.0 * f64::from(self.correct) / f64::from(n))
+    }
+
+    pub fn average_ms(&self) -> Option<u64> {
+        (self.correct > 0).then(|| self.total_ms / u64::from(self.correct))
+    }
+}
+
+/// Per-quiz tallies and misses per move (indexed by `Move::index`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotationStats {
+    quizzes: [QuizStats; 2],
+    misses: [u32; 18],
+}
+
+impl NotationStats {
+    pub fn quiz(&self, quiz: Quiz) -> QuizStats {
+        self.quizzes[quiz as usize]
+    }
+
+    pub fn record(&mut self, quiz: Quiz, target: Move, outcome: Outcome) {
+        let q = &mut self.quizzes[quiz as usize];
+        match outcome {
+            Outcome::Correct { millis } => {
+                q.correct += 1;
+                q.total_ms += millis;
+                return;
+            }
+            Outcome::Wrong { .. } => q.wrong += 1,
+            Outcome::TimedOut => q.timed_out += 1,
+        }
+        self.misses[target.index()] += 1;
+    }
+
+    /// The most-missed moves, worst first (at most `n`, misses > 0 only).
+    pub fn most_missed(&self, n: usize) -> Vec<(Move, u32)> {
+        let mut out: Vec<(Move, u32)> = Move::ALL
+            .iter()
+            .map(|&m| (m, self.misses[m.index()]))
+            .filter(|&(_, k)| k > 0)
+            .collect();
+        out.sort_by_key(|&(_, k)| std::cmp::Reverse(k));
+        out.truncate(n);
+        out
+    }
+
+    pub fn encode(&self) -> String {
+        let mut pairs: Vec<(&str, String)> = Quiz::ALL
+            .iter()
+            .map(|&q| {
+                let s = self.quiz(q);
+                (q.key(), format!("{} {} {} {}", s.correct, s.wrong, s.timed_out, s.total_ms))
+            })
+            .collect();
+        let misses: Vec<String> = self.misses.iter().map(|n| n.to_string()).collect();
+        pairs.push(("misses", misses.join(" ")));
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let mut stats = Self::default();
+        for q in Quiz::ALL {
+            let Some(v) = kv.get(q.key()) else { continue };
+            let nums: Vec<u64> = v.split_whitespace().filter_map(|n| n.parse().ok()).collect();
+            if let [correct, wrong, timed_out, total_ms] = nums[..] {
+                stats.quizzes[q as usize] = QuizStats {
+                    correct: correct as u32,
+                    wrong: wrong as u32,
+                    timed_out: timed_out as u32,
+                    total_ms,
+                };
+            }
+        }
+        if let Some(v) = kv.get("misses") {
+            for (slot, n) in stats.misses.iter_mut().zip(v.split_whitespace()) {
+                *slot = n.parse().unwrap_or(0);
+            }
+        }
+        stats
+    }
+}
+
+/// Location of the notation stats file.
+pub fn stats_path() -> PathBuf {
+    data_dir().join("notation.txt")
+}
+
+pub fn load() -> NotationStats {
+    std::fs::read_to_string(stats_path())
+        .map(|t| NotationStats::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(stats: &NotationStats) -> std::io::Result<()> {
+    save_file(&stats_path(), stats.encode())
+}
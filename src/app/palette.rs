@@ -0,0 +1,166 @@
+// src/app/palette.rs
+
+//! Command palette (Ctrl+P): one-line text commands such as `solve`,
+//! `scramble 20`, or `camera 30 45 0`, turned into the same messages the
+//! buttons send. A line of moves (`R U R'`) turns the cube. Controller
+//! buttons run the same command lines.
+
+use std::path::PathBuf;
+
+use iced::widget::text_input;
+
+use crate::i18n::Lang;
+use crate::logic::alg::parse_alg;
+use crate::logic::scramble::random_scramble;
+use crate::render::CameraPreset;
+use crate::storage::read_only;
+use crate::t;
+use super::{angle_msg, anki, backup, blueprint, report, screenshot, solve_sheet, Axis, Msg, ViewSide};
+
+/// Longest random scramble `scramble <moves>` makes.
+const MAX_SCRAMBLE: usize = 200;
+
+/// Each command's usage, as listed by `help`.
+pub const COMMANDS: [&str; 21] = [
+    "solve",
+    "scramble [moves]",
+    "seed <n>",
+    "<moves>",
+    "undo",
+    "redo",
+    "reset",
+    "camera [left|right] <rz> <rx> <ry>",
+    "preset iso|dimetric|front|top [left|right]",
+    "size <px>",
+    "export png|script|trace|report|blueprint|sheet|anki|backup [file]",
+    "restore [file]",
+    "lang en|fr",
+    "qr",
+    "metronome",
+    "walk",
+    "drill [stop]",
+    "classroom",
+    "watch",
+    "run",
+    "help",
+];
+
+/// Focus target for the palette's input.
+pub fn input_id() -> text_input::Id {
+    text_input::Id::new("command-palette")
+}
+
+/// Usages of the commands starting with the first word typed.
+pub fn matching(input: &str) -> Vec<&'static str> {
+    let word = input.split_whitespace().next().unwrap_or("").to_ascii_lowercase();
+    COMMANDS.iter().copied().filter(|c| !c.starts_with('<') && c.starts_with(&word)).collect()
+}
+
+/// The messages `line` stands for, in order; none for `help` or a blank
+/// line. Errors are translated messages.
+pub fn parse(line: &str) -> Result<Vec<Msg>, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((cmd, args)) = words.split_first() else { return Ok(Vec::new()) };
+    let usage = |cmd: &str| {
+        let usage = COMMANDS.iter().find(|u| u.starts_with(cmd)).copied().unwrap_or(cmd);
+        t!("palette-usage", usage = usage)
+    };
+    let cmd = cmd.to_ascii_lowercase();
+    let file = |rest: &[&str]| rest.join(" ");
+    Ok(match (cmd.as_str(), args) {
+        ("help", []) => Vec::new(),
+        ("solve", []) => vec![Msg::SolveCube],
+        ("scramble", []) => vec![Msg::Scramble],
+        ("scramble", [n]) => {
+            let n = n.parse().ok().filter(|n| (1..=MAX_SCRAMBLE).contains(n)).ok_or_else(|| usage("scramble"))?;
+            vec![Msg::CustomScrambleChanged(random_scramble(n)), Msg::ApplyCustomScramble]
+        }
+        ("seed", [n]) => vec![Msg::SeedChanged(n.to_string()), Msg::ApplySeed],
+        ("undo", []) => vec![Msg::Undo],
+        ("redo", []) => vec![Msg::Redo],
+        ("reset", []) => vec![Msg::ResetCube],
+        ("camera", args) => camera(args).ok_or_else(|| usage("camera"))?,
+        ("preset", [name, side @ ..]) => {
+            let preset = match name.to_ascii_lowercase().as_str() {
+                "iso" | "isometric" => CameraPreset::Isometric,
+                "dimetric" => CameraPreset::Dimetric,
+                "front" => CameraPreset::Front,
+                "top" => CameraPreset::TopDown,
+                _ => return Err(usage("preset")),
+            };
+            let side = match side.first().map(|s| s.to_ascii_lowercase()).as_deref() {
+                None | Some("left") => ViewSide::Left,
+                Some("right") if side.len() == 1 => ViewSide::Right,
+                _ => return Err(usage("preset")),
+            };
+            vec![Msg::NamedPreset(side, preset)]
+        }
+        ("size", [px]) => vec![Msg::SizeChanged(px.parse().map_err(|_| usage("size"))?)],
+        ("export", _) if read_only() => return Err(t!("demo-no-export")),
+        ("restore", _) if read_only() => return Err(t!("demo-read-only")),
+        ("export", ["png", rest @ ..]) => {
+            let path = if rest.is_empty() { screenshot::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportPng(path)]
+        }
+        ("export", ["script", rest @ ..]) => vec![Msg::ScriptPathChanged(file(rest)), Msg::ExportScript],
+        ("export", ["trace", rest @ ..]) => vec![Msg::TracePathChanged(file(rest)), Msg::SaveTrace],
+        ("export", ["report", rest @ ..]) => {
+            let path = if rest.is_empty() { report::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportReport(path)]
+        }
+        ("export", ["blueprint", rest @ ..]) => {
+            let path = if rest.is_empty() { blueprint::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportBlueprint(path)]
+        }
+        ("export", ["sheet", rest @ ..]) => {
+            let path = if rest.is_empty() { solve_sheet::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportSolveSheet(path)]
+        }
+        ("export", ["anki", rest @ ..]) => {
+            let path = if rest.is_empty() { anki::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportAnki(path)]
+        }
+        ("export", ["backup", rest @ ..]) => {
+            let path = if rest.is_empty() { backup::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::ExportBackup(path)]
+        }
+        ("restore", rest) => {
+            let path = if rest.is_empty() { backup::default_path() } else { PathBuf::from(file(rest)) };
+            vec![Msg::RestoreBackup(path)]
+        }
+        ("lang", [code]) => {
+            let lang = match code.to_ascii_lowercase().as_str() {
+                "en" => Lang::En,
+                "fr" => Lang::Fr,
+                _ => return Err(usage("lang")),
+            };
+            vec![Msg::LanguageChanged(lang)]
+        }
+        ("qr", []) => vec![Msg::ToggleQr],
+        ("metronome", []) => vec![Msg::ToggleMetronome],
+        ("walk", []) => vec![Msg::ToggleRandomWalk],
+        ("classroom", []) => vec![Msg::StartClassroom],
+        ("watch", []) => vec![Msg::WatchSolve],
+        ("run", []) => vec![Msg::RunConsole],
+        ("drill", []) => vec![Msg::StartDrill],
+        ("drill", ["stop"]) => vec![Msg::StopDrill],
+        _ if COMMANDS.iter().any(|c| c.split(' ').next() == Some(cmd.as_str())) => return Err(usage(&cmd)),
+        _ if parse_alg(line).is_ok() => words.iter().map(|w| Msg::Move(w.to_string())).collect(),
+        _ => return Err(t!("palette-unknown", command = cmd)),
+    })
+}
+
+/// `camera [left|right] <rz> <rx> <ry>`: all three angles of one view.
+fn camera(args: &[&str]) -> Option<Vec<Msg>> {
+    let (side, angles) = match args.first().map(|a| a.to_ascii_lowercase()).as_deref() {
+        Some("left") => (ViewSide::Left, &args[1..]),
+        Some("right") => (ViewSide::Right, &args[1..]),
+        _ => (ViewSide::Left, args),
+    };
+    let [rz, rx, ry] = angles else { return None };
+    let mut msgs = Vec::new();
+    for (axis, v) in [(Axis::Rz, rz), (Axis::Rx, rx), (Axis::Ry, ry)] {
+        msgs.push(angle_msg(side, axis, v.parse().ok()?));
+    }
+    Some(msgs)
+}
@@ -0,0 +1,47 @@
+// src/app/playback.rs
+
+//! Turns played back shown in motion. A turn made by the random walk or a
+//! script replay is drawn turning over the time until the next one (a
+//! quarter second at most), so quick playback flows rather than jumping
+//! from state to state. When turns come faster than frames, each frame
+//! shows its latest turn partway, which still reads as motion. Reduced
+//! motion shows every turn done at once.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Cube, Move};
+use crate::render::layer::LayerTurn;
+
+/// Redraw interval while a turn is in motion.
+pub const FRAME: Duration = Duration::from_millis(16);
+
+/// Longest a turn takes, however slow the playback.
+const LONGEST: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone)]
+pub struct TurnInFlight {
+    mv: Move,
+    /// The cube the turn left; once it changes otherwise, the turn is over.
+    after: Cube,
+    started: Instant,
+    span: Duration,
+}
+
+impl TurnInFlight {
+    /// `mv`, just made leaving `after`, turning until the next turn `gap`
+    /// later, and for at least one `frame` so it is seen partway at all.
+    pub fn new(mv: Move, after: Cube, gap: Option<Duration>, frame: Duration) -> Self {
+        let span = gap.map_or(LONGEST, |g| g.min(LONGEST)).max(frame);
+        Self { mv, after, started: Instant::now(), span }
+    }
+
+    /// The turn as drawn on `cube` now; `None` once it is done, or if
+    /// `cube` isn't the state it left.
+    pub fn layer(&self, cube: &Cube) -> Option<LayerTurn> {
+        if *cube != self.after {
+            return None;
+        }
+        let progress = self.started.elapsed().as_secs_f32() / self.span.as_secs_f32();
+        (progress < 1.0).then_some(LayerTurn { mv: self.mv, progress })
+    }
+}
@@ -0,0 +1,43 @@
+// src/app/plugins.rs
+
+//! Plugins registered at startup. Add an integration by registering it in
+//! [`registered`]; `update` only ever calls `Hooks::emit`.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+
+use crate::audio::Player;
+use crate::cube::Cube;
+use crate::events::{CubeEvent, Hooks};
+
+/// Environment variable naming a file to append one line per event to.
+const EVENT_LOG_VAR: &str = "ICEDCUBE_EVENT_LOG";
+
+/// The hooks the app starts with.
+pub fn registered(player: Option<&Player>) -> Hooks {
+    let mut hooks = Hooks::default();
+    if let Some(p) = player {
+        hooks.register(p.clone());
+    }
+    if let Some(path) = std::env::var_os(EVENT_LOG_VAR) {
+        match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => hooks.register(event_log(file)),
+            Err(e) => eprintln!("{EVENT_LOG_VAR}: cannot open {}: {e}", path.to_string_lossy()),
+        }
+    }
+    hooks
+}
+
+// `move R'`, `solved 14`, `scramble R U2 F …` plus the facelets after it,
+// e.g. for a stream overlay tailing the file.
+fn event_log(mut file: File) -> impl FnMut(&CubeEvent, &Cube) + Send {
+    move |event, cube| {
+        let what = match event {
+            CubeEvent::MoveApplied(m) => format!("move {m}"),
+            CubeEvent::Solved { moves } => format!("solved {moves}"),
+            CubeEvent::Scrambled(alg) => format!("scramble {alg}"),
+            CubeEvent::InspectionCall { seconds_left } => format!("inspection {seconds_left}"),
+        };
+        let _ = writeln!(file, "{what}\t{}", cube.to_facelets());
+    }
+}
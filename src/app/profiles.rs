@@ -0,0 +1,98 @@
+// src/app/profiles.rs
+
+//! User profiles, for computers shared by a family or a classroom. Each
+//! profile keeps its own settings, solve logs, letter scheme, controller
+//! bindings, and exports: the data folder is the profile's own (see
+//! [`crate::storage::data_dir`]). The default profile uses the data root
+//! itself, so files from before profiles existed stay where they were.
+//!
+//! The app opens the profile used last, or the one named with
+//! `--profile=<name>`, and can switch from the toolbar.
+
+use std::path::PathBuf;
+
+use crate::storage::{profiles_dir, read_kv, read_only, root_dir, save_file, set_profile, write_kv};
+use crate::t;
+
+const HEADER: &str = "rubics profile v1";
+
+/// Longest profile name.
+pub const NAME_MAX: usize = 32;
+
+/// A profile by name; the empty name is the default profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Profile(pub String);
+
+impl Profile {
+    /// `name` made a profile name: trimmed, and only letters, digits,
+    /// spaces, `-` and `_`, so it is a safe folder name everywhere; `None`
+    /// if nothing is left.
+    pub fn new(name: &str) -> Option<Profile> {
+        let name = name.trim();
+        let ok = !name.is_empty()
+            && name.chars().count() <= NAME_MAX
+            && name.chars().all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'));
+        ok.then(|| Profile(name.to_string()))
+    }
+
+    fn dir_name(&self) -> Option<String> {
+        (!self.0.is_empty()).then(|| self.0.clone())
+    }
+
+    /// Make this the active profile, for this run and the next (unless
+    /// the run is read-only).
+    pub fn activate(&self) -> std::io::Result<()> {
+        set_profile(self.dir_name());
+        if let Some(dir) = self.dir_name().filter(|_| !read_only()) {
+            std::fs::create_dir_all(profiles_dir().join(dir))?;
+        }
+        save_file(&last_path(), write_kv(HEADER, &[("name", self.0.clone())]))
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_str() {
+            "" => f.write_str(&t!("profile-default")),
+            name => f.write_str(name),
+        }
+    }
+}
+
+/// Location of the note of the profile used last. It sits in the data
+/// root, shared by all profiles.
+fn last_path() -> PathBuf {
+    root_dir().join("profile.txt")
+}
+
+/// The default profile, then the others by name.
+pub fn list() -> Vec<Profile> {
+    let mut out: Vec<Profile> = std::fs::read_dir(profiles_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| Profile::new(&e.file_name().to_string_lossy()))
+        .collect();
+    out.sort();
+    out.insert(0, Profile::default());
+    out
+}
+
+/// The profile to open: the one named on the command line, else the one
+/// used last. A read-only demo opens its own profile instead, if there is
+/// one, else the default.
+pub fn at_startup() -> Profile {
+    let named = std::env::args().find_map(|a| a.strip_prefix("--profile=").map(str::to_string));
+    named
+        .or_else(|| {
+            if read_only() {
+                let demo = super::demo::PROFILE;
+                return profiles_dir().join(demo).is_dir().then(|| demo.to_string());
+            }
+            let text = std::fs::read_to_string(last_path()).ok()?;
+            read_kv(&text).get("name").cloned()
+        })
+        .and_then(|name| Profile::new(&name))
+        .unwrap_or_default()
+}
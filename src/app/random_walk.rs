@@ -0,0 +1,118 @@
+// src/app/random_walk.rs
+
+//! Random-walk demo: random turns at a steady rate while the views slowly
+//! orbit and each turned face glows, as an ambient display. With "stop
+//! when solved" it doubles as an experiment: how many random turns until
+//! the cube happens to come back to solved (on average about one per
+//! distinct state, [`STATE_COUNT`](crate::logic::solver::STATE_COUNT)).
+
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::cube::{FaceId, Move};
+
+/// Selectable rate, in turns per second.
+pub const RATE_RANGE: RangeInclusive<u16> = 1..=500;
+
+/// Animation frame interval while walking.
+pub const FRAME: Duration = Duration::from_millis(33);
+
+/// How fast the views orbit while walking, in degrees per second.
+const ORBIT_SPEED: f32 = 12.0;
+
+/// A turned face glows for this long, or one turn period if shorter.
+const GLOW: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Clone, Copy)]
+pub struct RandomWalk {
+    /// Turns per second.
+    pub rate: u16,
+    /// End the walk when the cube is solved again.
+    pub stop_when_solved: bool,
+    pub running: bool,
+    /// Turns made since the walk started.
+    pub moves: u64,
+    /// Degrees added to both views' Z angle.
+    pub orbit: f32,
+    last: Option<(Move, Instant)>,
+    last_frame: Option<Instant>,
+    // Fractional turns carried between frames.
+    owed: f64,
+}
+
+impl Default for RandomWalk {
+    fn default() -> Self {
+        Self {
+            rate: 4,
+            stop_when_solved: false,
+            running: false,
+            moves: 0,
+            orbit: 0.0,
+            last: None,
+            last_frame: None,
+            owed: 0.0,
+        }
+    }
+}
+
+impl RandomWalk {
+    pub fn start(&mut self) {
+        self.running = true;
+        self.moves = 0;
+        self.owed = 0.0;
+        self.last = None;
+        self.last_frame = Some(Instant::now());
+    }
+
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.last_frame = None;
+    }
+
+    /// Time between turns.
+    pub fn period(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / f64::from(self.rate))
+    }
+
+    /// Advance the orbit to now (unless it is held still) and draw the
+    /// turns due since the last frame; never the same face twice in a row.
+    pub fn frame(&mut self, rng: &mut impl Rng, orbit: bool) -> Vec<Move> {
+        let now = Instant::now();
+        let dt = self.last_frame.map_or(0.0, |t| now.duration_since(t).as_secs_f64());
+        self.last_frame = Some(now);
+        if orbit {
+            self.orbit = (self.orbit + ORBIT_SPEED * dt as f32) % 360.0;
+        }
+
+        self.owed += f64::from(self.rate) * dt;
+        let due = self.owed.floor();
+        self.owed -= due;
+        let mut out = Vec::with_capacity(due as usize);
+        let mut prev = self.last.map(|(m, _)| m.face);
+        for _ in 0..due as usize {
+            let m = loop {
+                let m = *Move::ALL.choose(rng).expect("eighteen turns");
+                if Some(m.face) != prev {
+                    break m;
+                }
+            };
+            prev = Some(m.face);
+            out.push(m);
+        }
+        if let Some(&m) = out.last() {
+            self.last = Some((m, now));
+        }
+        out
+    }
+
+    /// The face turned last and its glow, fading from 1 to 0.
+    pub fn glow(&self) -> Option<(FaceId, f32)> {
+        let (m, at) = self.last.filter(|_| self.running)?;
+        let span = GLOW.min(self.period());
+        let left = 1.0 - at.elapsed().as_secs_f32() / span.as_secs_f32();
+        (left > 0.0).then_some((m.face, left))
+    }
+}
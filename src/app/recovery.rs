@@ -0,0 +1,151 @@
+// src/app/recovery.rs
+
+//! Crash recovery: the session is checkpointed to a file every few seconds
+//! and the file is removed on a clean window close. Finding it at startup
+//! means the previous session was interrupted, so the app offers a restore.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::cube::Cube;
+use crate::render::RenderMode;
+use crate::storage::{data_dir, read_kv, read_only, write_kv};
+use super::{App, LinkMode, Params, ViewUI};
+
+/// How often the session is checkpointed.
+pub const AUTOSAVE_EVERY: Duration = Duration::from_secs(10);
+
+const HEADER: &str = "rubics recovery v1";
+
+/// Location of the recovery checkpoint.
+pub fn recovery_path() -> PathBuf {
+    data_dir().join("recovery.txt")
+}
+
+/// The persisted part of [`App`]: cube, history, inputs, and camera setup.
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoint {
+    pub cube: Cube,
+    pub history: Vec<String>,
+    pub alg_input: String,
+    pub seed_input: String,
+    pub params: Params,
+    pub snap90: bool,
+    pub link_mode: LinkMode,
+    pub link_offset: ViewUI,
+}
+
+impl Checkpoint {
+    pub fn capture(app: &App) -> Self {
+        Self {
+            cube: app.cube.clone(),
+            history: app.history.clone(),
+            alg_input: app.alg_input.clone(),
+            seed_input: app.seed_input.clone(),
+            params: app.params,
+            snap90: app.snap90,
+            link_mode: app.link_mode,
+            link_offset: app.link_offset,
+        }
+    }
+
+    pub fn restore_into(self, app: &mut App) {
+        app.cube = self.cube;
+        app.history = self.history;
+        app.alg_input = self.alg_input;
+        app.seed_input = self.seed_input;
+        app.params = self.params;
+        app.snap90 = self.snap90;
+        app.link_mode = self.link_mode;
+        app.link_offset = self.link_offset;
+    }
+
+    pub fn encode(&self) -> String {
+        let p = &self.params;
+        write_kv(HEADER, &[
+            ("cube", self.cube.to_facelets()),
+            ("history", self.history.join(" ")),
+            ("alg", self.alg_input.clone()),
+            ("seed", self.seed_input.clone()),
+            ("size", p.size.to_string()),
+            ("left", fmt_view(p.left)),
+            ("right", fmt_view(p.right)),
+            ("left_mode", format!("{:?}", p.left_mode)),
+            ("right_mode", format!("{:?}", p.right_mode)),
+            ("snap90", self.snap90.to_string()),
+            ("link", format!("{:?}", self.link_mode)),
+            ("link_offset", fmt_view(self.link_offset)),
+        ])
+    }
+
+    /// Parse an encoded checkpoint; `None` if the header or cube is missing.
+    /// Other unreadable fields fall back to their defaults.
+    pub fn decode(text: &str) -> Option<Self> {
+        if text.lines().next()? != format!("# {HEADER}") {
+            return None;
+        }
+        let kv = read_kv(text);
+        let get = |k: &str| kv.get(k).map(String::as_str).unwrap_or("");
+        let d = Params::default();
+        Some(Self {
+            cube: Cube::from_facelets(get("cube"))?,
+            history: get("history").split_whitespace().map(str::to_string).collect(),
+            alg_input: get("alg").to_string(),
+            seed_input: get("seed").to_string(),
+            params: Params {
+                size: get("size").parse().unwrap_or(d.size),
+                left: parse_view(get("left")).unwrap_or(d.left),
+                right: parse_view(get("right")).unwrap_or(d.right),
+                left_mode: parse_debug(&RenderMode::ALL, get("left_mode")).unwrap_or(d.left_mode),
+                right_mode: parse_debug(&RenderMode::ALL, get("right_mode")).unwrap_or(d.right_mode),
+            },
+            snap90: get("snap90") == "true",
+            link_mode: parse_debug(&LinkMode::ALL, get("link")).unwrap_or_default(),
+            link_offset: parse_view(get("link_offset")).unwrap_or_default(),
+        })
+    }
+}
+
+/// Write the checkpoint text (via a temp file, so a crash mid-write
+/// never leaves a truncated checkpoint behind).
+pub fn save(text: &str) -> std::io::Result<()> {
+    if read_only() {
+        return Ok(());
+    }
+    let path = recovery_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, text)?;
+    std::fs::rename(tmp, path)
+}
+
+/// Checkpoint left behind by an interrupted session, if any.
+pub fn load() -> Option<Checkpoint> {
+    let text = std::fs::read_to_string(recovery_path()).ok()?;
+    Checkpoint::decode(&text)
+}
+
+/// Remove the checkpoint (clean exit or user discarded it).
+pub fn clear() {
+    if read_only() {
+        return;
+    }
+    let _ = std::fs::remove_file(recovery_path());
+}
+
+pub(super) fn fmt_view(v: ViewUI) -> String {
+    format!("{},{},{}", v.rz, v.rx, v.ry)
+}
+
+pub(super) fn parse_view(s: &str) -> Option<ViewUI> {
+    let mut it = s.split(',').map(|x| x.trim().parse::<f32>());
+    let (rz, rx, ry) = (it.next()?.ok()?, it.next()?.ok()?, it.next()?.ok()?);
+    Some(ViewUI { rz, rx, ry })
+}
+
+/// Match `s` against the `Debug` names of `all`.
+fn parse_debug<T: Copy + std::fmt::Debug>(all: &[T], s: &str) -> Option<T> {
+    all.iter().copied().find(|v| format!("{v:?}") == s)
+}
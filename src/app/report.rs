@@ -0,0 +1,189 @@
+// src/app/report.rs
+
+//! Session report: the neutrality drill solves of this session written as
+//! one standalone HTML file (inline styles and an SVG chart, no scripts),
+//! ready to send to a coach. It has a stats summary, a chart of the
+//! times, the best solve reconstructed, and every scramble.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cube::{Alg, Col, Cube, Move};
+use crate::logic::pauses::{self, Pause};
+use crate::logic::describe::color_name;
+use crate::render::markup::escape;
+use crate::storage::{data_dir, save_file};
+use crate::t;
+use crate::Error;
+use super::support::fmt_secs;
+
+/// Chart size in pixels, and the margin left for its axis labels.
+const CHART_W: f64 = 640.0;
+const CHART_H: f64 = 220.0;
+const MARGIN: f64 = 40.0;
+
+/// One drill solve, kept for the report.
+#[derive(Debug, Clone)]
+pub struct Solve {
+    /// Color built first.
+    pub color: Col,
+    pub scramble: Alg,
+    /// The turns of the solve, as typed, and when each was made after the
+    /// scramble (empty if not known).
+    pub moves: Vec<String>,
+    pub times: Vec<Duration>,
+    pub millis: u64,
+}
+
+/// The pauses of `threshold` or longer in the solves with their move times
+/// known, longest first.
+pub fn pauses(solves: &[Solve], threshold: Duration) -> Vec<Pause> {
+    let mut out = Vec::new();
+    for (i, solve) in solves.iter().enumerate() {
+        let moves: Option<Vec<Move>> = solve.moves.iter().map(|t| Move::parse(t)).collect();
+        let Some(moves) = moves.filter(|m| m.len() == solve.times.len()) else { continue };
+        let mut start = Cube::default();
+        start.apply_alg(&solve.scramble);
+        out.extend(pauses::find(i, &start, &moves, &solve.times, threshold));
+    }
+    out.sort_by_key(|p| std::cmp::Reverse(p.length));
+    out
+}
+
+/// Where the report goes when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("report.html")
+}
+
+/// WCA average of the last `n` times: the best and worst are dropped and
+/// the rest averaged. `None` with fewer than `n` times.
+fn average_of(times: &[u64], n: usize) -> Option<u64> {
+    let mut last = times.get(times.len().checked_sub(n)?..)?.to_vec();
+    last.sort_unstable();
+    let kept = &last[1..n - 1];
+    Some(kept.iter().sum::<u64>() / kept.len() as u64)
+}
+
+/// Best average of `n` over every window of the session.
+fn best_average_of(times: &[u64], n: usize) -> Option<u64> {
+    (n..=times.len()).filter_map(|end| average_of(&times[..end], n)).min()
+}
+
+fn time_cell(millis: Option<u64>) -> String {
+    millis.map_or_else(|| "—".to_string(), fmt_secs)
+}
+
+/// Line chart of the times in solve order, the best one marked.
+fn chart(times: &[u64]) -> String {
+    let top = times.iter().copied().max().unwrap_or(1).max(1) as f64;
+    let step = if times.len() > 1 { (CHART_W - 2.0 * MARGIN) / (times.len() - 1) as f64 } else { 0.0 };
+    let point = |i: usize, ms: u64| {
+        (MARGIN + step * i as f64, CHART_H - MARGIN - (CHART_H - 2.0 * MARGIN) * ms as f64 / top)
+    };
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{CHART_W}" height="{CHART_H}" viewBox="0 0 {CHART_W} {CHART_H}">"#
+    );
+    let (x0, y0, x1) = (MARGIN, CHART_H - MARGIN, CHART_W - MARGIN);
+    let _ = write!(svg, r##"<line x1="{x0}" y1="{y0}" x2="{x1}" y2="{y0}" stroke="#888"/>"##);
+    let _ = write!(svg, r##"<line x1="{x0}" y1="{MARGIN}" x2="{x0}" y2="{y0}" stroke="#888"/>"##);
+    let _ = write!(svg, r#"<text x="4" y="{}" font-size="11">{}</text>"#, MARGIN + 4.0, escape(&fmt_secs(top as u64)));
+    let _ = write!(svg, r#"<text x="4" y="{}" font-size="11">0</text>"#, y0 + 4.0);
+    let points: Vec<String> = times
+        .iter()
+        .enumerate()
+        .map(|(i, &ms)| {
+            let (x, y) = point(i, ms);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+    let _ = write!(svg, r##"<polyline fill="none" stroke="#2a6fdb" stroke-width="2" points="{}"/>"##, points.join(" "));
+    if let Some((i, &ms)) = times.iter().enumerate().min_by_key(|&(_, &ms)| ms) {
+        let (x, y) = point(i, ms);
+        let _ = write!(svg, r##"<circle cx="{x:.1}" cy="{y:.1}" r="4" fill="#1a9c3e"/>"##);
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// The whole report as an HTML document.
+pub fn html(solves: &[Solve]) -> String {
+    let times: Vec<u64> = solves.iter().map(|s| s.millis).collect();
+    let mean = (!times.is_empty()).then(|| times.iter().sum::<u64>() / times.len() as u64);
+    let title = escape(&t!("report-title"));
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>body{{font-family:sans-serif;max-width:760px;margin:2em auto;color:#222}}\
+         table{{border-collapse:collapse}}td,th{{border:1px solid #ccc;padding:3px 8px;text-align:left}}\
+         code{{font-size:1.05em}}</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+
+    let _ = writeln!(out, "<h2>{}</h2>\n<table>", escape(&t!("report-summary")));
+    let rows = [
+        (t!("report-solves"), solves.len().to_string()),
+        (t!("report-best"), time_cell(times.iter().copied().min())),
+        (t!("report-worst"), time_cell(times.iter().copied().max())),
+        (t!("report-mean"), time_cell(mean)),
+        (t!("report-average", n = 5), time_cell(average_of(&times, 5))),
+        (t!("report-best-average", n = 5), time_cell(best_average_of(&times, 5))),
+        (t!("report-average", n = 12), time_cell(average_of(&times, 12))),
+        (t!("report-best-average", n = 12), time_cell(best_average_of(&times, 12))),
+    ];
+    for (label, value) in rows {
+        let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", escape(&label), escape(&value));
+    }
+    out.push_str("</table>\n");
+
+    if !times.is_empty() {
+        let _ = writeln!(out, "<h2>{}</h2>\n{}", escape(&t!("report-chart")), chart(&times));
+    }
+
+    if let Some(best) = solves.iter().min_by_key(|s| s.millis) {
+        let secs = best.millis as f64 / 1000.0;
+        let tps = if secs > 0.0 { best.moves.len() as f64 / secs } else { 0.0 };
+        let _ = writeln!(out, "<h2>{}</h2>", escape(&t!("report-best-solve")));
+        let _ = writeln!(
+            out,
+            "<p>{}</p>",
+            escape(&t!("report-best-line",
+                color = color_name(best.color),
+                time = fmt_secs(best.millis),
+                n = best.moves.len(),
+                tps = format!("{tps:.2}")))
+        );
+        let _ = writeln!(out, "<p>{}: <code>{}</code></p>", escape(&t!("report-scramble")), escape(&best.scramble.to_string()));
+        let _ = writeln!(out, "<p>{}: <code>{}</code></p>", escape(&t!("report-solution")), escape(&best.moves.join(" ")));
+    }
+
+    let _ = writeln!(out, "<h2>{}</h2>", escape(&t!("report-scrambles")));
+    if solves.is_empty() {
+        let _ = writeln!(out, "<p>{}</p>", escape(&t!("report-empty")));
+    } else {
+        let _ = writeln!(
+            out,
+            "<table>\n<tr><th>#</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+            escape(&t!("report-color")),
+            escape(&t!("report-time")),
+            escape(&t!("report-scramble")),
+        );
+        for (i, s) in solves.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td><code>{}</code></td></tr>",
+                i + 1,
+                escape(&color_name(s.color)),
+                escape(&fmt_secs(s.millis)),
+                escape(&s.scramble.to_string()),
+            );
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn save(solves: &[Solve], path: &Path) -> Result<(), Error> {
+    save_file(path, html(solves)).map_err(|e| Error::write(path, e))
+}
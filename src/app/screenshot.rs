@@ -0,0 +1,33 @@
+// src/app/screenshot.rs
+
+//! Window screenshots saved as PNG (the palette's `export png`), bookmarking
+//! the scene (see [`super::bookmark`]).
+
+use std::path::{Path, PathBuf};
+
+use iced::window::Screenshot;
+
+use crate::storage::{data_dir, save_file};
+use super::bookmark::KEYWORD;
+use crate::Error;
+
+/// Where a screenshot goes when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("cube.png")
+}
+
+pub fn save_png(shot: &Screenshot, bookmark: &str, path: &Path) -> Result<(), Error> {
+    let encode_error = |e: png::EncodingError| match e {
+        png::EncodingError::IoError(e) => Error::write(path, e),
+        e => Error::write(path, std::io::Error::other(e)),
+    };
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, shot.size.width, shot.size.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.add_text_chunk(KEYWORD.to_string(), bookmark.to_string()).map_err(encode_error)?;
+    let mut writer = encoder.write_header().map_err(encode_error)?;
+    writer.write_image_data(&shot.bytes).map_err(encode_error)?;
+    writer.finish().map_err(encode_error)?;
+    save_file(path, png).map_err(|e| Error::write(path, e))
+}
@@ -0,0 +1,224 @@
+// src/app/script.rs
+
+//! Solve scripts on disk: the current line of the history tree exported as
+//! Markdown with its timestamps and comments, and scripts read back and
+//! replayed move by move at their recorded pace, each comment shown as its
+//! move comes up. A script can also be stepped to a typed or scrubbed time
+//! instead, to follow a video of the solve.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::cube::{Alg, Cube, Move};
+use crate::Error;
+use crate::logic::script::{fmt_time, SolveScript, Step};
+use crate::storage::{data_dir, save_file};
+use super::history_tree::HistoryTree;
+
+/// Replay timer interval.
+pub const TICK: Duration = Duration::from_millis(33);
+
+/// Pause before a move that has no recorded time.
+const UNTIMED_GAP: Duration = Duration::from_secs(1);
+
+/// Where a script goes when no path is typed.
+pub fn default_path() -> PathBuf {
+    data_dir().join("solve.md")
+}
+
+/// The typed path, or [`default_path`].
+pub fn script_path(input: &str) -> PathBuf {
+    match input.trim() {
+        "" => default_path(),
+        path => PathBuf::from(path),
+    }
+}
+
+/// The line from the tree's root to its current node. The scramble is
+/// written as an algorithm when `scramble` still produces the root state,
+/// and as facelets otherwise.
+pub fn current_script(tree: &HistoryTree, scramble: Option<&Alg>) -> SolveScript {
+    let line = tree.line(tree.current());
+    let start = line[0].cube.clone();
+    let scramble = scramble
+        .filter(|alg| {
+            let mut c = Cube::default();
+            c.apply_alg(alg);
+            c == start
+        })
+        .map_or_else(|| start.to_facelets(), |alg| alg.to_string());
+    let steps = line[1..]
+        .iter()
+        .map(|n| Step { token: n.token.clone(), at: n.at, comment: n.comment.clone() })
+        .collect();
+    SolveScript { scramble, start, note: line[0].comment.clone(), steps }
+}
+
+pub fn save(script: &SolveScript, path: &Path) -> Result<(), Error> {
+    save_file(path, script.to_markdown()).map_err(|e| Error::write(path, e))
+}
+
+pub fn load(path: &Path) -> Result<SolveScript, Error> {
+    let text = std::fs::read_to_string(path).map_err(|e| Error::read(path, e))?;
+    SolveScript::parse(&text)
+}
+
+/// A script being played back.
+#[derive(Debug, Clone)]
+pub struct ScriptReplay {
+    pub script: SolveScript,
+    /// Index of the next step to play.
+    pub next: usize,
+    /// The last comment reached, still on display.
+    pub note: Option<String>,
+    /// When each step is due, after `started`.
+    due: Vec<Duration>,
+    started: Instant,
+}
+
+/// When each step of `script` comes up, after the scramble. Untimed steps
+/// follow the one before after a pause; times never run backwards.
+fn due_times(script: &SolveScript) -> Vec<Duration> {
+    let mut t = Duration::ZERO;
+    script
+        .steps
+        .iter()
+        .map(|s| {
+            t = s.at.map_or(t.saturating_add(UNTIMED_GAP), |at| at.max(t));
+            t
+        })
+        .collect()
+}
+
+impl ScriptReplay {
+    pub fn start(script: SolveScript) -> Self {
+        let due = due_times(&script);
+        let note = Some(script.note.clone()).filter(|n| !n.is_empty());
+        Self { script, next: 0, note, due, started: Instant::now() }
+    }
+
+    /// The next step, if its time has come.
+    pub fn due_step(&self) -> Option<&Step> {
+        let due = *self.due.get(self.next)?;
+        (self.started.elapsed() >= due).then(|| &self.script.steps[self.next])
+    }
+
+    /// Time from the step just played to the next one.
+    pub fn gap(&self) -> Option<Duration> {
+        let next = *self.due.get(self.next)?;
+        Some(next - self.due[self.next.checked_sub(1)?])
+    }
+
+    pub fn finished(&self) -> bool {
+        self.next >= self.script.steps.len()
+    }
+}
+
+/// A script followed against a video: the cube shows the state at
+/// `position` on the video, whose solve starts at `offset`.
+#[derive(Debug, Clone)]
+pub struct VideoSync {
+    pub script: SolveScript,
+    /// Video time of the first moment of the solve (the scramble's end).
+    pub offset: Duration,
+    /// Video time shown.
+    pub position: Duration,
+    pub offset_input: String,
+    pub time_input: String,
+    due: Vec<Duration>,
+}
+
+impl VideoSync {
+    pub fn new(script: SolveScript) -> Self {
+        let due = due_times(&script);
+        Self {
+            script,
+            offset: Duration::ZERO,
+            position: Duration::ZERO,
+            offset_input: fmt_time(Duration::ZERO),
+            time_input: fmt_time(Duration::ZERO),
+            due,
+        }
+    }
+
+    /// Start the solve at video time `offset`, unless the last move would
+    /// then fall past the longest [`Duration`]; `false` if refused.
+    pub fn set_offset(&mut self, offset: Duration) -> bool {
+        let last = self.due.last().copied().unwrap_or_default();
+        let fits = offset.checked_add(last).is_some();
+        if fits {
+            self.offset = offset;
+        }
+        fits
+    }
+
+    /// Video time of the last move.
+    pub fn end(&self) -> Duration {
+        self.offset + self.due.last().copied().unwrap_or_default()
+    }
+
+    /// Steps played by video time `at`.
+    pub fn steps_at(&self, at: Duration) -> usize {
+        match at.checked_sub(self.offset) {
+            Some(into_solve) => self.due.partition_point(|&d| d <= into_solve),
+            None => 0,
+        }
+    }
+
+    /// The state and move tokens after the first `n` steps.
+    pub fn state_after(&self, n: usize) -> (Cube, Vec<String>) {
+        let mut cube = self.script.start.clone();
+        let tokens: Vec<String> = self.script.steps[..n].iter().map(|s| s.token.clone()).collect();
+        for tok in &tokens {
+            if let Some(m) = Move::parse(tok) {
+                cube.apply(m);
+            }
+        }
+        (cube, tokens)
+    }
+
+    /// The history tree for the whole script: its line, with the comments,
+    /// so scrubbing lands on existing nodes.
+    pub fn tree(&self) -> HistoryTree {
+        let mut tree = HistoryTree::new(self.script.start.clone());
+        tree.set_comment(self.script.note.clone());
+        let mut cube = self.script.start.clone();
+        for step in &self.script.steps {
+            if let Some(m) = Move::parse(&step.token) {
+                cube.apply(m);
+            }
+            tree.push(&step.token, &cube);
+            tree.set_comment(step.comment.clone());
+        }
+        tree.jump(0);
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(times: &[Option<u64>]) -> SolveScript {
+        let steps = times
+            .iter()
+            .map(|&at| Step { token: "R".into(), at: at.map(Duration::from_secs), comment: String::new() })
+            .collect();
+        SolveScript { scramble: String::new(), start: Cube::default(), note: String::new(), steps }
+    }
+
+    #[test]
+    fn offsets_past_the_longest_duration_are_refused() {
+        let mut sync = VideoSync::new(script(&[Some(1), Some(2)]));
+        assert!(sync.set_offset(Duration::from_secs(60)));
+        assert_eq!(sync.end(), Duration::from_secs(62));
+        assert!(!sync.set_offset(Duration::MAX));
+        assert_eq!(sync.end(), Duration::from_secs(62));
+    }
+
+    #[test]
+    fn untimed_steps_after_the_longest_time_do_not_overflow() {
+        let sync = VideoSync::new(script(&[Some(u64::MAX), None]));
+        assert_eq!(sync.end(), Duration::MAX);
+    }
+}
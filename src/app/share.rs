@@ -0,0 +1,172 @@
+// src/app/share.rs
+
+//! Share links: `icedcube://state?cube=…&left=…&right=…&alg=…` carries the
+//! facelets, both camera angles, and the algorithm text. The app copies one
+//! to the clipboard on request and opens one passed as the first CLI
+//! argument, so a bug report or lesson can point at an exact setup.
+//! A bare `?cube=…` query string is accepted too.
+//!
+//! Also builds alg.cubing.net links so a session can be replayed in the
+//! usual web viewer.
+
+use std::io;
+use std::process::Command;
+
+use crate::cube::{Alg, Cube};
+use crate::logic::alg::strip_comments;
+use super::recovery::{fmt_view, parse_view};
+use super::{App, ViewUI};
+
+const SCHEME: &str = "icedcube://state";
+
+/// Everything a link can carry; missing fields are left untouched.
+#[derive(Debug, Clone, Default)]
+pub struct SharedState {
+    pub cube: Option<Cube>,
+    pub left: Option<ViewUI>,
+    pub right: Option<ViewUI>,
+    pub alg: Option<String>,
+}
+
+/// Link for the current app state.
+pub fn encode(app: &App) -> String {
+    format!(
+        "{SCHEME}?cube={}&left={}&right={}&alg={}",
+        app.cube.to_facelets(),
+        fmt_view(app.params.left),
+        fmt_view(app.params.right),
+        percent_encode(&app.alg_input),
+    )
+}
+
+/// Shorter link with just the facelets and algorithm, for QR codes.
+pub fn encode_state(app: &App) -> String {
+    format!("{SCHEME}?cube={}&alg={}", app.cube.to_facelets(), percent_encode(&app.alg_input))
+}
+
+/// Parse a link; `None` unless it has a query with at least one known field.
+pub fn decode(link: &str) -> Option<SharedState> {
+    let query = link.trim().strip_prefix(SCHEME).unwrap_or(link.trim()).strip_prefix('?')?;
+    let mut out = SharedState::default();
+    let mut any = false;
+    for pair in query.split('&') {
+        let Some((k, v)) = pair.split_once('=') else { continue };
+        let v = percent_decode(v);
+        match k {
+            "cube" => out.cube = Some(Cube::from_facelets(&v)?),
+            "left" => out.left = Some(parse_view(&v)?),
+            "right" => out.right = Some(parse_view(&v)?),
+            "alg" => out.alg = Some(v),
+            _ => continue,
+        }
+        any = true;
+    }
+    any.then_some(out)
+}
+
+impl SharedState {
+    pub fn apply_to(self, app: &mut App) {
+        if let Some(cube) = self.cube {
+            app.cube = cube;
+            app.history.clear();
+        }
+        if let Some(v) = self.left { app.params.left = v; }
+        if let Some(v) = self.right { app.params.right = v; }
+        if let Some(alg) = self.alg { app.alg_input = alg; }
+    }
+}
+
+// ----- alg.cubing.net -----
+
+const ALG_CUBING: &str = "https://alg.cubing.net/?puzzle=2x2x2";
+
+/// This session as `(setup, moves)`: `setup` reaches the state before
+/// `history`, and `moves` is `history` itself.
+///
+/// The setup is the algorithm box when it reproduces that state (the usual
+/// case after a scramble); otherwise the inverse of an optimal solve, which
+/// needs the solver tables. `None` while those are still loading.
+pub fn session_algs(app: &App) -> Option<(Alg, Alg)> {
+    let moves = Alg::parse(&app.history.join(" ")).ok()?;
+    let mut before = app.cube.clone();
+    before.apply_alg(&moves.inverse());
+
+    let setup = match Alg::parse(&strip_comments(&app.alg_input)) {
+        Ok(alg) if reaches(&alg, &before) => alg,
+        _ if before.is_solved() => Alg::default(),
+        _ => app.solver.as_ref()?.solve(&before)?.inverse(),
+    };
+    Some((setup, moves))
+}
+
+/// alg.cubing.net link playing `moves` from the state `setup` reaches.
+pub fn alg_cubing_link(setup: &Alg, moves: &Alg) -> String {
+    format!("{ALG_CUBING}&setup={}&alg={}", url_alg(setup), url_alg(moves))
+}
+
+/// alg.cubing.net link replaying this session (see [`session_algs`]).
+pub fn alg_cubing_url(app: &App) -> Option<String> {
+    session_algs(app).map(|(setup, moves)| alg_cubing_link(&setup, &moves))
+}
+
+fn reaches(alg: &Alg, target: &Cube) -> bool {
+    let mut c = Cube::default();
+    c.apply_alg(alg);
+    c == *target
+}
+
+// alg.cubing.net's own URL spelling: `_` for spaces, `-` for primes.
+fn url_alg(alg: &Alg) -> String {
+    alg.to_string().replace(' ', "_").replace('\'', "-")
+}
+
+/// Hand `url` to the desktop's default browser without waiting for it.
+pub fn open_in_browser(url: &str) -> io::Result<()> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        // Not `cmd /C start`: cmd would split the URL at each `&`.
+        let mut c = Command::new("rundll32");
+        c.arg("url.dll,FileProtocolHandler");
+        c
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else {
+        Command::new("xdg-open")
+    };
+    cmd.arg(url).spawn().map(drop)
+}
+
+// ----- percent-encoding (just enough for move text) -----
+
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(b as char),
+            b' ' => out.push('+'),
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+/// A query-string value as written: `%XX` escapes decoded, `+` as space.
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                    (Some(hi), Some(lo)) => { out.push(hi << 4 | lo); i += 2; }
+                    _ => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,103 @@
+// src/app/solve_sheet.rs
+
+//! Printable solve sheet for classroom or competition practice: a page of
+//! fresh scrambles, each with its scrambled state as a net and a blank box
+//! for the time, and optionally the solutions printed upside down at the
+//! foot of the page. One standalone HTML file laid out for printing; the
+//! browser's print dialog also saves it as a PDF.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::cube::{Alg, Cube};
+use crate::logic::scramble::{seeded_alg, SCRAMBLE_LEN};
+use crate::logic::solver::DistanceTable;
+use crate::render::markup::escape;
+use crate::render::net;
+use crate::storage::{data_dir, save_file};
+use crate::t;
+use crate::Error;
+
+/// Scrambles on a sheet.
+pub const SCRAMBLES: usize = 5;
+
+/// Sticker size in the nets, in pixels.
+const NET_UNIT: f32 = 14.0;
+
+/// One scramble on the sheet.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub scramble: Alg,
+    pub state: Cube,
+    pub solution: Option<Alg>,
+}
+
+/// Where the sheet goes when no path is given.
+pub fn default_path() -> PathBuf {
+    data_dir().join("solve-sheet.html")
+}
+
+/// Fresh scrambles for a sheet, solved by `solver` when given.
+pub fn entries(solver: Option<&DistanceTable>) -> Vec<Entry> {
+    (0..SCRAMBLES)
+        .map(|_| {
+            let scramble = seeded_alg(SCRAMBLE_LEN, rand::random());
+            let mut state = Cube::default();
+            state.apply_alg(&scramble);
+            let solution = solver.and_then(|s| s.solve(&state));
+            Entry { scramble, state, solution }
+        })
+        .collect()
+}
+
+/// The sheet as an HTML document; solutions are printed if the entries
+/// have them.
+pub fn html(entries: &[Entry]) -> String {
+    let title = escape(&t!("sheet-title"));
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>@page{{margin:1.5cm}}body{{font-family:sans-serif;max-width:760px;margin:1em auto;color:#000}}\
+         table{{border-collapse:collapse;width:100%}}td,th{{border:1px solid #999;padding:6px;text-align:left;vertical-align:middle}}\
+         code{{font-size:1.1em}}.time{{width:140px;height:44px}}\
+         .solutions{{transform:rotate(180deg);margin-top:2em;font-size:.9em}}</style>\n\
+         </head>\n<body>\n<h1>{title}</h1>\n<p>{}: ____________________</p>\n",
+        escape(&t!("sheet-name")),
+    );
+    let _ = writeln!(
+        out,
+        "<table>\n<tr><th>#</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+        escape(&t!("report-scramble")),
+        escape(&t!("sheet-state")),
+        escape(&t!("report-time")),
+    );
+    for (i, e) in entries.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td><code>{}</code></td><td>{}</td><td class=\"time\"></td></tr>",
+            i + 1,
+            escape(&e.scramble.to_string()),
+            net::svg(&e.state, NET_UNIT),
+        );
+    }
+    let _ = writeln!(
+        out,
+        "<tr><th colspan=\"3\">{}</th><td class=\"time\"></td></tr>\n</table>",
+        escape(&t!("report-mean")),
+    );
+    if entries.iter().any(|e| e.solution.is_some()) {
+        let _ = writeln!(out, "<div class=\"solutions\">\n<h2>{}</h2>", escape(&t!("sheet-solutions")));
+        for (i, e) in entries.iter().enumerate() {
+            let solution = e.solution.as_ref().map_or_else(|| "—".to_string(), |s| s.to_string());
+            let _ = writeln!(out, "<p>{}. <code>{}</code></p>", i + 1, escape(&solution));
+        }
+        out.push_str("</div>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn save(entries: &[Entry], path: &Path) -> Result<(), Error> {
+    save_file(path, html(entries)).map_err(|e| Error::write(path, e))
+}
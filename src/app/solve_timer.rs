@@ -0,0 +1,132 @@
+// src/app/solve_timer.rs
+
+//! A plain solve timer for timing a physical cube, and the session's
+//! times. A button drives it, or the global hotkey when the app is behind
+//! another window: the first press starts inspection (see
+//! [`super::inspection`]), the next starts the solve, and the last stops
+//! it. With inspection off, the first press starts the solve.
+
+use std::time::{Duration, Instant};
+
+use super::inspection::{Inspection, InspectionRules, InspectionTick, Penalty};
+
+/// Redraw interval while the timer runs.
+pub const TICK: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, Default)]
+enum State {
+    #[default]
+    Idle,
+    Inspecting(Inspection),
+    Running { started: Instant, penalty: Penalty },
+}
+
+/// One timed attempt: the time on the clock and what inspection cost it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: Duration,
+    pub penalty: Penalty,
+}
+
+impl Attempt {
+    /// The time that counts; `None` for a DNF.
+    pub fn counted(&self) -> Option<Duration> {
+        self.penalty.apply(self.time)
+    }
+
+    pub fn describe(&self) -> String {
+        self.penalty.describe(self.time)
+    }
+}
+
+/// What a press of the timer did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerEvent {
+    Inspecting,
+    Started(Penalty),
+    /// The attempt is over: solved, or a DNF for starting too late.
+    Finished(Attempt),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SolveTimer {
+    state: State,
+    /// Attempts finished this session, oldest first.
+    pub times: Vec<Attempt>,
+}
+
+impl SolveTimer {
+    pub fn running(&self) -> bool {
+        matches!(self.state, State::Running { .. })
+    }
+
+    /// The inspection under way, if any.
+    pub fn inspection(&self) -> Option<&Inspection> {
+        match &self.state {
+            State::Inspecting(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Inspecting or running.
+    pub fn active(&self) -> bool {
+        !matches!(self.state, State::Idle)
+    }
+
+    /// Move on to the next phase of the attempt under `rules`.
+    pub fn press(&mut self, rules: InspectionRules) -> TimerEvent {
+        match self.state {
+            State::Idle if rules.enforced() => {
+                self.state = State::Inspecting(Inspection::new(rules));
+                TimerEvent::Inspecting
+            }
+            State::Idle => self.start(Penalty::None),
+            State::Inspecting(i) => match i.penalty() {
+                Penalty::Dnf => self.finish(Attempt { time: Duration::ZERO, penalty: Penalty::Dnf }),
+                penalty => self.start(penalty),
+            },
+            State::Running { started, penalty } => self.finish(Attempt { time: started.elapsed(), penalty }),
+        }
+    }
+
+    /// What is due during inspection; an overrun ends the attempt as a DNF,
+    /// recorded like any other.
+    pub fn tick(&mut self) -> Option<InspectionTick> {
+        let State::Inspecting(i) = &mut self.state else { return None };
+        let due = i.tick();
+        if due == Some(InspectionTick::Expired) {
+            self.finish(Attempt { time: Duration::ZERO, penalty: Penalty::Dnf });
+        }
+        due
+    }
+
+    fn start(&mut self, penalty: Penalty) -> TimerEvent {
+        self.state = State::Running { started: Instant::now(), penalty };
+        TimerEvent::Started(penalty)
+    }
+
+    fn finish(&mut self, attempt: Attempt) -> TimerEvent {
+        self.state = State::Idle;
+        self.times.push(attempt);
+        TimerEvent::Finished(attempt)
+    }
+
+    /// Time on the clock while running.
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self.state {
+            State::Running { started, .. } => Some(started.elapsed()),
+            _ => None,
+        }
+    }
+
+    /// The last attempt, once the timer is idle again.
+    pub fn last(&self) -> Option<Attempt> {
+        self.times.last().copied().filter(|_| !self.active())
+    }
+
+    /// Mean of the attempts that count, and how many do; DNFs are left out.
+    pub fn mean(&self) -> Option<(Duration, usize)> {
+        let counted: Vec<Duration> = self.times.iter().filter_map(Attempt::counted).collect();
+        (!counted.is_empty()).then(|| (counted.iter().sum::<Duration>() / counted.len() as u32, counted.len()))
+    }
+}
@@ -0,0 +1,75 @@
+// src/app/solver_cache.rs
+
+//! Solver table cache: the distance table is written to the cache dir after
+//! the first build and loaded from there on later startups. Versioning and
+//! checksums live in `DistanceTable::{to_bytes, from_bytes}`; a stale or
+//! damaged file is simply rebuilt.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::logic::solver::{DistanceTable, TABLE_VERSION};
+use crate::storage::{cache_dir, read_only};
+
+/// Where the table came from, for the status line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableOrigin {
+    Cache,
+    Built { millis: u128, saved: bool },
+}
+
+/// Location of the cached distance table.
+pub fn table_path() -> PathBuf {
+    cache_dir().join(format!("distance-v{TABLE_VERSION}.bin"))
+}
+
+/// Load the cached table, or build and cache a fresh one.
+pub fn load_or_build() -> (Arc<DistanceTable>, TableOrigin) {
+    if let Some(table) = load() {
+        return (Arc::new(table), TableOrigin::Cache);
+    }
+    rebuild()
+}
+
+/// Build a fresh table and overwrite the cache.
+pub fn rebuild() -> (Arc<DistanceTable>, TableOrigin) {
+    let start = Instant::now();
+    let table = DistanceTable::build();
+    let millis = start.elapsed().as_millis();
+    // A read-only run keeps nothing, so its table reads as unsaved.
+    let saved = !read_only() && save(&table).is_ok();
+    (Arc::new(table), TableOrigin::Built { millis, saved })
+}
+
+fn load() -> Option<DistanceTable> {
+    let bytes = std::fs::read(table_path()).ok()?;
+    DistanceTable::from_bytes(&bytes).ok()
+}
+
+fn save(table: &DistanceTable) -> std::io::Result<()> {
+    let path = table_path();
+    std::fs::create_dir_all(cache_dir())?;
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, table.to_bytes())?;
+    std::fs::rename(tmp, path)
+}
+
+/// Remove the cached table (next load rebuilds it).
+pub fn clear() {
+    if read_only() {
+        return;
+    }
+    let _ = std::fs::remove_file(table_path());
+}
+
+/// Async wrapper for `Command::perform`.
+pub async fn load_or_build_async() -> (Arc<DistanceTable>, TableOrigin) {
+    load_or_build()
+}
+
+/// Async wrapper for `Command::perform`.
+pub async fn rebuild_async() -> (Arc<DistanceTable>, TableOrigin) {
+    clear();
+    rebuild()
+}
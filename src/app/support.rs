@@ -1,8 +1,15 @@
 // src/app/support.rs
 
-//! Stateless helpers used by `app::update`: angle clamping and move parsing.
+//! Stateless helpers used by `app::update`: angle clamping, time formatting,
+//! editor text and completion, and move parsing (the latter lives in `logic::alg` and is
+//! re-exported here).
 
-use crate::cube::Cube;
+use iced::widget::text_editor;
+
+use crate::cube::Move;
+use crate::logic::library::{complete, NamedAlg};
+
+pub use crate::logic::alg::apply_token;
 
 /// Clamp/snap an angle in degrees to `[0, 360)`; optionally snap to 90°.
 pub fn set_deg(v: f32, snap90: bool) -> f32 {
@@ -16,43 +23,39 @@ pub fn set_deg(v: f32, snap90: bool) -> f32 {
     d
 }
 
-/// Apply a space-separated algorithm to the cube, e.g. `"R U R' U'"`.
-///
-/// Returns `Err` if any token is unknown.
-pub fn apply_alg(cube: &mut Cube, alg: &str) -> Result<(), String> {
-    for tok in alg.split_whitespace() {
-        apply_token(cube, tok)?;
-    }
-    Ok(())
+/// Text of a multi-line editor, without the final newline it always reports.
+pub fn editor_text(content: &text_editor::Content) -> String {
+    let mut text = content.text();
+    text.pop();
+    text
 }
 
-/// Apply a single token like `"R"`, `"R'"`, or `"R2"`.
-pub fn apply_token(cube: &mut Cube, tok: &str) -> Result<(), String> {
-    match tok {
-        "U"  => { cube.mv_u(); Ok(()) }
-        "U'" => { cube.mv_u_prime(); Ok(()) }
-        "U2" => { cube.mv_u2(); Ok(()) }
-
-        "D"  => { cube.mv_d(); Ok(()) }
-        "D'" => { cube.mv_d_prime(); Ok(()) }
-        "D2" => { cube.mv_d2(); Ok(()) }
-
-        "R"  => { cube.mv_r(); Ok(()) }
-        "R'" => { cube.mv_r_prime(); Ok(()) }
-        "R2" => { cube.mv_r2(); Ok(()) }
-
-        "L"  => { cube.mv_l(); Ok(()) }
-        "L'" => { cube.mv_l_prime(); Ok(()) }
-        "L2" => { cube.mv_l2(); Ok(()) }
-
-        "F"  => { cube.mv_f(); Ok(()) }
-        "F'" => { cube.mv_f_prime(); Ok(()) }
-        "F2" => { cube.mv_f2(); Ok(()) }
-
-        "B"  => { cube.mv_b(); Ok(()) }
-        "B'" => { cube.mv_b_prime(); Ok(()) }
-        "B2" => { cube.mv_b2(); Ok(()) }
+/// The name-like word just before the editor's cursor (letters, digits,
+/// hyphens), or "" inside a comment.
+pub fn word_before_cursor(content: &text_editor::Content) -> String {
+    let (line, col) = content.cursor_position();
+    let Some(text) = content.line(line) else { return String::new() };
+    let before = text.get(..col).unwrap_or(&text);
+    if before.contains("//") {
+        return String::new();
+    }
+    let start = before
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .map_or(0, |i| i + before[i..].chars().next().map_or(1, char::len_utf8));
+    before[start..].to_string()
+}
 
-        other => Err(format!("Unknown move: {other}")),
+/// Library algorithms whose name starts with the word being typed; none
+/// while that word already reads as a move.
+pub fn alg_completions(content: &text_editor::Content) -> Vec<&'static NamedAlg> {
+    let word = word_before_cursor(content);
+    if Move::parse(&word).is_some() {
+        return Vec::new();
     }
-}
\ No newline at end of file
+    complete(&word)
+}
+
+/// Milliseconds as seconds with two decimals, e.g. `12.34 s`.
+pub fn fmt_secs(millis: u64) -> String {
+    format!("{:.2} s", millis as f64 / 1000.0)
+}
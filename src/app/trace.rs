@@ -0,0 +1,360 @@
+// src/app/trace.rs
+
+//! Session traces: the state the session started from plus every action
+//! since, timestamped, so a whole session can be saved ("attach your
+//! session trace to the issue") and replayed deterministically.
+//!
+//! Only a serializable subset of [`Msg`] is recorded: turns, the algorithm
+//! and scramble inputs and their buttons, resets, twist fixes, and the
+//! camera. A random scramble is recorded as the seed it drew. Any other
+//! change to the cube (undo, a checkpoint, a drill, an import) is recorded
+//! as the resulting `state`, so replay ends where the session did.
+//!
+//! ```text
+//! # rubics session trace v1
+//! # rubics recovery v1
+//! cube=…
+//! …
+//! ---
+//! 0.000 seed 1234
+//! 0.000 apply-seed
+//! 2.416 move R
+//! 3.050 alg R U R' // sexy\nU2
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::cube::packed::CORNER_SLOTS;
+use crate::cube::Cube;
+use crate::storage::{data_dir, escape, save_file, unescape};
+use crate::Error;
+use super::recovery::Checkpoint;
+use super::{angle_msg, App, Axis, Msg, ViewSide};
+
+/// Replay timer interval.
+pub const TICK: Duration = Duration::from_millis(33);
+
+/// Idle stretches longer than this are shortened on replay.
+const MAX_GAP: Duration = Duration::from_secs(2);
+
+/// A trace this long starts over from the current state.
+const MAX_ACTIONS: usize = 100_000;
+
+const HEADER: &str = "# rubics session trace v1";
+const ACTIONS: &str = "---";
+
+/// Where a trace goes when no path is typed.
+pub fn default_path() -> PathBuf {
+    data_dir().join("session-trace.txt")
+}
+
+/// The typed path, or [`default_path`].
+pub fn trace_path(input: &str) -> PathBuf {
+    match input.trim() {
+        "" => default_path(),
+        path => PathBuf::from(path),
+    }
+}
+
+/// One recorded step of a session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Move(String),
+    /// The algorithm field's full text after an edit.
+    Alg(String),
+    ApplyAlg,
+    Seed(String),
+    ApplySeed,
+    MinDepth(u8),
+    CustomScramble(String),
+    ApplyCustomScramble,
+    ResetCube,
+    ConfirmReset,
+    CancelReset,
+    FixTwist(u8),
+    Angle(ViewSide, Axis, f32),
+    Size(f32),
+    /// The cube and history after a change no other action describes.
+    State { cube: Cube, history: Vec<String> },
+}
+
+impl Action {
+    /// The message that replays this action; `None` for the ones that set
+    /// fields directly (`Alg`, `State`).
+    pub fn to_msg(&self) -> Option<Msg> {
+        Some(match self {
+            Action::Move(tok) => Msg::Move(tok.clone()),
+            Action::ApplyAlg => Msg::ApplyAlg,
+            Action::Seed(s) => Msg::SeedChanged(s.clone()),
+            Action::ApplySeed => Msg::ApplySeed,
+            Action::MinDepth(d) => Msg::MinDepthChanged(*d),
+            Action::CustomScramble(s) => Msg::CustomScrambleChanged(s.clone()),
+            Action::ApplyCustomScramble => Msg::ApplyCustomScramble,
+            Action::ResetCube => Msg::ResetCube,
+            Action::ConfirmReset => Msg::ConfirmReset,
+            Action::CancelReset => Msg::CancelReset,
+            Action::FixTwist(slot) => Msg::FixTwist(*slot),
+            Action::Angle(side, axis, v) => angle_msg(*side, *axis, *v),
+            Action::Size(v) => Msg::SizeChanged(*v),
+            Action::Alg(_) | Action::State { .. } => return None,
+        })
+    }
+
+    /// `name args`, on one line.
+    fn encode(&self) -> String {
+        match self {
+            Action::Move(tok) => format!("move {tok}"),
+            Action::Alg(s) => format!("alg {}", escape(s)),
+            Action::ApplyAlg => "apply-alg".into(),
+            Action::Seed(s) => format!("seed {}", escape(s)),
+            Action::ApplySeed => "apply-seed".into(),
+            Action::MinDepth(d) => format!("min-depth {d}"),
+            Action::CustomScramble(s) => format!("custom-scramble {}", escape(s)),
+            Action::ApplyCustomScramble => "apply-custom-scramble".into(),
+            Action::ResetCube => "reset".into(),
+            Action::ConfirmReset => "confirm-reset".into(),
+            Action::CancelReset => "cancel-reset".into(),
+            Action::FixTwist(slot) => format!("fix-twist {slot}"),
+            Action::Angle(side, axis, v) => format!("angle {side:?} {axis:?} {v}"),
+            Action::Size(v) => format!("size {v}"),
+            Action::State { cube, history } => {
+                format!("state {} {}", cube.to_facelets(), history.join(" ")).trim_end().to_string()
+            }
+        }
+    }
+
+    fn decode(line: &str) -> Option<Action> {
+        let (name, args) = line.split_once(' ').unwrap_or((line, ""));
+        let mut words = args.split_whitespace();
+        Some(match name {
+            "move" => Action::Move(args.to_string()),
+            "alg" => Action::Alg(unescape(args)),
+            "apply-alg" => Action::ApplyAlg,
+            "seed" => Action::Seed(unescape(args)),
+            "apply-seed" => Action::ApplySeed,
+            "min-depth" => Action::MinDepth(args.parse().ok()?),
+            "custom-scramble" => Action::CustomScramble(unescape(args)),
+            "apply-custom-scramble" => Action::ApplyCustomScramble,
+            "reset" => Action::ResetCube,
+            "confirm-reset" => Action::ConfirmReset,
+            "cancel-reset" => Action::CancelReset,
+            "fix-twist" => Action::FixTwist(args.parse().ok().filter(|&slot: &u8| usize::from(slot) < CORNER_SLOTS.len())?),
+            "angle" => {
+                let side = match words.next()? { "Left" => ViewSide::Left, "Right" => ViewSide::Right, _ => return None };
+                let axis = match words.next()? { "Rz" => Axis::Rz, "Rx" => Axis::Rx, "Ry" => Axis::Ry, _ => return None };
+                Action::Angle(side, axis, words.next()?.parse().ok().filter(|v: &f32| v.is_finite())?)
+            }
+            "size" => Action::Size(args.parse().ok().filter(|v: &f32| v.is_finite())?),
+            "state" => Action::State {
+                cube: Cube::from_facelets(words.next()?)?,
+                history: words.map(str::to_string).collect(),
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// How a message shows up in the trace, worked out before it is handled.
+pub enum Traced {
+    Action(Action),
+    /// Edits the algorithm field: record its text if that changed.
+    AlgEdit,
+    /// Draws a random seed: record the seed it drew.
+    Scramble,
+    /// Replaces the whole session: start the trace over.
+    Restart,
+    Other,
+}
+
+impl Traced {
+    pub fn of(msg: &Msg) -> Traced {
+        let action = match msg {
+            Msg::Move(tok) => Action::Move(tok.clone()),
+            Msg::ApplyAlg => Action::ApplyAlg,
+            Msg::SeedChanged(s) => Action::Seed(s.clone()),
+            Msg::ClearSeed => Action::Seed(String::new()),
+            Msg::ApplySeed => Action::ApplySeed,
+            Msg::MinDepthChanged(d) => Action::MinDepth(*d),
+            Msg::CustomScrambleChanged(s) => Action::CustomScramble(s.clone()),
+            Msg::ApplyCustomScramble => Action::ApplyCustomScramble,
+            Msg::ResetCube => Action::ResetCube,
+            Msg::ConfirmReset => Action::ConfirmReset,
+            Msg::CancelReset => Action::CancelReset,
+            Msg::FixTwist(slot) => Action::FixTwist(*slot),
+            Msg::LeftRzChanged(v) => Action::Angle(ViewSide::Left, Axis::Rz, *v),
+            Msg::LeftRxChanged(v) => Action::Angle(ViewSide::Left, Axis::Rx, *v),
+            Msg::LeftRyChanged(v) => Action::Angle(ViewSide::Left, Axis::Ry, *v),
+            Msg::RightRzChanged(v) => Action::Angle(ViewSide::Right, Axis::Rz, *v),
+            Msg::RightRxChanged(v) => Action::Angle(ViewSide::Right, Axis::Rx, *v),
+            Msg::RightRyChanged(v) => Action::Angle(ViewSide::Right, Axis::Ry, *v),
+            Msg::SizeChanged(v) => Action::Size(*v),
+            Msg::AlgEdited(_) | Msg::CompleteAlg(_) | Msg::ClearAlg => return Traced::AlgEdit,
+            Msg::Scramble => return Traced::Scramble,
+            Msg::RestoreSession => return Traced::Restart,
+            _ => return Traced::Other,
+        };
+        Traced::Action(action)
+    }
+}
+
+/// The session so far.
+#[derive(Debug, Clone)]
+pub struct SessionTrace {
+    pub start: Checkpoint,
+    pub actions: Vec<(Duration, Action)>,
+    started: Instant,
+    /// Cube and algorithm text as of the last action, to tell when
+    /// something unrecorded changed them.
+    cube: Cube,
+    alg: String,
+}
+
+impl Default for SessionTrace {
+    fn default() -> Self {
+        Self::start(Checkpoint::default())
+    }
+}
+
+impl SessionTrace {
+    pub fn start(start: Checkpoint) -> Self {
+        let (cube, alg) = (start.cube.clone(), start.alg_input.clone());
+        Self { start, actions: Vec::new(), started: Instant::now(), cube, alg }
+    }
+
+    /// Record `action`, which left the app with `cube`.
+    pub fn push(&mut self, action: Action, cube: &Cube) {
+        if let Action::Alg(s) = &action {
+            self.alg.clone_from(s);
+        }
+        self.cube = cube.clone();
+        self.actions.push((self.started.elapsed(), action));
+    }
+
+    pub fn encode(&self) -> String {
+        let mut out = format!("{HEADER}\n{}{ACTIONS}\n", self.start.encode());
+        for (at, action) in &self.actions {
+            out.push_str(&format!("{:.3} {}\n", at.as_secs_f64(), action.encode()));
+        }
+        out
+    }
+
+    /// Read a trace written by [`encode`](Self::encode).
+    pub fn decode(text: &str) -> Result<SessionTrace, Error> {
+        let bad = |line: usize| Error::BadTrace { line };
+        let mut lines = text.lines().enumerate();
+        if lines.next().map(|(_, l)| l.trim()) != Some(HEADER) {
+            return Err(bad(1));
+        }
+        let head: Vec<&str> = lines.by_ref().map(|(_, l)| l).take_while(|l| l.trim() != ACTIONS).collect();
+        let start = Checkpoint::decode(&head.join("\n")).ok_or(bad(2))?;
+        let mut trace = SessionTrace::start(start);
+        for (n, line) in lines.filter(|(_, l)| !l.trim().is_empty()) {
+            let (at, action) = line.split_once(' ').ok_or(bad(n + 1))?;
+            let at = at.parse().ok().and_then(|s| Duration::try_from_secs_f64(s).ok()).ok_or(bad(n + 1))?;
+            let action = Action::decode(action).ok_or(bad(n + 1))?;
+            trace.actions.push((at, action));
+        }
+        Ok(trace)
+    }
+}
+
+/// Add `traced`, a message `update` just handled, to the app's trace.
+pub fn record(app: &mut App, traced: Traced) {
+    if matches!(traced, Traced::Restart) || app.trace.actions.len() >= MAX_ACTIONS {
+        app.trace = SessionTrace::start(Checkpoint::capture(app));
+        return;
+    }
+    let trace = &mut app.trace;
+    match traced {
+        Traced::Action(action) => trace.push(action, &app.cube),
+        Traced::AlgEdit if app.alg_input != trace.alg => trace.push(Action::Alg(app.alg_input.clone()), &app.cube),
+        Traced::Scramble => {
+            trace.push(Action::Seed(app.seed_input.clone()), &app.cube);
+            trace.push(Action::ApplySeed, &app.cube);
+        }
+        _ => {}
+    }
+    if app.cube != trace.cube {
+        trace.push(Action::State { cube: app.cube.clone(), history: app.history.clone() }, &app.cube);
+    }
+}
+
+pub fn save(trace: &SessionTrace, path: &Path) -> Result<(), Error> {
+    save_file(path, trace.encode()).map_err(|e| Error::write(path, e))
+}
+
+/// Whether `text` looks like a saved trace, going by its header.
+pub fn is_trace(text: &str) -> bool {
+    text.trim_start().starts_with(HEADER)
+}
+
+pub fn load(path: &Path) -> Result<SessionTrace, Error> {
+    let text = std::fs::read_to_string(path).map_err(|e| Error::read(path, e))?;
+    SessionTrace::decode(&text)
+}
+
+/// A trace being played back.
+#[derive(Debug, Clone)]
+pub struct TraceReplay {
+    pub actions: Vec<Action>,
+    /// Index of the next action to play.
+    pub next: usize,
+    /// When each action is due, after `started`.
+    due: Vec<Duration>,
+    started: Instant,
+}
+
+impl TraceReplay {
+    pub fn start(trace: SessionTrace) -> Self {
+        let mut last = Duration::ZERO;
+        let mut t = Duration::ZERO;
+        let (due, actions) = trace
+            .actions
+            .into_iter()
+            .map(|(at, action)| {
+                t += at.saturating_sub(last).min(MAX_GAP);
+                last = at;
+                (t, action)
+            })
+            .unzip();
+        Self { actions, next: 0, due, started: Instant::now() }
+    }
+
+    /// The next action, if its time has come.
+    pub fn due_action(&self) -> Option<&Action> {
+        let due = *self.due.get(self.next)?;
+        (self.started.elapsed() >= due).then(|| &self.actions[self.next])
+    }
+
+    pub fn finished(&self) -> bool {
+        self.next >= self.actions.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty trace's text followed by `actions`.
+    fn trace(actions: &str) -> Result<SessionTrace, Error> {
+        let head = SessionTrace::start(Checkpoint::default()).encode();
+        SessionTrace::decode(&format!("{head}{actions}"))
+    }
+
+    #[test]
+    fn actions_round_trip() {
+        let t = trace("0.500 move R\n1.250 fix-twist 7\n2.000 size 300\n").unwrap();
+        assert_eq!(t.actions.len(), 3);
+        assert_eq!(t.actions[0].0, Duration::from_millis(500));
+        assert!(matches!(t.actions[1].1, Action::FixTwist(7)));
+        assert_eq!(SessionTrace::decode(&t.encode()).unwrap().actions.len(), 3);
+    }
+
+    #[test]
+    fn bad_lines_are_refused() {
+        for line in ["1e30 move R", "-1 move R", "NaN move R", "1 fix-twist 8", "1 fix-twist 255", "1 size NaN", "1 angle Left Rz inf"] {
+            assert!(matches!(trace(&format!("{line}\n")), Err(Error::BadTrace { .. })), "{line}");
+        }
+    }
+}
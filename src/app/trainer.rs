@@ -0,0 +1,190 @@
+// src/app/trainer.rs
+
+//! Color-neutrality trainer: each drill scrambles the cube and assigns a
+//! random first-face color. Inspection follows the session's rules (see
+//! [`super::inspection`]); the clock starts on the first turn. The solve
+//! only counts if the assigned color is the first face completed. Per-color
+//! times, with any +2, are kept in a small stats file so weak colors stand
+//! out over time.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use rand::seq::SliceRandom;
+use rand::{thread_rng, RngCore};
+
+use crate::cube::{Alg, Col, Cube};
+use crate::logic::scramble::seeded_alg;
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use super::inspection::{Inspection, InspectionRules, InspectionTick, Penalty};
+
+const HEADER: &str = "rubics neutrality stats v1";
+
+/// Scramble length for drills.
+const DRILL_LEN: usize = 15;
+
+#[derive(Debug, Clone, Copy)]
+enum Phase {
+    /// From the scramble being shown until the first turn.
+    Inspecting(Inspection),
+    Solving { started: Instant, penalty: Penalty },
+}
+
+/// One running drill.
+#[derive(Debug, Clone, Copy)]
+pub struct Drill {
+    /// Face color the user must build first.
+    pub color: Col,
+    /// First face color completed during this solve.
+    pub first_face: Option<Col>,
+    phase: Phase,
+}
+
+/// What a turn did to the running drill.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DrillEvent {
+    /// Still solving.
+    Running,
+    /// Solved after building the assigned color first: the time on the
+    /// clock and any +2 from inspection.
+    Solved { time: Duration, penalty: Penalty },
+    /// Solved, but another color was built first.
+    WrongColor { built: Col },
+    /// The first turn came after the DNF threshold.
+    Dnf,
+}
+
+impl Drill {
+    /// Start a drill inspected under `rules`: a scramble with no face
+    /// already built, plus a color.
+    pub fn start(rules: InspectionRules) -> (Drill, Alg) {
+        let mut rng = thread_rng();
+        let alg = loop {
+            let alg = seeded_alg(DRILL_LEN, rng.next_u64());
+            let mut c = Cube::default();
+            c.apply_alg(&alg);
+            if !Col::ALL.iter().any(|&col| c.has_face(col)) {
+                break alg;
+            }
+        };
+        let color = *Col::ALL.choose(&mut rng).unwrap();
+        (Drill { color, first_face: None, phase: Phase::Inspecting(Inspection::new(rules)) }, alg)
+    }
+
+    /// Still inspecting, with a call or an overrun to come.
+    pub fn inspecting(&self) -> bool {
+        matches!(self.phase, Phase::Inspecting(i) if i.pending())
+    }
+
+    /// What is due during inspection, once it is.
+    pub fn inspection_tick(&mut self) -> Option<InspectionTick> {
+        match &mut self.phase {
+            Phase::Inspecting(i) => i.tick(),
+            Phase::Solving { .. } => None,
+        }
+    }
+
+    /// Update after a user turn on `cube`.
+    pub fn after_move(&mut self, cube: &Cube) -> DrillEvent {
+        if let Phase::Inspecting(i) = self.phase {
+            let penalty = i.penalty();
+            if penalty == Penalty::Dnf {
+                return DrillEvent::Dnf;
+            }
+            self.phase = Phase::Solving { started: Instant::now(), penalty };
+        }
+        let Phase::Solving { started, penalty } = self.phase else { return DrillEvent::Running };
+        if self.first_face.is_none() {
+            // Prefer the assigned color if one turn completed several faces.
+            self.first_face = std::iter::once(self.color)
+                .chain(Col::ALL)
+                .find(|&c| cube.has_face(c));
+        }
+        if !cube.is_solved() {
+            return DrillEvent::Running;
+        }
+        match self.first_face {
+            Some(c) if c != self.color => DrillEvent::WrongColor { built: c },
+            _ => DrillEvent::Solved { time: started.elapsed(), penalty },
+        }
+    }
+}
+
+/// Per-color solve counts and total times.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NeutralityStats {
+    counts: [u32; 6],
+    total_ms: [u64; 6],
+}
+
+impl NeutralityStats {
+    pub fn record(&mut self, color: Col, millis: u64) {
+        self.counts[color as usize] += 1;
+        self.total_ms[color as usize] += millis;
+    }
+
+    pub fn count(&self, color: Col) -> u32 {
+        self.counts[color as usize]
+    }
+
+    /// Mean solve time in milliseconds, if any solves were recorded.
+    pub fn average_ms(&self, color: Col) -> Option<u64> {
+        let n = self.counts[color as usize];
+        (n > 0).then(|| self.total_ms[color as usize] / u64::from(n))
+    }
+
+    /// Color with the highest average (needs at least two colors with solves).
+    pub fn slowest(&self) -> Option<Col> {
+        let timed: Vec<(Col, u64)> = Col::ALL
+            .iter()
+            .filter_map(|&c| self.average_ms(c).map(|a| (c, a)))
+            .collect();
+        if timed.len() < 2 {
+            return None;
+        }
+        timed.into_iter().max_by_key(|&(_, a)| a).map(|(c, _)| c)
+    }
+
+    pub fn encode(&self) -> String {
+        let pairs: Vec<(&str, String)> = Col::ALL
+            .iter()
+            .map(|&c| (color_key(c), format!("{} {}", self.counts[c as usize], self.total_ms[c as usize])))
+            .collect();
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let mut stats = Self::default();
+        for c in Col::ALL {
+            let Some((n, ms)) = kv.get(color_key(c)).and_then(|v| v.split_once(' ')) else { continue };
+            if let (Ok(n), Ok(ms)) = (n.parse(), ms.parse()) {
+                stats.counts[c as usize] = n;
+                stats.total_ms[c as usize] = ms;
+            }
+        }
+        stats
+    }
+}
+
+fn color_key(c: Col) -> &'static str {
+    match c {
+        Col::W => "white", Col::Y => "yellow", Col::G => "green",
+        Col::B => "blue", Col::O => "orange", Col::R => "red",
+    }
+}
+
+/// Location of the neutrality stats file.
+pub fn stats_path() -> PathBuf {
+    data_dir().join("neutrality.txt")
+}
+
+pub fn load() -> NeutralityStats {
+    std::fs::read_to_string(stats_path())
+        .map(|t| NeutralityStats::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(stats: &NeutralityStats) -> std::io::Result<()> {
+    save_file(&stats_path(), stats.encode())
+}
@@ -2,17 +2,83 @@
 
 //! Central update loop: handles all `Msg` variants and mutates `App` state.
 
-use iced::Command;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use iced::{Command, window};
+use iced::widget::text_editor::{Action, Edit};
+use iced::widget::text_input;
 use rand::{thread_rng, RngCore};
 
-use crate::cube::Cube;
-use crate::logic::scramble::scramble_with_seed;
+use crate::cube::{Alg, Cube, FaceId, Move};
+use crate::events::CubeEvent;
+use crate::audio::{self, Cue};
+use crate::controllers::{self, Input};
+use crate::gamepad::{self, Event as PadEvent};
+use crate::hotkey;
+use crate::leaderboard::{self, Submission};
+use crate::obs;
+use crate::render::camera::{face_angles, mirror_angles};
+use crate::render::Radians;
+use crate::render::quat::Quat;
+use crate::render::touch::Gesture;
+use crate::storage;
+use crate::t;
+use crate::Error;
+use crate::i18n;
+use crate::logic::scramble::{parse_seed, seeded_alg, SCRAMBLE_LEN};
+use crate::logic::describe::{color_name, describe};
+use crate::logic::first_face::analyze;
+use crate::logic::subgroup::explore;
+use crate::logic::diagnose::fix_twist;
+use crate::logic::diff::{diff, transform};
+use crate::logic::memo::{corner_memo, LetterScheme};
+use crate::logic::verify::{compare, parse_facelets};
+use crate::logic::alg::{parse_alg, strip_comments};
+use crate::cube::perm::CORNER_NAMES;
+use crate::logic::console;
+use crate::logic::script::{fmt_time, parse_time};
+use crate::logic::phases::breakdown;
+use crate::logic::kpuzzle;
+use crate::logic::regrip::MoveCosts;
+use crate::logic::solver::{fmt_generators, parse_generators, reachable, DistanceTable};
 
-use super::{App, Msg};
-use super::support::{set_deg, apply_alg, apply_token};
+use super::{App, Axis, Msg, NamedCheckpoint, Snapshot, ViewSide, ViewUI, LinkMode, angle_msg};
+use super::support::{set_deg, apply_token, editor_text, fmt_secs, word_before_cursor};
+use super::recovery::{self, Checkpoint};
+use super::debug;
+use super::window_state;
+use super::motion;
+use super::usage::{self, MoveUsage};
+use super::ab_test::AbTest;
+use super::playback::TurnInFlight;
+use super::glide::{self, CameraGlide};
+use super::alg_watch::AlgWatch;
+use super::attract::{self, Attract, Step};
+use super::classroom::Classroom;
+use super::watch::{self, Watch};
+use super::daily::{self, DailyRun, Day};
+use super::profiles::{self, Profile};
+use super::random_walk;
+use super::solver_cache::{self, TableOrigin};
+use super::trainer::{self, Drill, DrillEvent};
+use super::inspection::{InspectionTick, Penalty};
+use super::solve_timer::TimerEvent;
+use super::share;
+use super::metronome::BeatScore;
+use super::notation::{self, NotationDrill, Outcome};
+use super::bld::{self, MemoDrill};
+use super::compare::{parse_pattern, DiffPair, DiffReport};
+use super::history_tree::HistoryTree;
+use super::script::{self, ScriptReplay, VideoSync};
+use super::trace::{self, Action as TraceAction, SessionTrace, TraceReplay};
+use super::{anki, backup, blueprint, bookmark, forum, palette, report, screenshot, solve_sheet};
+use super::dropped::DropKind;
 
-/// Default length for generated scrambles.
-const SCRAMBLE_LEN: usize = 15;
+/// Seeds tried before giving up on the minimum-depth filter (depth 11 is
+/// roughly 1 in 1400 positions).
+const MAX_REROLLS: usize = 20_000;
 
 // --------- helpers ----------------------------------------------------------
 
@@ -24,39 +90,863 @@ fn wrap_deg(mut v: f32) -> f32 {
     v
 }
 
-// When the link is ON, compute the opposite-facing right view from left,
-// matching the D/L/B “opposite” feel (rotate ~180° about X).
+// Drive the right view from the left one according to the link mode.
+// `Opposite` matches the D/L/B “opposite” feel (rotate ~180° about X).
 fn sync_right_from_left(app: &mut App) {
-    if app.link_opposite {
-        app.params.right.rz = app.params.left.rz;
-        app.params.right.ry = app.params.left.ry;
-        app.params.right.rx = wrap_deg(app.params.left.rx + 180.0);
-    }
+    let l = app.params.left;
+    let (rz, rx, ry) = match app.link_mode {
+        LinkMode::Off      => return,
+        LinkMode::Opposite => (l.rz, l.rx + 180.0, l.ry),
+        LinkMode::Mirror   => (l.rz, l.rx, l.ry + 180.0),
+        LinkMode::Follow   => (l.rz, l.rx, l.ry),
+        LinkMode::Offset   => {
+            let o = app.link_offset;
+            (l.rz + o.rz, l.rx + o.rx, l.ry + o.ry)
+        }
+    };
+    app.params.right = ViewUI { rz: wrap_deg(rz), rx: wrap_deg(rx), ry: wrap_deg(ry) };
 }
 
 // Apply a text algorithm to the cube; update status accordingly.
 fn try_apply_alg(app: &mut App, alg: &str) {
     if alg.trim().is_empty() {
-        app.status = "Nothing to apply. Enter an algorithm or a seed.".into();
+        app.status = t!("nothing-to-apply");
+        return;
+    }
+    // Token by token so the history matches whatever actually got applied.
+    for tok in alg.split_whitespace() {
+        if let Err(e) = turn(app, tok) {
+            app.status = t!("alg-error", error = e);
+            return;
+        }
+        if drill_after_move(app) || daily_after_move(app) {
+            return;
+        }
+    }
+    app.status = t!("alg-applied");
+}
+
+// Apply the algorithm field to the cube.
+fn apply_alg_input(app: &mut App) {
+    let alg_text = strip_comments(&app.alg_input);
+    try_apply_alg(app, &alg_text);
+}
+
+// Check the watched algorithm file; if it was saved, rewind to where the
+// watch began and apply it.
+fn alg_watch_tick(app: &mut App) {
+    let Some(watch) = app.alg_watch.as_mut() else { return };
+    let Some(read) = watch.changed() else { return };
+    match read {
+        Ok(text) => {
+            let shown = watch.path.display().to_string();
+            let Snapshot { cube, history } = watch.base.clone();
+            app.cube = cube;
+            app.history = history;
+            app.confirm_reset = false;
+            app.alg_input = text.trim_end().to_string();
+            apply_alg_input(app);
+            if app.status == t!("alg-applied") {
+                app.status = t!("alg-watch-applied", path = shown);
+            }
+        }
+        Err(e) => app.status = e.to_string(),
+    }
+}
+
+// Apply one user turn: record it and notify the hooks (including a solve).
+fn turn(app: &mut App, tok: &str) -> Result<(), Error> {
+    let was_solved = app.cube.is_solved();
+    let m = apply_token(&mut app.cube, tok)?;
+    app.history.push(tok.to_string());
+    app.history_tree.push(tok, &app.cube);
+    app.hooks.emit(&CubeEvent::MoveApplied(m), &app.cube);
+    if !was_solved && app.cube.is_solved() {
+        app.hooks.emit(&CubeEvent::Solved { moves: app.history.len() }, &app.cube);
+    }
+    Ok(())
+}
+
+// A fresh scramble `alg` was just applied to a solved cube.
+fn scrambled(app: &mut App, alg: Alg) {
+    app.scramble = Some(alg.clone());
+    app.hooks.emit(&CubeEvent::Scrambled(alg), &app.cube);
+}
+
+// End the beat drill, if any, with its summary in the status line.
+fn finish_beat_drill(app: &mut App) {
+    let Some(score) = app.metronome.drill.take() else { return };
+    app.status = if score.turns() == 0 {
+        t!("beat-drill-empty")
+    } else {
+        t!("beat-drill-result",
+            accuracy = format!("{:.0}", score.accuracy(app.metronome.period())),
+            n = score.turns(),
+            offset = format!("{:.0}", score.mean_abs_ms()),
+            bias = format!("{:+.0}", score.bias_ms()))
+    };
+}
+
+// How long a least-cost / restricted solve may search before giving up.
+const SOLVE_BUDGET: Duration = Duration::from_secs(10);
+
+// Least-cost / restricted solve, run off the UI thread (extreme costs can
+// take a while).
+async fn solve_restricted(
+    table: Arc<DistanceTable>,
+    cube: Cube,
+    costs: MoveCosts,
+    faces: Vec<FaceId>,
+) -> (Cube, Result<Alg, Error>) {
+    let alg = table.solve_restricted(&cube, &costs, &faces, SOLVE_BUDGET);
+    (cube, alg)
+}
+
+// Console scripts run off the UI thread too: an experiment may loop over
+// thousands of scrambles.
+async fn run_console_script(src: String, cube: Cube, solver: Option<Arc<DistanceTable>>) -> console::Run {
+    console::run(&src, cube, solver)
+}
+
+// Start the console script on the current cube, unless one is running.
+fn run_console(app: &mut App) -> Command<Msg> {
+    let src = editor_text(&app.console_editor);
+    if app.console_run.is_some() || src.trim().is_empty() {
+        return Command::none();
+    }
+    app.console_run = Some(app.cube.clone());
+    app.status = t!("console-running");
+    Command::perform(run_console_script(src, app.cube.clone(), app.solver.clone()), Msg::ConsoleFinished)
+}
+
+// Run the palette's command line through the same messages as the
+// buttons. The palette closes on success and stays open (showing the
+// commands, or the error) otherwise.
+fn run_palette(app: &mut App) -> Command<Msg> {
+    let Some(line) = app.palette.take() else { return Command::none() };
+    match palette::parse(&line) {
+        Ok(msgs) if msgs.is_empty() => {
+            app.status = t!("palette-help", commands = palette::COMMANDS.join(" · "));
+            app.palette = Some(String::new());
+            Command::none()
+        }
+        Ok(msgs) => dispatch(app, msgs),
+        Err(e) => {
+            app.status = e;
+            app.palette = Some(line);
+            Command::none()
+        }
+    }
+}
+
+// Handle `msgs` in order, as if each had been sent on its own.
+fn dispatch(app: &mut App, msgs: Vec<Msg>) -> Command<Msg> {
+    Command::batch(msgs.into_iter().map(|msg| update(app, msg)).collect::<Vec<_>>())
+}
+
+// A controller button: fill in the binding being learned, or run the
+// command bound to it.
+fn controller_input(app: &mut App, input: Input) -> Command<Msg> {
+    if app.learning_binding {
+        app.learning_binding = false;
+        app.binding_input = input.to_string();
+        app.status = t!("binding-learned", input = input.to_string());
+        return Command::none();
+    }
+    let Some(line) = app.bindings.command(input).map(str::to_string) else {
+        app.status = t!("binding-unbound", input = input.to_string());
+        return Command::none();
+    };
+    match palette::parse(&line) {
+        Ok(msgs) => dispatch(app, msgs),
+        Err(e) => {
+            app.status = e;
+            Command::none()
+        }
+    }
+}
+
+// A pad event: a face button turns the cube; a stick starts orbiting.
+fn gamepad_event(app: &mut App, event: PadEvent) -> Command<Msg> {
+    if let PadEvent::Unavailable(e) = event {
+        app.gamepad_error = Some(e);
+        return Command::none();
+    }
+    let was_orbiting = app.pad.orbiting();
+    let turned = app.pad.apply(&app.gamepad, event);
+    if !was_orbiting && app.pad.orbiting() {
+        app.pad_frame = Some(Instant::now());
+    }
+    match turned {
+        Some(m) => update(app, Msg::Move(m.to_string())),
+        None => Command::none(),
+    }
+}
+
+// Orbit the views by the sticks for the time since the last frame, through
+// the same angle messages as the sliders.
+fn gamepad_frame(app: &mut App) -> Command<Msg> {
+    let now = Instant::now();
+    let dt = app.pad_frame.map_or(gamepad::FRAME, |t| now - t).min(4 * app.motion.frame(gamepad::FRAME));
+    app.pad_frame = Some(now);
+    let [left, right] = app.pad.orbit(&app.gamepad, dt);
+    let mut msgs = Vec::new();
+    for (side, view, (yaw, pitch)) in [(ViewSide::Left, app.params.left, left), (ViewSide::Right, app.params.right, right)] {
+        if yaw != 0.0 {
+            msgs.push(angle_msg(side, Axis::Rz, view.rz + yaw));
+        }
+        if pitch != 0.0 {
+            msgs.push(angle_msg(side, Axis::Rx, view.rx + pitch));
+        }
+    }
+    dispatch(app, msgs)
+}
+
+// Move the cameras as `set` does, each view that moves gliding there from
+// where it is drawn now.
+fn glide_cameras(app: &mut App, set: impl FnOnce(&mut App)) {
+    let from = [ViewSide::Left, ViewSide::Right].map(|side| glide::drawn(app, side));
+    let before = [app.params.left, app.params.right];
+    set(app);
+    let after = [app.params.left, app.params.right];
+    let reduced = app.motion.reduced;
+    for (i, glide) in app.glides.iter_mut().enumerate() {
+        if after[i] != before[i] {
+            *glide = (!reduced).then(|| CameraGlide::new(from[i], after[i]));
+        }
+    }
+}
+
+// Turn a view by a trackball step, the sliders taking the nearest angles.
+// Snapping is left out: it would undo every small step.
+fn roll(app: &mut App, side: ViewSide, turn: Quat) {
+    let v = ViewUI::from_orientation(turn * app.params.view(side).orientation());
+    let v = ViewUI { rz: wrap_deg(v.rz), rx: wrap_deg(v.rx), ry: wrap_deg(v.ry) };
+    set_view(app, side, v);
+    app.angle_edit = None;
+}
+
+// Put one view at `v`: the left one drives a linked right view, and moving
+// the right one by hand unlinks it.
+fn set_view(app: &mut App, side: ViewSide, v: ViewUI) {
+    match side {
+        ViewSide::Left => { app.params.left = v; sync_right_from_left(app); }
+        ViewSide::Right => { app.params.right = v; app.link_mode = LinkMode::Off; }
+    }
+}
+
+// A camera pose `(rz, rx, ry)`, mirrored in the mirrored layout.
+fn pose(app: &App, (rz, rx, ry): (f32, f32, f32)) -> ViewUI {
+    let (rz, rx, ry) = if app.mirrored { mirror_angles(rz, rx, ry) } else { (rz, rx, ry) };
+    ViewUI { rz, rx, ry }
+}
+
+// Swipes turn like typed moves; two-finger drags orbit through the slider
+// messages and pinches scale the cube within the size slider's range.
+fn touch_gesture(app: &mut App, gesture: Gesture) -> Command<Msg> {
+    match gesture {
+        Gesture::Turn(m) => update(app, Msg::Move(m.to_string())),
+        Gesture::Pinch { right, yaw, pitch, zoom } => {
+            // The mirrored layout draws the right view on the left.
+            let right = right != (app.mirrored && app.right_window.is_none());
+            let side = if right { ViewSide::Right } else { ViewSide::Left };
+            let view = app.params.view(side);
+            let size = (app.params.size * zoom).clamp(16.0, 40.0);
+            dispatch(app, vec![
+                angle_msg(side, Axis::Rz, view.rz + yaw),
+                angle_msg(side, Axis::Rx, view.rx + pitch),
+                Msg::SizeChanged(size),
+            ])
+        }
+    }
+}
+
+// Persist the motion settings, reporting a failure on the status line.
+fn motion_changed(app: &mut App) {
+    if let Err(e) = motion::save(&app.motion) {
+        app.status = t!("motion-save-failed", error = e);
+    }
+}
+
+// Persist the move usage, reporting a failure on the status line.
+fn usage_changed(app: &mut App) {
+    if let Err(e) = usage::save(&app.move_usage) {
+        app.status = t!("usage-save-failed", error = e);
+    }
+}
+
+// Grab the key the hotkey settings now ask for and persist them, reporting
+// a failure on the status line.
+fn hotkey_changed(app: &mut App) {
+    app.hotkey_error = hotkey::sync(&mut app.hotkey_grab, app.hotkey).err();
+    if let Some(e) = &app.hotkey_error {
+        app.status = e.clone();
+    }
+    if let Err(e) = hotkey::save(&app.hotkey) {
+        app.status = t!("hotkey-save-failed", error = e);
+    }
+}
+
+// Persist the gamepad settings, reporting a failure on the status line.
+fn gamepad_changed(app: &mut App) {
+    if let Err(e) = gamepad::save(&app.gamepad) {
+        app.status = t!("gamepad-save-failed", error = e);
+    }
+}
+
+// Persist the controller bindings, reporting a failure on the status line.
+fn bindings_changed(app: &mut App) {
+    if let Err(e) = controllers::save(&app.bindings) {
+        app.status = t!("bindings-save-failed", error = e);
+    }
+}
+
+// Show a finished script's output; if it changed the cube, the cube shown
+// becomes the one it left.
+fn console_finished(app: &mut App, run: console::Run) {
+    let Some(start) = app.console_run.take() else { return };
+    app.console_output.extend(run.output);
+    if run.cube != start {
+        app.cube = run.cube;
+        app.history.clear();
+        history_moved(app);
+    }
+    app.status = match run.result {
+        Ok(()) => t!("console-done"),
+        Err(e) => {
+            app.console_output.push(e.to_string());
+            e.to_string()
+        }
+    };
+    let excess = app.console_output.len().saturating_sub(console::MAX_OUTPUT);
+    app.console_output.drain(..excess);
+}
+
+// Push edited sound settings to the audio thread and persist them.
+fn sound_changed(app: &mut App) {
+    if let Some(p) = &app.player {
+        p.set_settings(app.sound);
+    }
+    if let Err(e) = audio::save(&app.sound) {
+        app.status = t!("sound-save-failed", error = e);
+    }
+}
+
+// Feed a user turn to today's challenge; true once it is solved (the
+// status line then holds the time).
+fn daily_after_move(app: &mut App) -> bool {
+    let Some(run) = app.daily.as_mut() else { return false };
+    let Some(millis) = run.after_move(&app.cube) else { return false };
+    let day = run.day;
+    app.daily = None;
+    if !app.daily_log.record(day, millis) {
+        app.status = t!("daily-solved-again", time = fmt_secs(millis));
+        return true;
+    }
+    app.status = t!("daily-solved", time = fmt_secs(millis), streak = app.daily_log.streak(day));
+    if let Err(e) = daily::save(&app.daily_log) {
+        app.status = t!("daily-save-failed", error = e);
+    }
+    if app.leaderboard.active() {
+        app.leaderboard_queue.0.push(Submission { day, millis });
+        queue_changed(app);
+    }
+    true
+}
+
+// Set the cube up for the A/B test's next trial.
+fn ab_trial(app: &mut App) {
+    let Some(test) = &app.ab_test else { return };
+    let setup = test.setup.clone();
+    app.status = t!("ab-trial",
+        n = test.done() + 1,
+        total = test.total(),
+        side = test.side.to_string(),
+        alg = test.alg(test.side).to_string());
+    app.cube = test.setup_cube();
+    app.history.clear();
+    app.confirm_reset = false;
+    scrambled(app, setup);
+}
+
+// Feed a user turn to the A/B test: a solve ends the trial and sets up
+// the next, or reports the test once all are timed.
+fn ab_after_move(app: &mut App) {
+    let Some(test) = app.ab_test.as_mut() else { return };
+    let Some(time) = test.after_move(&app.cube) else { return };
+    let timed = t!("ab-timed", side = test.side.other().to_string(), time = fmt_secs(time.as_millis() as u64));
+    if test.finished() {
+        app.status = format!("{timed} — {}", t!("ab-finished"));
+        return;
+    }
+    ab_trial(app);
+    app.status = format!("{timed} — {}", app.status);
+}
+
+// Make `profile` the active one and load its settings and logs. Drills
+// and timed runs belong to the profile they started in, so they stop.
+fn switch_profile(app: &mut App, profile: Profile) {
+    let saved = profile.activate();
+    app.load_profile();
+    if let Some(p) = &app.player {
+        p.set_settings(app.sound);
+    }
+    app.drill = None;
+    app.daily = None;
+    app.memo_drill = None;
+    app.notation = None;
+    app.board = None;
+    app.status = match saved {
+        Ok(()) => t!("profile-switched", name = profile.to_string()),
+        Err(e) => t!("profile-save-failed", error = e),
+    };
+    app.profile = profile;
+    app.profiles = profiles::list();
+}
+
+// Put a demo back as the next visitor should find it: a solved cube, no
+// drill or challenge running, and the profile's own settings.
+fn reset_demo(app: &mut App) {
+    app.cube = Cube::default();
+    app.history.clear();
+    app.trash = None;
+    app.confirm_reset = false;
+    app.drill = None;
+    app.daily = None;
+    app.memo_drill = None;
+    app.notation = None;
+    app.classroom = None;
+    app.round = None;
+    app.ab_test = None;
+    app.in_flight = None;
+    app.load_profile();
+    if let Some(p) = &app.player {
+        p.set_settings(app.sound);
+    }
+    app.status = t!("demo-reset");
+}
+
+// Persist the leaderboard settings, reporting a failure on the status line.
+fn leaderboard_changed(app: &mut App) {
+    if let Err(e) = leaderboard::save(&app.leaderboard) {
+        app.status = t!("leaderboard-save-failed", error = e);
+    }
+}
+
+// Persist the queue of unsent times, reporting a failure on the status line.
+fn queue_changed(app: &mut App) {
+    if let Err(e) = leaderboard::save_queue(&app.leaderboard_queue) {
+        app.status = t!("leaderboard-save-failed", error = e);
+    }
+}
+
+// Send the queued times and fetch today's board, unless a sync is running
+// or the user hasn't opted in.
+fn sync_leaderboard(app: &mut App) -> Command<Msg> {
+    if app.syncing || !app.leaderboard.active() {
+        return Command::none();
+    }
+    app.syncing = true;
+    let sync = leaderboard::sync(app.leaderboard.clone(), app.leaderboard_queue.0.clone(), Day::today());
+    Command::perform(sync, Msg::LeaderboardSynced)
+}
+
+// Persist the OBS settings, reporting a failure on the status line.
+fn obs_changed(app: &mut App) {
+    if let Err(e) = obs::save(&app.obs) {
+        app.status = t!("obs-save-failed", error = e);
+    }
+}
+
+// Tell OBS a timed solve ended, if the trigger is on.
+fn trigger_obs(app: &App) -> Command<Msg> {
+    if !app.obs.active() {
+        return Command::none();
+    }
+    Command::perform(obs::trigger(app.obs.clone()), Msg::ObsTriggered)
+}
+
+// Feed a user turn to the running drill; true once the drill has ended
+// (the status line then holds its result).
+fn drill_after_move(app: &mut App) -> bool {
+    let Some(drill) = app.drill.as_mut() else { return false };
+    let target = color_name(drill.color);
+    match drill.after_move(&app.cube) {
+        DrillEvent::Running => return false,
+        DrillEvent::Solved { time, penalty } => {
+            let millis = penalty.apply(time).unwrap_or(time).as_millis() as u64;
+            app.neutrality.record(drill.color, millis);
+            app.session_solves.push(report::Solve {
+                color: drill.color,
+                scramble: app.scramble.clone().unwrap_or_default(),
+                moves: app.history.clone(),
+                times: app.history_tree.line(app.history_tree.current())[1..]
+                    .iter()
+                    .map(|n| n.at)
+                    .collect::<Option<_>>()
+                    .unwrap_or_default(),
+                millis,
+            });
+            app.status = t!("drill-solved", color = target, time = penalty.describe(time));
+            if let Err(e) = trainer::save(&app.neutrality) {
+                app.status = t!("drill-save-failed", error = e);
+            }
+        }
+        DrillEvent::WrongColor { built } => {
+            app.status = t!("drill-wrong-color", built = color_name(built), target = target);
+        }
+        DrillEvent::Dnf => app.status = t!("drill-dnf", color = target),
+    }
+    app.drill = None;
+    true
+}
+
+
+// Compare the chosen pair of states and find the shortest algorithm
+// between them.
+fn compare_states(app: &mut App) {
+    let slot = |i: usize| app.diff_slots[i].clone().ok_or_else(|| t!("diff-slot-empty", slot = if i == 0 { "A" } else { "B" }));
+    let pair = match app.diff_pair {
+        DiffPair::CurrentA => slot(0).map(|a| (app.cube.clone(), a)),
+        DiffPair::CurrentB => slot(1).map(|b| (app.cube.clone(), b)),
+        DiffPair::AB => slot(0).and_then(|a| slot(1).map(|b| (a, b))),
+        DiffPair::CurrentPattern => parse_pattern(&app.diff_pattern).map(|p| (app.cube.clone(), p)),
+    };
+    let (from, to) = match pair {
+        Ok(pair) => pair,
+        Err(e) => {
+            app.status = e;
+            return;
+        }
+    };
+    let Some(table) = app.solver.clone() else {
+        app.status = t!("solver-not-ready");
+        return;
+    };
+    let d = diff(&from, &to);
+    app.status = if d.is_empty() { t!("diff-identical") } else { t!("diff-stickers", n = d.stickers.len()) };
+    let alg = transform(&from, &to, &table);
+    app.state_diff = Some(DiffReport { from, to, diff: d, alg });
+}
+
+// After undo/redo or a jump in the history tree: the cube changed without
+// a turn, so drop what was worked out for the old one.
+fn history_moved(app: &mut App) {
+    app.confirm_reset = false;
+    app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+    app.verify_result = None;
+    app.status = t!("history-at", n = app.history.len());
+}
+
+// Read the solve script at the typed path, set up its scramble, and start
+// replaying it.
+fn import_script(app: &mut App) {
+    let path = script::script_path(&app.script_path);
+    let solve = match script::load(&path) {
+        Ok(solve) => solve,
+        Err(e) => {
+            app.status = e.to_string();
+            return;
+        }
+    };
+    app.cube = solve.start.clone();
+    app.history.clear();
+    app.scramble = parse_alg(&solve.scramble).ok();
+    // Start the tree from the script's state now, so its note lands on the root.
+    app.history_tree = HistoryTree::new(app.cube.clone());
+    app.history_tree.set_comment(solve.note.clone());
+    history_moved(app);
+    app.status = t!("script-replaying", n = solve.steps.len());
+    app.script_replay = Some(ScriptReplay::start(solve));
+}
+
+// Load the solve script at the typed path for stepping along with a video:
+// the whole line goes into the history tree, and the cube starts at the
+// scramble.
+fn sync_script_to_video(app: &mut App) {
+    let path = script::script_path(&app.script_path);
+    let sync = match script::load(&path) {
+        Ok(solve) => VideoSync::new(solve),
+        Err(e) => {
+            app.status = e.to_string();
+            return;
+        }
+    };
+    app.script_replay = None;
+    app.scramble = parse_alg(&sync.script.scramble).ok();
+    app.history_tree = sync.tree();
+    app.status = t!("video-sync-loaded", n = sync.script.steps.len(), end = fmt_time(sync.end()));
+    app.video_sync = Some(sync);
+    seek_video(app, Duration::ZERO);
+}
+
+// Show the synced script's state at video time `at`, with the comment of
+// the last move played.
+fn seek_video(app: &mut App, at: Duration) {
+    let Some(sync) = app.video_sync.as_mut() else { return };
+    sync.position = at;
+    sync.time_input = fmt_time(at);
+    let n = sync.steps_at(at);
+    let (cube, history) = sync.state_after(n);
+    let comment = n.checked_sub(1).map(|i| sync.script.steps[i].comment.clone()).filter(|c| !c.is_empty());
+    let total = sync.script.steps.len();
+    app.cube = cube;
+    app.history = history;
+    history_moved(app);
+    app.status = match comment {
+        Some(comment) => t!("video-sync-at-comment", time = fmt_time(at), n = n, total = total, comment = comment),
+        None => t!("video-sync-at", time = fmt_time(at), n = n, total = total),
+    };
+}
+
+// Play every step of the replay whose time has come, carrying its comment
+// over to the history; stop if the user has turned the cube meanwhile.
+fn script_replay_tick(app: &mut App) {
+    while let Some(replay) = app.script_replay.as_ref() {
+        if app.history.len() != replay.next {
+            app.script_replay = None;
+            app.status = t!("script-replay-stopped");
+            return;
+        }
+        let Some(step) = replay.due_step().cloned() else { break };
+        if let Err(e) = turn(app, &step.token) {
+            app.status = e.to_string();
+            app.script_replay = None;
+            return;
+        }
+        app.history_tree.set_comment(step.comment.clone());
+        let Some(replay) = app.script_replay.as_mut() else { return };
+        replay.next += 1;
+        let gap = replay.gap();
+        if let Some(m) = Move::parse(&step.token) {
+            in_flight(app, m, gap, script::TICK);
+        }
+        let Some(replay) = app.script_replay.as_mut() else { return };
+        if !step.comment.is_empty() {
+            replay.note = Some(step.comment);
+        }
+    }
+    if app.script_replay.as_ref().is_some_and(|r| r.finished()) {
+        app.script_replay = None;
+        app.status = t!("script-replay-done", n = app.history.len());
+    }
+}
+
+// Make the watched solve's next turn if it's due; a move made by hand in
+// between ends the show.
+fn watch_tick(app: &mut App) {
+    let Some(watch) = app.watch.as_mut() else { return };
+    if !watch.in_step(app.history.len()) {
+        app.watch = None;
+        return;
+    }
+    let gap = app.watch_pace.gap();
+    let Some(m) = watch.due(gap) else { return };
+    let finished = watch.finished();
+    if let Err(e) = turn(app, &m.to_string()) {
+        app.status = e.to_string();
+        app.watch = None;
         return;
     }
-    match apply_alg(&mut app.cube, alg) {
-        Ok(()) => app.status = "Applied algorithm.".into(),
-        Err(e) => app.status = format!("Algorithm error: {e}"),
+    in_flight(app, m, Some(gap), watch::TICK);
+    if finished {
+        app.status = t!("watch-done", n = app.watch.as_ref().map_or(0, |w| w.shown));
+    }
+}
+
+// Read the session trace at the typed path, go back to the state it
+// started from, and start replaying it.
+fn replay_trace(app: &mut App) {
+    let path = trace::trace_path(&app.trace_path);
+    let session = match trace::load(&path) {
+        Ok(session) => session,
+        Err(e) => {
+            app.status = e.to_string();
+            return;
+        }
+    };
+    session.start.clone().restore_into(app);
+    app.history_tree = HistoryTree::default();
+    app.script_replay = None;
+    history_moved(app);
+    app.status = t!("trace-replaying", n = session.actions.len());
+    // The replay records itself into a fresh trace.
+    app.trace = SessionTrace::start(session.start.clone());
+    app.trace_replay = Some(TraceReplay::start(session));
+}
+
+// Play every action of the trace replay whose time has come.
+fn trace_replay_tick(app: &mut App) -> Command<Msg> {
+    let mut commands = Vec::new();
+    while let Some(action) = app.trace_replay.as_ref().and_then(|r| r.due_action()).cloned() {
+        match (action.to_msg(), &action) {
+            (Some(msg), _) => commands.push(update(app, msg)),
+            (None, TraceAction::Alg(s)) => app.alg_input.clone_from(s),
+            (None, TraceAction::State { cube, history }) => {
+                app.cube = cube.clone();
+                app.history.clone_from(history);
+                history_moved(app);
+            }
+            (None, _) => {}
+        }
+        app.trace.push(action, &app.cube);
+        let Some(replay) = app.trace_replay.as_mut() else { break };
+        replay.next += 1;
+    }
+    if let Some(replay) = app.trace_replay.as_ref().filter(|r| r.finished()) {
+        app.status = t!("trace-replay-done", n = replay.actions.len());
+        app.trace_replay = None;
+    }
+    Command::batch(commands)
+}
+
+// Persist the letter scheme; a stale memo no longer matches its letters.
+fn save_scheme(app: &mut App) {
+    app.memo = None;
+    if let Err(e) = bld::save_scheme(&app.scheme) {
+        app.status = t!("scheme-save-failed", error = e);
+    }
+}
+
+// Score the open notation prompt, persist the stats, and show the next one.
+fn notation_answered(app: &mut App, outcome: Outcome) {
+    let Some(drill) = app.notation.as_mut() else { return };
+    let target = drill.target;
+    app.notation_stats.record(drill.quiz, target, outcome);
+    drill.next();
+    let keys = notation::keys_for(target);
+    app.status = match outcome {
+        Outcome::Correct { millis } => t!("notation-correct", mv = target.to_string(), ms = millis),
+        Outcome::Wrong { typed } => t!("notation-wrong", typed = typed.to_string(), mv = target.to_string(), keys = keys),
+        Outcome::TimedOut => t!("notation-timeout", mv = target.to_string(), keys = keys),
+    };
+    if let Err(e) = notation::save(&app.notation_stats) {
+        app.status = t!("notation-save-failed", error = e);
     }
 }
 
+// Stop the walk and keep the views where the orbit left them.
+// Show playback turn `m` turning, unless motion is reduced.
+fn in_flight(app: &mut App, m: Move, gap: Option<Duration>, frame: Duration) {
+    app.in_flight = (!app.motion.reduced).then(|| TurnInFlight::new(m, app.cube.clone(), gap, frame));
+}
+
+fn stop_random_walk(app: &mut App) {
+    let walk = &mut app.random_walk;
+    walk.stop();
+    for v in [&mut app.params.left, &mut app.params.right] {
+        v.rz = set_deg(v.rz + walk.orbit, false);
+    }
+    walk.orbit = 0.0;
+}
+
 // Produce a deterministic scramble from a seed and apply it.
 // Also stores the textual sequence into `alg_input` for visibility.
 fn apply_seeded_scramble(app: &mut App, seed: u64) {
-    let seq = scramble_with_seed(SCRAMBLE_LEN, seed);
+    let alg = seeded_alg(SCRAMBLE_LEN, seed);
     app.cube = Cube::default();
-    match apply_alg(&mut app.cube, &seq) {
-        Ok(()) => {
-            app.alg_input = seq.clone();
-            app.status = format!("Applied seed = {seed}: {seq}");
+    app.history.clear();
+    app.confirm_reset = false;
+    app.cube.apply_alg(&alg);
+    app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+    let seq = alg.to_string();
+    app.status = t!("seed-applied", seed = seed, seq = seq);
+    app.alg_input = seq;
+    scrambled(app, alg);
+}
+
+/// Open a file dropped onto the window, or named on the command line, with
+/// the importer for its kind.
+pub fn open_file(app: &mut App, path: &Path) {
+    let shown = path.display().to_string();
+    let read = |path: &Path| std::fs::read_to_string(path).map_err(|e| Error::read(path, e));
+    match DropKind::of(path) {
+        None => app.status = t!("drop-unknown", path = shown),
+        Some(DropKind::Bookmark) => {
+            app.status = match bookmark::read(path) {
+                Ok(Some(scene)) => {
+                    scene.apply_to(app);
+                    t!("bookmark-opened", path = shown)
+                }
+                Ok(None) => t!("bookmark-none", path = shown),
+                Err(e) => e.to_string(),
+            };
+        }
+        Some(DropKind::KPuzzle) => {
+            app.kpuzzle_input = shown;
+            import_kpuzzle(app);
+        }
+        Some(DropKind::Alg) => match read(path) {
+            Ok(text) => {
+                app.alg_input = text.trim_end().to_string();
+                app.status = t!("drop-alg-loaded", path = shown);
+            }
+            Err(e) => app.status = e.to_string(),
+        },
+        Some(DropKind::Text) => match read(path) {
+            Ok(text) if trace::is_trace(&text) => {
+                app.trace_path = shown;
+                replay_trace(app);
+            }
+            Ok(text) => match parse_facelets(&text) {
+                Ok(cube) => {
+                    app.cube = cube;
+                    app.history.clear();
+                    app.confirm_reset = false;
+                    app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+                    app.verify_result = None;
+                    app.status = t!("drop-facelets-loaded", path = shown);
+                }
+                Err(e) => app.status = e.to_string(),
+            },
+            Err(e) => app.status = e.to_string(),
+        },
+    }
+}
+
+// Load cubing.js KPuzzle JSON, pasted or (if it isn't an object) read
+// from the file path in the input box.
+fn import_kpuzzle(app: &mut App) {
+    let input = app.kpuzzle_input.trim();
+    let json = if input.starts_with('{') {
+        input.to_string()
+    } else {
+        match std::fs::read_to_string(input) {
+            Ok(s) => s,
+            Err(e) => {
+                app.status = Error::read(input, e).to_string();
+                return;
+            }
         }
-        Err(e) => app.status = format!("Seeded scramble error: {e}"),
+    };
+    match kpuzzle::import(&json) {
+        Ok(import) => {
+            if let Some(cube) = import.cube {
+                app.cube = cube;
+                app.history.clear();
+                app.confirm_reset = false;
+                app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+                app.verify_result = None;
+            }
+            if let Some(alg) = import.alg {
+                app.alg_input = alg;
+            }
+            app.status = t!("kpuzzle-imported");
+        }
+        Err(e) => app.status = e.to_string(),
+    }
+}
+
+// Reset to solved, backing up an unsolved state into the trash slot.
+fn reset_cube(app: &mut App) {
+    let cube = std::mem::take(&mut app.cube);
+    let history = std::mem::take(&mut app.history);
+    app.confirm_reset = false;
+    if cube.is_solved() {
+        app.status = t!("cube-reset");
+    } else {
+        app.trash = Some(Snapshot { cube, history });
+        app.status = t!("cube-reset-kept");
     }
 }
 
@@ -64,6 +954,23 @@ fn apply_seeded_scramble(app: &mut App, seed: u64) {
 
 /// Handle one `Msg` and update `app` state. Returns any follow-up command.
 pub fn update(app: &mut App, msg: Msg) -> Command<Msg> {
+    // Any slider move supersedes a half-typed angle draft.
+    if matches!(msg,
+        Msg::LeftRzChanged(_) | Msg::LeftRxChanged(_) | Msg::LeftRyChanged(_)
+        | Msg::RightRzChanged(_) | Msg::RightRxChanged(_) | Msg::RightRyChanged(_))
+    {
+        app.angle_edit = None;
+    }
+    // Controllers count as someone at the stand too.
+    if let Some(demo) = &mut app.demo
+        && matches!(msg, Msg::DemoActivity | Msg::ControllerInput(_) | Msg::Gamepad(PadEvent::Button(..) | PadEvent::Axis(..)))
+    {
+        demo.touched(Instant::now());
+        if app.attract.take().is_some() {
+            reset_demo(app);
+        }
+    }
+
     match msg {
         // ----- cameras (left, drives right when linked) ----------
         Msg::LeftRzChanged(v) => { app.params.left.rz = set_deg(v, app.snap90); sync_right_from_left(app); }
@@ -71,96 +978,1195 @@ pub fn update(app: &mut App, msg: Msg) -> Command<Msg> {
         Msg::LeftRyChanged(v) => { app.params.left.ry = set_deg(v, app.snap90); sync_right_from_left(app); }
 
         // Right edits break the link (one-way sync to avoid ping-pong loops)
-        Msg::RightRzChanged(v) => { app.link_opposite = false; app.params.right.rz = set_deg(v, app.snap90); }
-        Msg::RightRxChanged(v) => { app.link_opposite = false; app.params.right.rx = set_deg(v, app.snap90); }
-        Msg::RightRyChanged(v) => { app.link_opposite = false; app.params.right.ry = set_deg(v, app.snap90); }
+        Msg::RightRzChanged(v) => { app.link_mode = LinkMode::Off; app.params.right.rz = set_deg(v, app.snap90); }
+        Msg::RightRxChanged(v) => { app.link_mode = LinkMode::Off; app.params.right.rx = set_deg(v, app.snap90); }
+        Msg::RightRyChanged(v) => { app.link_mode = LinkMode::Off; app.params.right.ry = set_deg(v, app.snap90); }
 
         Msg::SizeChanged(v) => { app.params.size = v; }
 
+        // Typed angles: keep the draft while editing, commit on Enter
+        Msg::AngleTextChanged(side, axis, s) => { app.angle_edit = Some((side, axis, s)); }
+        Msg::AngleTextSubmit => {
+            if let Some((side, axis, s)) = app.angle_edit.take() {
+                match s.trim().trim_end_matches('°').parse::<f32>() {
+                    Ok(v) if v.is_finite() => {
+                        return update(app, angle_msg(side, axis, v.clamp(0.0, 360.0)));
+                    }
+                    _ => app.status = t!("invalid-angle", input = s),
+                }
+            }
+        }
+        Msg::AngleNudge(side, axis, d) => {
+            app.angle_edit = None;
+            let cur = app.params.view(side).get(axis);
+            return update(app, angle_msg(side, axis, cur + d));
+        }
+
+        // Lighting
+        Msg::LightAzimuthChanged(v)   => { app.render.light.azimuth = Radians::from_degrees(wrap_deg(v)); }
+        Msg::LightElevationChanged(v) => { app.render.light.elevation = Radians::from_degrees(v.clamp(-90.0, 90.0)); }
+        Msg::LightIntensityChanged(v) => { app.render.light.intensity = v.clamp(0.0, 1.0); }
+
+        // Per-view render mode
+        Msg::LeftModeChanged(m)  => { app.params.left_mode = m; }
+        Msg::RightModeChanged(m) => { app.params.right_mode = m; }
+        Msg::CameraModelChanged(model) => { app.render.camera = model; }
+        Msg::Roll(side, turn) => roll(app, side, turn),
+
+        // Canvas overlays
+        Msg::ToggleGrid(on) => { app.render.show_grid = on; }
+        Msg::ToggleAxes(on) => { app.render.show_axes = on; }
+        Msg::ToggleHeat(on) => { app.render.show_heat = on; }
+        Msg::ToggleLetters(on) => { app.render.show_letters = on; }
+
+        // ----- piece tracking ----------------------------------------------
+        Msg::TrackPiece(p) => { app.tracked = Some(p); }
+        Msg::StopTracking => { app.tracked = None; }
+        Msg::ToggleTrail(on) => { app.show_trail = on; }
+        Msg::ScaleFactorChanged(s) => { app.render.scale_factor = s.factor(); }
+        Msg::ToggleProfiler => { app.render.show_profiler = !app.render.show_profiler; }
+        Msg::BackendChanged(b) => { app.render.backend = b; }
+
         // Presets / reset cameras
-        Msg::PresetLeft => {
+        Msg::PresetLeft => glide_cameras(app, |app| {
             // Left (cube 1) at 0,0,0
-            app.params.left  = super::ViewUI { rz: 0.0,  rx: 0.0,   ry: 0.0 };
+            app.params.left  = pose(app, (0.0, 0.0, 0.0));
             // If linked, drive right from left
             sync_right_from_left(app);
-        }
-        Msg::PresetRight => {
+        }),
+        Msg::PresetRight => glide_cameras(app, |app| {
             // Right (cube 2) at 90,180,0 (your requested pose)
-            app.params.right = super::ViewUI { rz: 90.0, rx: 180.0, ry: 0.0 };
+            app.params.right = pose(app, (90.0, 180.0, 0.0));
             // Manual edit → unlink (so this doesn't bounce back)
-            app.link_opposite = false;
+            app.link_mode = LinkMode::Off;
+        }),
+        Msg::NamedPreset(side, preset) => {
+            glide_cameras(app, |app| {
+                let v = pose(app, preset.angles());
+                set_view(app, side, v);
+            });
+            app.angle_edit = None;
+        }
+        // A face-on view needs no mirroring: its mirror image is the same
+        // face, turned over.
+        Msg::LookAtFace(side, face) => {
+            glide_cameras(app, |app| {
+                let (rz, rx, ry) = face_angles(face);
+                set_view(app, side, ViewUI { rz, rx, ry });
+            });
+            app.angle_edit = None;
         }
-        Msg::ResetCameras => {
-            app.params.left  = super::ViewUI { rz: 0.0,  rx: 0.0,   ry: 0.0 };
-            app.params.right = super::ViewUI { rz: 90.0, rx: 180.0, ry: 0.0 };
-            // Keep current link flag as-is
-            if app.link_opposite { sync_right_from_left(app); }
+        Msg::ResetCameras => glide_cameras(app, |app| {
+            app.params.left  = pose(app, (0.0, 0.0, 0.0));
+            app.params.right = pose(app, (90.0, 180.0, 0.0));
+            // Keep current link mode as-is
+            sync_right_from_left(app);
+        }),
+        Msg::GlideFrame => {
+            let targets = [app.params.left, app.params.right];
+            for (glide, target) in app.glides.iter_mut().zip(targets) {
+                if glide.is_some_and(|g| g.pose(target).is_none()) {
+                    *glide = None;
+                }
+            }
         }
 
         // Snap 90°
         Msg::ToggleSnap90(on) => { app.snap90 = on; }
+        // Switching layouts mirrors what both views show.
+        Msg::ToggleReducedMotion(on) => {
+            app.motion.reduced = on;
+            motion_changed(app);
+        }
+        Msg::FpsCapChanged(cap) => {
+            app.motion.fps_cap = cap;
+            motion_changed(app);
+        }
+        Msg::ToggleMirrored(on) => {
+            app.mirrored = on;
+            let mirror = |v: ViewUI| {
+                let (rz, rx, ry) = mirror_angles(v.rz, v.rx, v.ry);
+                ViewUI { rz, rx, ry }
+            };
+            app.params.left = mirror(app.params.left);
+            app.params.right = mirror(app.params.right);
+            app.angle_edit = None;
+        }
 
-        // Opposite-right link toggle
-        Msg::ToggleOpposite(on) => {
-            app.link_opposite = on;
-            if on { sync_right_from_left(app); }
+        // Right-view link mode
+        Msg::LinkModeChanged(mode) => {
+            if mode == LinkMode::Offset {
+                let (l, r) = (app.params.left, app.params.right);
+                app.link_offset = ViewUI { rz: r.rz - l.rz, rx: r.rx - l.rx, ry: r.ry - l.ry };
+            }
+            app.link_mode = mode;
+            sync_right_from_left(app);
         }
 
         // ----- algorithm / seed actions ------------------------------------
 
-        Msg::AlgChanged(s) => { app.alg_input = s; }
+        // Enter breaks the line; Ctrl+Enter applies.
+        Msg::AlgEdited(Action::Edit(Edit::Enter)) if app.modifiers.command() => apply_alg_input(app),
+        Msg::AlgEdited(action) => {
+            app.alg_editor.perform(action);
+            app.alg_input = editor_text(&app.alg_editor);
+        }
+        Msg::CompleteAlg(alg) => {
+            // Replace the typed name with the moves, keeping the name as a
+            // comment so the text still says what they are.
+            for _ in word_before_cursor(&app.alg_editor).chars() {
+                app.alg_editor.perform(Action::Edit(Edit::Backspace));
+            }
+            let expansion = format!("{} // {}\n", alg.moves, alg.name);
+            app.alg_editor.perform(Action::Edit(Edit::Paste(Arc::new(expansion))));
+            app.alg_input = editor_text(&app.alg_editor);
+        }
 
-        Msg::ApplyAlg => {
-            // Own the text so we can mutably borrow `app` below
-            let alg_text = app.alg_input.trim().to_owned();
+        Msg::ApplyAlg => apply_alg_input(app),
+        Msg::ClearAlg => {
+            app.alg_input.clear();
+            app.scramble_depth = None;
+        }
 
-            if !alg_text.is_empty() {
-                // Now safe: `alg_text` doesn't borrow from `app`
-                try_apply_alg(app, &alg_text);
-            } else if let Ok(seed) = app.seed_input.trim().parse::<u64>() {
-                apply_seeded_scramble(app, seed);
-            } else {
-                app.status = "Nothing to apply. Enter an algorithm or a seed.".into();
+        Msg::AlgWatchPathChanged(s) => { app.alg_watch_path = s; }
+        Msg::StartAlgWatch => {
+            let path = std::path::PathBuf::from(app.alg_watch_path.trim());
+            let base = Snapshot { cube: app.cube.clone(), history: app.history.clone() };
+            match AlgWatch::new(path, base) {
+                Ok(watch) => {
+                    app.alg_watch = Some(watch);
+                    alg_watch_tick(app);
+                }
+                Err(e) => app.status = e.to_string(),
             }
         }
+        Msg::StopAlgWatch => {
+            app.alg_watch = None;
+            app.status = t!("alg-watch-stopped");
+        }
+        Msg::AlgWatchTick => alg_watch_tick(app),
+
+        Msg::ShowAlgOrder => match parse_alg(&app.alg_input) {
+            Err(e) => app.status = e.to_string(),
+            // An open popup just switches to the new algorithm.
+            Ok(alg) => match &mut app.order_window {
+                Some((_, shown)) => *shown = alg,
+                None => {
+                    let (id, spawn) = window::spawn(window::Settings {
+                        size: iced::Size::new(460.0, 220.0),
+                        exit_on_close_request: false,
+                        ..Default::default()
+                    });
+                    app.order_window = Some((id, alg));
+                    return spawn;
+                }
+            },
+        },
 
         Msg::ResetCube => {
+            // Unsolved with moves on record → ask first.
+            if !app.cube.is_solved() && !app.history.is_empty() {
+                app.confirm_reset = true;
+                app.status = t!("reset-confirm", n = app.history.len());
+            } else {
+                reset_cube(app);
+            }
+        }
+        Msg::ConfirmReset => { reset_cube(app); }
+        Msg::CancelReset => {
+            app.confirm_reset = false;
+            app.status = t!("reset-cancelled");
+        }
+        Msg::AnnounceState => {
+            app.status = describe(&app.cube, app.render.show_letters.then_some(&app.scheme));
+            // Also to stdout, where terminal screen readers can pick it up.
+            println!("{}", app.status);
+        }
+        Msg::StartDrill => {
+            let (drill, alg) = Drill::start(app.inspection);
+            app.daily = None;
+            app.ab_test = None;
+            app.cube = Cube::default();
+            app.cube.apply_alg(&alg);
+            app.history.clear();
+            app.confirm_reset = false;
+            app.status = t!("drill-started", color = color_name(drill.color));
+            app.drill = Some(drill);
+            scrambled(app, alg);
+        }
+        Msg::StopDrill => { app.drill = None; }
+        Msg::AbCaseChanged(case) => {
+            app.ab_case = Some(case);
+            app.ab_inputs[0] = case.moves.to_string();
+        }
+        Msg::AbAlgChanged(side, text) => { app.ab_inputs[side as usize] = text; }
+        Msg::AbTrialsChanged(trials) => { app.ab_trials = trials; }
+        Msg::StartAbTest => {
+            let Some(case) = app.ab_case else {
+                app.status = t!("ab-no-case");
+                return Command::none();
+            };
+            let [a, b] = &app.ab_inputs;
+            match AbTest::new(case, [a, b], app.ab_trials) {
+                Ok(test) => {
+                    app.drill = None;
+                    app.daily = None;
+                    app.ab_test = Some(test);
+                    ab_trial(app);
+                }
+                Err(e) => app.status = e,
+            }
+        }
+        Msg::RedoAbTrial => {
+            if let Some(test) = app.ab_test.as_mut().filter(|t| !t.finished()) {
+                test.restart();
+                ab_trial(app);
+            }
+        }
+        Msg::StopAbTest => { app.ab_test = None; }
+        Msg::ToggleTimer => match app.timer.press(app.inspection) {
+            TimerEvent::Inspecting => app.status = t!("timer-inspecting", secs = app.inspection.secs.0),
+            TimerEvent::Started(Penalty::PlusTwo) => app.status = t!("timer-started-plus-two"),
+            TimerEvent::Started(_) => app.status = t!("timer-started"),
+            TimerEvent::Finished(attempt) => {
+                app.status = t!("timer-stopped", time = attempt.describe());
+                if attempt.counted().is_some() {
+                    return trigger_obs(app);
+                }
+            }
+        },
+        Msg::InspectionModeChanged(mode) => { app.inspection.mode = mode; }
+        Msg::InspectionSecsChanged(secs) => { app.inspection.secs = secs; }
+        Msg::Hotkey(key) if app.hotkey.enabled && key == app.hotkey.key => return update(app, Msg::ToggleTimer),
+        Msg::Hotkey(_) => {}
+        Msg::ToggleHotkey(on) => {
+            app.hotkey.enabled = on;
+            hotkey_changed(app);
+        }
+        Msg::HotkeyChanged(key) => {
+            app.hotkey.key = key;
+            hotkey_changed(app);
+        }
+        Msg::ProfileSelected(profile) if profile != app.profile => switch_profile(app, profile),
+        Msg::ProfileInputChanged(name) => { app.profile_input = name; }
+        Msg::CreateProfile => match Profile::new(&app.profile_input) {
+            Some(profile) => {
+                app.profile_input.clear();
+                switch_profile(app, profile);
+            }
+            None => app.status = t!("profile-bad-name", max = profiles::NAME_MAX),
+        },
+        Msg::ExportPng(_)
+        | Msg::ExportScript
+        | Msg::SaveTrace
+        | Msg::ExportReport(_)
+        | Msg::ExportBlueprint(_)
+        | Msg::ExportSolveSheet(_)
+        | Msg::ExportAnki(_)
+        | Msg::ExportBackup(_)
+            if storage::read_only() => app.status = t!("demo-no-export"),
+        Msg::ExportBackup(path) => {
+            app.status = match backup::save(&path) {
+                Ok(n) => t!("backup-exported", n = n, path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::RestoreBackup(_) if storage::read_only() => app.status = t!("demo-read-only"),
+        Msg::RestoreBackup(path) => {
+            app.status = t!("backup-restore-confirm", path = path.display().to_string(), name = app.profile.to_string());
+            app.pending_restore = Some(path);
+        }
+        Msg::ConfirmRestore => {
+            if let Some(path) = app.pending_restore.take() {
+                app.status = match backup::restore(&path) {
+                    Ok(n) => {
+                        app.load_profile();
+                        if let Some(p) = &app.player {
+                            p.set_settings(app.sound);
+                        }
+                        app.drill = None;
+                        app.daily = None;
+                        app.memo_drill = None;
+                        app.notation = None;
+                        t!("backup-restored", n = n, path = path.display().to_string())
+                    }
+                    Err(e) => e.to_string(),
+                };
+            }
+        }
+        Msg::CancelRestore => {
+            app.pending_restore = None;
+            app.status = t!("backup-restore-cancelled");
+        }
+        Msg::DemoTick if app.demo.as_mut().is_some_and(|d| d.timed_out(Instant::now())) => reset_demo(app),
+        Msg::DemoTick if app.attract.is_none() && app.demo.is_some_and(|d| d.attract_due(Instant::now())) => {
+            reset_demo(app);
+            app.attract = Some(Attract::start());
+            app.status = t!("attract-hint");
+        }
+        Msg::DemoActivity | Msg::DemoTick => {}
+        Msg::AttractFrame => {
+            let orbit = !app.motion.reduced;
+            let step = app.attract.as_mut().and_then(|a| a.frame(&app.cube, app.solver.as_deref(), orbit));
+            match step {
+                Some(Step::Scramble(alg)) => app.cube.apply_alg(&alg),
+                Some(Step::Turn(m)) => {
+                    app.cube.apply(m);
+                    in_flight(app, m, None, app.motion.frame(attract::FRAME));
+                }
+                None => {}
+            }
+        }
+        Msg::StartDaily => {
+            let (run, alg) = DailyRun::start();
             app.cube = Cube::default();
-            app.status = "Cube reset.".into();
+            app.cube.apply_alg(&alg);
+            app.history.clear();
+            app.confirm_reset = false;
+            app.drill = None;
+            app.ab_test = None;
+            app.status = t!("daily-started", day = run.day.to_string());
+            app.daily = Some(run);
+            scrambled(app, alg);
+        }
+        Msg::StopDaily => { app.daily = None; }
+        Msg::LeaderboardOptIn(on) => {
+            app.leaderboard.opted_in = on;
+            // Opting out takes back what hasn't gone out yet.
+            if !on {
+                app.leaderboard_queue.0.clear();
+                app.board = None;
+                queue_changed(app);
+            }
+            leaderboard_changed(app);
+        }
+        Msg::LeaderboardEndpointChanged(url) => {
+            app.leaderboard.endpoint = url;
+            leaderboard_changed(app);
+        }
+        Msg::LeaderboardNameChanged(name) => {
+            app.leaderboard.name = name.chars().take(leaderboard::NAME_MAX).collect();
+            leaderboard_changed(app);
+        }
+        Msg::ObsEnabled(on) => {
+            app.obs.enabled = on;
+            obs_changed(app);
+        }
+        Msg::ObsHostChanged(host) => {
+            app.obs.host = host;
+            obs_changed(app);
+        }
+        Msg::ObsPortChanged(port) => {
+            // Only digits reach the setting; an empty box means the default.
+            if port.is_empty() {
+                app.obs.port = obs::DEFAULT_PORT;
+            } else if let Ok(port) = port.parse() {
+                app.obs.port = port;
+            }
+            obs_changed(app);
+        }
+        Msg::ObsPasswordChanged(password) => {
+            app.obs.password = password;
+            obs_changed(app);
+        }
+        Msg::ObsActionChanged(action) => {
+            app.obs.action = action;
+            obs_changed(app);
+        }
+        Msg::ObsSceneChanged(scene) => {
+            app.obs.scene = scene;
+            obs_changed(app);
+        }
+        Msg::TestObs => {
+            app.status = t!("obs-testing");
+            return Command::perform(obs::trigger(app.obs.clone()), Msg::ObsTriggered);
+        }
+        Msg::ObsTriggered(Ok(())) if app.status == t!("obs-testing") => app.status = t!("obs-test-ok"),
+        Msg::ObsTriggered(Ok(())) => {}
+        Msg::ObsTriggered(Err(e)) => app.status = t!("obs-failed", error = e),
+        Msg::SyncLeaderboard => return sync_leaderboard(app),
+        Msg::LeaderboardSynced(synced) => {
+            app.syncing = false;
+            let sent = synced.sent.min(app.leaderboard_queue.0.len());
+            if sent > 0 {
+                app.leaderboard_queue.0.drain(..sent);
+                queue_changed(app);
+            }
+            match synced.board {
+                Ok(board) => app.board = Some(board),
+                Err(e) => app.status = t!("leaderboard-failed", error = e),
+            }
+        }
+        Msg::ToggleAnnotate(on) => { app.annotations.on = on; }
+        Msg::AnnotateToolChanged(tool) => { app.annotations.tool = tool; }
+        Msg::AnnotateInkChanged(ink) => { app.annotations.ink = ink; }
+        Msg::ToggleKeepMarks(keep) => { app.annotations.keep = keep; }
+        Msg::MarkDrawn(mark) => app.annotations.add(&app.cube, mark),
+        Msg::UndoMark => app.annotations.undo(&app.cube),
+        Msg::ClearMarks => app.annotations.clear(&app.cube),
+
+        Msg::PauseThresholdChanged(threshold) => {
+            app.pause_threshold = threshold;
+            if app.pauses.is_some() {
+                app.pauses = Some(report::pauses(&app.session_solves, threshold.duration()));
+            }
+        }
+        Msg::FindPauses => {
+            let found = report::pauses(&app.session_solves, app.pause_threshold.duration());
+            app.status = t!("pauses-found", n = found.len(), solves = app.session_solves.len());
+            app.pauses = Some(found);
+        }
+        Msg::ClosePauses => { app.pauses = None; }
+        Msg::ToggleMoveUsage => { app.show_usage = !app.show_usage; }
+        Msg::ResetMoveUsage => {
+            app.move_usage = MoveUsage::default();
+            usage_changed(app);
+            app.status = t!("usage-reset");
+        }
+        Msg::ExportReport(path) => {
+            app.status = match report::save(&app.session_solves, &path) {
+                Ok(()) => t!("report-exported", n = app.session_solves.len(), path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::ToggleSheetSolutions(on) => { app.sheet_solutions = on; }
+        Msg::NewRound => { app.round = Some(solve_sheet::entries(None)); }
+        Msg::CloseRound => { app.round = None; }
+
+        // ----- classroom mode ----------------------------------------------
+        Msg::StartClassroom => { app.classroom = Some(Classroom::start(app.classroom_interval)); }
+        Msg::LeaveClassroom => { app.classroom = None; }
+        Msg::ClassroomNext => {
+            if let Some(room) = app.classroom.as_mut() {
+                room.next();
+            }
+        }
+        Msg::ClassroomTick => {
+            if let Some(room) = app.classroom.as_mut() {
+                room.tick();
+            }
+        }
+        Msg::ClassroomIntervalChanged(every) => {
+            app.classroom_interval = every;
+            if let Some(room) = app.classroom.as_mut() {
+                room.interval = every;
+            }
+        }
+        Msg::ExportSolveSheet(path) => {
+            let solver = app.solver.as_deref().filter(|_| app.sheet_solutions);
+            let entries = match &app.round {
+                Some(round) => round
+                    .iter()
+                    .map(|e| solve_sheet::Entry { solution: solver.and_then(|s| s.solve(&e.state)), ..e.clone() })
+                    .collect(),
+                None => solve_sheet::entries(solver),
+            };
+            app.status = match solve_sheet::save(&entries, &path) {
+                Ok(()) if app.sheet_solutions && solver.is_none() => {
+                    t!("sheet-exported-unsolved", path = path.display().to_string())
+                }
+                Ok(()) => t!("sheet-exported", n = entries.len(), path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::AnkiSetChanged(set) => { app.anki_set = set; }
+        Msg::ExportAnki(path) => {
+            app.status = match anki::save(app.anki_set, &path) {
+                Ok(n) => t!("anki-exported", n = n, path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::ExportBlueprint(path) => {
+            app.status = match blueprint::save(&app.cube, &share::encode(app), &path) {
+                Ok(()) => t!("blueprint-exported", path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::InspectionTick => {
+            let drill = app.drill.filter(Drill::inspecting).map(|d| d.color);
+            let due = match app.drill.as_mut().filter(|d| d.inspecting()) {
+                Some(d) => d.inspection_tick(),
+                None => app.timer.tick(),
+            };
+            match due {
+                Some(InspectionTick::Call(left)) => {
+                    app.status = t!("inspection-call", seconds = left);
+                    app.hooks.emit(&CubeEvent::InspectionCall { seconds_left: left }, &app.cube);
+                }
+                Some(InspectionTick::Expired) => match drill {
+                    Some(color) => {
+                        app.status = t!("drill-dnf", color = color_name(color));
+                        app.drill = None;
+                    }
+                    None => app.status = t!("timer-stopped", time = t!("result-dnf")),
+                },
+                None => {}
+            }
+        }
+
+        // ----- metronome -----------------------------------------------------
+        Msg::ToggleMetronome => {
+            if app.metronome.running {
+                app.metronome.stop();
+                finish_beat_drill(app);
+            } else {
+                app.metronome.start();
+            }
+        }
+        Msg::MetronomeBpmChanged(bpm) => { app.metronome.bpm = bpm; }
+        Msg::MetronomeClickToggled(on) => { app.metronome.click = on; }
+        Msg::MetronomeBeat => {
+            app.metronome.beat();
+            if let Some(p) = app.player.as_ref().filter(|_| app.metronome.click) {
+                p.play(Cue::Beat);
+            }
+        }
+        Msg::StartBeatDrill => {
+            if !app.metronome.running {
+                app.metronome.start();
+            }
+            app.metronome.drill = Some(BeatScore::default());
+            app.status = t!("beat-drill-started", bpm = app.metronome.bpm);
+        }
+        Msg::StopBeatDrill => finish_beat_drill(app),
+
+        // ----- random-walk demo ----------------------------------------------
+        Msg::ToggleRandomWalk => {
+            if app.random_walk.running {
+                stop_random_walk(app);
+                app.status = t!("random-walk-stopped", n = app.random_walk.moves);
+            } else {
+                // The walk's turns aren't undoable history.
+                app.history.clear();
+                app.confirm_reset = false;
+                app.scramble_depth = None;
+                app.random_walk.start();
+                app.status.clear();
+            }
+        }
+        Msg::RandomWalkRateChanged(rate) => { app.random_walk.rate = rate; }
+        Msg::RandomWalkStopWhenSolved(on) => { app.random_walk.stop_when_solved = on; }
+        Msg::RandomWalkFrame if app.random_walk.running => {
+            let moves = app.random_walk.frame(&mut thread_rng(), !app.motion.reduced);
+            let mut last = None;
+            for m in moves {
+                app.cube.apply(m);
+                last = Some(m);
+                app.random_walk.moves += 1;
+                if app.random_walk.stop_when_solved && app.cube.is_solved() {
+                    stop_random_walk(app);
+                    app.status = t!("random-walk-solved", n = app.random_walk.moves);
+                    break;
+                }
+            }
+            if let Some(m) = last {
+                let frame = app.motion.frame(random_walk::FRAME);
+                in_flight(app, m, Some(app.random_walk.period()), frame);
+            }
+        }
+
+        Msg::TurnFrame if app.in_flight.as_ref().is_some_and(|t| t.layer(&app.cube).is_none()) => { app.in_flight = None; }
+
+        // ----- state diagnostics -------------------------------------------
+        Msg::ToggleDiagnostics(on) => { app.show_diagnostics = on; }
+        Msg::FixTwist(slot) => {
+            if let Some(cube) = fix_twist(&app.cube, slot) {
+                app.cube = cube;
+                app.history.clear();
+                app.confirm_reset = false;
+                app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+                app.verify_result = None;
+                app.status = t!("diag-twist-fixed", slot = CORNER_NAMES[slot as usize]);
+            }
+        }
+
+        // ----- state comparison --------------------------------------------
+        Msg::SaveDiffSlot(i) => {
+            app.diff_slots[i] = Some(app.cube.clone());
+            app.status = t!("diff-saved", slot = if i == 0 { "A" } else { "B" });
+        }
+        Msg::DiffPairChanged(pair) => { app.diff_pair = pair; }
+        Msg::DiffPatternChanged(s) => { app.diff_pattern = s; }
+        Msg::CompareStates => compare_states(app),
+        Msg::ClearDiff => { app.state_diff = None; }
+
+        // ----- BLD memo ----------------------------------------------------
+        Msg::ShowMemo => match corner_memo(&app.cube, &app.scheme) {
+            Some(memo) => app.memo = Some((app.cube.clone(), memo)),
+            None => app.status = t!("memo-illegal"),
+        },
+        Msg::StartMemoDrill => match MemoDrill::start(&app.cube, &app.scheme) {
+            Some(drill) => {
+                app.memo_drill = Some(drill);
+                app.memo_input.clear();
+                app.memo = None;
+                app.status = t!("memo-drill-started");
+            }
+            None => app.status = t!("memo-illegal"),
+        },
+        Msg::CancelMemoDrill => {
+            app.memo_drill = None;
+            app.status.clear();
+        }
+        Msg::MemoInputChanged(s) => { app.memo_input = s; }
+        Msg::ToggleSchemeEditor(on) => { app.scheme_editor = on; }
+        Msg::SchemeLetterChanged(sticker, s) => {
+            // The box holds one letter: keep the one just typed.
+            if let Some(letter) = s.chars().rev().find(|c| c.is_alphanumeric()) {
+                app.scheme.set(sticker, letter);
+                save_scheme(app);
+            }
+        }
+        Msg::ResetScheme => {
+            app.scheme = LetterScheme::SPEFFZ;
+            save_scheme(app);
+        }
+        Msg::SubmitMemo => {
+            if let Some(drill) = app.memo_drill.take() {
+                let (correct, took) = drill.finish(&app.memo_input);
+                let time = fmt_secs(took.as_millis() as u64);
+                app.status = if !correct {
+                    t!("memo-wrong", typed = app.memo_input.trim(), memo = drill.memo.to_string())
+                } else if app.memo_best.is_none_or(|b| took < b) {
+                    app.memo_best = Some(took);
+                    t!("memo-correct-best", time = time)
+                } else {
+                    t!("memo-correct", time = time)
+                };
+                app.memo = Some((drill.cube, drill.memo));
+            }
+        }
+
+        // ----- notation trainer --------------------------------------------
+        Msg::StartNotation => {
+            let limit = Duration::from_millis(app.notation_limit_ms.into());
+            app.notation = Some(NotationDrill::start(app.notation_quiz, limit));
+            app.status = t!("notation-started");
+        }
+        Msg::StopNotation => {
+            app.notation = None;
+            app.status.clear();
+        }
+        Msg::NotationQuizChanged(q) => {
+            app.notation_quiz = q;
+            if app.notation.is_some() {
+                return update(app, Msg::StartNotation);
+            }
+        }
+        Msg::NotationLimitChanged(ms) => {
+            app.notation_limit_ms = ms;
+            if let Some(d) = app.notation.as_mut() {
+                d.limit = Duration::from_millis(ms.into());
+            }
+        }
+        Msg::NotationKey(key) => {
+            if let Some(outcome) = app.notation.as_mut().and_then(|d| d.key(key)) {
+                notation_answered(app, outcome);
+            }
+        }
+        Msg::NotationTick if app.notation.as_ref().is_some_and(|d| d.timed_out()) => {
+            notation_answered(app, Outcome::TimedOut);
+        }
+
+        // ----- sound effects -----------------------------------------------
+        Msg::ControllerInput(input) => return controller_input(app, input),
+        Msg::BindingInputChanged(s) => {
+            app.binding_input = s;
+            app.learning_binding = false;
+        }
+        Msg::BindingCommandChanged(s) => { app.binding_command = s; }
+        Msg::LearnBinding => {
+            app.learning_binding = !app.learning_binding;
+            if app.learning_binding {
+                app.status = t!("binding-learning");
+            }
+        }
+        Msg::AddBinding => {
+            let command = app.binding_command.trim().to_string();
+            match Input::parse(&app.binding_input) {
+                None => app.status = t!("binding-bad-input", input = app.binding_input.trim()),
+                Some(_) if command.is_empty() => app.status = t!("binding-no-command"),
+                Some(input) => match palette::parse(&command) {
+                    Err(e) => app.status = e,
+                    Ok(_) => {
+                        app.status = t!("binding-added", input = input.to_string(), command = command.clone());
+                        app.bindings.set(input, command);
+                        app.binding_input.clear();
+                        app.binding_command.clear();
+                        bindings_changed(app);
+                    }
+                },
+            }
+        }
+        Msg::RemoveBinding(input) => {
+            app.bindings.remove(input);
+            bindings_changed(app);
+        }
+
+        Msg::Gamepad(event) => return gamepad_event(app, event),
+        Msg::GamepadFrame => return gamepad_frame(app),
+        Msg::Touch(gesture) => return touch_gesture(app, gesture),
+        Msg::GamepadFaceChanged(i, button) => {
+            app.gamepad.faces[i] = button;
+            gamepad_changed(app);
+        }
+        Msg::GamepadPrimeChanged(c) => {
+            app.gamepad.prime = c;
+            gamepad_changed(app);
+        }
+        Msg::GamepadDoubleChanged(c) => {
+            app.gamepad.double = c;
+            gamepad_changed(app);
+        }
+        Msg::GamepadSpeedChanged(s) => {
+            app.gamepad.speed = s;
+            gamepad_changed(app);
+        }
+        Msg::GamepadInvertToggled(on) => {
+            app.gamepad.invert_y = on;
+            gamepad_changed(app);
+        }
+
+        Msg::SoundClickChanged(c) => {
+            app.sound.click = c;
+            sound_changed(app);
+        }
+        Msg::SoundVolumeChanged(cue, v) => {
+            app.sound.cue_mut(cue).volume = v;
+            sound_changed(app);
+        }
+        Msg::SoundMuteToggled(cue, on) => {
+            app.sound.cue_mut(cue).muted = !on;
+            sound_changed(app);
+        }
+        Msg::CopyShareLink => {
+            let link = share::encode(app);
+            app.status = t!("link-copied", link = link);
+            return iced::clipboard::write(link);
+        }
+        Msg::OpenAlgCubing => {
+            app.status = match share::alg_cubing_url(app) {
+                None => t!("alg-cubing-unavailable"),
+                Some(url) => match share::open_in_browser(&url) {
+                    Ok(()) => t!("alg-cubing-opened", url = url),
+                    Err(e) => t!("alg-cubing-failed", url = url, error = e),
+                },
+            };
+        }
+        Msg::PostFormatChanged(format) => { app.post_format = format; }
+        Msg::CopyForumPost => match share::session_algs(app) {
+            None => app.status = t!("alg-cubing-unavailable"),
+            Some((_, solution)) if solution.is_empty() => app.status = t!("post-empty"),
+            Some((scramble, solution)) => {
+                let time = app.timer.last().and_then(|a| a.counted());
+                let post = forum::post(&forum::Solve { scramble, solution, time }, app.post_format);
+                app.status = t!("post-copied");
+                return iced::clipboard::write(post);
+            }
+        },
+        Msg::ToggleQr => { app.show_qr = !app.show_qr; }
+        Msg::AnalyzeFirstFaces => {
+            app.face_analysis = Some((app.cube.clone(), analyze(&app.cube)));
+        }
+        Msg::ExploreSubgroups => match explore(&app.cube) {
+            Some(report) => app.subgroups = Some((app.cube.clone(), report)),
+            None => app.status = t!("subgroup-illegal"),
+        },
+        Msg::SolveCube if app.weighted_solve || !app.generators.is_empty() => {
+            let Some(table) = app.solver.clone() else {
+                app.status = t!("solver-not-ready");
+                return Command::none();
+            };
+            let faces = if app.generators.is_empty() { FaceId::ALL.to_vec() } else { app.generators.clone() };
+            if !reachable(&app.cube, &faces) {
+                app.status = t!("solve-unreachable", generators = fmt_generators(&faces));
+                return Command::none();
+            }
+            let costs = if app.weighted_solve { app.costs } else { MoveCosts::HTM };
+            app.solving = true;
+            app.status = t!("solving");
+            return Command::perform(solve_restricted(table, app.cube.clone(), costs, faces), Msg::SearchSolved);
+        }
+        Msg::SolveCube => {
+            match app.solver.as_ref().map(|s| s.solve(&app.cube)) {
+                Some(Some(alg)) => app.solution = Some((app.cube.clone(), alg)),
+                Some(None) => app.status = t!("solve-invalid"),
+                None => app.status = t!("solver-not-ready"),
+            }
+        }
+        Msg::SearchSolved((cube, alg)) => {
+            app.solving = false;
+            match alg {
+                Ok(alg) => {
+                    app.status = t!("solve-found", n = alg.len());
+                    app.solution = Some((cube, alg));
+                }
+                Err(e) => app.status = e.to_string(),
+            }
+        }
+        Msg::WatchSolve => match app.solver.as_ref().map(|s| s.solve(&app.cube)) {
+            Some(Some(alg)) if alg.is_empty() => app.status = t!("solve-solved"),
+            Some(Some(alg)) => {
+                app.status = t!("watch-started", n = alg.len());
+                app.solution = Some((app.cube.clone(), alg.clone()));
+                app.watch = Some(Watch::new(alg, app.history.len()));
+            }
+            Some(None) => app.status = t!("solve-invalid"),
+            None => app.status = t!("solver-not-ready"),
+        },
+        Msg::StopWatching => {
+            app.watch = None;
+            app.status = t!("watch-stopped");
+        }
+        Msg::WatchPaceChanged(pace) => { app.watch_pace = pace; }
+        Msg::WatchTick => watch_tick(app),
+        Msg::GeneratorsChanged(s) => {
+            match parse_generators(&s) {
+                Ok(faces) => app.generators = faces,
+                Err(e) => app.status = e.to_string(),
+            }
+            app.generators_input = s;
+        }
+        Msg::ToggleWeightedSolve(on) => { app.weighted_solve = on; }
+        Msg::CostModelChanged(s) => {
+            match MoveCosts::parse(&s) {
+                Ok(costs) => app.costs = costs,
+                Err(e) => app.status = e.to_string(),
+            }
+            app.cost_input = s;
+        }
+        Msg::RestoreTrash => {
+            if let Some(snap) = app.trash.take() {
+                app.cube = snap.cube;
+                app.history = snap.history;
+                app.status = t!("trash-restored");
+            }
+        }
+
+        Msg::CheckpointNameChanged(s) => { app.checkpoint_name = s; }
+        Msg::AddCheckpoint => {
+            let name = match app.checkpoint_name.trim() {
+                "" => t!("checkpoint-default", n = app.history.len()),
+                name => name.to_string(),
+            };
+            app.status = t!("checkpoint-added", name = name.as_str());
+            app.checkpoints.push(NamedCheckpoint {
+                name,
+                state: Snapshot { cube: app.cube.clone(), history: app.history.clone() },
+            });
+            app.checkpoint_name.clear();
+        }
+        Msg::JumpToCheckpoint(i) => {
+            if let Some(cp) = app.checkpoints.get(i) {
+                app.cube = cp.state.cube.clone();
+                app.history = cp.state.history.clone();
+                app.confirm_reset = false;
+                app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+                app.verify_result = None;
+                app.status = t!("checkpoint-restored", name = cp.name.as_str(), n = cp.state.history.len());
+            }
+        }
+        Msg::RemoveCheckpoint(i) if i < app.checkpoints.len() => {
+            app.checkpoints.remove(i);
+        }
+
+        Msg::Undo => {
+            if let Some(node) = app.history_tree.undo() {
+                app.cube = node.cube.clone();
+                app.history.pop();
+                history_moved(app);
+            }
+        }
+        Msg::Redo => {
+            if let Some(node) = app.history_tree.redo() {
+                app.cube = node.cube.clone();
+                app.history.push(node.token.clone());
+                history_moved(app);
+            }
+        }
+        Msg::JumpToHistory(i) => {
+            if let Some(node) = app.history_tree.jump(i) {
+                app.cube = node.cube.clone();
+                app.history = app.history_tree.path(i);
+                history_moved(app);
+            }
+        }
+
+        Msg::MoveCommentChanged(s) => app.history_tree.set_comment(s),
+        Msg::ScriptPathChanged(s) => { app.script_path = s; }
+        Msg::ExportScript => {
+            let path = script::script_path(&app.script_path);
+            let solve = script::current_script(&app.history_tree, app.scramble.as_ref());
+            app.status = match script::save(&solve, &path) {
+                Ok(()) => t!("script-exported", n = solve.steps.len(), path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::ImportScript => import_script(app),
+        Msg::StopScriptReplay => {
+            app.script_replay = None;
+            app.status = t!("script-replay-stopped");
+        }
+        Msg::ScriptReplayTick => script_replay_tick(app),
+        Msg::BreakDownSolve => {
+            let solve = script::current_script(&app.history_tree, app.scramble.as_ref());
+            match breakdown(&solve.start, &solve.steps).filter(|b| b.moves > 0) {
+                Some(b) => app.breakdown = Some(b),
+                None => app.status = t!("breakdown-untimed"),
+            }
+        }
+        Msg::CloseBreakdown => { app.breakdown = None; }
+        Msg::SyncScriptToVideo => sync_script_to_video(app),
+        Msg::VideoTimeChanged(s) => {
+            if let Some(sync) = app.video_sync.as_mut() {
+                sync.time_input = s;
+            }
+        }
+        Msg::ApplyVideoTime => {
+            let typed = app.video_sync.as_ref().map(|s| s.time_input.trim().to_string());
+            match typed.as_deref().map(parse_time) {
+                Some(Some(at)) => seek_video(app, at),
+                Some(None) => app.status = t!("video-sync-bad-time", time = typed.unwrap_or_default()),
+                None => {}
+            }
+        }
+        Msg::VideoScrubbed(secs) => seek_video(app, Duration::from_secs_f32(secs.max(0.0))),
+        Msg::VideoOffsetChanged(s) => {
+            let Some(sync) = app.video_sync.as_mut() else { return Command::none() };
+            let offset = parse_time(s.trim());
+            sync.offset_input = s;
+            if let Some(offset) = offset
+                && sync.set_offset(offset)
+            {
+                let at = sync.position;
+                seek_video(app, at);
+            }
+        }
+        Msg::CloseVideoSync => { app.video_sync = None; }
+
+        Msg::TracePathChanged(s) => { app.trace_path = s; }
+        Msg::SaveTrace => {
+            let path = trace::trace_path(&app.trace_path);
+            app.status = match trace::save(&app.trace, &path) {
+                Ok(()) => t!("trace-saved", n = app.trace.actions.len(), path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::ReplayTrace => replay_trace(app),
+        Msg::StopTraceReplay => {
+            app.trace_replay = None;
+            app.status = t!("trace-replay-stopped");
+        }
+        Msg::TraceReplayTick => return trace_replay_tick(app),
+
+        Msg::ConsoleEdited(Action::Edit(Edit::Enter)) if app.modifiers.command() => return run_console(app),
+        Msg::ConsoleEdited(action) => app.console_editor.perform(action),
+        Msg::RunConsole => return run_console(app),
+        Msg::ConsoleFinished(run) => console_finished(app, run),
+        Msg::ClearConsole => app.console_output.clear(),
+
+        Msg::TogglePalette if app.palette.is_some() => { app.palette = None; }
+        Msg::TogglePanels => { app.hide_panels = !app.hide_panels; }
+        Msg::ToggleAbout => { app.show_about = !app.show_about; }
+        Msg::ToggleDebugInfo => { app.show_debug = !app.show_debug; }
+        Msg::CopyDebugBundle => {
+            app.status = t!("debug-copied");
+            return iced::clipboard::write(debug::bundle(app));
+        }
+        Msg::TogglePalette => {
+            app.palette = Some(String::new());
+            return text_input::focus(palette::input_id());
+        }
+        Msg::ClosePalette => { app.palette = None; }
+        Msg::PaletteInputChanged(s) => { app.palette = Some(s); }
+        Msg::RunPaletteCommand => return run_palette(app),
+        Msg::ExportPng(path) => {
+            return window::screenshot(window::Id::MAIN, move |shot| Msg::ScreenshotTaken(path, shot));
+        }
+        Msg::ScreenshotTaken(path, shot) => {
+            app.status = match screenshot::save_png(&shot, &share::encode(app), &path) {
+                Ok(()) => t!("png-exported", path = path.display().to_string()),
+                Err(e) => e.to_string(),
+            };
+        }
+        Msg::FileHovered(path) => { app.drop_target = DropKind::of(&path).is_some(); }
+        Msg::FilesHoveredLeft => { app.drop_target = false; }
+        Msg::FileDropped(path) => {
+            app.drop_target = false;
+            open_file(app, &path);
         }
 
         Msg::SeedChanged(s) => { app.seed_input = s; }
 
         Msg::ApplySeed => {
-            if let Ok(seed) = app.seed_input.trim().parse::<u64>() {
+            if let Some(seed) = parse_seed(&app.seed_input) {
                 apply_seeded_scramble(app, seed);
             } else {
-                app.status = "No valid seed entered — nothing applied.".into();
+                app.status = t!("no-valid-seed");
             }
         }
 
         Msg::Scramble => {
             // Pick a brand-new random seed and apply a deterministic scramble.
             let mut rng = thread_rng();
-            let seed = rng.next_u64();                // no recursion, no gen() ambiguity
+            let mut seed = rng.next_u64();            // no recursion, no gen() ambiguity
+            if let Some(solver) = app.solver.as_ref().filter(|_| app.min_depth > 0) {
+                for _ in 0..MAX_REROLLS {
+                    let mut c = Cube::default();
+                    c.apply_alg(&seeded_alg(SCRAMBLE_LEN, seed));
+                    if solver.distance(&c).is_some_and(|d| d >= app.min_depth) {
+                        break;
+                    }
+                    seed = rng.next_u64();
+                }
+            }
             app.seed_input = seed.to_string();
             apply_seeded_scramble(app, seed);
         }
+        Msg::ClearSeed => { app.seed_input.clear(); }
+        Msg::MinDepthChanged(d) => { app.min_depth = d; }
 
-        // “Reset” for the seed/algorithm inputs (kept for convenience)
-        Msg::Reset => {
-            app.alg_input.clear();
-            app.seed_input.clear();
-            app.status.clear();
+        // ----- custom scramble + verification ------------------------------
+        Msg::CustomScrambleChanged(s) => { app.custom_scramble = s; }
+        Msg::ApplyCustomScramble => {
+            match parse_alg(&app.custom_scramble) {
+                Ok(alg) if !alg.is_empty() => {
+                    app.cube = Cube::default();
+                    app.history.clear();
+                    app.confirm_reset = false;
+                    app.cube.apply_alg(&alg);
+                    app.scramble_depth = app.solver.as_ref().and_then(|s| s.distance(&app.cube));
+                    app.verify_result = None;
+                    app.alg_input = alg.to_string();
+                    app.status = t!("custom-applied", n = alg.len());
+                    scrambled(app, alg);
+                }
+                Ok(_) => app.status = t!("nothing-to-apply"),
+                Err(e) => app.status = t!("alg-error", error = e),
+            }
+        }
+        Msg::KPuzzleInputChanged(s) => { app.kpuzzle_input = s; }
+        Msg::ImportKPuzzle => import_kpuzzle(app),
+        Msg::VerifyInputChanged(s) => { app.verify_input = s; }
+        Msg::VerifyState => {
+            match compare(&app.cube, &app.verify_input) {
+                Ok(diff) => {
+                    app.status = if diff.is_empty() {
+                        t!("verify-ok")
+                    } else {
+                        t!("verify-mismatch", n = diff.len())
+                    };
+                    app.verify_result = Some(diff);
+                }
+                Err(e) => {
+                    app.status = e.to_string();
+                    app.verify_result = None;
+                }
+            }
         }
-
-        Msg::SeedClear => { app.seed_input.clear(); }
 
         // ----- single move buttons -----------------------------------------
         Msg::Move(tok) => {
-            match apply_token(&mut app.cube, &tok) {
-                Ok(()) => app.status = format!("Did {tok}"),
-                Err(e)  => app.status = e,
+            let was_solved = app.cube.is_solved();
+            match turn(app, &tok) {
+                Ok(()) => {
+                    app.status = t!("did-move", token = tok);
+                    app.metronome.record_turn();
+                    if !was_solved && app.cube.is_solved() {
+                        app.move_usage.record(&app.history);
+                        usage_changed(app);
+                    }
+                    ab_after_move(app);
+                    if !drill_after_move(app) && daily_after_move(app) {
+                        return Command::batch([sync_leaderboard(app), trigger_obs(app)]);
+                    }
+                }
+                Err(e)  => app.status = e.to_string(),
+            }
+        }
+
+        // ----- language -----------------------------------------------------
+        Msg::LanguageChanged(lang) => {
+            app.lang = lang;
+            i18n::set_lang(lang);
+        }
+
+        // ----- auto-save / crash recovery ----------------------------------
+        // Don't clobber an unanswered recovery offer with a fresh session.
+        Msg::AutoSaveTick if app.pending_recovery.is_none() => {
+            let text = Checkpoint::capture(app).encode();
+            if text != app.last_checkpoint {
+                match recovery::save(&text) {
+                    Ok(()) => app.last_checkpoint = text,
+                    Err(e) => app.status = t!("autosave-failed", error = e),
+                }
+            }
+        }
+        Msg::RestoreSession => {
+            if let Some(cp) = app.pending_recovery.take() {
+                cp.restore_into(app);
+                app.status = t!("session-restored");
+            }
+        }
+        Msg::DiscardRecovery => {
+            app.pending_recovery = None;
+            recovery::clear();
+            app.status.clear();
+        }
+        Msg::CloseRequested(id) if Some(id) == app.right_window => {
+            return update(app, Msg::ReattachRight);
+        }
+        Msg::CloseRequested(id) if app.order_window.as_ref().is_some_and(|(w, _)| *w == id) => {
+            app.order_window = None;
+            return window::close(id);
+        }
+        Msg::CloseRequested(id) => {
+            // Clean exit: no checkpoint means nothing to recover next launch.
+            recovery::clear();
+            // Nowhere left to report a failure; the next launch just opens
+            // at the default size.
+            let _ = window_state::save(&app.window);
+            let mut close = vec![window::close(id)];
+            close.extend(app.right_window.take().map(window::close));
+            close.extend(app.order_window.take().map(|(w, _)| window::close(w)));
+            return Command::batch(close);
+        }
+        Msg::WindowMoved(at) => app.window.moved(at),
+        Msg::WindowResized(size) => {
+            return window::fetch_maximized(window::Id::MAIN, move |maximized| Msg::WindowSized(size, maximized));
+        }
+        Msg::WindowSized(size, maximized) => app.window.resized(size, maximized),
+        Msg::ModifiersChanged(m) => { app.modifiers = m; }
+
+        // ----- solver tables -----------------------------------------------
+        Msg::SolverReady((table, origin)) => {
+            app.solver = Some(table);
+            app.solver_origin = Some(origin);
+            app.status = match origin {
+                TableOrigin::Cache => t!("solver-loaded"),
+                TableOrigin::Built { millis, saved: true } => t!("solver-built", ms = millis),
+                TableOrigin::Built { millis, saved: false } => t!("solver-built-unsaved", ms = millis),
+            };
+        }
+        Msg::RebuildSolverTables if app.solver.is_some() => {
+            app.solver = None;
+            app.status = t!("solver-rebuilding");
+            return Command::perform(solver_cache::rebuild_async(), Msg::SolverReady);
+        }
+
+        // ----- detachable right view ---------------------------------------
+        Msg::DetachRight if app.right_window.is_none() => {
+            let (id, spawn) = window::spawn(window::Settings {
+                size: iced::Size::new(420.0, 360.0),
+                exit_on_close_request: false,
+                ..Default::default()
+            });
+            app.right_window = Some(id);
+            return spawn;
+        }
+        Msg::ReattachRight => {
+            if let Some(id) = app.right_window.take() {
+                return window::close(id);
             }
         }
 
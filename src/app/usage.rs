@@ -0,0 +1,100 @@
+// src/app/usage.rs
+
+//! Move usage: how often each face is turned each way over the user's
+//! solves, kept per profile, for a heatmap that shows habits such as
+//! leaning on B or D turns, which are slow to execute. A solve's turns are
+//! counted when it ends solved.
+
+use std::path::PathBuf;
+
+use crate::cube::{FaceId, Move, Turn};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+const HEADER: &str = "rubics move usage v1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MoveUsage {
+    /// Turns made, by face and by direction: clockwise, counter-clockwise,
+    /// half.
+    pub counts: [[u64; 3]; 6],
+    pub solves: u64,
+}
+
+fn turn_index(t: Turn) -> usize {
+    match t {
+        Turn::Cw => 0,
+        Turn::Ccw => 1,
+        Turn::Half => 2,
+    }
+}
+
+impl MoveUsage {
+    /// Count the turns of one solve; tokens that aren't face turns are
+    /// skipped.
+    pub fn record(&mut self, tokens: &[String]) {
+        for m in tokens.iter().filter_map(|t| Move::parse(t)) {
+            self.counts[m.face as usize][turn_index(m.turn)] += 1;
+        }
+        self.solves += 1;
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().flatten().sum()
+    }
+
+    pub fn face_total(&self, face: FaceId) -> u64 {
+        self.counts[face as usize].iter().sum()
+    }
+
+    /// The most turned face and its share of all turns.
+    pub fn busiest(&self) -> Option<(FaceId, f32)> {
+        let total = self.total();
+        let face = FaceId::ALL.into_iter().max_by_key(|&f| self.face_total(f))?;
+        (total > 0).then(|| (face, self.face_total(face) as f32 / total as f32))
+    }
+
+    pub fn encode(&self) -> String {
+        let mut pairs: Vec<(&str, String)> = FaceId::ALL
+            .iter()
+            .map(|&f| (face_key(f), self.counts[f as usize].map(|n| n.to_string()).join(" ")))
+            .collect();
+        pairs.push(("solves", self.solves.to_string()));
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let mut usage = Self::default();
+        for f in FaceId::ALL {
+            let Some(v) = kv.get(face_key(f)) else { continue };
+            let counts: Vec<u64> = v.split_whitespace().filter_map(|n| n.parse().ok()).collect();
+            if let Ok(counts) = counts.try_into() {
+                usage.counts[f as usize] = counts;
+            }
+        }
+        usage.solves = kv.get("solves").and_then(|v| v.parse().ok()).unwrap_or_default();
+        usage
+    }
+}
+
+fn face_key(f: FaceId) -> &'static str {
+    match f {
+        FaceId::U => "u", FaceId::D => "d", FaceId::F => "f",
+        FaceId::B => "b", FaceId::L => "l", FaceId::R => "r",
+    }
+}
+
+/// Location of the move usage file.
+pub fn usage_path() -> PathBuf {
+    data_dir().join("moves.txt")
+}
+
+pub fn load() -> MoveUsage {
+    std::fs::read_to_string(usage_path())
+        .map(|t| MoveUsage::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(usage: &MoveUsage) -> std::io::Result<()> {
+    save_file(&usage_path(), usage.encode())
+}
@@ -3,54 +3,182 @@
 //! Top-level view layout: canvas, controls, sliders, toggles, and status.
 
 use iced::{
-    Alignment, Element, Length,
-    widget::{column, row, text, container, Space, Canvas, slider, checkbox},
+    Alignment, Border, Color, Element, Length,
+    widget::{column, row, text, button, container, Row, Space, Canvas, slider, checkbox, pick_list},
 };
 
-use super::{App, Msg};
-use crate::render::{CubeCanvas, ViewParams, RotZ, RotX, RotY};
+use crate::cube::{Alg, Move};
+use crate::logic::diagnose::diagnose;
+use crate::logic::tracking::Sticker;
+use super::{App, Msg, ViewSide, ViewUI, LinkMode, ScaleChoice};
+use crate::t;
+use crate::i18n::Lang;
+use rubics::qr::QrCode;
+use super::share;
+use super::classroom::{Classroom, Interval};
+use super::solve_sheet::Entry;
+use super::daily::Day;
+use super::notation::Quiz;
+use super::support::alg_completions;
+use super::debug::Field;
+use super::glide;
+use crate::render::layer::LayerTurn;
+use crate::render::renderer;
+use crate::render::{CanvasEvent, CubeGrid, QrCanvas, Scene, ViewParams, RenderMode, Radians, RotZ, RotX, RotY, SCALE_FACTORS};
 use crate::ui::{
+    build_menu_bar,
+    build_about_panel,
+    build_debug_panel,
     build_angle_block,
+    build_lighting_block,
+    build_mode_row,
+    build_recovery_banner,
+    build_palette,
+    build_reset_row,
+    build_checkpoints_row,
+    build_history_panel,
+    build_script_panel,
+    build_breakdown_panel,
+    build_video_sync_panel,
+    build_trace_panel,
+    build_console_panel,
     build_presets_row,
+    build_motion_row,
     build_seed_panel,
+    build_alg_watch_row,
     build_algorithm_panel,
+    build_alg_order,
+    build_face_analysis,
+    build_trainer_panel,
+    build_timer_row,
+    build_ab_panel,
+    build_usage_panel,
+    build_daily_panel,
+    build_pauses_panel,
+    build_profile_row,
+    build_leaderboard_panel,
+    build_obs_panel,
+    build_gamepad_panel,
+    build_controllers_panel,
+    build_annotate_panel,
+    build_custom_scramble_panel,
+    build_sound_panel,
+    build_metronome_panel,
+    build_solution_panel,
+    build_watch_row,
+    build_watch_strip,
+    build_subgroup_panel,
+    build_random_walk_panel,
+    build_tracking_panel,
+    build_notation_panel,
+    build_memo_panel,
+    build_diagnostics_panel,
+    build_compare_panel,
 };
 use crate::ui::moves::build_moves_scroller;
+use crate::ui::labels::labeled;
+
+/// Canvas border color on a metronome beat.
+const BEAT_FLASH: Color = Color { r: 0.95, g: 0.75, b: 0.2, a: 1.0 };
+
+/// On-screen side length of the QR code, in logical pixels.
+const QR_SIDE: f32 = 220.0;
+
+/// Renderer params for one view. Origins are auto-laid out in render code when NaN.
+fn view_params(v: ViewUI, size: f32, mode: RenderMode) -> ViewParams {
+    ViewParams {
+        rz: RotZ(Radians::from_degrees(v.rz)),
+        rx: RotX(Radians::from_degrees(v.rx)),
+        ry: RotY(Radians::from_degrees(v.ry)),
+        origin: (f32::NAN, f32::NAN),
+        size,
+        mode,
+    }
+}
+
+/// A view's angles as drawn: partway along a camera glide, with the
+/// random-walk or attract loop orbit added.
+fn orbited(app: &App, side: ViewSide) -> ViewUI {
+    let v = glide::drawn(app, side);
+    let attract = app.attract.as_ref().map_or(0.0, |a| a.orbit);
+    ViewUI { rz: v.rz + app.random_walk.orbit + attract, ..v }
+}
+
+/// The random walk's face glow, unless motion is reduced.
+fn glow(app: &App) -> Option<(crate::cube::FaceId, f32)> {
+    app.random_walk.glow().filter(|_| !app.motion.reduced)
+}
+
+/// The playback turn in motion on the cube shown.
+fn turning(app: &App) -> Option<LayerTurn> {
+    app.in_flight.as_ref().and_then(|t| t.layer(&app.cube))
+}
+
+/// Stickers to outline from the last comparison, while the cube shown is
+/// one of the two states compared.
+fn diff_marks(app: &App) -> &[Sticker] {
+    app.state_diff
+        .as_ref()
+        .filter(|r| r.from == app.cube || r.to == app.cube)
+        .map_or(&[], |r| &r.diff.stickers)
+}
+
+/// `first` then `second` in a row, the other way round in the mirrored layout.
+fn side_by_side<'a>(mirrored: bool, first: Element<'a, Msg>, second: Element<'a, Msg>) -> Row<'a, Msg> {
+    let (a, b) = if mirrored { (second, first) } else { (first, second) };
+    row![a, Space::with_width(16), b]
+}
 
 /// Build the full UI tree for the current `App` state.
 pub fn view(app: &App) -> Element<Msg> {
-    // Build ViewParams for the renderer. Origins are auto-laid out in render code when NaN.
-    let left  = ViewParams {
-        rz: RotZ(app.params.left.rz),
-        rx: RotX(app.params.left.rx),
-        ry: RotY(app.params.left.ry),
-        origin: (f32::NAN, f32::NAN),
-        size: app.params.size,
-    };
-    let right = ViewParams {
-        rz: RotZ(app.params.right.rz),
-        rx: RotX(app.params.right.rx),
-        ry: RotY(app.params.right.ry),
-        origin: (f32::NAN, f32::NAN),
-        size: app.params.size,
+    if let Some(room) = &app.classroom {
+        return classroom_view(app, room);
+    }
+    let left  = view_params(orbited(app, ViewSide::Left), app.params.size, app.params.left_mode);
+    let right = view_params(orbited(app, ViewSide::Right), app.params.size, app.params.right_mode);
+    // A detached right view is drawn in its own window instead; the
+    // mirrored layout puts it on the left.
+    let (left, right) = match app.right_window {
+        Some(_) => (left, None),
+        None if app.mirrored => (right, Some(left)),
+        None => (left, Some(right)),
     };
 
     // ── Fixed canvas area so controls never get squeezed ─────────────
     const CANVAS_H: f32 = 320.0; // stable space for both cubes
-    let canvas_raw: Element<()> = Canvas::new(CubeCanvas { cube: &app.cube, left, right })
+    let glow = glow(app);
+    let tracked = app.tracked.and_then(|p| p.stickers(&app.cube));
+    let canvas_raw: Element<CanvasEvent> = container(renderer::draw(Scene {
+        cube: &app.cube, left, right, settings: app.render, glow, tracked, scheme: &app.scheme, diff: diff_marks(app),
+        marks: app.annotations.marks(&app.cube), pen: app.annotations.pen(), turning: turning(app), drop_target: app.drop_target,
+    }))
         .width(Length::Fill)
         .height(Length::Fixed(CANVAS_H))
         .into();
 
     // Give the compiler an explicit type to avoid inference errors (E0283).
-    let canvas_el: Element<Msg> = container(canvas_raw.map(|_| Msg::Noop))
+    // The metronome flashes a border around the canvas on each beat.
+    let flash = if app.metronome.flashing() && !app.motion.reduced { BEAT_FLASH } else { Color::TRANSPARENT };
+    let canvas_el: Element<Msg> = container(canvas_raw.map(|e| match e {
+        CanvasEvent::Mark(mark) => Msg::MarkDrawn(mark),
+        CanvasEvent::Gesture(g) => Msg::Touch(g),
+        // The mirrored layout draws the right view on the left.
+        CanvasEvent::Roll { right, turn } => {
+            let right = right != (app.mirrored && app.right_window.is_none());
+            Msg::Roll(if right { ViewSide::Right } else { ViewSide::Left }, turn)
+        }
+    }))
         .width(Length::Fill)
         .center_x()
+        .style(container::Appearance {
+            border: Border { color: flash, width: 3.0, radius: 4.0.into() },
+            ..Default::default()
+        })
         .into();
 
     // ── Size slider (16..=40) ───────────────────────────────────────
     let size_row = row![
-        text("Size"),
+        text(t!("size")),
         Space::with_width(8),
         slider(16.0..=40.0, app.params.size, Msg::SizeChanged)
             .step(1.0)
@@ -62,30 +190,62 @@ pub fn view(app: &App) -> Element<Msg> {
         .align_items(Alignment::Center)
         .width(Length::Fill);
 
-    // ── Angle blocks ─────────────────────────────────────────────────
-    let angles_row = row![
-        build_angle_block(
-            "Left view",
-            app.params.left.rz, app.params.left.rx, app.params.left.ry,
-            Msg::LeftRzChanged, Msg::LeftRxChanged, Msg::LeftRyChanged
+    // ── Lighting ─────────────────────────────────────────────────────
+    let light_row = row![
+        build_lighting_block(app.render.light),
+        Space::with_width(16),
+        build_mode_row(app.params.left_mode, app.params.right_mode, app.render.camera),
+        checkbox(t!("grid"), app.render.show_grid).on_toggle(Msg::ToggleGrid),
+        checkbox(t!("axes"), app.render.show_axes).on_toggle(Msg::ToggleAxes),
+        labeled(checkbox(t!("heatmap"), app.render.show_heat).on_toggle(Msg::ToggleHeat), t!("heatmap-desc")),
+        labeled(checkbox(t!("letters"), app.render.show_letters).on_toggle(Msg::ToggleLetters), t!("letters-desc")),
+        text(t!("display-scale")),
+        pick_list(
+            SCALE_FACTORS.map(ScaleChoice::from_factor).to_vec(),
+            Some(ScaleChoice::from_factor(app.render.scale_factor)),
+            Msg::ScaleFactorChanged,
         ),
         Space::with_width(16),
-        build_angle_block(
-            "Right view",
-            app.params.right.rz, app.params.right.rx, app.params.right.ry,
-            Msg::RightRzChanged, Msg::RightRxChanged, Msg::RightRyChanged
+        text(t!("language")),
+        pick_list(&Lang::ALL[..], Some(app.lang), Msg::LanguageChanged),
+        // A demo stays on its profile.
+        if app.demo.is_some() {
+            Space::with_width(0).into()
+        } else {
+            build_profile_row(&app.profiles, &app.profile, &app.profile_input, app.pending_restore.is_some())
+        },
+        labeled(
+            button(text(t!("rebuild-tables")))
+                .on_press_maybe(app.solver.is_some().then_some(Msg::RebuildSolverTables)),
+            t!("rebuild-tables-desc"),
         ),
     ]
         .spacing(12)
+        .align_items(Alignment::Center)
         .width(Length::Fill);
+    let motion_row = build_motion_row(app.motion, app.render.backend);
 
-    // ── Presets + “Opposite right” toggle ────────────────────────────
-    let presets = build_presets_row(app.snap90);
+    // ── Angle blocks ─────────────────────────────────────────────────
+    let angles_row = side_by_side(
+        app.mirrored,
+        build_angle_block(&t!("left-view"), ViewSide::Left, app.params.left, app.angle_edit.as_ref()),
+        build_angle_block(&t!("right-view"), ViewSide::Right, app.params.right, app.angle_edit.as_ref()),
+    )
+        .spacing(12)
+        .width(Length::Fill);
+
+    // ── Presets + right-view link mode ────────────────────────────
+    let presets = build_presets_row(app.snap90, app.mirrored);
     let presets_row = row![
         presets,
         Space::with_width(12),
-        checkbox("Opposite right", app.link_opposite)
-            .on_toggle(Msg::ToggleOpposite),
+        text(t!("right-link")),
+        pick_list(&LinkMode::ALL[..], Some(app.link_mode), Msg::LinkModeChanged),
+        if app.right_window.is_some() {
+            button(text(t!("dock-right"))).on_press(Msg::ReattachRight)
+        } else {
+            button(text(t!("pop-out-right"))).on_press(Msg::DetachRight)
+        },
     ]
         .spacing(12)
         .align_items(Alignment::Center)
@@ -95,15 +255,94 @@ pub fn view(app: &App) -> Element<Msg> {
     let moves_scroller = build_moves_scroller();
 
     // ── Seed / Algorithm panels ──────────────────────────────────────
-    let seed_panel = build_seed_panel(&app.seed_input);
-    let alg_panel  = build_algorithm_panel(&app.alg_input);
+    let seed_panel = build_seed_panel(&app.seed_input, app.min_depth);
+    let alg_panel: Element<Msg> = column![
+        build_algorithm_panel(&app.alg_editor, &alg_completions(&app.alg_editor), app.scramble_depth, app.anki_set),
+        build_alg_watch_row(&app.alg_watch_path, app.alg_watch.is_some()),
+    ]
+        .spacing(4)
+        .into();
+    let reset_row  = build_reset_row(app.confirm_reset, app.trash.is_some(), app.post_format);
+    let analysis   = build_face_analysis(
+        app.face_analysis.as_ref().filter(|(c, _)| *c == app.cube).map(|(_, r)| r.as_slice()),
+    );
+
+    // Moves since the last reset/scramble, replayed for the tracking trail.
+    let trail = match app.tracked.filter(|_| app.show_trail) {
+        Some(p) => {
+            let moves: Vec<Move> = app.history.iter().filter_map(|t| Move::parse(t)).collect();
+            p.trail(&app.cube, &moves)
+        }
+        None => Vec::new(),
+    };
+
+    // The read quiz plays its move on a small solved cube beside the panel.
+    let round: Element<Msg> = match &app.round {
+        Some(round) => round_view(app, round),
+        None => Space::with_height(0).into(),
+    };
+
+    let notation = build_notation_panel(app.notation.as_ref(), app.notation_quiz, app.notation_limit_ms, &app.notation_stats);
+    let notation: Element<Msg> = match &app.notation {
+        Some(d) if d.quiz == Quiz::Read => {
+            let glow = d.showing_after().then_some((d.target.face, 1.0));
+            let demo = view_params(app.params.left, app.params.size * 0.6, app.params.left_mode);
+            let canvas: Element<CanvasEvent> = container(renderer::draw(Scene { cube: d.frame(), left: demo, right: None, settings: app.render, glow, tracked: None, scheme: &app.scheme, diff: &[], marks: &[], pen: None, turning: None, drop_target: false }))
+                .width(Length::Fixed(180.0))
+                .height(Length::Fixed(150.0))
+                .into();
+            row![canvas.map(|_| Msg::Noop), notation].spacing(16).into()
+        }
+        _ => notation,
+    };
+
+    // ── QR code of the state (on request) ───────────────────────────
+    let qr: Element<Msg> = if app.show_qr {
+        match QrCode::encode(share::encode_state(app).as_bytes()) {
+            Some(code) => {
+                let canvas: Element<()> = Canvas::new(QrCanvas { code })
+                    .width(Length::Fixed(QR_SIDE))
+                    .height(Length::Fixed(QR_SIDE))
+                    .into();
+                canvas.map(|_| Msg::Noop)
+            }
+            None => text(t!("qr-too-long")).into(),
+        }
+    } else {
+        Space::with_height(0).into()
+    };
+
+    let sound: Element<Msg> = if crate::audio::AVAILABLE {
+        build_sound_panel(app.sound)
+    } else {
+        Space::with_height(0).into()
+    };
+
+    let board: Element<Msg> = if crate::leaderboard::AVAILABLE {
+        build_leaderboard_panel(&app.leaderboard, app.leaderboard_queue.0.len(), app.board.as_ref(), app.syncing)
+    } else {
+        Space::with_height(0).into()
+    };
+
+    let obs: Element<Msg> = if crate::obs::AVAILABLE {
+        build_obs_panel(&app.obs)
+    } else {
+        Space::with_height(0).into()
+    };
+
+    let pad: Element<Msg> = if crate::gamepad::AVAILABLE {
+        build_gamepad_panel(app.gamepad, app.gamepad_error.as_deref())
+    } else {
+        Space::with_height(0).into()
+    };
 
     // ── Info + status line ───────────────────────────────────────────
-    let info = text(format!(
-        "Left (Rz,Rx,Ry)=({:.0}°, {:.0}°, {:.0}°)   Right (Rz,Rx,Ry)=({:.0}°, {:.0}°, {:.0}°)   Size={:.0}px",
-        app.params.left.rz,  app.params.left.rx,  app.params.left.ry,
-        app.params.right.rz, app.params.right.rx, app.params.right.ry,
-        app.params.size
+    let angles = |v: ViewUI| format!("{:.0}°, {:.0}°, {:.0}°", v.rz, v.rx, v.ry);
+    let info = text(t!(
+        "view-info",
+        l = angles(app.params.left),
+        r = angles(app.params.right),
+        size = format!("{:.0}", app.params.size),
     ));
 
     let status: Element<Msg> = if app.status.is_empty() {
@@ -116,33 +355,243 @@ pub fn view(app: &App) -> Element<Msg> {
     };
 
     // ── Title (smaller, centered) ────────────────────────────────────
-    let title: Element<Msg> = container(text("2×2 Pocket Cube — Isometric 3D").size(24))
+    let title: Element<Msg> = container(text(t!("app-title")).size(24))
         .width(Length::Fill)
         .center_x()
         .into();
 
-    // ── Layout ───────────────────────────────────────────────────────
-    column![
-        title,
-        canvas_el,
-        Space::with_height(8),
-        size_row,
+    let banner: Element<Msg> = if app.pending_recovery.is_some() {
+        container(build_recovery_banner()).width(Length::Fill).center_x().into()
+    } else {
+        Space::with_height(0).into()
+    };
+
+    // The watched solve's moves, until the cube is turned otherwise.
+    let watch: Element<Msg> = match app.watch.as_ref().filter(|w| w.in_step(app.history.len())) {
+        Some(w) => container(build_watch_strip(w)).width(Length::Fill).center_x().into(),
+        None => Space::with_height(0).into(),
+    };
+
+    let palette: Element<Msg> = match &app.palette {
+        Some(input) => container(build_palette(input)).width(Length::Fill).center_x().into(),
+        None => Space::with_height(0).into(),
+    };
+
+    // The View menu can fold the panels away, keeping the status line.
+    let panels: Element<Msg> = if app.hide_panels {
+        container(column![info, status].spacing(10)).padding(10).width(Length::Fill).into()
+    } else {
         container(
             column![
                 angles_row,
                 presets_row,
                 moves_scroller,
-                row![seed_panel, Space::with_width(16), alg_panel].spacing(16),
+                side_by_side(app.mirrored, seed_panel, alg_panel).spacing(16),
+                reset_row,
+                build_checkpoints_row(&app.checkpoints, &app.checkpoint_name),
+                build_history_panel(&app.history_tree),
+                build_script_panel(
+                    &app.history_tree.nodes()[app.history_tree.current()].comment,
+                    &app.script_path,
+                    app.script_replay.as_ref(),
+                ),
+                app.breakdown.as_ref().map_or_else(|| Space::with_height(0).into(), build_breakdown_panel),
+                app.video_sync.as_ref().map_or_else(|| Space::with_height(0).into(), build_video_sync_panel),
+                build_trace_panel(&app.trace_path, app.trace.actions.len(), app.trace_replay.as_ref()),
+                build_console_panel(&app.console_editor, &app.console_output, app.console_run.is_some()),
+                qr,
+                build_custom_scramble_panel(
+                    &app.custom_scramble,
+                    &app.kpuzzle_input,
+                    &app.verify_input,
+                    app.verify_result.as_deref(),
+                ),
+                build_tracking_panel(
+                    app.tracked,
+                    app.tracked.and_then(|p| p.locate(&app.cube)),
+                    app.show_trail,
+                    &trail,
+                ),
+                analysis,
+                build_subgroup_panel(app.subgroups.as_ref().filter(|(c, _)| *c == app.cube).map(|(_, r)| r)),
+                build_diagnostics_panel(app.show_diagnostics.then(|| diagnose(&app.cube)).as_ref()),
+                build_compare_panel(&app.diff_slots, app.diff_pair, &app.diff_pattern, app.state_diff.as_ref()),
+                build_memo_panel(
+                    app.memo.as_ref().filter(|(c, _)| *c == app.cube).map(|(_, m)| m),
+                    app.memo_drill.is_some(),
+                    &app.memo_input,
+                    app.memo_best,
+                    &app.scheme,
+                    app.scheme_editor,
+                ),
+                build_solution_panel(
+                    app.solution.as_ref().filter(|(c, _)| *c == app.cube).map(|(_, a)| a),
+                    &app.cost_input,
+                    &app.costs,
+                    &app.generators_input,
+                    app.weighted_solve,
+                    app.solving,
+                ),
+                build_watch_row(app.watch.as_ref().is_some_and(|w| !w.finished()), app.watch_pace),
+                build_trainer_panel(app.drill.map(|d| d.color), &app.neutrality, app.sheet_solutions, app.classroom_interval),
+                build_timer_row(&app.timer, app.hotkey, app.hotkey_error.as_deref(), app.inspection),
+                build_ab_panel(app.ab_case, &app.ab_inputs, app.ab_trials, app.ab_test.as_ref()),
+                build_usage_panel(&app.move_usage, app.show_usage),
+                build_pauses_panel(
+                    app.pause_threshold,
+                    app.pauses.as_deref(),
+                    view_params(app.params.left, app.params.size, app.params.left_mode),
+                    app.render,
+                ),
+                round,
+                build_daily_panel(&app.daily_log, app.daily.is_some(), Day::today()),
+                board,
+                obs,
+                build_annotate_panel(&app.annotations, !app.annotations.marks(&app.cube).is_empty()),
+                build_metronome_panel(&app.metronome, crate::audio::AVAILABLE && app.player.is_some()),
+                build_random_walk_panel(&app.random_walk),
+                notation,
+                sound,
+                pad,
+                build_controllers_panel(&app.bindings, &app.binding_input, &app.binding_command, app.learning_binding),
                 info,
                 status,
             ]
             .spacing(10)
             .width(Length::Fill)
+            .align_items(if app.mirrored { Alignment::End } else { Alignment::Start })
         )
         .padding(10)
-        .width(Length::Fill),
+        .width(Length::Fill)
+        .into()
+    };
+
+    let about: Element<Msg> = if app.show_about {
+        container(build_about_panel()).width(Length::Fill).center_x().into()
+    } else {
+        Space::with_height(0).into()
+    };
+    let debug: Element<Msg> = if app.show_debug {
+        let fields = Field::ALL.into_iter().map(|f| (f, f.value(app))).collect();
+        container(build_debug_panel(fields)).width(Length::Fill).center_x().into()
+    } else {
+        Space::with_height(0).into()
+    };
+
+    // ── Layout ───────────────────────────────────────────────────────
+    // A demo's visitors get no File menu to quit or write from.
+    let menu_bar: Element<Msg> = if app.demo.is_some() {
+        Space::with_height(0).into()
+    } else {
+        build_menu_bar(&app.render, !app.hide_panels, app.show_qr)
+    };
+
+    column![
+        menu_bar,
+        title,
+        about,
+        debug,
+        banner,
+        palette,
+        watch,
+        canvas_el,
+        Space::with_height(8),
+        size_row,
+        light_row,
+        motion_row,
+        panels,
     ]
         .spacing(12)
         .width(Length::Fill)
         .into()
-}
\ No newline at end of file
+}
+
+/// Height of the round preview's grid of cubes.
+const ROUND_H: f32 = 220.0;
+
+/// The round's scrambles as a grid of cubes, all seen like the left view,
+/// with the moves listed beside them.
+fn round_view<'a>(app: &'a App, round: &'a [Entry]) -> Element<'a, Msg> {
+    let view = view_params(app.params.left, app.params.size, app.params.left_mode);
+    let grid = Canvas::new(CubeGrid { cells: round.iter().map(|e| (&e.state, view)).collect(), settings: app.render })
+        .width(Length::Fill)
+        .height(Length::Fixed(ROUND_H));
+    let mut list = column![].spacing(4);
+    for (i, e) in round.iter().enumerate() {
+        list = list.push(text(t!("round-scramble", n = i + 1, moves = e.scramble.to_string())).size(14));
+    }
+    list = list.push(
+        row![
+            button(text(t!("round-new"))).on_press(Msg::NewRound),
+            button(text(t!("round-close"))).on_press(Msg::CloseRound),
+        ]
+            .spacing(8),
+    );
+    row![grid, list].spacing(16).align_items(Alignment::Center).into()
+}
+
+/// Scramble text size in classroom mode, and how much larger than usual
+/// the cubes are drawn.
+const CLASSROOM_TEXT: u16 = 64;
+const CLASSROOM_ZOOM: f32 = 1.6;
+
+/// Classroom mode: the scramble in huge type over both views of the cube it
+/// makes, with a footer for the teacher.
+fn classroom_view<'a>(app: &'a App, room: &'a Classroom) -> Element<'a, Msg> {
+    let size = app.params.size * CLASSROOM_ZOOM;
+    let left = view_params(app.params.left, size, app.params.left_mode);
+    let right = view_params(app.params.right, size, app.params.right_mode);
+    let canvas: Element<CanvasEvent> = container(renderer::draw(Scene {
+        cube: &room.state, left, right: Some(right), settings: app.render, glow: None, tracked: None,
+        scheme: &app.scheme, diff: &[], marks: &[], pen: None, turning: None, drop_target: false,
+    }))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+
+    let countdown = match room.remaining() {
+        Some(secs) => t!("classroom-countdown", secs = secs),
+        None => t!("classroom-hint"),
+    };
+    let footer = row![
+        text(t!("classroom-number", n = room.number)),
+        text(countdown),
+        Space::with_width(Length::Fill),
+        pick_list(&Interval::ALL[..], Some(room.interval), Msg::ClassroomIntervalChanged),
+        button(text(t!("classroom-next"))).on_press(Msg::ClassroomNext),
+        button(text(t!("classroom-leave"))).on_press(Msg::LeaveClassroom),
+    ]
+        .spacing(12)
+        .align_items(Alignment::Center);
+
+    column![
+        container(text(room.scramble.to_string()).size(CLASSROOM_TEXT))
+            .width(Length::Fill)
+            .center_x(),
+        canvas.map(|_| Msg::Noop),
+        footer,
+    ]
+        .spacing(16)
+        .padding(24)
+        .into()
+}
+
+/// Contents of the detached right-view window: the right cube alone.
+pub fn right_window_view(app: &App) -> Element<'_, Msg> {
+    let right = view_params(orbited(app, ViewSide::Right), app.params.size, app.params.right_mode);
+    let glow = glow(app);
+    let tracked = app.tracked.and_then(|p| p.stickers(&app.cube));
+    let canvas_raw: Element<CanvasEvent> = container(renderer::draw(Scene { cube: &app.cube, left: right, right: None, settings: app.render, glow, tracked, scheme: &app.scheme, diff: diff_marks(app), marks: &[], pen: None, turning: turning(app), drop_target: false }))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+    canvas_raw.map(|e| match e {
+        CanvasEvent::Roll { turn, .. } => Msg::Roll(ViewSide::Right, turn),
+        _ => Msg::Noop,
+    })
+}
+
+/// Contents of the algorithm-order popup window.
+pub fn order_window_view(alg: &Alg) -> Element<'static, Msg> {
+    container(build_alg_order(alg)).padding(12).width(Length::Fill).into()
+}
@@ -0,0 +1,78 @@
+// src/app/watch.rs
+
+//! "Solve & watch": the solver's solution played on the cube a turn at a
+//! time at a chosen pace, each turn shown turning, while the solution's
+//! moves appear one by one over the views as they are made. The turns go
+//! into the history like any others, so the solve can be stepped back
+//! through afterwards; turning the cube by hand ends the show.
+
+use std::time::{Duration, Instant};
+
+use crate::cube::{Alg, Move};
+use crate::t;
+
+/// Playback timer interval.
+pub const TICK: Duration = Duration::from_millis(33);
+
+/// Pause before the first turn, to see the cube it starts from.
+const LEAD_IN: Duration = Duration::from_millis(600);
+
+/// Time between turns, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pace(pub u16);
+
+impl Pace {
+    pub const ALL: [Pace; 4] = [Pace(250), Pace(500), Pace(1000), Pace(2000)];
+
+    pub fn gap(self) -> Duration {
+        Duration::from_millis(u64::from(self.0))
+    }
+}
+
+impl Default for Pace {
+    fn default() -> Self {
+        Pace(1000)
+    }
+}
+
+impl std::fmt::Display for Pace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&t!("watch-pace-value", secs = format!("{}", f32::from(self.0) / 1000.0)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Watch {
+    pub solution: Alg,
+    /// Turns made so far.
+    pub shown: usize,
+    /// History length when the show started.
+    base: usize,
+    next_at: Instant,
+}
+
+impl Watch {
+    /// Play `solution` on a cube with `history_len` moves on record.
+    pub fn new(solution: Alg, history_len: usize) -> Self {
+        Self { solution, shown: 0, base: history_len, next_at: Instant::now() + LEAD_IN }
+    }
+
+    /// The next turn, once it is due; the one after follows `gap` later.
+    pub fn due(&mut self, gap: Duration) -> Option<Move> {
+        let now = Instant::now();
+        let m = *self.solution.0.get(self.shown).filter(|_| now >= self.next_at)?;
+        self.shown += 1;
+        self.next_at = now + gap;
+        Some(m)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.shown >= self.solution.len()
+    }
+
+    /// Whether the history is still the one the show is making, with no
+    /// moves of anyone else's since.
+    pub fn in_step(&self, history_len: usize) -> bool {
+        history_len == self.base + self.shown
+    }
+}
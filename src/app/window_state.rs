@@ -0,0 +1,107 @@
+// src/app/window_state.rs
+
+//! The main window's size, position, and maximized state, saved on exit and
+//! restored on the next launch. Positions are desktop coordinates, so the
+//! window opens again on the monitor it was closed on. A first launch gets
+//! a size that fits every control, and the window never shrinks below one
+//! where they would clip.
+
+use std::path::PathBuf;
+
+use iced::{window, Point, Size};
+
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+const HEADER: &str = "rubics window v1";
+
+/// Size on first launch.
+pub const DEFAULT_SIZE: Size = Size::new(1280.0, 900.0);
+/// Smallest size before the control rows start to clip.
+pub const MIN_SIZE: Size = Size::new(960.0, 640.0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowState {
+    /// Size when not maximized, in logical pixels.
+    pub size: Size,
+    /// Top-left corner when not maximized; `None` until the window has
+    /// been placed, leaving it to the window manager.
+    pub position: Option<Point>,
+    pub maximized: bool,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self { size: DEFAULT_SIZE, position: None, maximized: false }
+    }
+}
+
+impl WindowState {
+    /// Settings opening the main window as it was left. Maximizing has no
+    /// setting; it is a command once the window exists.
+    pub fn settings(&self) -> window::Settings {
+        window::Settings {
+            size: Size::new(self.size.width.max(MIN_SIZE.width), self.size.height.max(MIN_SIZE.height)),
+            position: self.position.map_or(window::Position::Default, window::Position::Specific),
+            min_size: Some(MIN_SIZE),
+            exit_on_close_request: false,
+            ..Default::default()
+        }
+    }
+
+    /// Follow a resize. The maximized size isn't kept, so un-maximizing
+    /// next time restores the size from before.
+    pub fn resized(&mut self, size: Size, maximized: bool) {
+        self.maximized = maximized;
+        if !maximized {
+            self.size = size;
+        }
+    }
+
+    pub fn moved(&mut self, at: Point) {
+        if !self.maximized {
+            self.position = Some(at);
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let mut pairs = vec![
+            ("width", format!("{:.0}", self.size.width)),
+            ("height", format!("{:.0}", self.size.height)),
+            ("maximized", self.maximized.to_string()),
+        ];
+        if let Some(p) = self.position {
+            pairs.push(("x", format!("{:.0}", p.x)));
+            pairs.push(("y", format!("{:.0}", p.y)));
+        }
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let num = |k: &str| kv.get(k).and_then(|v| v.parse::<f32>().ok()).filter(|v| v.is_finite());
+        let mut out = Self::default();
+        if let (Some(w), Some(h)) = (num("width"), num("height")) {
+            out.size = Size::new(w.max(MIN_SIZE.width), h.max(MIN_SIZE.height));
+        }
+        if let (Some(x), Some(y)) = (num("x"), num("y")) {
+            out.position = Some(Point::new(x, y));
+        }
+        out.maximized = kv.get("maximized").is_some_and(|v| v == "true");
+        out
+    }
+}
+
+/// Location of the window state file.
+pub fn state_path() -> PathBuf {
+    data_dir().join("window.txt")
+}
+
+pub fn load() -> WindowState {
+    std::fs::read_to_string(state_path())
+        .map(|t| WindowState::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(state: &WindowState) -> std::io::Result<()> {
+    save_file(&state_path(), state.encode())
+}
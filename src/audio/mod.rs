@@ -0,0 +1,246 @@
+// src/audio/mod.rs
+
+//! Sound effects: a click per turn, a beep at each inspection call, a
+//! chime on solve, and the metronome tick, each with its own volume and
+//! mute. The tones are
+//! synthesized, so there are no asset files. Playback runs on its own
+//! thread because the output stream can't move between threads.
+//!
+//! Playback needs the `sound` feature (rodio). Without it [`Player::spawn`]
+//! returns `None` and the settings panel is hidden.
+
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+use crate::cube::Cube;
+use crate::events::{CubeEvent, Plugin};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+/// Whether this build can play sound.
+pub const AVAILABLE: bool = cfg!(feature = "sound");
+
+const HEADER: &str = "rubics sound settings v1";
+
+/// The moments that make a sound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cue { Move, Countdown, Solved, Beat }
+
+impl Cue {
+    pub const ALL: [Cue; 4] = [Cue::Move, Cue::Countdown, Cue::Solved, Cue::Beat];
+
+    fn key(self) -> &'static str {
+        match self {
+            Cue::Move => "move", Cue::Countdown => "countdown", Cue::Solved => "solved", Cue::Beat => "beat",
+        }
+    }
+}
+
+impl std::fmt::Display for Cue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Cue::Move      => t!("cue-move"),
+            Cue::Countdown => t!("cue-countdown"),
+            Cue::Solved    => t!("cue-solved"),
+            Cue::Beat      => t!("cue-beat"),
+        }.as_str())
+    }
+}
+
+/// Which click plays on each turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClickSound { #[default] Tick, Wood, Beep }
+
+impl ClickSound {
+    pub const ALL: [ClickSound; 3] = [ClickSound::Tick, ClickSound::Wood, ClickSound::Beep];
+
+    fn key(self) -> &'static str {
+        match self { ClickSound::Tick => "tick", ClickSound::Wood => "wood", ClickSound::Beep => "beep" }
+    }
+}
+
+impl std::fmt::Display for ClickSound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ClickSound::Tick => t!("click-tick"),
+            ClickSound::Wood => t!("click-wood"),
+            ClickSound::Beep => t!("click-beep"),
+        }.as_str())
+    }
+}
+
+/// Volume (0–1) and mute for one cue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CueSettings {
+    pub volume: f32,
+    pub muted: bool,
+}
+
+impl Default for CueSettings {
+    fn default() -> Self {
+        Self { volume: 0.5, muted: false }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SoundSettings {
+    pub click: ClickSound,
+    cues: [CueSettings; 4],
+}
+
+impl SoundSettings {
+    pub fn cue(&self, cue: Cue) -> CueSettings {
+        self.cues[cue as usize]
+    }
+
+    pub fn cue_mut(&mut self, cue: Cue) -> &mut CueSettings {
+        &mut self.cues[cue as usize]
+    }
+
+    pub fn encode(&self) -> String {
+        let mut pairs = vec![("click", self.click.key().to_string())];
+        for cue in Cue::ALL {
+            let s = self.cue(cue);
+            pairs.push((cue.key(), format!("{:.2} {}", s.volume, if s.muted { "muted" } else { "on" })));
+        }
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let mut out = Self::default();
+        if let Some(c) = kv.get("click").and_then(|v| ClickSound::ALL.into_iter().find(|c| c.key() == v)) {
+            out.click = c;
+        }
+        for cue in Cue::ALL {
+            let Some((vol, state)) = kv.get(cue.key()).and_then(|v| v.split_once(' ')) else { continue };
+            if let Ok(vol) = vol.parse::<f32>() {
+                *out.cue_mut(cue) = CueSettings { volume: vol.clamp(0.0, 1.0), muted: state == "muted" };
+            }
+        }
+        out
+    }
+}
+
+/// Location of the sound settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("sound.txt")
+}
+
+pub fn load() -> SoundSettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| SoundSettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &SoundSettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
+
+// ----- player -----
+
+#[derive(Debug)]
+#[cfg_attr(not(feature = "sound"), allow(dead_code))]
+enum Request {
+    Play(Cue),
+    Settings(SoundSettings),
+}
+
+/// Handle to the audio thread; cheap to clone.
+#[derive(Debug, Clone)]
+pub struct Player(mpsc::Sender<Request>);
+
+impl Player {
+    /// Start the audio thread. `None` without the `sound` feature or when
+    /// no output device opens.
+    pub fn spawn(settings: SoundSettings) -> Option<Player> {
+        let (tx, rx) = mpsc::channel();
+        backend::spawn(settings, rx).then_some(Player(tx))
+    }
+
+    pub fn play(&self, cue: Cue) {
+        let _ = self.0.send(Request::Play(cue));
+    }
+
+    pub fn set_settings(&self, settings: SoundSettings) {
+        let _ = self.0.send(Request::Settings(settings));
+    }
+}
+
+impl Plugin for Player {
+    fn on_event(&mut self, event: &CubeEvent, _cube: &Cube) {
+        match event {
+            CubeEvent::MoveApplied(_) => self.play(Cue::Move),
+            CubeEvent::InspectionCall { .. } => self.play(Cue::Countdown),
+            CubeEvent::Solved { .. } => self.play(Cue::Solved),
+            CubeEvent::Scrambled(_) => {}
+        }
+    }
+}
+
+#[cfg(feature = "sound")]
+mod backend {
+    use std::sync::mpsc::{self, Receiver};
+    use std::time::Duration;
+
+    use rodio::source::{SineWave, Source};
+    use rodio::{OutputStream, OutputStreamHandle};
+
+    use super::{ClickSound, Cue, Request, SoundSettings};
+
+    pub fn spawn(mut settings: SoundSettings, rx: Receiver<Request>) -> bool {
+        // Report whether the device opened before handing back a player.
+        let (ready_tx, ready_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let Ok((_stream, handle)) = OutputStream::try_default() else {
+                let _ = ready_tx.send(false);
+                return;
+            };
+            let _ = ready_tx.send(true);
+            for req in rx {
+                match req {
+                    Request::Settings(s) => settings = s,
+                    Request::Play(cue) => play(&handle, &settings, cue),
+                }
+            }
+        });
+        ready_rx.recv().unwrap_or(false)
+    }
+
+    fn play(handle: &OutputStreamHandle, settings: &SoundSettings, cue: Cue) {
+        let s = settings.cue(cue);
+        if s.muted || s.volume <= 0.0 {
+            return;
+        }
+        let tone = |hz: f32, ms: u64| {
+            SineWave::new(hz).take_duration(Duration::from_millis(ms)).fade_in(Duration::from_millis(2))
+        };
+        let _ = match cue {
+            Cue::Move => {
+                let (hz, ms) = match settings.click {
+                    ClickSound::Tick => (2000.0, 12),
+                    ClickSound::Wood => (700.0, 25),
+                    ClickSound::Beep => (1200.0, 40),
+                };
+                handle.play_raw(tone(hz, ms).amplify(s.volume))
+            }
+            Cue::Countdown => handle.play_raw(tone(880.0, 150).amplify(s.volume)),
+            Cue::Beat => handle.play_raw(tone(1000.0, 30).amplify(s.volume)),
+            Cue::Solved => {
+                let chime = tone(660.0, 160).mix(tone(990.0, 240).delay(Duration::from_millis(140)));
+                handle.play_raw(chime.amplify(s.volume))
+            }
+        };
+    }
+}
+
+#[cfg(not(feature = "sound"))]
+mod backend {
+    use std::sync::mpsc::Receiver;
+
+    use super::{Request, SoundSettings};
+
+    pub fn spawn(_settings: SoundSettings, _rx: Receiver<Request>) -> bool {
+        false
+    }
+}
@@ -0,0 +1,16 @@
+// src/background.rs
+
+//! Blocking work behind `Command::perform`: network exchanges with their
+//! own timeouts run on a thread of their own, so a slow peer never holds
+//! up the executor that also drives the UI.
+
+use iced::futures::channel::oneshot;
+
+/// Run `work` on a new thread and wait for its result without blocking.
+pub async fn run<T: Send + 'static>(work: impl FnOnce() -> T + Send + 'static) -> T {
+    let (tx, rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(work());
+    });
+    rx.await.expect("background work panicked")
+}
@@ -0,0 +1,192 @@
+// src/cli.rs
+
+//! Command-line tools, run instead of the app when the first argument
+//! names one.
+//!
+//! `rubics thumbs <dir> [file]` reads algorithms, one per line from `file`
+//! or standard input, and writes into `dir` a last-layer thumbnail of the
+//! case each one solves, plus `cases.tsv` pairing every picture with its
+//! algorithm, ready to import as flash cards. A line may start with a name
+//! and a colon (`Sune: R U R' U R U2 R'`); `//` comments are ignored.
+//!
+//! `rubics serve [port]` runs a local diagram server (see `crate::serve`).
+//!
+//! `rubics register` adds the app to the desktop's "Open with" choices
+//! (freedesktop.org desktops only): a desktop entry running this binary on
+//! the file, and MIME types for `.alg` files and saved session traces.
+
+use std::fmt::Write as _;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+
+use crate::cube::{Alg, Cube};
+use crate::logic::alg::parse_alg;
+use crate::render::thumb;
+use crate::{t, Error};
+
+/// Sticker size in the thumbnails, in pixels.
+const THUMB_UNIT: f32 = 40.0;
+
+/// Run the tool the arguments name, returning the exit code; `None` when
+/// they name none, to start the app.
+pub fn run() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((cmd, rest)) if cmd == "thumbs" => thumbs(rest),
+        Some((cmd, rest)) if cmd == "serve" => serve(rest),
+        Some((cmd, rest)) if cmd == "register" => register(rest),
+        _ => return None,
+    };
+    Some(match result {
+        Ok(done) => {
+            println!("{done}");
+            0
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    })
+}
+
+/// One case: its name, if the line gave one, and the algorithm solving it.
+struct Case {
+    name: Option<String>,
+    alg: Alg,
+}
+
+/// The cases in `text`, one per non-blank line.
+fn cases(text: &str) -> Result<Vec<Case>, String> {
+    let mut out = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split_once("//").map_or(line, |(code, _)| code).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (name, moves) = match line.split_once(':') {
+            Some((name, moves)) => (Some(name.trim().to_string()), moves),
+            None => (None, line),
+        };
+        let alg = parse_alg(moves).map_err(|e| t!("thumbs-bad-line", line = i + 1, error = e.to_string()))?;
+        out.push(Case { name, alg });
+    }
+    Ok(out)
+}
+
+/// File name for case `n` (from 1): its number, then its name lowercased
+/// with anything but letters and digits as dashes.
+fn file_name(n: usize, name: Option<&str>) -> String {
+    let slug: String = name
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    let slug = slug.split('-').filter(|s| !s.is_empty()).collect::<Vec<_>>().join("-");
+    if slug.is_empty() { format!("{n:02}.svg") } else { format!("{n:02}-{slug}.svg") }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| Error::write(path, e).to_string())
+}
+
+fn thumbs(args: &[String]) -> Result<String, String> {
+    let (dir, source) = match args {
+        [dir] => (Path::new(dir), None),
+        [dir, file] => (Path::new(dir), Some(Path::new(file))),
+        _ => return Err(t!("thumbs-usage")),
+    };
+    let text = match source {
+        Some(file) => std::fs::read_to_string(file).map_err(|e| Error::read(file, e).to_string())?,
+        None => {
+            let mut text = String::new();
+            std::io::stdin().read_to_string(&mut text).map_err(|e| e.to_string())?;
+            text
+        }
+    };
+    let cases = cases(&text)?;
+    if cases.is_empty() {
+        return Err(t!("thumbs-empty"));
+    }
+    std::fs::create_dir_all(dir).map_err(|e| Error::write(dir, e).to_string())?;
+
+    let mut deck = String::new();
+    for (i, case) in cases.iter().enumerate() {
+        // The case is the state the algorithm solves.
+        let mut cube = Cube::default();
+        cube.apply_alg(&case.alg.inverse());
+        let file = file_name(i + 1, case.name.as_deref());
+        write(&dir.join(&file), &thumb::svg(&cube, THUMB_UNIT))?;
+        let _ = writeln!(deck, "<img src=\"{file}\">\t{}\t{}", case.name.as_deref().unwrap_or(""), case.alg);
+    }
+    write(&dir.join("cases.tsv"), &deck)?;
+    Ok(t!("thumbs-written", n = cases.len(), dir = dir.display().to_string()))
+}
+
+fn serve(args: &[String]) -> Result<String, String> {
+    let port = match args {
+        [] => crate::serve::DEFAULT_PORT,
+        [port] => port.parse().map_err(|_| t!("serve-usage"))?,
+        _ => return Err(t!("serve-usage")),
+    };
+    crate::serve::run(port).map(|()| String::new())
+}
+
+/// MIME types for the files the app opens that have none of their own.
+const MIME_PACKAGE: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+  <mime-type type="text/x-rubics-alg">
+    <sub-class-of type="text/plain"/>
+    <comment>Cube algorithm</comment>
+    <glob pattern="*.alg"/>
+  </mime-type>
+  <mime-type type="text/x-rubics-trace">
+    <sub-class-of type="text/plain"/>
+    <comment>Rubics session trace</comment>
+    <magic priority="60">
+      <match type="string" offset="0" value="# rubics session trace"/>
+    </magic>
+  </mime-type>
+</mime-info>
+"##;
+
+fn desktop_entry(exe: &Path) -> String {
+    format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Rubics\n\
+         Comment={}\n\
+         Exec=\"{}\" %f\n\
+         Terminal=false\n\
+         Categories=Education;Game;\n\
+         MimeType=text/x-rubics-alg;text/x-rubics-trace;application/json;\n",
+        t!("app-title"),
+        exe.display(),
+    )
+}
+
+fn register(args: &[String]) -> Result<String, String> {
+    if !args.is_empty() {
+        return Err(t!("register-usage"));
+    }
+    if !cfg!(target_os = "linux") {
+        return Err(t!("register-unsupported"));
+    }
+    let share = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+        .ok_or_else(|| t!("register-no-home"))?;
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let (mime, apps) = (share.join("mime"), share.join("applications"));
+    for dir in [mime.join("packages"), apps.clone()] {
+        std::fs::create_dir_all(&dir).map_err(|e| Error::write(&dir, e).to_string())?;
+    }
+    write(&mime.join("packages/rubics.xml"), MIME_PACKAGE)?;
+    let entry = apps.join("rubics.desktop");
+    write(&entry, &desktop_entry(&exe))?;
+    // Refresh the desktop's caches; without the tools it picks the files
+    // up on its next scan.
+    for (tool, dir) in [("update-mime-database", &mime), ("update-desktop-database", &apps)] {
+        let _ = std::process::Command::new(tool).arg(dir).status();
+    }
+    Ok(t!("register-done", exe = exe.display().to_string(), entry = entry.display().to_string()))
+}
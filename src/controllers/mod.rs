@@ -0,0 +1,308 @@
+// src/controllers/mod.rs
+
+//! Hardware buttons: MIDI controllers (notes and buttons sending control
+//! changes) and Elgato Stream Decks, each press mapped to a command-palette
+//! line such as `R U R'`, `camera 30 45 0`, or `drill`. The bindings are
+//! kept in a small settings file and edited in the controller panel.
+//!
+//! Listening needs the `controllers` feature and Linux. It reads the raw
+//! devices (`/dev/snd/midiC*D*`, `/dev/midi*`, and the Stream Deck's
+//! `/dev/hidraw*`) on one thread each, so it needs no extra libraries.
+//! Other platforms have no such devices: there the feature listens to
+//! nothing, and the controller panel says so.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use iced::Subscription;
+
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+/// Whether this platform has the devices controllers are read from.
+pub const SUPPORTED: bool = cfg!(target_os = "linux");
+
+/// Whether this build listens to controllers.
+pub const AVAILABLE: bool = SUPPORTED && cfg!(feature = "controllers");
+
+const HEADER: &str = "rubics controller bindings v1";
+
+/// One button on a controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Input {
+    /// MIDI note-on, by note number.
+    Note(u8),
+    /// MIDI control change going to 64 or more, by controller number.
+    Control(u8),
+    /// Stream Deck key, counted from 0 at the top left.
+    Deck(u8),
+}
+
+impl Input {
+    /// Read back the text [`Display`](fmt::Display) writes, e.g. `note 60`.
+    pub fn parse(s: &str) -> Option<Input> {
+        let (kind, n) = s.trim().split_once(' ')?;
+        let n = n.trim().parse().ok()?;
+        match kind.to_ascii_lowercase().as_str() {
+            "note" => Some(Input::Note(n)),
+            "cc" => Some(Input::Control(n)),
+            "deck" => Some(Input::Deck(n)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Note(n) => write!(f, "note {n}"),
+            Input::Control(n) => write!(f, "cc {n}"),
+            Input::Deck(n) => write!(f, "deck {n}"),
+        }
+    }
+}
+
+/// Each input's command line, ordered by input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bindings(Vec<(Input, String)>);
+
+impl Bindings {
+    pub fn iter(&self) -> impl Iterator<Item = &(Input, String)> {
+        self.0.iter()
+    }
+
+    pub fn command(&self, input: Input) -> Option<&str> {
+        self.0.iter().find(|(i, _)| *i == input).map(|(_, c)| c.as_str())
+    }
+
+    /// Bind `input` to `command`, replacing its old binding.
+    pub fn set(&mut self, input: Input, command: String) {
+        self.remove(input);
+        let at = self.0.partition_point(|(i, _)| *i < input);
+        self.0.insert(at, (input, command));
+    }
+
+    pub fn remove(&mut self, input: Input) {
+        self.0.retain(|(i, _)| *i != input);
+    }
+
+    pub fn encode(&self) -> String {
+        let keys: Vec<String> = self.0.iter().map(|(i, _)| i.to_string()).collect();
+        let pairs: Vec<(&str, String)> = keys.iter().zip(&self.0).map(|(k, (_, c))| (k.as_str(), c.clone())).collect();
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let mut out = Self::default();
+        for (k, v) in read_kv(text) {
+            if let Some(input) = Input::parse(&k) {
+                out.set(input, v);
+            }
+        }
+        out
+    }
+}
+
+/// Location of the bindings file.
+pub fn bindings_path() -> PathBuf {
+    data_dir().join("controllers.txt")
+}
+
+pub fn load() -> Bindings {
+    std::fs::read_to_string(bindings_path())
+        .map(|t| Bindings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(bindings: &Bindings) -> std::io::Result<()> {
+    save_file(&bindings_path(), bindings.encode())
+}
+
+// ----- decoding -----
+
+/// MIDI byte stream decoder, keeping the running status between reads.
+#[derive(Debug, Default)]
+#[cfg_attr(not(all(feature = "controllers", target_os = "linux")), allow(dead_code))]
+struct MidiDecoder {
+    status: u8,
+    data: Vec<u8>,
+}
+
+#[cfg_attr(not(all(feature = "controllers", target_os = "linux")), allow(dead_code))]
+impl MidiDecoder {
+    /// Feed one byte; a press once a note-on or control change completes.
+    fn push(&mut self, byte: u8) -> Option<Input> {
+        match byte {
+            // Real-time bytes may appear anywhere and leave the status alone.
+            0xF8..=0xFF => return None,
+            0x80..=0xEF => {
+                self.status = byte;
+                self.data.clear();
+                return None;
+            }
+            // System messages cancel the running status.
+            0xF0..=0xF7 => {
+                self.status = 0;
+                self.data.clear();
+                return None;
+            }
+            _ => {}
+        }
+        let len = match self.status & 0xF0 {
+            0xC0 | 0xD0 => 1,
+            0x80..=0xE0 => 2,
+            _ => return None,
+        };
+        self.data.push(byte);
+        if self.data.len() < len {
+            return None;
+        }
+        let data = std::mem::take(&mut self.data);
+        match (self.status & 0xF0, &data[..]) {
+            (0x90, &[note, velocity]) if velocity > 0 => Some(Input::Note(note)),
+            (0xB0, &[control, value]) if value >= 64 => Some(Input::Control(control)),
+            _ => None,
+        }
+    }
+}
+
+/// Longest input report of the original decks.
+const V1_REPORT: usize = 17;
+
+/// Keys newly down in a Stream Deck input report, given the keys down
+/// before (updated). Original decks send short reports: `01` then one byte
+/// per key. Later ones send long reports: `01 00`, a little-endian key
+/// count, then one byte per key.
+#[cfg_attr(not(all(feature = "controllers", target_os = "linux")), allow(dead_code))]
+fn deck_presses(report: &[u8], down: &mut Vec<bool>) -> Vec<Input> {
+    let keys = match report {
+        [0x01, rest @ ..] if report.len() <= V1_REPORT => rest,
+        [0x01, 0x00, lo, hi, rest @ ..] => {
+            let n = usize::from(*lo) | usize::from(*hi) << 8;
+            &rest[..n.min(rest.len())]
+        }
+        _ => return Vec::new(),
+    };
+    down.resize(keys.len(), false);
+    let mut out = Vec::new();
+    for (i, (&k, was)) in keys.iter().zip(down.iter_mut()).enumerate() {
+        let now = k != 0;
+        if now && !*was {
+            out.push(Input::Deck(i as u8));
+        }
+        *was = now;
+    }
+    out
+}
+
+// ----- listening -----
+
+/// Controller presses, for as long as the app runs.
+pub fn subscription() -> Subscription<Input> {
+    backend::subscription()
+}
+
+#[cfg(all(feature = "controllers", target_os = "linux"))]
+mod backend {
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+
+    use iced::futures::channel::mpsc::{self, UnboundedSender};
+    use iced::futures::{SinkExt, StreamExt};
+    use iced::Subscription;
+
+    use super::{deck_presses, Input, MidiDecoder};
+
+    /// USB vendor id of Elgato, in the `HID_ID` of `/sys/class/hidraw/*`.
+    const ELGATO: &str = "00000FD9";
+
+    pub fn subscription() -> Subscription<Input> {
+        struct Controllers;
+        iced::subscription::channel(std::any::TypeId::of::<Controllers>(), 64, |mut output| async move {
+            let (tx, mut rx) = mpsc::unbounded();
+            for path in midi_devices() {
+                let tx = tx.clone();
+                std::thread::spawn(move || read_midi(&path, tx));
+            }
+            for path in deck_devices() {
+                let tx = tx.clone();
+                std::thread::spawn(move || read_deck(&path, tx));
+            }
+            drop(tx);
+            while let Some(input) = rx.next().await {
+                let _ = output.send(input).await;
+            }
+            // Every device closed: nothing more will come.
+            loop {
+                iced::futures::future::pending::<()>().await;
+            }
+        })
+    }
+
+    fn entries(dir: &str, prefix: &str) -> Vec<PathBuf> {
+        let Ok(dir) = std::fs::read_dir(dir) else { return Vec::new() };
+        let mut out: Vec<PathBuf> = dir
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+            .map(|e| e.path())
+            .collect();
+        out.sort();
+        out
+    }
+
+    fn midi_devices() -> Vec<PathBuf> {
+        let mut out = entries("/dev/snd", "midiC");
+        out.extend(entries("/dev", "midi"));
+        out
+    }
+
+    fn deck_devices() -> Vec<PathBuf> {
+        entries("/dev", "hidraw")
+            .into_iter()
+            .filter(|dev| {
+                let name = dev.file_name().unwrap_or_default();
+                let uevent = Path::new("/sys/class/hidraw").join(name).join("device/uevent");
+                std::fs::read_to_string(uevent).is_ok_and(|u| u.contains(ELGATO))
+            })
+            .collect()
+    }
+
+    fn read_midi(path: &Path, tx: UnboundedSender<Input>) {
+        let Ok(mut file) = File::open(path) else { return };
+        let mut decoder = MidiDecoder::default();
+        let mut buf = [0u8; 64];
+        while let Ok(n @ 1..) = file.read(&mut buf) {
+            for &byte in &buf[..n] {
+                if let Some(input) = decoder.push(byte)
+                    && tx.unbounded_send(input).is_err()
+                {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn read_deck(path: &Path, tx: UnboundedSender<Input>) {
+        let Ok(mut file) = File::open(path) else { return };
+        let mut down = Vec::new();
+        let mut report = [0u8; 512];
+        while let Ok(n @ 1..) = file.read(&mut report) {
+            for input in deck_presses(&report[..n], &mut down) {
+                if tx.unbounded_send(input).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(all(feature = "controllers", target_os = "linux")))]
+mod backend {
+    use iced::Subscription;
+
+    use super::Input;
+
+    pub fn subscription() -> Subscription<Input> {
+        Subscription::none()
+    }
+}
@@ -3,17 +3,47 @@
 //! In-memory 2×2 cube model with face rotations (U, D, F, B, L, R).
 //! The representation uses 2×2 faces and exposes move methods and getters.
 
-// src/cube/mod.rs
+pub mod moves;
+pub mod packed;
+pub mod perm;
+pub mod tables;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub use moves::{Alg, Move, Turn};
+pub use packed::PackedCube;
+pub use perm::CornerPerm;
+pub use tables::MoveTables;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FaceId { U, D, F, B, L, R }
 
+impl FaceId {
+    /// All faces, in storage order.
+    pub const ALL: [FaceId; 6] = [FaceId::U, FaceId::D, FaceId::F, FaceId::B, FaceId::L, FaceId::R];
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Col { W, Y, G, B, O, R }
 
+impl Col {
+    pub const ALL: [Col; 6] = [Col::W, Col::Y, Col::G, Col::B, Col::O, Col::R];
+
+    /// Single-letter name used in facelet strings.
+    pub fn letter(self) -> char {
+        match self { Col::W => 'W', Col::Y => 'Y', Col::G => 'G', Col::B => 'B', Col::O => 'O', Col::R => 'R' }
+    }
+
+    pub fn from_letter(ch: char) -> Option<Col> {
+        match ch.to_ascii_uppercase() {
+            'W' => Some(Col::W), 'Y' => Some(Col::Y), 'G' => Some(Col::G),
+            'B' => Some(Col::B), 'O' => Some(Col::O), 'R' => Some(Col::R),
+            _ => None,
+        }
+    }
+}
+
 pub type Face = [[Col; 2]; 2];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cube {
     faces: [Face; 6], // order: U, D, F, B, L, R
 }
@@ -39,6 +69,48 @@ impl Cube {
     #[inline] pub fn face(&self, id: FaceId) -> &Face {
         &self.faces[id as usize]
     }
+
+    /// Overwrite one sticker, e.g. to repair a hand-entered state.
+    pub fn set_sticker(&mut self, id: FaceId, r: usize, c: usize, col: Col) {
+        self.faces[id as usize][r][c] = col;
+    }
+
+    /// 24-char facelet string: faces in U, D, F, B, L, R order, each row-major,
+    /// one color letter (`W Y G B O R`) per sticker.
+    pub fn to_facelets(&self) -> String {
+        self.faces.iter().flatten().flatten().map(|&c| c.letter()).collect()
+    }
+
+    /// Inverse of [`Cube::to_facelets`]; `None` on bad length or letters.
+    pub fn from_facelets(s: &str) -> Option<Cube> {
+        let cols: Vec<Col> = s.chars().map(Col::from_letter).collect::<Option<_>>()?;
+        if cols.len() != 24 {
+            return None;
+        }
+        let mut faces = [[[Col::W; 2]; 2]; 6];
+        for (i, c) in cols.into_iter().enumerate() {
+            faces[i / 4][(i / 2) % 2][i % 2] = c;
+        }
+        Some(Cube { faces })
+    }
+
+    /// Copy with every sticker color `c` replaced by `map[c as usize]`.
+    pub fn recolored(&self, map: &[Col; 6]) -> Cube {
+        Cube { faces: self.faces.map(|f| f.map(|row| row.map(|c| map[c as usize]))) }
+    }
+
+    /// True when some face shows only color `c`.
+    pub fn has_face(&self, c: Col) -> bool {
+        self.faces.iter().any(|f| f.iter().flatten().all(|&x| x == c))
+    }
+
+    /// True when every face shows a single color.
+    pub fn is_solved(&self) -> bool {
+        self.faces.iter().all(|f| {
+            let c = f[0][0];
+            f.iter().flatten().all(|&x| x == c)
+        })
+    }
 }
 
 // --------- small helpers ---------
@@ -111,15 +183,15 @@ impl Cube {
     pub fn mv_b2(&mut self)         { self.b_180(); }
 }
 
-// Each move is face rotation + a 4-way cycle of edge rows/cols.
-// The cycles below are chosen to work with the renderer’s face orientations.
+// Each move is a face rotation plus a 4-way cycle of the adjacent strips.
+// Sticker rows/cols follow the renderer's layout (`render::face`), so U and
+// D are stored mirrored relative to looking at them from outside: a physical
+// CW turn of U/D is `rot_face_ccw` in storage order.
 //
-// If anything looks mirrored in your specific render, swap the order marked
-// with comments (“// may need reverse”); but these should match the earlier
-// U/F/R identity and the D/L/B flipped view.
+// Strips are always read in ascending storage order; "(reversed)" marks
+// cycles where the pair lands in the opposite order.
 
 impl Cube {
-
     fn u_cw(&mut self) {
         const U: usize = FaceId::U as usize;
         const F: usize = FaceId::F as usize;
@@ -127,19 +199,14 @@ impl Cube {
         const B: usize = FaceId::B as usize;
         const L: usize = FaceId::L as usize;
 
-        rot_face_cw(&mut self.faces[U]);
+        rot_face_ccw(&mut self.faces[U]);
 
-        // snapshot rows
+        // cycle F -> L -> B -> R -> F (top rows)
         let f0 = self.faces[F][0];
-        let r0 = self.faces[R][0];
-        let b0 = self.faces[B][0];
-        let l0 = self.faces[L][0];
-
-        // cycle F -> R -> B -> L -> F (top rows)
-        self.faces[R][0] = f0;
-        self.faces[B][0] = r0;
-        self.faces[L][0] = b0;
-        self.faces[F][0] = l0;
+        self.faces[F][0] = self.faces[R][0];
+        self.faces[R][0] = self.faces[B][0];
+        self.faces[B][0] = self.faces[L][0];
+        self.faces[L][0] = f0;
     }
 
     fn d_cw(&mut self) {
@@ -149,19 +216,14 @@ impl Cube {
         const B: usize = FaceId::B as usize;
         const L: usize = FaceId::L as usize;
 
-        rot_face_cw(&mut self.faces[D]);
+        rot_face_ccw(&mut self.faces[D]);
 
-        // snapshot rows
+        // cycle F -> R -> B -> L -> F (bottom rows)
         let f1 = self.faces[F][1];
-        let r1 = self.faces[R][1];
-        let b1 = self.faces[B][1];
-        let l1 = self.faces[L][1];
-
-        // cycle F(bottom) -> L(bottom) -> B(bottom) -> R(bottom) -> F(bottom)
-        self.faces[L][1] = f1;
-        self.faces[B][1] = l1;
-        self.faces[R][1] = b1;
-        self.faces[F][1] = r1;
+        self.faces[F][1] = self.faces[L][1];
+        self.faces[L][1] = self.faces[B][1];
+        self.faces[B][1] = self.faces[R][1];
+        self.faces[R][1] = f1;
     }
 
     fn r_cw(&mut self) {
@@ -173,27 +235,27 @@ impl Cube {
 
         rot_face_cw(&mut self.faces[R]);
 
-        // snapshot columns (right col of U/F/D, left col of B, note reversals)
+        // snapshot columns (right col of U/F/D, left col of B)
         let u_col = [self.faces[U][0][1], self.faces[U][1][1]];
         let f_col = [self.faces[F][0][1], self.faces[F][1][1]];
         let d_col = [self.faces[D][0][1], self.faces[D][1][1]];
         let b_col = [self.faces[B][0][0], self.faces[B][1][0]]; // B left
 
-        // U right -> F right
-        self.faces[F][0][1] = u_col[0];
-        self.faces[F][1][1] = u_col[1];
+        // U right -> B left
+        self.faces[B][0][0] = u_col[0];
+        self.faces[B][1][0] = u_col[1];
 
-        // F right -> D right
-        self.faces[D][0][1] = f_col[0];
-        self.faces[D][1][1] = f_col[1];
+        // B left -> D right
+        self.faces[D][0][1] = b_col[0];
+        self.faces[D][1][1] = b_col[1];
 
-        // D right -> B left (reversed)
-        self.faces[B][0][0] = d_col[1];
-        self.faces[B][1][0] = d_col[0];
+        // D right -> F right (reversed)
+        self.faces[F][0][1] = d_col[1];
+        self.faces[F][1][1] = d_col[0];
 
-        // B left (reversed) -> U right
-        self.faces[U][0][1] = b_col[1];
-        self.faces[U][1][1] = b_col[0];
+        // F right -> U right (reversed)
+        self.faces[U][0][1] = f_col[1];
+        self.faces[U][1][1] = f_col[0];
     }
 
     fn l_cw(&mut self) {
@@ -211,21 +273,21 @@ impl Cube {
         let d_col = [self.faces[D][0][0], self.faces[D][1][0]];
         let b_col = [self.faces[B][0][1], self.faces[B][1][1]]; // B right
 
-        // U left -> B right (reversed)
-        self.faces[B][0][1] = u_col[1];
-        self.faces[B][1][1] = u_col[0];
+        // U left -> F left (reversed)
+        self.faces[F][0][0] = u_col[1];
+        self.faces[F][1][0] = u_col[0];
 
-        // B right (reversed) -> D left
-        self.faces[D][0][0] = b_col[1];
-        self.faces[D][1][0] = b_col[0];
+        // F left -> D left (reversed)
+        self.faces[D][0][0] = f_col[1];
+        self.faces[D][1][0] = f_col[0];
 
-        // D left -> F left
-        self.faces[F][0][0] = d_col[0];
-        self.faces[F][1][0] = d_col[1];
+        // D left -> B right
+        self.faces[B][0][1] = d_col[0];
+        self.faces[B][1][1] = d_col[1];
 
-        // F left -> U left
-        self.faces[U][0][0] = f_col[0];
-        self.faces[U][1][0] = f_col[1];
+        // B right -> U left
+        self.faces[U][0][0] = b_col[0];
+        self.faces[U][1][0] = b_col[1];
     }
 
     fn f_cw(&mut self) {
@@ -238,26 +300,26 @@ impl Cube {
         rot_face_cw(&mut self.faces[F]);
 
         // snapshot strips
-        let u_bot = [self.faces[U][1][0], self.faces[U][1][1]]; // U bottom
-        let r_lft = [self.faces[R][0][0], self.faces[R][1][0]]; // R left (top->bottom)
-        let d_top = [self.faces[D][0][0], self.faces[D][0][1]]; // D top
-        let l_rgt = [self.faces[L][0][1], self.faces[L][1][1]]; // L right (top->bottom)
-
-        // U bottom -> R left (reversed)
-        self.faces[R][0][0] = u_bot[1];
-        self.faces[R][1][0] = u_bot[0];
-
-        // R left -> D top
-        self.faces[D][0][0] = r_lft[0];
-        self.faces[D][0][1] = r_lft[1];
-
-        // D top -> L right (reversed)
-        self.faces[L][0][1] = d_top[1];
-        self.faces[L][1][1] = d_top[0];
-
-        // L right -> U bottom
-        self.faces[U][1][0] = l_rgt[0];
-        self.faces[U][1][1] = l_rgt[1];
+        let u_frt = [self.faces[U][0][0], self.faces[U][0][1]]; // U front row
+        let r_lft = [self.faces[R][0][0], self.faces[R][1][0]]; // R left
+        let d_frt = [self.faces[D][1][0], self.faces[D][1][1]]; // D front row
+        let l_rgt = [self.faces[L][0][1], self.faces[L][1][1]]; // L right
+
+        // U front -> R left
+        self.faces[R][0][0] = u_frt[0];
+        self.faces[R][1][0] = u_frt[1];
+
+        // R left -> D front (reversed)
+        self.faces[D][1][0] = r_lft[1];
+        self.faces[D][1][1] = r_lft[0];
+
+        // D front -> L right
+        self.faces[L][0][1] = d_frt[0];
+        self.faces[L][1][1] = d_frt[1];
+
+        // L right -> U front (reversed)
+        self.faces[U][0][0] = l_rgt[1];
+        self.faces[U][0][1] = l_rgt[0];
     }
 
     fn b_cw(&mut self) {
@@ -267,56 +329,380 @@ impl Cube {
         const L: usize = FaceId::L as usize;
         const R: usize = FaceId::R as usize;
 
-        rot_face_cw(&mut self.faces[FaceId::B as usize]);
+        rot_face_cw(&mut self.faces[B]);
 
         // snapshot strips
-        let u_top = [self.faces[U][0][0], self.faces[U][0][1]]; // U top
+        let u_bck = [self.faces[U][1][0], self.faces[U][1][1]]; // U back row
         let l_lft = [self.faces[L][0][0], self.faces[L][1][0]]; // L left
-        let d_bot = [self.faces[D][1][0], self.faces[D][1][1]]; // D bottom
+        let d_bck = [self.faces[D][0][0], self.faces[D][0][1]]; // D back row
         let r_rgt = [self.faces[R][0][1], self.faces[R][1][1]]; // R right
 
-        // U top -> L left (reversed)
-        self.faces[L][0][0] = u_top[1];
-        self.faces[L][1][0] = u_top[0];
+        // U back -> L left (reversed)
+        self.faces[L][0][0] = u_bck[1];
+        self.faces[L][1][0] = u_bck[0];
 
-        // L left -> D bottom
-        self.faces[D][1][0] = l_lft[0];
-        self.faces[D][1][1] = l_lft[1];
+        // L left -> D back
+        self.faces[D][0][0] = l_lft[0];
+        self.faces[D][0][1] = l_lft[1];
 
-        // D bottom -> R right (reversed)
-        self.faces[R][0][1] = d_bot[1];
-        self.faces[R][1][1] = d_bot[0];
+        // D back -> R right (reversed)
+        self.faces[R][0][1] = d_bck[1];
+        self.faces[R][1][1] = d_bck[0];
 
-        // R right -> U top
-        self.faces[U][0][0] = r_rgt[0];
-        self.faces[U][0][1] = r_rgt[1];
+        // R right -> U back
+        self.faces[U][1][0] = r_rgt[0];
+        self.faces[U][1][1] = r_rgt[1];
     }
-
 }
 
+// Inverse and half turns, written out directly rather than as repeated CW
+// turns. Each one is the CW cycle above run backwards (CCW) or twice (180).
 
 impl Cube {
     // ── U helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn u_ccw(&mut self) { self.u_cw(); self.u_cw(); self.u_cw(); }
-    #[inline] fn u_180(&mut self) { self.u_cw(); self.u_cw(); }
+    fn u_ccw(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const F: usize = FaceId::F as usize;
+        const R: usize = FaceId::R as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_cw(&mut self.faces[U]);
+
+        // cycle F -> R -> B -> L -> F (top rows)
+        let f0 = self.faces[F][0];
+        self.faces[F][0] = self.faces[L][0];
+        self.faces[L][0] = self.faces[B][0];
+        self.faces[B][0] = self.faces[R][0];
+        self.faces[R][0] = f0;
+    }
+
+    fn u_180(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const F: usize = FaceId::F as usize;
+        const R: usize = FaceId::R as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_180(&mut self.faces[U]);
+
+        // swap opposite top rows
+        let (f0, r0) = (self.faces[F][0], self.faces[R][0]);
+        self.faces[F][0] = self.faces[B][0];
+        self.faces[B][0] = f0;
+        self.faces[R][0] = self.faces[L][0];
+        self.faces[L][0] = r0;
+    }
 
     // ── D helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn d_ccw(&mut self) { self.d_cw(); self.d_cw(); self.d_cw(); }
-    #[inline] fn d_180(&mut self) { self.d_cw(); self.d_cw(); }
+    fn d_ccw(&mut self) {
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const R: usize = FaceId::R as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_cw(&mut self.faces[D]);
+
+        // cycle F -> L -> B -> R -> F (bottom rows)
+        let f1 = self.faces[F][1];
+        self.faces[F][1] = self.faces[R][1];
+        self.faces[R][1] = self.faces[B][1];
+        self.faces[B][1] = self.faces[L][1];
+        self.faces[L][1] = f1;
+    }
+
+    fn d_180(&mut self) {
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const R: usize = FaceId::R as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_180(&mut self.faces[D]);
+
+        // swap opposite bottom rows
+        let (f1, r1) = (self.faces[F][1], self.faces[R][1]);
+        self.faces[F][1] = self.faces[B][1];
+        self.faces[B][1] = f1;
+        self.faces[R][1] = self.faces[L][1];
+        self.faces[L][1] = r1;
+    }
 
     // ── R helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn r_ccw(&mut self) { self.r_cw(); self.r_cw(); self.r_cw(); }
-    #[inline] fn r_180(&mut self) { self.r_cw(); self.r_cw(); }
+    fn r_ccw(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const B: usize = FaceId::B as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_ccw(&mut self.faces[R]);
+
+        let u_col = [self.faces[U][0][1], self.faces[U][1][1]];
+        let f_col = [self.faces[F][0][1], self.faces[F][1][1]];
+        let d_col = [self.faces[D][0][1], self.faces[D][1][1]];
+        let b_col = [self.faces[B][0][0], self.faces[B][1][0]]; // B left
+
+        // B left -> U right
+        self.faces[U][0][1] = b_col[0];
+        self.faces[U][1][1] = b_col[1];
+
+        // D right -> B left
+        self.faces[B][0][0] = d_col[0];
+        self.faces[B][1][0] = d_col[1];
+
+        // F right -> D right (reversed)
+        self.faces[D][0][1] = f_col[1];
+        self.faces[D][1][1] = f_col[0];
+
+        // U right -> F right (reversed)
+        self.faces[F][0][1] = u_col[1];
+        self.faces[F][1][1] = u_col[0];
+    }
+
+    fn r_180(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const B: usize = FaceId::B as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_180(&mut self.faces[R]);
+
+        let u_col = [self.faces[U][0][1], self.faces[U][1][1]];
+        let f_col = [self.faces[F][0][1], self.faces[F][1][1]];
+        let d_col = [self.faces[D][0][1], self.faces[D][1][1]];
+        let b_col = [self.faces[B][0][0], self.faces[B][1][0]]; // B left
+
+        // U right <-> D right
+        self.faces[U][0][1] = d_col[0];
+        self.faces[U][1][1] = d_col[1];
+        self.faces[D][0][1] = u_col[0];
+        self.faces[D][1][1] = u_col[1];
+
+        // F right <-> B left (reversed)
+        self.faces[F][0][1] = b_col[1];
+        self.faces[F][1][1] = b_col[0];
+        self.faces[B][0][0] = f_col[1];
+        self.faces[B][1][0] = f_col[0];
+    }
 
     // ── L helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn l_ccw(&mut self) { self.l_cw(); self.l_cw(); self.l_cw(); }
-    #[inline] fn l_180(&mut self) { self.l_cw(); self.l_cw(); }
+    fn l_ccw(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_ccw(&mut self.faces[L]);
+
+        let u_col = [self.faces[U][0][0], self.faces[U][1][0]];
+        let f_col = [self.faces[F][0][0], self.faces[F][1][0]];
+        let d_col = [self.faces[D][0][0], self.faces[D][1][0]];
+        let b_col = [self.faces[B][0][1], self.faces[B][1][1]]; // B right
+
+        // F left -> U left (reversed)
+        self.faces[U][0][0] = f_col[1];
+        self.faces[U][1][0] = f_col[0];
+
+        // D left -> F left (reversed)
+        self.faces[F][0][0] = d_col[1];
+        self.faces[F][1][0] = d_col[0];
+
+        // B right -> D left
+        self.faces[D][0][0] = b_col[0];
+        self.faces[D][1][0] = b_col[1];
+
+        // U left -> B right
+        self.faces[B][0][1] = u_col[0];
+        self.faces[B][1][1] = u_col[1];
+    }
+
+    fn l_180(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+
+        rot_face_180(&mut self.faces[L]);
+
+        let u_col = [self.faces[U][0][0], self.faces[U][1][0]];
+        let f_col = [self.faces[F][0][0], self.faces[F][1][0]];
+        let d_col = [self.faces[D][0][0], self.faces[D][1][0]];
+        let b_col = [self.faces[B][0][1], self.faces[B][1][1]]; // B right
+
+        // U left <-> D left
+        self.faces[U][0][0] = d_col[0];
+        self.faces[U][1][0] = d_col[1];
+        self.faces[D][0][0] = u_col[0];
+        self.faces[D][1][0] = u_col[1];
+
+        // F left <-> B right (reversed)
+        self.faces[F][0][0] = b_col[1];
+        self.faces[F][1][0] = b_col[0];
+        self.faces[B][0][1] = f_col[1];
+        self.faces[B][1][1] = f_col[0];
+    }
 
     // ── F helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn f_ccw(&mut self) { self.f_cw(); self.f_cw(); self.f_cw(); }
-    #[inline] fn f_180(&mut self) { self.f_cw(); self.f_cw(); }
+    fn f_ccw(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const L: usize = FaceId::L as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_ccw(&mut self.faces[F]);
+
+        let u_frt = [self.faces[U][0][0], self.faces[U][0][1]]; // U front row
+        let r_lft = [self.faces[R][0][0], self.faces[R][1][0]]; // R left
+        let d_frt = [self.faces[D][1][0], self.faces[D][1][1]]; // D front row
+        let l_rgt = [self.faces[L][0][1], self.faces[L][1][1]]; // L right
+
+        // R left -> U front
+        self.faces[U][0][0] = r_lft[0];
+        self.faces[U][0][1] = r_lft[1];
+
+        // D front -> R left (reversed)
+        self.faces[R][0][0] = d_frt[1];
+        self.faces[R][1][0] = d_frt[0];
+
+        // L right -> D front
+        self.faces[D][1][0] = l_rgt[0];
+        self.faces[D][1][1] = l_rgt[1];
+
+        // U front -> L right (reversed)
+        self.faces[L][0][1] = u_frt[1];
+        self.faces[L][1][1] = u_frt[0];
+    }
+
+    fn f_180(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const F: usize = FaceId::F as usize;
+        const L: usize = FaceId::L as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_180(&mut self.faces[F]);
+
+        let u_frt = [self.faces[U][0][0], self.faces[U][0][1]]; // U front row
+        let r_lft = [self.faces[R][0][0], self.faces[R][1][0]]; // R left
+        let d_frt = [self.faces[D][1][0], self.faces[D][1][1]]; // D front row
+        let l_rgt = [self.faces[L][0][1], self.faces[L][1][1]]; // L right
+
+        // U front <-> D front (both reversed)
+        self.faces[U][0][0] = d_frt[1];
+        self.faces[U][0][1] = d_frt[0];
+        self.faces[D][1][0] = u_frt[1];
+        self.faces[D][1][1] = u_frt[0];
+
+        // R left <-> L right (both reversed)
+        self.faces[R][0][0] = l_rgt[1];
+        self.faces[R][1][0] = l_rgt[0];
+        self.faces[L][0][1] = r_lft[1];
+        self.faces[L][1][1] = r_lft[0];
+    }
 
     // ── B helpers ──────────────────────────────────────────────────────────────
-    #[inline] fn b_ccw(&mut self) { self.b_cw(); self.b_cw(); self.b_cw(); }
-    #[inline] fn b_180(&mut self) { self.b_cw(); self.b_cw(); }
-}
\ No newline at end of file
+    fn b_ccw(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_ccw(&mut self.faces[B]);
+
+        let u_bck = [self.faces[U][1][0], self.faces[U][1][1]]; // U back row
+        let l_lft = [self.faces[L][0][0], self.faces[L][1][0]]; // L left
+        let d_bck = [self.faces[D][0][0], self.faces[D][0][1]]; // D back row
+        let r_rgt = [self.faces[R][0][1], self.faces[R][1][1]]; // R right
+
+        // L left -> U back (reversed)
+        self.faces[U][1][0] = l_lft[1];
+        self.faces[U][1][1] = l_lft[0];
+
+        // D back -> L left
+        self.faces[L][0][0] = d_bck[0];
+        self.faces[L][1][0] = d_bck[1];
+
+        // R right -> D back (reversed)
+        self.faces[D][0][0] = r_rgt[1];
+        self.faces[D][0][1] = r_rgt[0];
+
+        // U back -> R right
+        self.faces[R][0][1] = u_bck[0];
+        self.faces[R][1][1] = u_bck[1];
+    }
+
+    fn b_180(&mut self) {
+        const U: usize = FaceId::U as usize;
+        const D: usize = FaceId::D as usize;
+        const B: usize = FaceId::B as usize;
+        const L: usize = FaceId::L as usize;
+        const R: usize = FaceId::R as usize;
+
+        rot_face_180(&mut self.faces[B]);
+
+        let u_bck = [self.faces[U][1][0], self.faces[U][1][1]]; // U back row
+        let l_lft = [self.faces[L][0][0], self.faces[L][1][0]]; // L left
+        let d_bck = [self.faces[D][0][0], self.faces[D][0][1]]; // D back row
+        let r_rgt = [self.faces[R][0][1], self.faces[R][1][1]]; // R right
+
+        // U back <-> D back (both reversed)
+        self.faces[U][1][0] = d_bck[1];
+        self.faces[U][1][1] = d_bck[0];
+        self.faces[D][0][0] = u_bck[1];
+        self.faces[D][0][1] = u_bck[0];
+
+        // L left <-> R right (both reversed)
+        self.faces[L][0][0] = r_rgt[1];
+        self.faces[L][1][0] = r_rgt[0];
+        self.faces[R][0][1] = l_lft[1];
+        self.faces[R][1][1] = l_lft[0];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The solved cube and the states along a short scramble, so a move
+    /// that mixes two corners' stickers shows up as an impossible piece.
+    fn states() -> Vec<Cube> {
+        let mut cube = Cube::default();
+        let mut out = vec![cube.clone()];
+        for m in Alg::parse("R U F' D2 L B' U2 R'").unwrap().0 {
+            cube.apply(m);
+            out.push(cube.clone());
+        }
+        out
+    }
+
+    #[test]
+    fn moves_keep_corners_together() {
+        for start in states() {
+            for m in Move::ALL {
+                let mut cube = start.clone();
+                cube.apply(m);
+                assert!(PackedCube::pack(&cube).is_some(), "{m} split a corner");
+            }
+        }
+    }
+
+    #[test]
+    fn four_turns_are_the_identity() {
+        for start in states() {
+            for m in Move::ALL {
+                let mut cube = start.clone();
+                for _ in 0..4 {
+                    cube.apply(m);
+                }
+                assert_eq!(cube, start, "{m}");
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// src/cube/moves.rs
+
+//! Parsed move notation: `Move` (face + turn), `Alg` (a move list), and the
+//! allocation-free `Cube::apply`/`Cube::apply_alg` fast path used by
+//! scrambles and hot loops instead of re-parsing token strings.
+
+use std::fmt;
+
+use super::{Cube, FaceId};
+use crate::Error;
+
+/// How many moves `Cube::apply_moves` applies between progress callbacks.
+pub const PROGRESS_STRIDE: usize = 256;
+
+/// Quarter/half turn amount, clockwise as seen looking at the face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn { Cw, Ccw, Half }
+
+/// One face turn, e.g. `R'` = `Move { face: R, turn: Ccw }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move {
+    pub face: FaceId,
+    pub turn: Turn,
+}
+
+impl Move {
+    pub const fn new(face: FaceId, turn: Turn) -> Self { Self { face, turn } }
+
+    /// All 18 face turns, in scramble-table order (U, D, R, L, F, B × CW, CCW, 2).
+    pub const ALL: [Move; 18] = {
+        use FaceId::*;
+        use Turn::*;
+        [
+            Move::new(U, Cw), Move::new(U, Ccw), Move::new(U, Half),
+            Move::new(D, Cw), Move::new(D, Ccw), Move::new(D, Half),
+            Move::new(R, Cw), Move::new(R, Ccw), Move::new(R, Half),
+            Move::new(L, Cw), Move::new(L, Ccw), Move::new(L, Half),
+            Move::new(F, Cw), Move::new(F, Ccw), Move::new(F, Half),
+            Move::new(B, Cw), Move::new(B, Ccw), Move::new(B, Half),
+        ]
+    };
+
+    /// Parse a single token like `"R"`, `"R'"`, or `"R2"`.
+    pub fn parse(tok: &str) -> Option<Move> {
+        let mut chars = tok.chars();
+        let face = match chars.next()? {
+            'U' => FaceId::U, 'D' => FaceId::D, 'F' => FaceId::F,
+            'B' => FaceId::B, 'L' => FaceId::L, 'R' => FaceId::R,
+            _ => return None,
+        };
+        let turn = match chars.as_str() {
+            ""  => Turn::Cw,
+            "'" => Turn::Ccw,
+            "2" => Turn::Half,
+            _ => return None,
+        };
+        Some(Move { face, turn })
+    }
+
+    /// Position of this move in [`Move::ALL`].
+    #[inline]
+    pub const fn index(self) -> usize {
+        let face = match self.face {
+            FaceId::U => 0, FaceId::D => 1, FaceId::R => 2,
+            FaceId::L => 3, FaceId::F => 4, FaceId::B => 5,
+        };
+        let turn = match self.turn { Turn::Cw => 0, Turn::Ccw => 1, Turn::Half => 2 };
+        face * 3 + turn
+    }
+
+    /// The move that undoes this one.
+    pub fn inverse(self) -> Move {
+        let turn = match self.turn { Turn::Cw => Turn::Ccw, Turn::Ccw => Turn::Cw, Turn::Half => Turn::Half };
+        Move { turn, ..self }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.face)?;
+        match self.turn {
+            Turn::Cw => Ok(()),
+            Turn::Ccw => f.write_str("'"),
+            Turn::Half => f.write_str("2"),
+        }
+    }
+}
+
+/// A pre-parsed sequence of moves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alg(pub Vec<Move>);
+
+impl Alg {
+    /// Parse whitespace-separated tokens; `Err` names the first bad token.
+    pub fn parse(s: &str) -> Result<Alg, Error> {
+        s.split_whitespace()
+            .map(|tok| Move::parse(tok).ok_or_else(|| Error::ParseMove { token: tok.to_string() }))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Alg)
+    }
+
+    pub fn len(&self) -> usize { self.0.len() }
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// The algorithm that undoes this one (reversed, each move inverted).
+    pub fn inverse(&self) -> Alg {
+        Alg(self.0.iter().rev().map(|m| m.inverse()).collect())
+    }
+}
+
+impl fmt::Display for Alg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, m) in self.0.iter().enumerate() {
+            if i > 0 { f.write_str(" ")?; }
+            write!(f, "{m}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Cube {
+    /// Apply one parsed move.
+    #[inline]
+    pub fn apply(&mut self, m: Move) {
+        use FaceId::*;
+        use Turn::*;
+        match (m.face, m.turn) {
+            (U, Cw) => self.mv_u(), (U, Ccw) => self.mv_u_prime(), (U, Half) => self.mv_u2(),
+            (D, Cw) => self.mv_d(), (D, Ccw) => self.mv_d_prime(), (D, Half) => self.mv_d2(),
+            (R, Cw) => self.mv_r(), (R, Ccw) => self.mv_r_prime(), (R, Half) => self.mv_r2(),
+            (L, Cw) => self.mv_l(), (L, Ccw) => self.mv_l_prime(), (L, Half) => self.mv_l2(),
+            (F, Cw) => self.mv_f(), (F, Ccw) => self.mv_f_prime(), (F, Half) => self.mv_f2(),
+            (B, Cw) => self.mv_b(), (B, Ccw) => self.mv_b_prime(), (B, Half) => self.mv_b2(),
+        }
+    }
+
+    /// Apply a batch of moves, calling `progress(applied)` every
+    /// `PROGRESS_STRIDE` moves and once at the end. Callers that need to
+    /// spread a long sequence across frames can pass consecutive sub-slices.
+    pub fn apply_moves(&mut self, moves: &[Move], mut progress: impl FnMut(usize)) {
+        for (i, chunk) in moves.chunks(PROGRESS_STRIDE).enumerate() {
+            for &m in chunk {
+                self.apply(m);
+            }
+            progress(i * PROGRESS_STRIDE + chunk.len());
+        }
+        if moves.is_empty() {
+            progress(0);
+        }
+    }
+
+    /// Apply a pre-parsed algorithm (no parsing, no allocation).
+    #[inline]
+    pub fn apply_alg(&mut self, alg: &Alg) {
+        for &m in &alg.0 {
+            self.apply(m);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+// src/cube/packed.rs
+
+//! Bit-packed cube state: corner permutation (Lehmer rank, 16 bits) and
+//! corner orientation (base-3 over the first seven corners, 12 bits) in a
+//! `u64`. Used as a compact hash key for search visited-sets and tables.
+
+use super::{Col, Cube, FaceId};
+
+/// Sticker position: face, row, col (storage order, see `render::face`).
+type Pos = (FaceId, usize, usize);
+
+/// The eight corner slots. Each lists its U/D sticker first, then the other
+/// two in the same rotational sense for every slot, so twists sum to 0 mod 3.
+pub const CORNER_SLOTS: [[Pos; 3]; 8] = {
+    use FaceId::*;
+    [
+        [(U, 0, 0), (L, 0, 1), (F, 0, 0)], // ULF
+        [(U, 0, 1), (F, 0, 1), (R, 0, 0)], // UFR
+        [(U, 1, 1), (R, 0, 1), (B, 0, 0)], // URB
+        [(U, 1, 0), (B, 0, 1), (L, 0, 0)], // UBL
+        [(D, 1, 0), (F, 1, 0), (L, 1, 1)], // DFL
+        [(D, 1, 1), (R, 1, 0), (F, 1, 1)], // DRF
+        [(D, 0, 1), (B, 1, 0), (R, 1, 1)], // DBR
+        [(D, 0, 0), (L, 1, 0), (B, 1, 1)], // DLB
+    ]
+};
+
+/// Number of corner permutations (8!).
+pub const PERM_COUNT: usize = 40_320;
+/// Number of corner orientations (3^7; the eighth is implied).
+pub const ORI_COUNT: usize = 2_187;
+
+const ORI_BITS: u32 = 12;
+
+/// Packed corner state; equal cubes pack to equal keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedCube(pub u64);
+
+impl PackedCube {
+    /// Pack from permutation/orientation indices (`< PERM_COUNT`, `< ORI_COUNT`).
+    #[inline]
+    pub const fn from_indices(perm: u16, ori: u16) -> Self {
+        Self(((perm as u64) << ORI_BITS) | ori as u64)
+    }
+
+    #[inline] pub const fn perm_index(self) -> u16 { (self.0 >> ORI_BITS) as u16 }
+    #[inline] pub const fn ori_index(self) -> u16 { (self.0 & ((1 << ORI_BITS) - 1)) as u16 }
+
+    /// The solved state in the default color scheme.
+    pub const SOLVED: PackedCube = PackedCube(0);
+
+    /// Pack a cube. `None` if the stickers don't form eight valid corners
+    /// (e.g. a hand-entered facelet string with a duplicated piece).
+    pub fn pack(cube: &Cube) -> Option<Self> {
+        let home = home_colors();
+        let mut perm = [0u8; 8];
+        let mut ori = [0u8; 8];
+        let mut seen = 0u8;
+        for (slot, pos) in CORNER_SLOTS.iter().enumerate() {
+            let cols = pos.map(|(f, r, c)| cube.face(f)[r][c]);
+            let o = cols.iter().position(|&c| is_ud(c))?;
+            let rotated = [cols[o], cols[(o + 1) % 3], cols[(o + 2) % 3]];
+            let piece = home.iter().position(|h| *h == rotated)?;
+            if seen & (1 << piece) != 0 {
+                return None;
+            }
+            seen |= 1 << piece;
+            perm[slot] = piece as u8;
+            ori[slot] = o as u8;
+        }
+        if ori.iter().map(|&o| o as u32).sum::<u32>() % 3 != 0 {
+            return None;
+        }
+        Some(Self::from_indices(perm_rank(&perm), ori_rank(&ori)))
+    }
+
+    /// Expand back into a sticker-level cube (centers-free 2×2, so every
+    /// sticker belongs to a corner).
+    pub fn unpack(self) -> Cube {
+        let home = home_colors();
+        let perm = perm_unrank(self.perm_index());
+        let ori = ori_unrank(self.ori_index());
+        let mut cube = Cube::default();
+        for (slot, pos) in CORNER_SLOTS.iter().enumerate() {
+            let cols = home[perm[slot] as usize];
+            let o = ori[slot] as usize;
+            for (k, &col) in cols.iter().enumerate() {
+                let (f, r, c) = pos[(o + k) % 3];
+                cube.faces[f as usize][r][c] = col;
+            }
+        }
+        cube
+    }
+}
+
+#[inline]
+pub(crate) fn is_ud(c: Col) -> bool {
+    matches!(c, Col::W | Col::Y)
+}
+
+/// Sticker colors of each corner piece in its home slot (solved cube).
+pub(crate) fn home_colors() -> [[Col; 3]; 8] {
+    let solved = Cube::default();
+    CORNER_SLOTS.map(|pos| pos.map(|(f, r, c)| solved.face(f)[r][c]))
+}
+
+// ----- ranking helpers -----
+
+pub(crate) fn perm_rank(p: &[u8; 8]) -> u16 {
+    let mut rank = 0u32;
+    for i in 0..8 {
+        let smaller = p[i + 1..].iter().filter(|&&x| x < p[i]).count() as u32;
+        rank = rank * (8 - i as u32) + smaller;
+    }
+    rank as u16
+}
+
+pub(crate) fn perm_unrank(mut rank: u16) -> [u8; 8] {
+    let mut digits = [0u8; 8];
+    for i in (0..8).rev() {
+        let base = (8 - i) as u16;
+        digits[i] = (rank % base) as u8;
+        rank /= base;
+    }
+    let mut pool: Vec<u8> = (0..8).collect();
+    digits.map(|d| pool.remove(d as usize))
+}
+
+pub(crate) fn ori_rank(o: &[u8; 8]) -> u16 {
+    o[..7].iter().fold(0u16, |acc, &x| acc * 3 + x as u16)
+}
+
+pub(crate) fn ori_unrank(mut rank: u16) -> [u8; 8] {
+    let mut o = [0u8; 8];
+    for i in (0..7).rev() {
+        o[i] = (rank % 3) as u8;
+        rank /= 3;
+    }
+    o[7] = ((3 - o[..7].iter().map(|&x| x as u32).sum::<u32>() % 3) % 3) as u8;
+    o
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cube::Move;
+
+    #[test]
+    fn indices_round_trip() {
+        for perm in (0..PERM_COUNT as u16).step_by(97) {
+            for ori in (0..ORI_COUNT as u16).step_by(5) {
+                let p = PackedCube::from_indices(perm, ori);
+                assert_eq!(PackedCube::pack(&p.unpack()), Some(p));
+            }
+        }
+    }
+
+    #[test]
+    fn cubes_round_trip() {
+        let mut cube = Cube::default();
+        assert_eq!(PackedCube::pack(&cube), Some(PackedCube::SOLVED));
+        for (i, m) in Move::ALL.iter().cycle().step_by(7).take(200).enumerate() {
+            cube.apply(*m);
+            let p = PackedCube::pack(&cube).unwrap();
+            assert_eq!(p.unpack(), cube, "after {} moves", i + 1);
+        }
+    }
+
+    #[test]
+    fn impossible_corners_do_not_pack() {
+        let mut cube = Cube::default();
+        cube.set_sticker(FaceId::U, 0, 0, Col::Y);
+        assert_eq!(PackedCube::pack(&cube), None);
+    }
+}
@@ -0,0 +1,142 @@
+// src/cube/perm.rs
+
+//! Permutation-level view of a turn sequence: for each corner slot, which
+//! slot its piece came from and how far it is twisted. Unlike a sticker
+//! cube this composes in O(8), so an algorithm's order and cycle structure
+//! follow without replaying it.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::packed::{ori_rank, ori_unrank, perm_rank, perm_unrank};
+use super::{Alg, Cube, Move, PackedCube};
+
+/// Slot names in [`CORNER_SLOTS`](super::packed::CORNER_SLOTS) order.
+pub const CORNER_NAMES: [&str; 8] = ["ULF", "UFR", "URB", "UBL", "DFL", "DRF", "DBR", "DLB"];
+
+/// A corner permutation with twists: slot `s` receives the piece from slot
+/// `from[s]`, twisted by `twist[s]` (1 = counterclockwise, 2 = clockwise).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CornerPerm {
+    pub from: [u8; 8],
+    pub twist: [u8; 8],
+}
+
+impl CornerPerm {
+    pub const IDENTITY: CornerPerm = CornerPerm { from: [0, 1, 2, 3, 4, 5, 6, 7], twist: [0; 8] };
+
+    /// The permutation taking solved to `state`.
+    pub fn from_packed(state: PackedCube) -> Self {
+        Self { from: perm_unrank(state.perm_index()), twist: ori_unrank(state.ori_index()) }
+    }
+
+    /// The state this permutation makes from solved.
+    pub fn to_packed(self) -> PackedCube {
+        PackedCube::from_indices(perm_rank(&self.from), ori_rank(&self.twist))
+    }
+
+    pub fn of_move(m: Move) -> Self {
+        static MOVES: OnceLock<Vec<CornerPerm>> = OnceLock::new();
+        MOVES.get_or_init(|| {
+            Move::ALL
+                .iter()
+                .map(|&m| {
+                    let mut c = Cube::default();
+                    c.apply(m);
+                    CornerPerm::from_packed(PackedCube::pack(&c).expect("face turn yields a valid cube"))
+                })
+                .collect()
+        })[m.index()]
+    }
+
+    pub fn of_alg(alg: &Alg) -> Self {
+        alg.0.iter().fold(Self::IDENTITY, |p, &m| p.then(Self::of_move(m)))
+    }
+
+    /// `self` followed by `next`.
+    pub fn then(self, next: CornerPerm) -> CornerPerm {
+        let mut out = Self::IDENTITY;
+        for s in 0..8 {
+            let via = next.from[s] as usize;
+            out.from[s] = self.from[via];
+            out.twist[s] = (self.twist[via] + next.twist[s]) % 3;
+        }
+        out
+    }
+
+    /// The permutation that undoes this one.
+    pub fn inverse(self) -> CornerPerm {
+        let mut out = Self::IDENTITY;
+        for s in 0..8 {
+            let f = self.from[s] as usize;
+            out.from[f] = s as u8;
+            out.twist[f] = (3 - self.twist[s]) % 3;
+        }
+        out
+    }
+
+    /// Disjoint cycles in the order pieces travel, each with its net twist;
+    /// fixed corners are listed only when twisted in place.
+    pub fn cycles(self) -> Vec<Cycle> {
+        let mut to = [0u8; 8];
+        for (s, &f) in self.from.iter().enumerate() {
+            to[f as usize] = s as u8;
+        }
+        let mut seen = [false; 8];
+        let mut out = Vec::new();
+        for start in 0..8 {
+            if seen[start] {
+                continue;
+            }
+            let mut slots = Vec::new();
+            let mut s = start;
+            while !seen[s] {
+                seen[s] = true;
+                slots.push(s as u8);
+                s = to[s] as usize;
+            }
+            let twist = slots.iter().map(|&s| self.twist[s as usize]).sum::<u8>() % 3;
+            if slots.len() > 1 || twist != 0 {
+                out.push(Cycle { slots, twist });
+            }
+        }
+        out
+    }
+
+    /// Repetitions until every corner is home and untwisted again: the lcm
+    /// of the cycle orders.
+    pub fn order(self) -> u32 {
+        fn gcd(a: u32, b: u32) -> u32 {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+        self.cycles().iter().map(Cycle::order).fold(1, |acc, n| acc / gcd(acc, n) * n)
+    }
+}
+
+/// One cycle of corner slots and the twist a piece picks up going round it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub slots: Vec<u8>,
+    pub twist: u8,
+}
+
+impl Cycle {
+    /// A twisted cycle needs three trips round to untwist.
+    pub fn order(&self) -> u32 {
+        self.slots.len() as u32 * if self.twist == 0 { 1 } else { 3 }
+    }
+}
+
+/// `(UFR URB UBL)`, with `+` for a net clockwise twist and `-` for
+/// counterclockwise, e.g. `(UFR)+`.
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.slots.iter().map(|&s| CORNER_NAMES[s as usize]).collect();
+        write!(f, "({})", names.join(" "))?;
+        match self.twist {
+            1 => f.write_str("-"),
+            2 => f.write_str("+"),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// src/cube/tables.rs
+
+//! Move tables on packed states: `PackedCube × Move → PackedCube` as two
+//! table lookups (permutation and orientation are independent), built once
+//! on first use so search loops never touch facelets.
+
+use std::sync::OnceLock;
+
+use super::packed::{ori_rank, ori_unrank, perm_rank, perm_unrank, ORI_COUNT, PERM_COUNT};
+use super::{Cube, Move, PackedCube};
+
+/// Per-move transition tables indexed by `[state_index][Move::index()]`.
+pub struct MoveTables {
+    perm: Vec<[u16; 18]>,
+    ori: Vec<[u16; 18]>,
+}
+
+impl MoveTables {
+    /// Shared tables, generated on first call (~1.5 MB).
+    pub fn get() -> &'static MoveTables {
+        static TABLES: OnceLock<MoveTables> = OnceLock::new();
+        TABLES.get_or_init(MoveTables::build)
+    }
+
+    /// Apply `m` to a packed state by table lookup.
+    #[inline]
+    pub fn apply(&self, state: PackedCube, m: Move) -> PackedCube {
+        let i = m.index();
+        PackedCube::from_indices(
+            self.perm[state.perm_index() as usize][i],
+            self.ori[state.ori_index() as usize][i],
+        )
+    }
+
+    /// Apply a sequence of moves.
+    pub fn apply_all(&self, mut state: PackedCube, moves: &[Move]) -> PackedCube {
+        for &m in moves {
+            state = self.apply(state, m);
+        }
+        state
+    }
+
+    fn build() -> MoveTables {
+        // Each move as a corner action: slot `s` receives the piece from slot
+        // `from[s]`, twisted by `twist[s]`. Read off the sticker engine so the
+        // tables always agree with `Cube::apply`.
+        let actions: Vec<([u8; 8], [u8; 8])> = Move::ALL
+            .iter()
+            .map(|&m| {
+                let mut c = Cube::default();
+                c.apply(m);
+                let p = PackedCube::pack(&c).expect("face turn yields a valid cube");
+                (perm_unrank(p.perm_index()), ori_unrank(p.ori_index()))
+            })
+            .collect();
+
+        let perm = (0..PERM_COUNT)
+            .map(|idx| {
+                let p = perm_unrank(idx as u16);
+                let mut row = [0u16; 18];
+                for (out, (from, _)) in row.iter_mut().zip(&actions) {
+                    *out = perm_rank(&from.map(|s| p[s as usize]));
+                }
+                row
+            })
+            .collect();
+
+        let ori = (0..ORI_COUNT)
+            .map(|idx| {
+                let o = ori_unrank(idx as u16);
+                let mut row = [0u16; 18];
+                for (out, (from, twist)) in row.iter_mut().zip(&actions) {
+                    let mut next = [0u8; 8];
+                    for s in 0..8 {
+                        next[s] = (o[from[s] as usize] + twist[s]) % 3;
+                    }
+                    *out = ori_rank(&next);
+                }
+                row
+            })
+            .collect();
+
+        MoveTables { perm, ori }
+    }
+}
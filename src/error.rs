@@ -0,0 +1,113 @@
+// src/error.rs
+
+//! Errors from the library API. Every variant displays as a translated
+//! message, so the app can put one straight on the status line, while
+//! callers that care can match on what went wrong.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::cube::FaceId;
+use crate::logic::solver::fmt_generators;
+use crate::t;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// A token that isn't one of the 18 face turns.
+    #[error("{}", t!("unknown-move", token = .token))]
+    ParseMove { token: String },
+
+    /// Sticker colors typed as letters: not 24 of them, or one that names
+    /// no color.
+    #[error("{}", t!("verify-bad-length", n = .n))]
+    FaceletCount { n: usize },
+    #[error("{}", t!("verify-bad-letter", letter = .letter))]
+    FaceletColor { letter: char },
+
+    /// A state no sequence of turns reaches.
+    #[error("{}", .0)]
+    InvalidState(StateProblem),
+
+    /// A generator set naming something other than a face.
+    #[error("{}", t!("generators-bad", ch = .ch))]
+    BadGenerator { ch: char },
+    /// A move-cost entry that isn't `key=cost` with a known key and a
+    /// usable cost.
+    #[error("{}", t!("cost-bad-token", token = .token))]
+    BadCost { token: String },
+
+    /// KPuzzle import: not JSON, or JSON with nothing to import.
+    #[error("{}", t!("kpuzzle-bad-json"))]
+    BadJson,
+    #[error("{}", t!("kpuzzle-empty"))]
+    NothingToImport,
+
+    /// Solve script: a solution line that isn't a move, or no scramble.
+    #[error("{}", t!("script-bad-line", line = .line, text = .text))]
+    ScriptLine { line: usize, text: String },
+    #[error("{}", t!("script-no-scramble"))]
+    NoScramble,
+
+    /// Console script: a syntax or runtime error on `line`.
+    #[error("{}", t!("console-error", line = .line, detail = .detail))]
+    Console { line: usize, detail: String },
+
+    /// Session trace: a line that can't be read back.
+    #[error("{}", t!("trace-bad-line", line = .line))]
+    BadTrace { line: usize },
+
+    /// A backup that isn't an archive of stored files, or is damaged.
+    #[error("{}", t!("backup-bad-archive"))]
+    BadArchive,
+
+    /// A cached solver table that is truncated, from another version, or
+    /// fails its checksum.
+    #[error("{}", t!("solver-table-bad", detail = .0))]
+    CorruptTable(String),
+    /// The search ran out of its time budget.
+    #[error("{}", t!("solve-timeout"))]
+    SolverTimeout,
+    /// No solution turning only `faces`.
+    #[error("{}", t!("solve-unreachable", generators = fmt_generators(.faces)))]
+    Unreachable { faces: Vec<FaceId> },
+
+    /// Reading or writing a file. The source sits behind an `Arc` so the
+    /// error stays `Clone`.
+    #[error("{}", match .op {
+        IoOp::Read => t!("io-read-failed", path = .path.display(), error = .source),
+        IoOp::Write => t!("io-write-failed", path = .path.display(), error = .source),
+    })]
+    Io { op: IoOp, path: PathBuf, source: Arc<std::io::Error> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoOp {
+    Read,
+    Write,
+}
+
+/// Why a state can't be reached by turning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum StateProblem {
+    /// Pieces missing, repeated, or out of range.
+    #[error("{}", t!("kpuzzle-bad-corners"))]
+    BadCorners,
+    /// Corner twists that don't add up to a whole turn.
+    #[error("{}", t!("kpuzzle-twisted"))]
+    Twisted,
+    /// Any other impossible sticker pattern.
+    #[error("{}", t!("solve-invalid"))]
+    Illegal,
+}
+
+impl Error {
+    /// Reading `path` failed.
+    pub fn read(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Error::Io { op: IoOp::Read, path: path.into(), source: Arc::new(source) }
+    }
+
+    /// Writing `path` failed.
+    pub fn write(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Error::Io { op: IoOp::Write, path: path.into(), source: Arc::new(source) }
+    }
+}
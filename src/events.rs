@@ -0,0 +1,88 @@
+// src/events.rs
+
+//! Event hooks for integrations (logging, stream overlays, sound effects):
+//! the app reports what happens to the cube through a [`Hooks`] registry,
+//! so a new integration is one [`Plugin`] registered at startup instead of
+//! another branch in the update loop.
+//!
+//! A plugin is any `FnMut(&CubeEvent, &Cube)` closure or a type
+//! implementing [`Plugin`]. For work on another thread,
+//! [`Hooks::subscribe`] hands out an mpsc receiver of the same events.
+
+use std::fmt;
+use std::sync::mpsc;
+
+use crate::cube::{Alg, Cube, Move};
+
+/// Something that happened to the cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CubeEvent {
+    /// A user turn (button, keyboard, or algorithm token) was applied.
+    MoveApplied(Move),
+    /// A turn brought the cube to solved, `moves` turns after the last
+    /// scramble or reset.
+    Solved { moves: usize },
+    /// A new scramble was generated and applied to a solved cube.
+    Scrambled(Alg),
+    /// An inspection call (WCA-style, at 8 s and 12 s) before the first turn.
+    InspectionCall { seconds_left: u32 },
+}
+
+/// Receives every [`CubeEvent`] along with the cube state right after it.
+pub trait Plugin: Send {
+    fn on_event(&mut self, event: &CubeEvent, cube: &Cube);
+}
+
+impl<F: FnMut(&CubeEvent, &Cube) + Send> Plugin for F {
+    fn on_event(&mut self, event: &CubeEvent, cube: &Cube) {
+        self(event, cube)
+    }
+}
+
+// Forwards events to a `subscribe` receiver; a dropped receiver is ignored.
+struct Forward(mpsc::Sender<CubeEvent>);
+
+impl Plugin for Forward {
+    fn on_event(&mut self, event: &CubeEvent, _cube: &Cube) {
+        let _ = self.0.send(event.clone());
+    }
+}
+
+/// Registered plugins, called in registration order.
+#[derive(Default)]
+pub struct Hooks {
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl Hooks {
+    pub fn register(&mut self, plugin: impl Plugin + 'static) {
+        self.plugins.push(Box::new(plugin));
+    }
+
+    /// A channel receiving a copy of every later event.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<CubeEvent> {
+        let (tx, rx) = mpsc::channel();
+        self.register(Forward(tx));
+        rx
+    }
+
+    pub fn emit(&mut self, event: &CubeEvent, cube: &Cube) {
+        for p in &mut self.plugins {
+            p.on_event(event, cube);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+}
+
+impl fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooks").field("plugins", &self.plugins.len()).finish()
+    }
+}
@@ -0,0 +1,392 @@
+// src/gamepad/mod.rs
+
+//! Game controllers for couch and demo use: the face buttons and bumpers
+//! turn the six faces, held triggers make the turn prime or double, and the
+//! sticks orbit the two views. Which button turns which face, the two
+//! modifiers, the orbit speed, and the vertical direction are settings.
+//!
+//! Listening needs the `gamepad` feature, which uses the `gilrs` crate:
+//! evdev on Linux, IOKit on macOS, and Windows.Gaming.Input on Windows, with
+//! every pad mapped onto one standard layout. Buttons are named by that
+//! layout (the face buttons by position, so "south" is A on an Xbox pad and
+//! cross on a PlayStation one), which keeps the settings the same on every
+//! platform. Without the feature no input ever arrives; where gilrs has no
+//! backend, the settings say so.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use iced::Subscription;
+
+use crate::cube::{FaceId, Move, Turn};
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+
+/// Whether this build listens to game controllers.
+pub const AVAILABLE: bool = cfg!(feature = "gamepad");
+
+const HEADER: &str = "rubics gamepad settings v2";
+
+/// Orbit update interval while a stick is pushed.
+pub const FRAME: Duration = Duration::from_millis(16);
+
+/// Orbit speeds offered, in degrees per second.
+pub const SPEED_MIN: f32 = 30.0;
+pub const SPEED_MAX: f32 = 360.0;
+
+/// Stick axes (x, y) of the left and right sticks.
+const STICKS: [(Axis, Axis); 2] = [(Axis::LeftX, Axis::LeftY), (Axis::RightX, Axis::RightY)];
+/// Stick deflection (0–1) ignored around the center.
+const DEADZONE: f32 = 0.15;
+
+/// The order faces are listed in the settings.
+pub const FACES: [FaceId; 6] = [FaceId::U, FaceId::R, FaceId::F, FaceId::D, FaceId::L, FaceId::B];
+
+/// What the pad reported.
+#[cfg_attr(not(feature = "gamepad"), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A button went down (`true`) or up.
+    Button(Button, bool),
+    /// A stick moved, from −1 to 1; up and right are positive.
+    Axis(Axis, f32),
+    /// No pad can be read, and why.
+    Unavailable(String),
+}
+
+/// A button of the standard layout. The triggers count as held once
+/// pressed past their middle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Button {
+    South,
+    East,
+    North,
+    West,
+    LeftBumper,
+    RightBumper,
+    LeftTrigger,
+    RightTrigger,
+    Select,
+    Start,
+    Mode,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl Button {
+    /// Every button, for the pickers.
+    pub const ALL: [Button; 17] = [
+        Button::South, Button::East, Button::North, Button::West,
+        Button::LeftBumper, Button::RightBumper, Button::LeftTrigger, Button::RightTrigger,
+        Button::Select, Button::Start, Button::Mode, Button::LeftStick, Button::RightStick,
+        Button::DPadUp, Button::DPadDown, Button::DPadLeft, Button::DPadRight,
+    ];
+
+    fn parse(s: &str) -> Option<Button> {
+        Button::ALL.into_iter().find(|b| b.to_string() == s.trim())
+    }
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Button::South => "South",
+            Button::East => "East",
+            Button::North => "North",
+            Button::West => "West",
+            Button::LeftBumper => "Left bumper",
+            Button::RightBumper => "Right bumper",
+            Button::LeftTrigger => "Left trigger",
+            Button::RightTrigger => "Right trigger",
+            Button::Select => "Select",
+            Button::Start => "Start",
+            Button::Mode => "Mode",
+            Button::LeftStick => "Left stick",
+            Button::RightStick => "Right stick",
+            Button::DPadUp => "D-pad up",
+            Button::DPadDown => "D-pad down",
+            Button::DPadLeft => "D-pad left",
+            Button::DPadRight => "D-pad right",
+        })
+    }
+}
+
+/// One direction of a stick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    LeftX,
+    LeftY,
+    RightX,
+    RightY,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GamepadSettings {
+    /// Button turning each face, in [`FACES`] order.
+    pub faces: [Button; 6],
+    /// Held for a prime (counter-clockwise) or a double turn.
+    pub prime: Button,
+    pub double: Button,
+    /// Orbit speed at full deflection, in degrees per second.
+    pub speed: f32,
+    /// Pushing a stick up tilts the view down instead of up.
+    pub invert_y: bool,
+}
+
+impl Default for GamepadSettings {
+    fn default() -> Self {
+        // U on the top face button, R on the right one, F on the bottom one,
+        // L on the left one, D and B on the bumpers; the triggers modify.
+        Self {
+            faces: [
+                Button::North, Button::East, Button::South,
+                Button::LeftBumper, Button::West, Button::RightBumper,
+            ],
+            prime: Button::LeftTrigger,
+            double: Button::RightTrigger,
+            speed: 120.0,
+            invert_y: false,
+        }
+    }
+}
+
+impl GamepadSettings {
+    pub fn encode(&self) -> String {
+        let mut pairs: Vec<(&str, String)> = FACES
+            .iter()
+            .zip(self.faces)
+            .map(|(f, b)| (face_key(*f), b.to_string()))
+            .collect();
+        pairs.push(("prime", self.prime.to_string()));
+        pairs.push(("double", self.double.to_string()));
+        pairs.push(("speed", format!("{:.0}", self.speed)));
+        pairs.push(("invert_y", self.invert_y.to_string()));
+        write_kv(HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let mut out = Self::default();
+        for (i, f) in FACES.iter().enumerate() {
+            if let Some(b) = kv.get(face_key(*f)).and_then(|v| Button::parse(v)) {
+                out.faces[i] = b;
+            }
+        }
+        if let Some(b) = kv.get("prime").and_then(|v| Button::parse(v)) {
+            out.prime = b;
+        }
+        if let Some(b) = kv.get("double").and_then(|v| Button::parse(v)) {
+            out.double = b;
+        }
+        if let Some(s) = kv.get("speed").and_then(|v| v.parse::<f32>().ok()).filter(|s| s.is_finite()) {
+            out.speed = s.clamp(SPEED_MIN, SPEED_MAX);
+        }
+        out.invert_y = kv.get("invert_y").is_some_and(|v| v == "true");
+        out
+    }
+}
+
+fn face_key(f: FaceId) -> &'static str {
+    match f {
+        FaceId::U => "U", FaceId::D => "D", FaceId::F => "F",
+        FaceId::B => "B", FaceId::L => "L", FaceId::R => "R",
+    }
+}
+
+/// Location of the gamepad settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("gamepad.txt")
+}
+
+pub fn load() -> GamepadSettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| GamepadSettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &GamepadSettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
+
+// ----- pad state -----
+
+/// Buttons held and stick positions, as last reported.
+#[derive(Debug, Clone, Default)]
+pub struct Pad {
+    buttons: [bool; Button::ALL.len()],
+    axes: [f32; 4],
+}
+
+impl Pad {
+    /// Take in `event`; the turn it makes, if it pressed a face button.
+    pub fn apply(&mut self, settings: &GamepadSettings, event: Event) -> Option<Move> {
+        match event {
+            Event::Axis(axis, v) => {
+                self.axes[axis as usize] = v;
+                None
+            }
+            Event::Button(b, down) => {
+                let was = std::mem::replace(&mut self.buttons[b as usize], down);
+                if !down || was {
+                    return None;
+                }
+                let face = FACES[settings.faces.iter().position(|&f| f == b)?];
+                let turn = if self.held(settings.double) {
+                    Turn::Half
+                } else if self.held(settings.prime) {
+                    Turn::Ccw
+                } else {
+                    Turn::Cw
+                };
+                Some(Move::new(face, turn))
+            }
+            Event::Unavailable(_) => None,
+        }
+    }
+
+    fn held(&self, b: Button) -> bool {
+        self.buttons[b as usize]
+    }
+
+    /// Deflection (−1–1) of `axis`, zero inside the dead zone.
+    fn stick(&self, axis: Axis) -> f32 {
+        let v = self.axes[axis as usize];
+        if v.abs() < DEADZONE { 0.0 } else { v.clamp(-1.0, 1.0) }
+    }
+
+    /// Whether either stick is pushed.
+    pub fn orbiting(&self) -> bool {
+        STICKS.iter().any(|&(x, y)| self.stick(x) != 0.0 || self.stick(y) != 0.0)
+    }
+
+    /// How far each stick turns its view over `dt`: degrees about the
+    /// vertical axis (Rz) and the horizontal one (Rx), left view first.
+    /// Up tilts by a positive angle unless inverted.
+    pub fn orbit(&self, settings: &GamepadSettings, dt: Duration) -> [(f32, f32); 2] {
+        let step = settings.speed * dt.as_secs_f32();
+        let flip = if settings.invert_y { -1.0 } else { 1.0 };
+        STICKS.map(|(x, y)| (self.stick(x) * step, self.stick(y) * step * flip))
+    }
+}
+
+// ----- listening -----
+
+/// Pad events, for as long as the app runs.
+pub fn subscription() -> Subscription<Event> {
+    backend::subscription()
+}
+
+#[cfg(feature = "gamepad")]
+mod backend {
+    use std::time::Duration;
+
+    use gilrs::{EventType, Gilrs};
+    use iced::futures::channel::mpsc::{self, UnboundedSender};
+    use iced::futures::{SinkExt, StreamExt};
+    use iced::Subscription;
+
+    use super::{Axis, Button, Event};
+    use crate::t;
+
+    /// How long the reading thread waits for input before checking whether
+    /// anyone still listens.
+    const POLL: Duration = Duration::from_millis(500);
+
+    pub fn subscription() -> Subscription<Event> {
+        struct Gamepads;
+        iced::subscription::channel(std::any::TypeId::of::<Gamepads>(), 64, |mut output| async move {
+            let (tx, mut rx) = mpsc::unbounded();
+            std::thread::spawn(move || listen(tx));
+            while let Some(event) = rx.next().await {
+                let _ = output.send(event).await;
+            }
+            // The reader stopped: nothing more will come.
+            loop {
+                iced::futures::future::pending::<()>().await;
+            }
+        })
+    }
+
+    /// Read every pad, plugged in now or later, until the app stops
+    /// listening.
+    fn listen(tx: UnboundedSender<Event>) {
+        let mut gilrs = match Gilrs::new() {
+            Ok(g) => g,
+            Err(gilrs::Error::NotImplemented(_)) => {
+                let _ = tx.unbounded_send(Event::Unavailable(t!("gamepad-unsupported")));
+                return;
+            }
+            Err(e) => {
+                let _ = tx.unbounded_send(Event::Unavailable(t!("gamepad-failed", error = e)));
+                return;
+            }
+        };
+        while !tx.is_closed() {
+            let Some(ev) = gilrs.next_event_blocking(Some(POLL)) else { continue };
+            if let Some(event) = convert(ev.event)
+                && tx.unbounded_send(event).is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    fn convert(event: EventType) -> Option<Event> {
+        match event {
+            EventType::ButtonPressed(b, _) => Some(Event::Button(button(b)?, true)),
+            EventType::ButtonReleased(b, _) => Some(Event::Button(button(b)?, false)),
+            EventType::AxisChanged(a, v, _) => Some(Event::Axis(axis(a)?, v)),
+            _ => None,
+        }
+    }
+
+    fn button(b: gilrs::Button) -> Option<Button> {
+        use gilrs::Button as G;
+        Some(match b {
+            G::South => Button::South,
+            G::East => Button::East,
+            G::North => Button::North,
+            G::West => Button::West,
+            G::LeftTrigger => Button::LeftBumper,
+            G::RightTrigger => Button::RightBumper,
+            G::LeftTrigger2 => Button::LeftTrigger,
+            G::RightTrigger2 => Button::RightTrigger,
+            G::Select => Button::Select,
+            G::Start => Button::Start,
+            G::Mode => Button::Mode,
+            G::LeftThumb => Button::LeftStick,
+            G::RightThumb => Button::RightStick,
+            G::DPadUp => Button::DPadUp,
+            G::DPadDown => Button::DPadDown,
+            G::DPadLeft => Button::DPadLeft,
+            G::DPadRight => Button::DPadRight,
+            G::C | G::Z | G::Unknown => return None,
+        })
+    }
+
+    fn axis(a: gilrs::Axis) -> Option<Axis> {
+        use gilrs::Axis as G;
+        match a {
+            G::LeftStickX => Some(Axis::LeftX),
+            G::LeftStickY => Some(Axis::LeftY),
+            G::RightStickX => Some(Axis::RightX),
+            G::RightStickY => Some(Axis::RightY),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(feature = "gamepad"))]
+mod backend {
+    use iced::Subscription;
+
+    use super::Event;
+
+    pub fn subscription() -> Subscription<Event> {
+        Subscription::none()
+    }
+}
@@ -0,0 +1,244 @@
+// src/hotkey/mod.rs
+
+//! System-wide hotkey for the solve timer: one key starts and stops it even
+//! while another window has focus, for timing a physical solve with the app
+//! minimized behind a video. Whether the hotkey is on, and which key it is,
+//! are settings; it is off until turned on.
+//!
+//! Grabbing the key needs the `hotkey` feature, which uses the
+//! `global-hotkey` crate: `RegisterHotKey` on Windows, Carbon hot keys on
+//! macOS, and an X11 key grab elsewhere. A pure Wayland session has no way
+//! to grab keys, so there the grab fails and the settings say why rather
+//! than offering a key that never arrives.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use iced::Subscription;
+
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+/// Whether this build listens for the hotkey.
+pub const AVAILABLE: bool = cfg!(feature = "hotkey");
+
+const HEADER: &str = "rubics hotkey settings v1";
+
+const DEFAULT_KEY: Key = Key::F9;
+
+/// Keys offered as the hotkey: ones that rarely mean anything to the
+/// window in front. F12 is left out; it toggles the render profiler.
+/// Macs have no Scroll Lock key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    F7,
+    F8,
+    F9,
+    F10,
+    ScrollLock,
+}
+
+impl Key {
+    #[cfg(not(target_os = "macos"))]
+    pub const ALL: [Key; 5] = [Key::F7, Key::F8, Key::F9, Key::F10, Key::ScrollLock];
+    #[cfg(target_os = "macos")]
+    pub const ALL: [Key; 4] = [Key::F7, Key::F8, Key::F9, Key::F10];
+
+    fn parse(s: &str) -> Option<Key> {
+        Key::ALL.into_iter().find(|k| k.to_string() == s.trim())
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::ScrollLock => "Scroll Lock",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HotkeySettings {
+    pub enabled: bool,
+    pub key: Key,
+}
+
+impl Default for HotkeySettings {
+    fn default() -> Self {
+        Self { enabled: false, key: DEFAULT_KEY }
+    }
+}
+
+impl HotkeySettings {
+    pub fn encode(&self) -> String {
+        write_kv(HEADER, &[
+            ("enabled", self.enabled.to_string()),
+            ("key", self.key.to_string()),
+        ])
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        Self {
+            enabled: kv.get("enabled").is_some_and(|v| v == "true"),
+            key: kv.get("key").and_then(|v| Key::parse(v)).unwrap_or(DEFAULT_KEY),
+        }
+    }
+}
+
+/// Location of the hotkey settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("hotkey.txt")
+}
+
+pub fn load() -> HotkeySettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| HotkeySettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &HotkeySettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
+
+// ----- listening -----
+
+/// The hotkey held system-wide while it is on; dropping it lets the key go.
+pub struct Grab {
+    key: Key,
+    _inner: backend::Grab,
+}
+
+/// Hold a grab for `settings`' key while the hotkey is on, and none while
+/// it is off; a translated reason if the key couldn't be grabbed.
+pub fn sync(grab: &mut Option<Grab>, settings: HotkeySettings) -> Result<(), String> {
+    if !settings.enabled {
+        *grab = None;
+        return Ok(());
+    }
+    if grab.as_ref().is_some_and(|g| g.key == settings.key) {
+        return Ok(());
+    }
+    // Let the old key go first, in case the new grab fails.
+    *grab = None;
+    let inner = backend::Grab::new(settings.key)
+        .map_err(|error| t!("hotkey-failed", key = settings.key.to_string(), error = error))?;
+    *grab = Some(Grab { key: settings.key, _inner: inner });
+    Ok(())
+}
+
+/// Presses of the grabbed key anywhere on the desktop, for as long as the
+/// subscription is kept.
+pub fn subscription() -> Subscription<Key> {
+    backend::subscription()
+}
+
+#[cfg(feature = "hotkey")]
+mod backend {
+    use std::time::Duration;
+
+    use global_hotkey::hotkey::{Code, HotKey};
+    use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+    use iced::futures::channel::mpsc;
+    use iced::futures::{SinkExt, StreamExt};
+    use iced::Subscription;
+
+    use super::Key;
+
+    /// How long the event thread waits before checking whether anyone
+    /// still listens.
+    const POLL: Duration = Duration::from_millis(500);
+
+    fn hotkey(key: Key) -> HotKey {
+        let code = match key {
+            Key::F7 => Code::F7,
+            Key::F8 => Code::F8,
+            Key::F9 => Code::F9,
+            Key::F10 => Code::F10,
+            Key::ScrollLock => Code::ScrollLock,
+        };
+        HotKey::new(None, code)
+    }
+
+    pub struct Grab {
+        manager: GlobalHotKeyManager,
+        hotkey: HotKey,
+    }
+
+    impl Grab {
+        /// Register `key` with the desktop. Called from the UI thread, which
+        /// runs the event loop the Windows and macOS grabs report through.
+        pub fn new(key: Key) -> Result<Grab, String> {
+            // The X11 grab needs a display to connect to; the crate doesn't
+            // check for one.
+            #[cfg(all(unix, not(target_os = "macos")))]
+            if std::env::var_os("DISPLAY").is_none() {
+                return Err(crate::t!("hotkey-no-x11"));
+            }
+            let manager = GlobalHotKeyManager::new().map_err(|e| e.to_string())?;
+            let hotkey = hotkey(key);
+            manager.register(hotkey).map_err(|e| e.to_string())?;
+            Ok(Grab { manager, hotkey })
+        }
+    }
+
+    impl Drop for Grab {
+        fn drop(&mut self) {
+            let _ = self.manager.unregister(self.hotkey);
+        }
+    }
+
+    pub fn subscription() -> Subscription<Key> {
+        struct Hotkey;
+        iced::subscription::channel(std::any::TypeId::of::<Hotkey>(), 16, |mut output| async move {
+            let (tx, mut rx) = mpsc::unbounded();
+            std::thread::spawn(move || {
+                let events = GlobalHotKeyEvent::receiver();
+                loop {
+                    match events.recv_timeout(POLL) {
+                        Ok(event) if event.state == HotKeyState::Pressed => {
+                            let key = Key::ALL.into_iter().find(|&k| hotkey(k).id() == event.id);
+                            if let Some(key) = key
+                                && tx.unbounded_send(key).is_err()
+                            {
+                                return;
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(e) if e.is_timeout() && !tx.is_closed() => {}
+                        Err(_) => return,
+                    }
+                }
+            });
+            while let Some(key) = rx.next().await {
+                let _ = output.send(key).await;
+            }
+            loop {
+                iced::futures::future::pending::<()>().await;
+            }
+        })
+    }
+}
+
+#[cfg(not(feature = "hotkey"))]
+mod backend {
+    use iced::Subscription;
+
+    use super::Key;
+
+    pub struct Grab;
+
+    impl Grab {
+        pub fn new(_key: Key) -> Result<Grab, String> {
+            Err(crate::t!("hotkey-unavailable"))
+        }
+    }
+
+    pub fn subscription() -> Subscription<Key> {
+        Subscription::none()
+    }
+}
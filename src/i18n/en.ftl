@@ -0,0 +1,810 @@
+# English (reference bundle; every key must exist here)
+
+app-title = 2×2 Pocket Cube — Isometric 3D
+right-window-title = 2×2 Pocket Cube — Right view
+order-window-title = 2×2 Pocket Cube — Algorithm order
+language = Language
+
+# canvas / render settings
+size = Size
+light = Light
+light-az = Az
+light-el = El
+light-shade = Shade
+left-render = Left render
+right-render = Right render
+grid = Grid
+axes = Axes
+heatmap = Heatmap
+heatmap-desc = Tint each corner by how many turns it alone is from its home slot and orientation: none when home, then yellow, orange, red
+letters = Letters
+letters-desc = Label every sticker position with its letter (Speffz unless edited: A–D on U, E–H L, I–L F, M–P R, Q–T B, U–X D); state announcements name the letters too
+display-scale = Display scale
+mode-filled = Filled
+mode-wireframe = Wireframe
+
+# cameras
+camera-model = Camera
+camera-model-desc = Euler: the Rz/Rx/Ry sliders alone set each view. Trackball: drag a view to roll it like a ball under the cursor, about any axis; the sliders follow with the nearest angles
+camera-euler = Euler sliders
+camera-trackball = Trackball
+left-view = Left view
+right-view = Right view
+preset-isometric = Isometric
+preset-dimetric = Dimetric
+preset-front = Front
+preset-top = Top
+preset-left = Preset Left U/F/R
+preset-right = Preset Right D/L/B
+reset-cameras = Reset Cameras
+snap-90 = Snap 90°
+preset-left-mirrored = Preset Left U/F/L
+preset-right-mirrored = Preset Right D/R/B
+mirrored-layout = Mirrored layout
+reduced-motion = Reduced motion
+fps-cap = Frame rate
+fps-uncapped = Uncapped
+fps-cap-value = { $fps } fps
+renderer = Renderer
+backend-canvas = Canvas
+backend-gpu = GPU
+right-link = Right link
+link-off = Off
+link-opposite = Opposite
+link-mirror = Mirror (Y-flip)
+link-follow = Follow
+link-offset = Fixed offset
+dock-right = Dock right view
+pop-out-right = Pop out right view
+angle-placeholder = deg
+view-info = Left (Rz,Rx,Ry)=({ $l })   Right (Rz,Rx,Ry)=({ $r })   Size={ $size }px
+
+# moves / seed / algorithm
+moves = Moves
+seed = Seed
+seed-placeholder = Enter seed (e.g., 12345)
+apply = Apply
+scramble = Scramble
+reset = Reset
+clear = Clear
+seed-invalid = not a whole number
+alg-editor-desc = Algorithm, e.g. R U R' U'; may span lines, // starts a comment to the end of the line
+alg-move-count = { $n } moves
+alg-invalid-count = { $n } moves, { $bad } not recognized
+alg-complete = Expand:
+alg-order-button = Order
+alg-order-desc = How many repetitions of this algorithm return to the start, and how it cycles the corners
+alg-empty = (empty algorithm)
+alg-order = This algorithm has order { $n } (lcm of the cycle orders { $parts }).
+alg-order-identity = This algorithm has order 1: every corner ends up home and untwisted.
+alg-cycles = Corner cycles: { $list }
+alg-cycles-legend = + / - mark a net clockwise / counterclockwise twist; a twisted cycle needs three times its length to untwist.
+custom-scramble = Custom scramble
+custom-scramble-placeholder = Paste a scramble (e.g., R U2 F' R2 U)
+custom-scramble-desc = Reset to solved and apply this scramble
+custom-applied = Applied custom scramble ({ $n } moves).
+kpuzzle = cubing.js
+kpuzzle-placeholder = Paste KPuzzle pattern JSON, or a path to a .json file
+kpuzzle-desc = Load a cubing.js KPuzzle pattern (CORNERS orbit) and optional "alg"
+import = Import
+kpuzzle-imported = Imported cubing.js pattern.
+kpuzzle-bad-json = Not valid JSON.
+kpuzzle-empty = No CORNERS pattern or "alg" found in the JSON.
+kpuzzle-bad-corners = CORNERS needs 8 distinct pieces (0–7) and orientations 0–2.
+kpuzzle-twisted = Corner twists don't add up; this pattern can't be reached by turning.
+verify = Verify
+verify-placeholder = Stickers of your cube: U D F B L R faces, 4 letters each (e.g., WWWW YYYY …)
+verify-desc = Compare the colors of your physical cube with the state shown
+verify-ok = All 24 stickers match.
+verify-mismatch = { $n } sticker(s) differ:
+verify-sticker = { $face } face, row { $row }, column { $col }: expected { $expected }, entered { $found }
+verify-bad-length = Expected 24 sticker letters, got { $n }.
+verify-bad-letter = Unknown color letter "{ $letter }" (use W Y G B O R).
+min-depth = Min depth
+min-depth-desc = New random scrambles must be at least this many moves from solved
+scramble-depth = { $n } moves from solved
+reset-cube = Reset cube
+confirm-reset = Confirm reset
+cancel = Cancel
+restore = Restore
+discard = Discard
+restore-session-prompt = Restore the interrupted session?
+
+# status messages
+nothing-to-apply = Nothing to apply.
+alg-error = Algorithm error: { $error }
+alg-applied = Applied algorithm.
+alg-watch-file = Watch file:
+alg-watch-placeholder = path/to/algorithm.alg
+alg-watch = Watch
+alg-watch-stop = Stop watching
+alg-watch-applied = Applied { $path } as saved.
+alg-watch-stopped = Stopped watching the algorithm file.
+seed-applied = Applied seed = { $seed }: { $seq }
+no-valid-seed = No valid seed entered — nothing applied.
+cube-reset = Cube reset.
+cube-reset-kept = Cube reset (previous state kept — use Restore to undo).
+reset-confirm = Reset discards { $n } move(s). Confirm?
+reset-cancelled = Reset cancelled.
+trash-restored = Restored state from before the last reset.
+checkpoints = Checkpoints
+checkpoint-placeholder = name, e.g. after first face
+checkpoint-add = Checkpoint
+checkpoint-add-desc = Remember the cube and its moves so far under this name, to jump back to later
+checkpoint-default = after move { $n }
+checkpoint-added = Checkpoint "{ $name }" saved.
+checkpoint-jump-desc = Jump back here ({ $n } moves): { $moves }
+checkpoint-restored = Back at checkpoint "{ $name }" ({ $n } moves).
+history = History
+history-undo = Undo
+history-undo-desc = Step back one move (Ctrl+Z); a different move from there starts a new branch
+history-redo = Redo
+history-redo-desc = Step forward along the branch last visited (Ctrl+Y)
+history-summary = { $states } states, { $branches } branches
+history-graph-desc = Every line of moves tried; click a dot to go to that state
+history-at = At move { $n }.
+script = Script
+script-comment-placeholder = Comment on this move (before the first move: on the scramble)
+script-comment-desc = Saved with the move and written into exported solve scripts
+script-path-placeholder = Script file (default { $path })
+script-export = Export
+script-export-desc = Write the scramble and the moves so far, with their times and comments, as a Markdown solve script
+script-import = Replay
+script-import-desc = Load a solve script and play it back at its recorded pace, showing each comment as its move comes up
+script-stop = Stop
+script-progress = Move { $n } of { $total }
+script-exported = Exported { $n } moves to { $path }.
+script-bad-line = Line { $line } isn't a move: { $text }
+script-no-scramble = No scramble found (expected a `code` line under "## Scramble").
+script-replaying = Replaying a { $n }-move solve script.
+script-replay-stopped = Replay stopped.
+script-replay-done = Replay finished after { $n } moves.
+breakdown = Break down
+breakdown-desc = Split the timed solve on the current line into its steps (face, OLL, PBL) with each step's time, recognition pause and turns per second
+breakdown-close = Close
+breakdown-untimed = Nothing to break down: the solve needs moves with their times recorded.
+breakdown-total = { $moves } moves in { $time }, { $tps } TPS
+breakdown-step = Step
+breakdown-moves = Moves
+breakdown-time = Time
+breakdown-recognition = Recognition
+breakdown-tps = TPS
+breakdown-exec-tps = Exec. TPS
+breakdown-skip = { $step } (skip)
+breakdown-unfinished = { $step } (unfinished)
+breakdown-no-pauses = No pauses of { $min } or more.
+breakdown-pauses = { $n } pause(s) of { $min } or more, { $total } in all; the longest, { $longest }, before move { $at }.
+phase-face = Face
+phase-oll = OLL
+phase-pbl = PBL
+pauses-find = Find pauses
+pauses-find-desc = Look through this session's drill solves for the gaps between moves at least this long, and what the cube was at each
+pauses-close = Close
+pause-threshold = Pauses from
+pause-threshold-desc = Shortest gap between two moves counted as a pause
+pause-threshold-value = { $secs } s
+pauses-found = Found { $n } pause(s) in { $solves } solve(s).
+pauses-none = No pauses that long in this session's timed solves.
+pause-line = { $n }. Solve { $solve }, before move { $before }: { $time } — { $case }
+pauses-by-case = By case, most time lost first:
+pause-case-line = { $case }: { $n } pause(s), { $total } in all, { $mean } on average, longest { $longest }
+case-named = { $step }: { $name }
+case-other = { $step }: another case
+case-pbl = { $step }: { $first } / { $second }
+case-solved = Solved
+menu-file = File
+menu-edit = Edit
+menu-view = View
+menu-help = Help
+menu-open-session = Open session (replay trace)
+menu-save-session = Save session trace
+menu-export-image = Export image (PNG)
+menu-export-script = Export solve script
+menu-import-script = Import solve script
+menu-back-up = Back up profile
+menu-quit = Quit
+menu-undo = Undo
+menu-redo = Redo
+menu-reset-cube = Reset cube
+menu-palette = Command palette…
+menu-panels = Panels
+menu-qr = QR code
+menu-profiler = Render profiler
+menu-reset-cameras = Reset cameras
+menu-diagnostics = Diagnostics
+debug-title = Diagnostics
+debug-version = Version
+debug-features = Build features
+debug-platform = Platform
+debug-backend = Renderer
+debug-tables = Solver tables
+debug-profile = Profile
+debug-data-dir = Data folder
+debug-read-only = Read-only
+debug-language = Language
+debug-none = none
+debug-tables-loading = Still loading or building.
+debug-no-cache = No cache file at { $path }.
+debug-copy = Copy debug bundle
+debug-copy-desc = Copy these details, your settings files, and this session's trace, to paste into a bug report
+debug-copied = Debug bundle copied to the clipboard.
+menu-about = About
+about-title = Rubics { $version }
+about-body = A 2×2 pocket cube: turn it, scramble it, solve it, and train with it.
+about-license = Licensed under the SSCPL-NC v1.0.
+about-close = Close
+timer-start = Start timer
+timer-stop = Stop timer
+timer-desc = Time a solve on a physical cube
+timer-started = Timer running.
+timer-stopped = Time: { $time }
+timer-mean = Mean { $time } over { $n }
+timer-start-solve = Start solve
+timer-inspecting = Inspecting: { $secs } s.
+timer-started-plus-two = Timer running, +2 for a late start.
+inspection-wca = WCA inspection
+inspection-relaxed = Relaxed inspection
+inspection-off = No inspection
+inspection-secs = { $secs } s
+inspection-mode-desc = WCA: +2 for starting after the inspection time, DNF two seconds later. Relaxed: calls only
+inspection-secs-desc = Inspection length; the calls and penalties move with it
+result-dnf = DNF
+result-plus-two = { $time } (+2)
+hotkey = Global hotkey
+hotkey-desc = Start and stop the timer with this key even while another window has focus
+hotkey-save-failed = Could not save hotkey settings: { $error }
+hotkey-failed = Could not grab { $key } as the global hotkey: { $error }
+hotkey-no-x11 = the global hotkey needs an X11 session (Wayland alone can't grab keys)
+hotkey-unavailable = this build has no global hotkey support
+ab-title = A/B:
+ab-case = Case
+ab-alg-placeholder = Algorithm { $side }
+ab-trials-value = { $n } each
+ab-trials-desc = How many times to execute each algorithm
+ab-start = Compare
+ab-start-desc = Alternate between the two algorithms on the case, timing each execution, to see which is faster for you
+ab-no-case = Pick a case to compare algorithms on.
+ab-alg-empty = Algorithm { $side } is empty.
+ab-alg-wrong-case = Algorithm { $side } doesn't solve { $case }.
+ab-trial = Trial { $n } of { $total }: solve with { $side }: { $alg }
+ab-running = { $case }, trial { $n } of { $total }: { $side }: { $alg }
+ab-redo = Redo trial
+ab-redo-desc = Set the case up again and start this trial over, untimed
+ab-stop = Stop
+ab-close = Close
+ab-timed = { $side }: { $time }
+ab-finished = All trials done.
+ab-mean = { $side }: { $time } on average over { $n }
+ab-verdict-waiting = Two times of each algorithm are needed to compare them.
+ab-verdict-faster = { $side } is faster for you, by { $gap } (p = { $p }).
+ab-verdict-unclear = No clear difference yet (p = { $p }); more trials would tell.
+usage-show = Move usage
+usage-hide = Hide move usage
+usage-show-desc = A heatmap of how often you turn each face each way, over this profile's solves
+usage-empty = No solves counted yet; turns are counted when you solve the cube.
+usage-summary = { $turns } turn(s) over { $solves } solve(s); most turned: { $face }, { $share }% of turns
+usage-reset-button = Reset
+usage-reset-desc = Forget the turns counted so far
+usage-reset = Move usage reset.
+usage-slow-face = { $face } turns are { $share }% of your turns; they are slow to make, so look for algorithms that avoid them.
+usage-save-failed = Could not save move usage: { $error }
+layer-solved = solved
+layer-adjacent = adjacent swap
+layer-diagonal = diagonal swap
+video-sync = Sync to video
+video-sync-desc = Load a solve script and step it to a typed or scrubbed time, to follow a video of the solve
+video-sync-offset = Solve starts at
+video-sync-offset-desc = Video time where the solve starts (m:ss.cc or seconds); script times count from here
+video-sync-time = Video time
+video-sync-time-desc = Type a video time (m:ss.cc or seconds) and press Enter to show the cube at that moment
+video-sync-close = Close
+video-sync-loaded = Loaded a { $n }-move solve script ending at { $end }; type or scrub a video time.
+video-sync-at = { $time }: move { $n } of { $total }.
+video-sync-at-comment = { $time }: move { $n } of { $total } — “{ $comment }”
+video-sync-bad-time = "{ $time }" isn't a time (use m:ss.cc or seconds).
+trace = Session trace
+trace-recorded = { $n } actions recorded
+trace-path-placeholder = Trace file (default { $path })
+trace-save = Save
+trace-save-desc = Write the starting state and every action since, with their times, so the session can be replayed (e.g. attached to a bug report)
+trace-replay = Replay
+trace-replay-desc = Load a session trace, return to the state it started from, and play its actions back at their recorded pace (long pauses shortened)
+trace-progress = Action { $n } of { $total }
+trace-saved = Saved { $n } actions to { $path }.
+trace-bad-line = Line { $line } of the session trace can't be read.
+trace-replaying = Replaying a session trace of { $n } actions.
+trace-replay-stopped = Trace replay stopped.
+trace-replay-done = Trace replay finished after { $n } actions.
+console = Console
+console-run = Run
+console-run-desc = Run the script on the current cube (Ctrl+Enter); a cube it changes replaces the one shown
+console-clear-desc = Clear the console output
+console-editor-desc = Rhai script: cube.apply("R U"), cube.state(), cube.reset(), cube.solved(), scramble(seed), solve(), depth(), invert(alg), order(alg), random(), print(…)
+console-running = Running script…
+console-done = Script finished.
+console-error = Line { $line }: { $detail }
+palette-placeholder = Command (Enter to run, Esc to close), e.g. scramble 20, camera 30 45 0, R U R'
+palette-help = Commands: { $commands }
+palette-usage = Usage: { $usage }
+palette-unknown = Unknown command "{ $command }" (type help for the list).
+png-exported = Saved a screenshot to { $path }; drop it on the window to come back to this scene.
+bookmark-opened = Restored the scene saved in { $path }.
+bookmark-none = { $path } holds no saved scene.
+drop-unknown = Can't open { $path }: drop an exported PNG or SVG, a KPuzzle .json, an .alg file, or a .txt of sticker letters or a session trace.
+drop-alg-loaded = Loaded the algorithm from { $path }.
+drop-facelets-loaded = Set the cube to the stickers in { $path }.
+invalid-angle = Invalid angle: "{ $input }" (expected degrees 0–360)
+did-move = Did { $token }
+unknown-move = Unknown move: { $token }
+autosave-failed = Auto-save failed: { $error }
+io-read-failed = Could not read { $path }: { $error }
+io-write-failed = Could not write { $path }: { $error }
+session-interrupted = The previous session ended unexpectedly.
+session-restored = Restored interrupted session.
+link-opened = Opened shared link.
+link-invalid = Not a share link or a file the app opens: { $link }
+link-copied = Copied share link: { $link }
+
+# solver
+solver-loaded = Solver tables loaded from cache.
+solver-built = Solver tables built in { $ms } ms and cached.
+solver-built-unsaved = Solver tables built in { $ms } ms (could not write the cache).
+solver-rebuilding = Rebuilding solver tables…
+rebuild-tables = Rebuild solver tables
+rebuild-tables-desc = Delete the cached solver tables and generate them again
+analyze-faces = Analyze first faces
+analyze-faces-desc = Find the shortest ways to build a face of each color from this position
+face-report = { $color }: { $n } moves ({ $ways } ways) — { $algs }
+face-report-built = { $color }: already built
+face-report-deep = { $color }: more than { $n } moves
+explore-subgroups = Explore subgroups
+explore-subgroups-desc = Which standard subgroups contain this position, and how many repetitions of the moves that reached it return to solved
+subgroup-list = Subgroups: { $list }
+subgroup-none = No smaller standard subgroup: this position needs the whole cube group
+subgroup-solved = solved
+subgroup-one-face = ⟨{ $face }⟩, one turn from solved (or ⟨{ $opposite }⟩)
+subgroup-half-turns = ⟨U2, D2, F2, B2, L2, R2⟩, half turns only
+subgroup-two-gen = ⟨{ $a }, { $b }⟩
+subgroup-oriented = orientation solved, permutation remaining
+subgroup-permuted = permutation solved, orientation remaining
+subgroup-illegal = Not a legal cube state, so there is no subgroup to report.
+element-order = Order { $order }: repeating the moves that reached this position { $order } times returns to solved.
+element-order-rotated = Order { $order }: { $reps } repetitions of the moves that reached this position return to solved (as a whole-cube rotation), { $order } restore it exactly.
+
+# state diagnostics
+diagnostics = Diagnostics
+diagnostics-desc = Check the stickers shown: does every slot hold a real corner, once each, and can the state be solved?
+diag-slot = { $slot }: { $problem }
+diag-mirrored = the { $piece } piece's colors in mirror-image order (two stickers swapped)
+diag-unknown = { $colors } isn't any corner
+diag-duplicated = Appears more than once: { $pieces }
+diag-missing = Missing: { $pieces }
+diag-parity-even = Corner permutation: even
+diag-parity-odd = Corner permutation: odd (fine on a 2×2, a quarter turn is odd)
+diag-parity-unknown = Corner permutation: unknown until every corner is valid
+diag-twist-ok = Total twist: 0
+diag-twist-ccw = Total twist: +1, as if one corner were twisted counterclockwise in place
+diag-twist-cw = Total twist: +2, as if one corner were twisted clockwise in place
+diag-solvable = Solvable.
+diag-unsolvable = Not solvable.
+diag-fix-twist = Fix twist at
+diag-fix-twist-desc = Twist the { $slot } corner in place so the total twist is 0
+diag-twist-fixed = Twisted the { $slot } corner in place; the state is solvable again.
+
+# state comparison
+diff = Compare
+diff-save = Save as { $slot }
+diff-saved = Saved the current cube as state { $slot }.
+diff-slot-full = Saved; click to replace it with the current cube
+diff-slot-empty = Nothing saved as state { $slot } yet.
+diff-current-a = Current → A
+diff-current-b = Current → B
+diff-a-b = A → B
+diff-current-pattern = Current → pattern
+diff-pattern-placeholder = 24 sticker letters, or moves from solved
+diff-pattern-desc = The state to compare with: facelets (U D F B L R faces, e.g. WWWW YYYY …) or an algorithm applied to a solved cube
+diff-bad-pattern = Not 24 sticker letters or an algorithm: { $error }
+diff-compare = Compare
+diff-compare-desc = List the stickers and corners that differ, outline the stickers on the cube, and find the shortest algorithm from the first state to the second
+diff-clear = Clear
+diff-identical = The two states are identical.
+diff-stickers = { $n } sticker(s) differ (outlined on the cube).
+diff-slots = { $n } corner slot(s) hold a different piece or twist: { $slots }
+diff-slots-none = Every corner slot holds the same piece and twist.
+diff-illegal = One of the states isn't a legal cube, so only stickers are compared.
+diff-alg = Shortest algorithm ({ $n } moves): { $alg }
+
+# color-neutrality trainer
+drill-start = Neutrality drill
+drill-start-desc = Scramble and assign a random color to build first; the clock starts on your first turn
+drill-stop = Stop drill
+drill-target = Build { $color } first
+drill-started = Build { $color } first — the clock starts on your first turn.
+drill-solved = Solved from { $color } in { $time }.
+drill-wrong-color = Solved, but { $built } was built first (target was { $target }); not counted.
+drill-dnf = DNF: inspection ran over before the first turn (target was { $color }); not counted.
+drill-stats-empty = No drill solves yet.
+drill-stat = { $color }: { $time } avg ({ $n })
+drill-slowest = slowest
+drill-save-failed = Could not save drill stats: { $error }
+report-export = Export report
+report-export-desc = Save this session's drill solves as an HTML report: stats, a time chart, the best solve, and every scramble
+report-exported = Saved a report of { $n } solves to { $path }.
+sheet-export = Solve sheet
+sheet-export-desc = Save a printable page of fresh scrambles, each with its state as a net and a box for the time, as HTML in the data folder
+sheet-solutions = Solutions
+sheet-solutions-desc = Print the solutions upside down at the foot of solve sheets
+sheet-exported = Saved a solve sheet of { $n } scrambles to { $path }.
+sheet-exported-unsolved = Saved a solve sheet to { $path }, without solutions: the solver isn't ready yet.
+sheet-title = Solve sheet
+sheet-name = Name
+sheet-state = Scrambled state
+alg-set-all = All algorithms
+alg-set-oll = Orientation cases
+alg-set-perm = Permutations
+alg-set-trigger = Triggers
+anki-set-desc = Algorithms the Anki deck covers
+anki-export = Anki deck
+anki-export-desc = Save the algorithms as Anki flash cards, the case on the front and the moves on the back, as a text file for File ▸ Import in the data folder
+anki-deck = Rubics::{ $set }
+anki-exported = Saved an Anki deck of { $n } cards to { $path }.
+thumbs-usage = Usage: rubics thumbs <folder> [file], with one algorithm per line in the file or on standard input.
+thumbs-bad-line = Line { $line }: { $error }
+thumbs-empty = No algorithms to draw.
+thumbs-written = Wrote { $n } case thumbnails and cases.tsv to { $dir }.
+serve-usage = Usage: rubics serve [port], the port being a number (default 8642).
+serve-bind-failed = Could not listen on port { $port }: { $error }
+serve-listening = Serving cube diagrams at { $url } — press Ctrl+C to stop.
+serve-bad-request = Malformed request.
+serve-too-long = Request line or headers too long.
+serve-get-only = Only GET requests are answered.
+serve-not-found = Nothing at { $path }; try /state.svg?alg=R+U+R'
+serve-no-png = PNG diagrams are not available; use /state.svg.
+serve-bad-view = Unknown view "{ $view }"; use net, blueprint, or ll.
+register-usage = Usage: rubics register, with no other arguments.
+register-unsupported = Registering file types is only supported on Linux desktops.
+register-no-home = Neither XDG_DATA_HOME nor HOME is set; nowhere to register.
+register-done = Registered { $exe } to open .alg files and session traces, in { $entry }.
+profile = Profile
+profile-desc = Whose settings, solve logs, letter scheme and bindings are in use; each profile keeps its own
+profile-default = Default
+profile-new-hint = New profile
+profile-add = Add
+profile-add-desc = Create a profile with this name and switch to it
+profile-switched = Switched to profile { $name }.
+profile-bad-name = A profile name is up to { $max } letters, digits, spaces, dashes and underscores.
+profile-save-failed = Couldn't save the profile choice: { $error }
+backup-export = Back up
+backup-export-desc = Save all of this profile's settings, times and schemes in one ZIP archive, backup.zip in the data folder
+backup-exported = Backed up { $n } files to { $path }.
+backup-restore = Restore
+backup-restore-desc = Put back the settings, times and schemes saved in backup.zip, over this profile's
+backup-restore-confirm = Restoring { $path } overwrites the files of profile { $name }. Confirm?
+backup-confirm-restore = Confirm restore
+backup-restore-cancelled = Restore cancelled.
+backup-restored = Restored { $n } files from { $path }.
+backup-bad-archive = Not a backup archive, or a damaged one.
+demo-started = Demo mode: nothing is saved, and the cube resets after { $secs } s without input.
+demo-reset = Ready for the next visitor.
+attract-hint = Press a key or click to play.
+demo-read-only = Demo mode saves nothing, so backups can't be restored.
+demo-no-export = Demo mode saves nothing, so files can't be exported.
+daily-start = Daily challenge
+daily-start-desc = Scramble the cube with today's challenge, the same for everyone; the first solve of the day is recorded
+daily-stop = Give up
+daily-started = Daily challenge for { $day }: the clock starts on your first turn.
+daily-solved = Daily challenge solved in { $time }. Streak: { $streak } days.
+daily-solved-again = Solved in { $time }; today's time was already recorded.
+daily-save-failed = Couldn't save the daily challenge times: { $error }
+daily-streak = Streak: { $n } days
+daily-today = Today: { $time }
+daily-best = Best: { $time }
+daily-day-solved = { $day }: { $time }
+leaderboard-opt-in = Share daily times
+leaderboard-opt-in-desc = Send your daily challenge times, under the name below, to the leaderboard server; nothing is sent without this, and turning it off drops times not yet sent
+leaderboard-endpoint-hint = http://server/leaderboard
+leaderboard-endpoint-desc = Address of the leaderboard server (plain http only)
+leaderboard-name-hint = Name
+leaderboard-name-desc = Name shown next to your times on the board; left blank, you appear as "anonymous"
+leaderboard-refresh = Refresh board
+leaderboard-queued = { $n } times waiting to be sent
+leaderboard-title = Leaderboard for { $day }
+leaderboard-empty = No times yet today.
+leaderboard-entry = { $rank }. { $name } — { $time }
+leaderboard-failed = Leaderboard: { $error }
+leaderboard-save-failed = Couldn't save the leaderboard settings: { $error }
+leaderboard-status = the server answered { $code }
+leaderboard-bad-url = "{ $url }" isn't an http:// address
+leaderboard-unreachable = couldn't reach the server ({ $error })
+leaderboard-too-large = the server sent more than { $limit } KiB
+leaderboard-unavailable = this build has no leaderboard client
+obs-enabled = Trigger OBS
+obs-enabled-desc = When a timed solve ends, ask OBS Studio (through its WebSocket server) to save the replay buffer or switch scenes
+obs-host-hint = localhost
+obs-host-desc = Computer OBS runs on, and the port of its WebSocket server (Tools → WebSocket Server Settings)
+obs-port-hint = 4455
+obs-password-hint = Password
+obs-password-desc = The WebSocket server password, if it has one; saved with your settings as typed
+obs-save-replay = Save replay buffer
+obs-switch-scene = Switch to scene
+obs-scene-hint = Scene name
+obs-test = Test
+obs-test-desc = Send the action to OBS now
+obs-testing = Sending to OBS…
+obs-test-ok = OBS took the action.
+obs-failed = OBS: { $error }
+obs-save-failed = Couldn't save the OBS settings: { $error }
+obs-unreachable = couldn't reach OBS ({ $error })
+obs-bad-reply = OBS answered something other than obs-websocket 5
+obs-refused = OBS refused the request (code { $code })
+obs-unavailable = this build has no OBS client
+round-new = New round
+round-new-desc = Preview a round of fresh scrambles side by side; a solve sheet prints these while they're shown
+round-close = Close round
+round-scramble = { $n }. { $moves }
+classroom = Classroom
+classroom-desc = Show only a big scramble and its cube, for projecting to a room; Space or → for the next one, Esc to leave
+classroom-on-key = Next on key
+classroom-every = Next every { $secs } s
+classroom-next = Next
+classroom-leave = Leave
+classroom-number = Scramble { $n }
+classroom-countdown = Next in { $secs } s
+classroom-hint = Space or → for the next scramble, Esc to leave
+blueprint-export = Blueprint
+blueprint-export-desc = Save the cube seen straight on from the top, front, and right as an SVG diagram in the data folder
+blueprint-exported = Saved a blueprint to { $path }.
+blueprint-top = Top
+blueprint-front = Front
+blueprint-right = Right
+report-title = Session report
+report-summary = Summary
+report-solves = Solves
+report-best = Best
+report-worst = Worst
+report-mean = Mean
+report-average = Current average of { $n }
+report-best-average = Best average of { $n }
+report-chart = Times
+report-best-solve = Best solve
+report-best-line = { $color } first, { $time }: { $n } moves, { $tps } turns per second.
+report-scramble = Scramble
+report-solution = Solution
+report-scrambles = Scrambles
+report-color = Color
+report-time = Time
+report-empty = No drill solves this session.
+annotate = Draw on the cube
+annotate-desc = Coach mode: drag on the cube view to draw freehand lines, arrows, or circles
+annotate-pen = Freehand
+annotate-arrow = Arrow
+annotate-circle = Circle
+annotate-red = Red
+annotate-yellow = Yellow
+annotate-blue = Blue
+annotate-keep = Keep per step
+annotate-keep-desc = Keep each state's drawing and show it again when you step back to it, instead of clearing on the next move
+annotate-undo = Undo mark
+annotate-clear = Clear marks
+annotate-snapshot = Save snapshot
+annotate-snapshot-desc = Save the window, drawing included, as a PNG in the data folder
+
+# accessibility: state description and control labels
+announce = Announce state
+announce-desc = Describe every face's stickers in the status line
+share-link = Copy share link
+qr-code = QR code
+qr-code-desc = Show or hide a QR code of the facelets and algorithm for scanning with a phone
+qr-too-long = Too much to fit in a QR code; shorten the algorithm.
+share-link-desc = Copy a link to this cube state, camera, and algorithm (open it by passing it to the app)
+alg-cubing = Open in alg.cubing.net
+alg-cubing-desc = Replay the scramble and the moves made since in alg.cubing.net (opens the web browser)
+alg-cubing-opened = Opened { $url }
+alg-cubing-failed = Could not launch a browser ({ $error }); open this by hand: { $url }
+alg-cubing-unavailable = Solver tables are still loading; try again in a moment.
+post-copy = Copy as forum post
+post-copy-desc = Copy the scramble, the moves made since as the solution, the move count, the last timer time, and an alg.cubing.net link, ready to paste into a forum
+post-format-desc = BBCode for speedsolving.com, Markdown for Reddit and chat
+post-bbcode = BBCode
+post-markdown = Markdown
+post-scramble = Scramble
+post-solution = Solution
+post-moves = Moves
+post-moves-value = { $n } HTM
+post-time = Time
+post-link = View on alg.cubing.net
+post-copied = Copied the solve as a forum post.
+post-empty = No moves since the scramble to post.
+describe-solved = Solved.
+describe-face = { $face } face: { $top } / { $bottom }
+color-white = white
+color-yellow = yellow
+color-green = green
+color-blue = blue
+color-orange = orange
+color-red = red
+face-up = Up
+face-down = Down
+face-front = Front
+face-back = Back
+face-left = Left
+face-right = Right
+turn-cw = clockwise
+turn-ccw = counter-clockwise
+turn-180 = by a half turn
+move-desc = Turn the { $face } face { $turn }
+nudge-desc = Rotate { $axis } by { $deg }°
+angle-input-desc = Exact { $axis } angle in degrees; press Enter to apply
+named-preset-desc = Set this view to the { $preset } camera angle
+look-at = Look at
+look-at-desc = Turn this view to face the { $face } face squarely
+preset-left-desc = Put the left view at 0°, 0°, 0°
+preset-right-desc = Put the right view at 90°, 180°, 0°
+preset-left-mirrored-desc = Put the left view at 270°, 0°, 0°
+preset-right-mirrored-desc = Put the right view at 0°, 180°, 0°
+mirrored-layout-desc = For left-handed use and mirrored lessons: swap the two views and their controls, line the panels up on the right, and mirror the camera presets
+renderer-desc = GPU draws the cubes as 3D triangles with exact depth, for filled views only and without the overlays, annotations, or touch gestures; the canvas draws everything else
+reduced-motion-desc = Stop the random walk's orbit and glow and the metronome flash, and redraw less while idle
+reset-cameras-desc = Restore both camera views to their defaults
+apply-seed-desc = Scramble the cube from the seed
+scramble-desc = Scramble with a new random seed
+seed-clear-desc = Clear the seed field
+alg-clear-desc = Clear the algorithm field
+alg-apply-desc = Turn the cube through the algorithm (Ctrl+Enter in the field)
+alg-watch-desc = Apply the algorithm in this file now and again each time it is saved, from the cube as it is now — for editing it in another editor
+reset-cube-desc = Return the cube to the solved state
+
+# sound effects (built with the `sound` feature)
+sound = Sound
+click-sound = Click
+click-tick = Tick
+click-wood = Wood block
+click-beep = Beep
+cue-move = Turns
+cue-countdown = Inspection calls
+cue-solved = Solved chime
+sound-unavailable = No audio output device; sound effects are off.
+sound-save-failed = Could not save sound settings: { $error }
+controllers = Controller buttons
+controllers-unavailable = This build doesn't listen to controllers (built without the "controllers" feature); bindings can still be edited.
+controllers-unsupported = Controllers are only read on Linux; bindings can still be edited, but no presses arrive on this platform.
+binding-input-placeholder = note 60, cc 20, deck 3
+binding-input-desc = The button: a MIDI note (note N), a MIDI control change (cc N), or a Stream Deck key (deck N, from 0 at the top left)
+binding-learn = Learn
+binding-learn-cancel = Stop learning
+binding-learn-desc = Press a controller button to fill in its name
+binding-command-placeholder = Command, e.g. R U R', preset iso, drill
+binding-command-desc = Any command-palette line: moves, camera or preset, scramble, drill, undo…
+binding-add = Bind
+binding-remove = Remove
+binding-learning = Press a button on the controller…
+binding-learned = Learned { $input }; now type its command.
+binding-unbound = { $input } isn't bound to a command.
+binding-bad-input = "{ $input }" isn't a controller button (use note N, cc N, or deck N).
+binding-no-command = Type the command to bind.
+binding-added = Bound { $input } to "{ $command }".
+bindings-save-failed = Could not save controller bindings: { $error }
+gamepad = Gamepad
+gamepad-prime = Prime
+gamepad-double = Double
+gamepad-speed = Orbit { $speed }°/s
+gamepad-speed-desc = How fast the sticks orbit the views at full tilt (left stick: left view, right stick: right view)
+gamepad-invert = Invert vertical
+gamepad-save-failed = Could not save gamepad settings: { $error }
+gamepad-unsupported = Game controllers are not supported on this platform
+gamepad-failed = Could not read game controllers: { $error }
+motion-save-failed = Could not save motion settings: { $error }
+inspection-call = Inspection: { $seconds } s left
+
+# metronome / beat drill
+metronome-start = Start metronome
+metronome-stop = Stop metronome
+metronome-desc = Flash the cube border on every beat to pace your turns
+metronome-bpm = { $bpm } BPM
+metronome-click = Click
+cue-beat = Metronome
+beat-drill-start = Beat drill
+beat-drill-stop = End drill
+beat-drill-desc = Score how close each turn lands to the beat (starts the metronome)
+beat-drill-live = { $n } turns, { $accuracy }% on beat
+beat-drill-started = Beat drill at { $bpm } BPM: turn on each flash.
+beat-drill-empty = Beat drill ended with no turns.
+beat-drill-result = Beat drill: { $accuracy }% on beat over { $n } turns (±{ $offset } ms average, bias { $bias } ms).
+
+# piece tracking
+track = Follow
+track-placeholder = Pick a corner…
+track-desc = Outline this corner's stickers wherever it goes, through scrambles and solutions
+track-stop = Stop following
+track-trail = Trail
+track-trail-desc = List the slot the corner reaches after each move since the last reset or scramble (+ / - = twisted clockwise / counterclockwise)
+track-at = Now at { $slot }
+track-trail-empty = Trail: no moves since the last reset or scramble.
+track-trail-list = Trail: { $stops }
+
+# random-walk demo
+random-walk-start = Random walk
+random-walk-stop = Stop walk
+random-walk-desc = Keep making random turns while the view slowly orbits, as an ambient display
+random-walk-rate = { $rate } turns/s
+random-walk-until-solved = Stop when solved
+random-walk-until-solved-desc = Experiment: how many random turns until the cube is solved again? It takes about { $states } on average, one per distinct state.
+random-walk-moves = { $n } turns
+random-walk-stopped = Random walk stopped after { $n } turns.
+random-walk-solved = Solved again after { $n } random turns!
+
+# notation trainer
+notation-start = Notation drill
+notation-stop = Stop drill
+notation-desc = Practise the virtual-cube keyboard layout: key in each move before time runs out
+notation-quiz-keys = Type the move
+notation-quiz-read = Read the move
+notation-limit = { $secs } s per move
+notation-started = Notation drill started: keep the cube window focused and type with the keys below.
+notation-prompt-read = Which move is the small cube playing?
+notation-correct = { $mv } correct ({ $ms } ms).
+notation-wrong = You typed { $typed }; { $mv } is { $keys }.
+notation-timeout = Too slow: { $mv } is { $keys }.
+notation-stats = { $quiz }: { $accuracy }% of { $n } ({ $avg } ms average, { $timed_out } too slow)
+notation-stats-none = { $quiz }: no attempts yet
+notation-missed = Most missed: { $moves }
+notation-keymap = Keys: { $keys } (a half turn is the same key twice)
+notation-save-failed = Couldn't save notation stats: { $error }
+
+# BLD memo
+memo = BLD memo
+memo-show = Show memo
+memo-show-desc = Trace the corner memo for the cube as shown (your letter scheme, Old Pochmann from the UBL buffer)
+memo-drill = Time memo
+memo-drill-desc = Start the clock, memorize the corners, then type the memo back to stop it
+memo-placeholder = letters, e.g. CJ MX
+memo-check = Check
+memo-cancel = Cancel
+memo-drill-started = Memorizing: type the memo when you're ready.
+memo-letters = Corners ({ $n } targets): { $memo }
+memo-parity = Corners ({ $n } targets, parity): { $memo }
+memo-none = Corners already solved: nothing to memorize.
+memo-illegal = This sticker pattern isn't a reachable cube state, so it has no memo.
+memo-correct = Memo correct in { $time }.
+memo-correct-best = Memo correct in { $time }, a new best!
+memo-wrong = "{ $typed }" doesn't match the memo { $memo }.
+memo-best = Best: { $time }
+scheme-edit = Edit letters
+scheme-reset = Speffz
+scheme-reset-desc = Reset every position to its Speffz letter
+scheme-duplicates = Used more than once, so memos are ambiguous: { $letters }
+scheme-save-failed = Couldn't save the letter scheme: { $error }
+
+# solver output + regrip hints
+solve = Solve
+solve-desc = Find a solution for the cube shown: fewest moves (R, U, F turns), or least cost
+solve-weighted = Least cost
+solve-weighted-desc = Minimize the total move cost below (any face) instead of the move count
+solving = Searching…
+solve-found = Found a { $n }-move solution.
+solve-unreachable = Can't be solved turning only { $generators }.
+solve-timeout = Gave up searching after the time limit; try fewer restrictions or other costs.
+solver-table-bad = Solver table unusable: { $detail }
+generators = Using
+generators-placeholder = all faces
+generators-desc = Faces the solver may turn, e.g. "R U" or "<R,U,F>" (empty = all)
+generators-bad = "{ $ch }" isn't a face (use U D F B L R).
+solve-solved = Already solved.
+solve-result = Solution ({ $n } moves, cost { $cost }): { $alg }
+solve-invalid = This sticker pattern isn't a reachable cube state.
+solver-not-ready = Solver tables are still loading; try again in a moment.
+watch-solve = Solve & watch
+watch-solve-desc = Find a solution and play it on the cube a turn at a time, its moves appearing over the views
+watch-stop = Stop
+watch-pace = Pace
+watch-pace-desc = Time between the turns of the watched solve
+watch-pace-value = { $secs } s per turn
+watch-started = Watching a { $n }-move solution.
+watch-stopped = Stopped watching.
+watch-done = Solved in { $n } moves.
+watch-progress = { $n }/{ $total }
+cost-model = Move costs
+cost-model-desc = Per-move costs for least-cost solving and regrip hints, e.g. "B=3 y=1" (U D F B L R, half, x y z; others keep their defaults)
+cost-bad-token = Bad move cost "{ $token }" (expected e.g. B=2.5).
+regrip-none = No regrip helps (cost { $cost }).
+regrip-hint = With regrips: { $alg } (cost { $from } → { $to })
@@ -0,0 +1,810 @@
+# Français
+
+app-title = Cube 2×2 — 3D isométrique
+right-window-title = Cube 2×2 — Vue droite
+order-window-title = Cube 2×2 — Ordre de l'algorithme
+language = Langue
+
+# canevas / rendu
+size = Taille
+light = Lumière
+light-az = Az
+light-el = Él
+light-shade = Ombrage
+left-render = Rendu gauche
+right-render = Rendu droit
+grid = Grille
+axes = Axes
+heatmap = Carte de chaleur
+heatmap-desc = Teinter chaque coin selon le nombre de mouvements qui le séparent, seul, de sa place et de son orientation : rien s'il est en place, puis jaune, orange, rouge
+letters = Lettres
+letters-desc = Afficher sur chaque emplacement d'autocollant sa lettre (Speffz sauf modification : A–D sur U, E–H L, I–L F, M–P R, Q–T B, U–X D) ; les annonces de l'état citent aussi les lettres
+display-scale = Échelle d'affichage
+mode-filled = Plein
+mode-wireframe = Fil de fer
+
+# caméras
+camera-model = Caméra
+camera-model-desc = Euler : seuls les curseurs Rz/Rx/Ry règlent chaque vue. Boule de commande : faire glisser une vue la fait rouler comme une boule sous le curseur, autour de n'importe quel axe ; les curseurs suivent avec les angles les plus proches
+camera-euler = Curseurs d'Euler
+camera-trackball = Boule de commande
+left-view = Vue gauche
+right-view = Vue droite
+preset-isometric = Isométrique
+preset-dimetric = Dimétrique
+preset-front = Face
+preset-top = Dessus
+preset-left = Préréglage gauche U/F/R
+preset-right = Préréglage droit D/L/B
+reset-cameras = Réinitialiser les caméras
+snap-90 = Aimanter à 90°
+preset-left-mirrored = Préréglage gauche U/F/L
+preset-right-mirrored = Préréglage droit D/R/B
+mirrored-layout = Disposition en miroir
+reduced-motion = Animations réduites
+fps-cap = Images par seconde
+fps-uncapped = Sans limite
+fps-cap-value = { $fps } i/s
+renderer = Rendu
+backend-canvas = Canevas
+backend-gpu = GPU
+right-link = Lien droite
+link-off = Aucun
+link-opposite = Opposé
+link-mirror = Miroir (retournement Y)
+link-follow = Suivre
+link-offset = Décalage fixe
+dock-right = Ancrer la vue droite
+pop-out-right = Détacher la vue droite
+angle-placeholder = deg
+view-info = Gauche (Rz,Rx,Ry)=({ $l })   Droite (Rz,Rx,Ry)=({ $r })   Taille={ $size }px
+
+# mouvements / graine / algorithme
+moves = Mouvements
+seed = Graine
+seed-placeholder = Saisir une graine (ex. 12345)
+apply = Appliquer
+scramble = Mélanger
+reset = Effacer
+clear = Vider
+seed-invalid = pas un nombre entier
+alg-editor-desc = Algorithme, ex. R U R' U' ; peut tenir sur plusieurs lignes, // commence un commentaire jusqu'à la fin de la ligne
+alg-move-count = { $n } coups
+alg-invalid-count = { $n } coups, { $bad } non reconnus
+alg-complete = Développer :
+alg-order-button = Ordre
+alg-order-desc = Combien de répétitions de cet algorithme ramènent au départ, et comment il permute les coins
+alg-empty = (algorithme vide)
+alg-order = Cet algorithme est d'ordre { $n } (ppcm des ordres des cycles { $parts }).
+alg-order-identity = Cet algorithme est d'ordre 1 : chaque coin revient à sa place, sans torsion.
+alg-cycles = Cycles des coins : { $list }
+alg-cycles-legend = + / - indiquent une torsion nette horaire / antihoraire ; un cycle tordu doit être parcouru trois fois pour se détordre.
+custom-scramble = Mélange personnalisé
+custom-scramble-placeholder = Coller un mélange (ex. R U2 F' R2 U)
+custom-scramble-desc = Revenir à l'état résolu et appliquer ce mélange
+custom-applied = Mélange personnalisé appliqué ({ $n } coups).
+kpuzzle = cubing.js
+kpuzzle-placeholder = Collez le JSON d'un motif KPuzzle, ou le chemin d'un fichier .json
+kpuzzle-desc = Charger un motif KPuzzle de cubing.js (orbite CORNERS) et un « alg » facultatif
+import = Importer
+kpuzzle-imported = Motif cubing.js importé.
+kpuzzle-bad-json = JSON invalide.
+kpuzzle-empty = Aucun motif CORNERS ni « alg » trouvé dans le JSON.
+kpuzzle-bad-corners = CORNERS exige 8 pièces distinctes (0–7) et des orientations 0–2.
+kpuzzle-twisted = La somme des torsions des coins est fausse ; ce motif est inaccessible en tournant.
+verify = Vérifier
+verify-placeholder = Autocollants de votre cube : faces U D F B L R, 4 lettres chacune (ex. WWWW YYYY …)
+verify-desc = Comparer les couleurs de votre cube physique avec l'état affiché
+verify-ok = Les 24 autocollants correspondent.
+verify-mismatch = { $n } autocollant(s) différent(s) :
+verify-sticker = Face { $face }, ligne { $row }, colonne { $col } : attendu { $expected }, saisi { $found }
+verify-bad-length = 24 lettres attendues, { $n } reçues.
+verify-bad-letter = Lettre de couleur inconnue « { $letter } » (utiliser W Y G B O R).
+min-depth = Profondeur min.
+min-depth-desc = Les nouveaux mélanges aléatoires doivent être à au moins ce nombre de coups de la solution
+scramble-depth = à { $n } coups de la solution
+reset-cube = Réinitialiser le cube
+confirm-reset = Confirmer
+cancel = Annuler
+restore = Restaurer
+discard = Ignorer
+restore-session-prompt = Restaurer la session interrompue ?
+
+# messages d'état
+nothing-to-apply = Rien à appliquer.
+alg-error = Erreur d'algorithme : { $error }
+alg-applied = Algorithme appliqué.
+alg-watch-file = Fichier suivi :
+alg-watch-placeholder = chemin/vers/algorithme.alg
+alg-watch = Suivre
+alg-watch-stop = Arrêter le suivi
+alg-watch-applied = { $path } appliqué tel qu'enregistré.
+alg-watch-stopped = Suivi du fichier d'algorithme arrêté.
+seed-applied = Graine appliquée = { $seed } : { $seq }
+no-valid-seed = Aucune graine valide — rien n'a été appliqué.
+cube-reset = Cube réinitialisé.
+cube-reset-kept = Cube réinitialisé (état précédent conservé — Restaurer pour annuler).
+reset-confirm = La réinitialisation efface { $n } mouvement(s). Confirmer ?
+reset-cancelled = Réinitialisation annulée.
+trash-restored = État d'avant la dernière réinitialisation restauré.
+checkpoints = Points de reprise
+checkpoint-placeholder = nom, ex. après la première face
+checkpoint-add = Point de reprise
+checkpoint-add-desc = Mémoriser le cube et ses coups jusqu'ici sous ce nom, pour y revenir plus tard
+checkpoint-default = après le coup { $n }
+checkpoint-added = Point de reprise « { $name } » enregistré.
+checkpoint-jump-desc = Revenir ici ({ $n } coups) : { $moves }
+checkpoint-restored = Retour au point de reprise « { $name } » ({ $n } coups).
+history = Historique
+history-undo = Annuler
+history-undo-desc = Revenir d'un coup (Ctrl+Z) ; un autre coup à partir de là crée une nouvelle branche
+history-redo = Rétablir
+history-redo-desc = Avancer le long de la dernière branche visitée (Ctrl+Y)
+history-summary = { $states } états, { $branches } branches
+history-graph-desc = Toutes les suites de coups essayées ; cliquer sur un point pour aller à cet état
+history-at = Au coup { $n }.
+script = Script
+script-comment-placeholder = Commentaire sur ce coup (avant le premier coup : sur le mélange)
+script-comment-desc = Enregistré avec le coup et écrit dans les scripts de résolution exportés
+script-path-placeholder = Fichier de script (par défaut { $path })
+script-export = Exporter
+script-export-desc = Écrire le mélange et les coups joués, avec leurs temps et commentaires, en script de résolution Markdown
+script-import = Rejouer
+script-import-desc = Charger un script de résolution et le rejouer à son rythme enregistré, en affichant chaque commentaire au moment de son coup
+script-stop = Arrêter
+script-progress = Coup { $n } sur { $total }
+script-exported = { $n } coups exportés dans { $path }.
+script-bad-line = La ligne { $line } n'est pas un coup : { $text }
+script-no-scramble = Aucun mélange trouvé (une ligne `code` est attendue sous « ## Scramble »).
+script-replaying = Lecture d'un script de résolution de { $n } coups.
+script-replay-stopped = Lecture arrêtée.
+script-replay-done = Lecture terminée après { $n } coups.
+breakdown = Décomposer
+breakdown-desc = Découper la résolution chronométrée de la ligne courante en étapes (face, OLL, PBL) avec pour chacune son temps, sa pause de reconnaissance et ses coups par seconde
+breakdown-close = Fermer
+breakdown-untimed = Rien à décomposer : il faut des coups avec leurs temps enregistrés.
+breakdown-total = { $moves } coups en { $time }, { $tps } TPS
+breakdown-step = Étape
+breakdown-moves = Coups
+breakdown-time = Temps
+breakdown-recognition = Reconnaissance
+breakdown-tps = TPS
+breakdown-exec-tps = TPS d'exéc.
+breakdown-skip = { $step } (sautée)
+breakdown-unfinished = { $step } (inachevée)
+breakdown-no-pauses = Aucune pause de { $min } ou plus.
+breakdown-pauses = { $n } pause(s) de { $min } ou plus, { $total } en tout ; la plus longue, { $longest }, avant le coup { $at }.
+phase-face = Face
+phase-oll = OLL
+phase-pbl = PBL
+pauses-find = Trouver les pauses
+pauses-find-desc = Chercher dans les résolutions d'entraînement de la session les écarts entre coups au moins aussi longs, et l'état du cube à chacun
+pauses-close = Fermer
+pause-threshold = Pauses à partir de
+pause-threshold-desc = Plus court écart entre deux coups compté comme une pause
+pause-threshold-value = { $secs } s
+pauses-found = { $n } pause(s) trouvée(s) dans { $solves } résolution(s).
+pauses-none = Aucune pause aussi longue dans les résolutions chronométrées de la session.
+pause-line = { $n }. Résolution { $solve }, avant le coup { $before } : { $time } — { $case }
+pauses-by-case = Par cas, du plus coûteux au moins coûteux :
+pause-case-line = { $case } : { $n } pause(s), { $total } en tout, { $mean } en moyenne, la plus longue { $longest }
+case-named = { $step } : { $name }
+case-other = { $step } : autre cas
+case-pbl = { $step } : { $first } / { $second }
+case-solved = Résolu
+menu-file = Fichier
+menu-edit = Édition
+menu-view = Affichage
+menu-help = Aide
+menu-open-session = Ouvrir une session (rejouer la trace)
+menu-save-session = Enregistrer la trace de session
+menu-export-image = Exporter une image (PNG)
+menu-export-script = Exporter le script de résolution
+menu-import-script = Importer un script de résolution
+menu-back-up = Sauvegarder le profil
+menu-quit = Quitter
+menu-undo = Annuler
+menu-redo = Rétablir
+menu-reset-cube = Réinitialiser le cube
+menu-palette = Palette de commandes…
+menu-panels = Panneaux
+menu-qr = Code QR
+menu-profiler = Profileur de rendu
+menu-reset-cameras = Réinitialiser les caméras
+menu-diagnostics = Diagnostic
+debug-title = Diagnostic
+debug-version = Version
+debug-features = Fonctions compilées
+debug-platform = Plateforme
+debug-backend = Rendu
+debug-tables = Tables du solveur
+debug-profile = Profil
+debug-data-dir = Dossier de données
+debug-read-only = Lecture seule
+debug-language = Langue
+debug-none = aucune
+debug-tables-loading = Chargement ou génération en cours.
+debug-no-cache = Aucun fichier de cache à { $path }.
+debug-copy = Copier le paquet de débogage
+debug-copy-desc = Copier ces détails, vos fichiers de réglages et la trace de cette session, à coller dans un rapport de bogue
+debug-copied = Paquet de débogage copié dans le presse-papiers.
+menu-about = À propos
+about-title = Rubics { $version }
+about-body = Un cube 2×2 : tournez-le, mélangez-le, résolvez-le et entraînez-vous.
+about-license = Sous licence SSCPL-NC v1.0.
+about-close = Fermer
+timer-start = Démarrer le chrono
+timer-stop = Arrêter le chrono
+timer-desc = Chronométrer une résolution sur un vrai cube
+timer-started = Chrono en marche.
+timer-stopped = Temps : { $time }
+timer-mean = Moyenne { $time } sur { $n }
+timer-start-solve = Lancer la résolution
+timer-inspecting = Inspection : { $secs } s.
+timer-started-plus-two = Chrono en marche, +2 pour un départ tardif.
+inspection-wca = Inspection WCA
+inspection-relaxed = Inspection souple
+inspection-off = Sans inspection
+inspection-secs = { $secs } s
+inspection-mode-desc = WCA : +2 si le départ suit la fin de l'inspection, DNF deux secondes après. Souple : annonces seulement
+inspection-secs-desc = Durée de l'inspection ; les annonces et pénalités suivent
+result-dnf = DNF
+result-plus-two = { $time } (+2)
+hotkey = Raccourci global
+hotkey-desc = Démarrer et arrêter le chrono avec cette touche même quand une autre fenêtre a le focus
+hotkey-save-failed = Impossible d'enregistrer les réglages du raccourci : { $error }
+hotkey-failed = Impossible de réserver { $key } comme raccourci global : { $error }
+hotkey-no-x11 = le raccourci global a besoin d'une session X11 (Wayland seul ne peut pas réserver de touches)
+hotkey-unavailable = cette version ne gère pas le raccourci global
+ab-title = A/B :
+ab-case = Cas
+ab-alg-placeholder = Algorithme { $side }
+ab-trials-value = { $n } chacun
+ab-trials-desc = Combien de fois exécuter chaque algorithme
+ab-start = Comparer
+ab-start-desc = Alterner entre les deux algorithmes sur le cas, en chronométrant chaque exécution, pour voir lequel est le plus rapide pour vous
+ab-no-case = Choisissez un cas sur lequel comparer les algorithmes.
+ab-alg-empty = L'algorithme { $side } est vide.
+ab-alg-wrong-case = L'algorithme { $side } ne résout pas { $case }.
+ab-trial = Essai { $n } sur { $total } : résolvez avec { $side } : { $alg }
+ab-running = { $case }, essai { $n } sur { $total } : { $side } : { $alg }
+ab-redo = Refaire l'essai
+ab-redo-desc = Remettre le cas en place et recommencer cet essai, sans chronomètre
+ab-stop = Arrêter
+ab-close = Fermer
+ab-timed = { $side } : { $time }
+ab-finished = Tous les essais sont faits.
+ab-mean = { $side } : { $time } en moyenne sur { $n }
+ab-verdict-waiting = Il faut deux temps de chaque algorithme pour les comparer.
+ab-verdict-faster = { $side } est plus rapide pour vous, de { $gap } (p = { $p }).
+ab-verdict-unclear = Pas de différence nette pour l'instant (p = { $p }) ; plus d'essais le diraient.
+usage-show = Usage des mouvements
+usage-hide = Masquer l'usage des mouvements
+usage-show-desc = Une carte de chaleur de la fréquence à laquelle vous tournez chaque face dans chaque sens, sur les résolutions de ce profil
+usage-empty = Aucune résolution comptée pour l'instant ; les coups sont comptés quand vous résolvez le cube.
+usage-summary = { $turns } coup(s) sur { $solves } résolution(s) ; face la plus tournée : { $face }, { $share } % des coups
+usage-reset-button = Réinitialiser
+usage-reset-desc = Oublier les coups comptés jusqu'ici
+usage-reset = Usage des mouvements réinitialisé.
+usage-slow-face = Les coups { $face } font { $share } % de vos coups ; ils sont lents à exécuter, cherchez des algorithmes qui les évitent.
+usage-save-failed = Impossible d'enregistrer l'usage des mouvements : { $error }
+layer-solved = résolue
+layer-adjacent = échange adjacent
+layer-diagonal = échange diagonal
+video-sync = Synchroniser avec une vidéo
+video-sync-desc = Charger un script de résolution et l'avancer jusqu'à un instant tapé ou glissé, pour suivre une vidéo de la résolution
+video-sync-offset = Début de la résolution
+video-sync-offset-desc = Instant de la vidéo où commence la résolution (m:ss.cc ou secondes) ; les temps du script partent de là
+video-sync-time = Temps de la vidéo
+video-sync-time-desc = Tapez un instant de la vidéo (m:ss.cc ou secondes) puis Entrée pour afficher le cube à ce moment
+video-sync-close = Fermer
+video-sync-loaded = Script de résolution de { $n } mouvements chargé, se terminant à { $end } ; tapez ou glissez un instant de la vidéo.
+video-sync-at = { $time } : mouvement { $n } sur { $total }.
+video-sync-at-comment = { $time } : mouvement { $n } sur { $total } — « { $comment } »
+video-sync-bad-time = « { $time } » n'est pas un temps (utilisez m:ss.cc ou des secondes).
+trace = Trace de session
+trace-recorded = { $n } actions enregistrées
+trace-path-placeholder = Fichier de trace (par défaut { $path })
+trace-save = Enregistrer
+trace-save-desc = Écrire l'état de départ et chaque action depuis, avec leurs instants, pour pouvoir rejouer la session (par ex. jointe à un rapport de bogue)
+trace-replay = Rejouer
+trace-replay-desc = Charger une trace de session, revenir à son état de départ et rejouer ses actions à leur rythme enregistré (longues pauses raccourcies)
+trace-progress = Action { $n } sur { $total }
+trace-saved = { $n } actions enregistrées dans { $path }.
+trace-bad-line = La ligne { $line } de la trace de session est illisible.
+trace-replaying = Relecture d'une trace de session de { $n } actions.
+trace-replay-stopped = Relecture de la trace arrêtée.
+trace-replay-done = Relecture de la trace terminée après { $n } actions.
+console = Console
+console-run = Exécuter
+console-run-desc = Exécuter le script sur le cube actuel (Ctrl+Entrée) ; un cube qu'il modifie remplace celui affiché
+console-clear-desc = Effacer la sortie de la console
+console-editor-desc = Script Rhai : cube.apply("R U"), cube.state(), cube.reset(), cube.solved(), scramble(graine), solve(), depth(), invert(alg), order(alg), random(), print(…)
+console-running = Exécution du script…
+console-done = Script terminé.
+console-error = Ligne { $line } : { $detail }
+palette-placeholder = Commande (Entrée pour exécuter, Échap pour fermer), ex. scramble 20, camera 30 45 0, R U R'
+palette-help = Commandes : { $commands }
+palette-usage = Utilisation : { $usage }
+palette-unknown = Commande inconnue « { $command } » (tapez help pour la liste).
+png-exported = Capture d'écran enregistrée dans { $path } ; la déposer sur la fenêtre ramène à cette scène.
+bookmark-opened = Scène enregistrée dans { $path } rétablie.
+bookmark-none = { $path } ne contient aucune scène enregistrée.
+drop-unknown = Impossible d'ouvrir { $path } : déposer un PNG ou SVG exporté, un .json KPuzzle, un fichier .alg, ou un .txt de lettres d'autocollants ou de trace de session.
+drop-alg-loaded = Algorithme chargé depuis { $path }.
+drop-facelets-loaded = Cube réglé sur les autocollants de { $path }.
+invalid-angle = Angle invalide : « { $input } » (degrés attendus 0–360)
+did-move = Mouvement { $token }
+unknown-move = Mouvement inconnu : { $token }
+autosave-failed = Échec de la sauvegarde automatique : { $error }
+io-read-failed = Impossible de lire { $path } : { $error }
+io-write-failed = Impossible d'écrire { $path } : { $error }
+session-interrupted = La session précédente s'est terminée de façon inattendue.
+session-restored = Session interrompue restaurée.
+link-opened = Lien partagé ouvert.
+link-invalid = Ni un lien de partage ni un fichier que l'application sait ouvrir : { $link }
+link-copied = Lien de partage copié : { $link }
+
+# solveur
+solver-loaded = Tables du solveur chargées depuis le cache.
+solver-built = Tables du solveur générées en { $ms } ms et mises en cache.
+solver-built-unsaved = Tables du solveur générées en { $ms } ms (écriture du cache impossible).
+solver-rebuilding = Régénération des tables du solveur…
+rebuild-tables = Régénérer les tables du solveur
+rebuild-tables-desc = Supprimer les tables du solveur en cache et les générer à nouveau
+analyze-faces = Analyser les premières faces
+analyze-faces-desc = Trouver les façons les plus courtes de construire une face de chaque couleur depuis cette position
+face-report = { $color } : { $n } coups ({ $ways } façons) — { $algs }
+face-report-built = { $color } : déjà construite
+face-report-deep = { $color } : plus de { $n } coups
+explore-subgroups = Explorer les sous-groupes
+explore-subgroups-desc = Quels sous-groupes classiques contiennent cette position, et combien de répétitions des coups qui y ont mené ramènent à l'état résolu
+subgroup-list = Sous-groupes : { $list }
+subgroup-none = Aucun sous-groupe classique plus petit : cette position demande le groupe du cube entier
+subgroup-solved = résolu
+subgroup-one-face = ⟨{ $face }⟩, à un coup de l'état résolu (ou ⟨{ $opposite }⟩)
+subgroup-half-turns = ⟨U2, D2, F2, B2, L2, R2⟩, demi-tours seulement
+subgroup-two-gen = ⟨{ $a }, { $b }⟩
+subgroup-oriented = orientation résolue, permutation restante
+subgroup-permuted = permutation résolue, orientation restante
+subgroup-illegal = Ce n'est pas un état légal du cube : aucun sous-groupe à indiquer.
+element-order = Ordre { $order } : répéter { $order } fois les coups qui ont mené à cette position ramène à l'état résolu.
+element-order-rotated = Ordre { $order } : { $reps } répétitions des coups qui ont mené à cette position ramènent à l'état résolu (à une rotation du cube près), { $order } la restaurent exactement.
+
+# diagnostic de l'état
+diagnostics = Diagnostic
+diagnostics-desc = Vérifier les autocollants affichés : chaque emplacement contient-il un vrai coin, une seule fois, et l'état est-il résoluble ?
+diag-slot = { $slot } : { $problem }
+diag-mirrored = les couleurs du coin { $piece } en ordre miroir (deux autocollants échangés)
+diag-unknown = { $colors } ne correspond à aucun coin
+diag-duplicated = Présents plusieurs fois : { $pieces }
+diag-missing = Manquants : { $pieces }
+diag-parity-even = Permutation des coins : paire
+diag-parity-odd = Permutation des coins : impaire (sans problème sur un 2×2, un quart de tour est impair)
+diag-parity-unknown = Permutation des coins : inconnue tant que tous les coins ne sont pas valides
+diag-twist-ok = Orientation totale : 0
+diag-twist-ccw = Orientation totale : +1, comme si un coin était tourné sur place dans le sens antihoraire
+diag-twist-cw = Orientation totale : +2, comme si un coin était tourné sur place dans le sens horaire
+diag-solvable = Résoluble.
+diag-unsolvable = Non résoluble.
+diag-fix-twist = Corriger l'orientation en
+diag-fix-twist-desc = Tourner le coin { $slot } sur place pour ramener l'orientation totale à 0
+diag-twist-fixed = Coin { $slot } tourné sur place : l'état est de nouveau résoluble.
+
+# comparaison d'états
+diff = Comparer
+diff-save = Enregistrer en { $slot }
+diff-saved = Cube actuel enregistré comme état { $slot }.
+diff-slot-full = Enregistré ; cliquer pour le remplacer par le cube actuel
+diff-slot-empty = Aucun état { $slot } enregistré pour l'instant.
+diff-current-a = Actuel → A
+diff-current-b = Actuel → B
+diff-a-b = A → B
+diff-current-pattern = Actuel → motif
+diff-pattern-placeholder = 24 lettres d'autocollants, ou des coups depuis l'état résolu
+diff-pattern-desc = L'état à comparer : autocollants (faces U D F B L R, ex. WWWW YYYY …) ou un algorithme appliqué au cube résolu
+diff-bad-pattern = Ni 24 lettres d'autocollants ni un algorithme : { $error }
+diff-compare = Comparer
+diff-compare-desc = Lister les autocollants et les coins qui diffèrent, entourer les autocollants sur le cube, et trouver l'algorithme le plus court du premier état au second
+diff-clear = Effacer
+diff-identical = Les deux états sont identiques.
+diff-stickers = { $n } autocollant(s) diffèrent (entourés sur le cube).
+diff-slots = { $n } emplacement(s) de coin contiennent une autre pièce ou orientation : { $slots }
+diff-slots-none = Chaque emplacement de coin contient la même pièce, orientée pareil.
+diff-illegal = L'un des états n'est pas un cube légal : seuls les autocollants sont comparés.
+diff-alg = Algorithme le plus court ({ $n } coups) : { $alg }
+
+# entraîneur de neutralité des couleurs
+drill-start = Exercice de neutralité
+drill-start-desc = Mélanger et tirer au sort la couleur à construire en premier ; le chrono démarre au premier coup
+drill-stop = Arrêter l'exercice
+drill-target = Construire { $color } d'abord
+drill-started = Construire { $color } d'abord — le chrono démarre au premier coup.
+drill-solved = Résolu depuis { $color } en { $time }.
+drill-wrong-color = Résolu, mais { $built } a été construit en premier (cible : { $target }) ; non compté.
+drill-dnf = DNF : l'inspection a dépassé avant le premier mouvement (cible : { $color }) ; non compté.
+drill-stats-empty = Aucune résolution pour l'instant.
+drill-stat = { $color } : { $time } moy. ({ $n })
+drill-slowest = la plus lente
+drill-save-failed = Impossible d'enregistrer les statistiques : { $error }
+report-export = Exporter le rapport
+report-export-desc = Enregistrer les résolutions d'entraînement de cette session en rapport HTML : statistiques, graphique des temps, meilleure résolution et tous les mélanges
+report-exported = Rapport de { $n } résolutions enregistré dans { $path }.
+sheet-export = Feuille de résolution
+sheet-export-desc = Enregistrer une page imprimable de nouveaux mélanges, chacun avec son état en patron et une case pour le temps, en HTML dans le dossier de données
+sheet-solutions = Solutions
+sheet-solutions-desc = Imprimer les solutions à l'envers au pied des feuilles de résolution
+sheet-exported = Feuille de { $n } mélanges enregistrée dans { $path }.
+sheet-exported-unsolved = Feuille enregistrée dans { $path }, sans solutions : le solveur n'est pas encore prêt.
+sheet-title = Feuille de résolution
+sheet-name = Nom
+sheet-state = État mélangé
+alg-set-all = Tous les algorithmes
+alg-set-oll = Cas d'orientation
+alg-set-perm = Permutations
+alg-set-trigger = Déclencheurs
+anki-set-desc = Algorithmes couverts par le paquet Anki
+anki-export = Paquet Anki
+anki-export-desc = Enregistrer les algorithmes en cartes Anki, le cas au recto et les mouvements au verso, dans un fichier texte pour Fichier ▸ Importer dans le dossier de données
+anki-deck = Rubics::{ $set }
+anki-exported = Paquet Anki de { $n } cartes enregistré dans { $path }.
+thumbs-usage = Usage : rubics thumbs <dossier> [fichier], avec un algorithme par ligne dans le fichier ou sur l'entrée standard.
+thumbs-bad-line = Ligne { $line } : { $error }
+thumbs-empty = Aucun algorithme à dessiner.
+thumbs-written = { $n } vignettes de cas et cases.tsv écrits dans { $dir }.
+serve-usage = Usage : rubics serve [port], le port étant un nombre (8642 par défaut).
+serve-bind-failed = Impossible d'écouter sur le port { $port } : { $error }
+serve-listening = Diagrammes du cube servis à { $url } — Ctrl+C pour arrêter.
+serve-bad-request = Requête mal formée.
+serve-too-long = Ligne de requête ou en-têtes trop longs.
+serve-get-only = Seules les requêtes GET sont traitées.
+serve-not-found = Rien à { $path } ; essayez /state.svg?alg=R+U+R'
+serve-no-png = Les diagrammes PNG ne sont pas disponibles ; utilisez /state.svg.
+serve-bad-view = Vue « { $view } » inconnue ; utilisez net, blueprint ou ll.
+register-usage = Usage : rubics register, sans autre argument.
+register-unsupported = L'association de types de fichiers n'est prise en charge que sur les bureaux Linux.
+register-no-home = Ni XDG_DATA_HOME ni HOME n'est défini ; impossible d'enregistrer l'application.
+register-done = { $exe } ouvre désormais les fichiers .alg et les traces de session ; entrée créée dans { $entry }.
+profile = Profil
+profile-desc = Profil dont les réglages, temps, schéma de lettres et raccourcis sont utilisés ; chaque profil garde les siens
+profile-default = Par défaut
+profile-new-hint = Nouveau profil
+profile-add = Ajouter
+profile-add-desc = Créer un profil de ce nom et y passer
+profile-switched = Profil { $name } activé.
+profile-bad-name = Un nom de profil compte au plus { $max } lettres, chiffres, espaces, tirets et soulignés.
+profile-save-failed = Impossible d'enregistrer le choix du profil : { $error }
+backup-export = Sauvegarder
+backup-export-desc = Enregistrer tous les réglages, temps et schémas de ce profil dans une archive ZIP, backup.zip dans le dossier de données
+backup-exported = { $n } fichiers sauvegardés dans { $path }.
+backup-restore = Restaurer
+backup-restore-desc = Remettre les réglages, temps et schémas enregistrés dans backup.zip, à la place de ceux de ce profil
+backup-restore-confirm = Restaurer { $path } remplace les fichiers du profil { $name }. Confirmer ?
+backup-confirm-restore = Confirmer la restauration
+backup-restore-cancelled = Restauration annulée.
+backup-restored = { $n } fichiers restaurés depuis { $path }.
+backup-bad-archive = Ce n'est pas une archive de sauvegarde, ou elle est abîmée.
+demo-started = Mode démo : rien n'est enregistré, et le cube se réinitialise après { $secs } s sans action.
+demo-reset = Prêt pour le prochain visiteur.
+attract-hint = Appuyez sur une touche ou cliquez pour jouer.
+demo-read-only = Le mode démo n'enregistre rien : impossible de restaurer une sauvegarde.
+demo-no-export = Le mode démo n'enregistre rien : aucun fichier ne peut être exporté.
+daily-start = Défi du jour
+daily-start-desc = Mélanger le cube avec le défi du jour, le même pour tout le monde ; la première résolution du jour est enregistrée
+daily-stop = Abandonner
+daily-started = Défi du { $day } : le chrono démarre au premier mouvement.
+daily-solved = Défi du jour résolu en { $time }. Série : { $streak } jours.
+daily-solved-again = Résolu en { $time } ; le temps du jour était déjà enregistré.
+daily-save-failed = Impossible d'enregistrer les temps du défi du jour : { $error }
+daily-streak = Série : { $n } jours
+daily-today = Aujourd'hui : { $time }
+daily-best = Meilleur : { $time }
+daily-day-solved = { $day } : { $time }
+leaderboard-opt-in = Partager les temps du jour
+leaderboard-opt-in-desc = Envoyer vos temps du défi du jour, sous le nom ci-dessous, au serveur de classement ; rien n'est envoyé sans cela, et le désactiver abandonne les temps pas encore envoyés
+leaderboard-endpoint-hint = http://serveur/classement
+leaderboard-endpoint-desc = Adresse du serveur de classement (http simple uniquement)
+leaderboard-name-hint = Nom
+leaderboard-name-desc = Nom affiché à côté de vos temps dans le classement ; laissé vide, vous apparaissez comme « anonymous »
+leaderboard-refresh = Actualiser le classement
+leaderboard-queued = { $n } temps en attente d'envoi
+leaderboard-title = Classement du { $day }
+leaderboard-empty = Aucun temps aujourd'hui.
+leaderboard-entry = { $rank }. { $name } — { $time }
+leaderboard-failed = Classement : { $error }
+leaderboard-save-failed = Impossible d'enregistrer les réglages du classement : { $error }
+leaderboard-status = le serveur a répondu { $code }
+leaderboard-bad-url = « { $url } » n'est pas une adresse http://
+leaderboard-unreachable = serveur injoignable ({ $error })
+leaderboard-too-large = le serveur a envoyé plus de { $limit } Kio
+leaderboard-unavailable = cette version n'a pas de client de classement
+obs-enabled = Déclencher OBS
+obs-enabled-desc = À la fin d'une résolution chronométrée, demander à OBS Studio (via son serveur WebSocket) d'enregistrer le tampon de relecture ou de changer de scène
+obs-host-hint = localhost
+obs-host-desc = Ordinateur où tourne OBS, et port de son serveur WebSocket (Outils → Paramètres du serveur WebSocket)
+obs-port-hint = 4455
+obs-password-hint = Mot de passe
+obs-password-desc = Le mot de passe du serveur WebSocket, s'il en a un ; enregistré tel quel avec vos réglages
+obs-save-replay = Enregistrer le tampon de relecture
+obs-switch-scene = Passer à la scène
+obs-scene-hint = Nom de la scène
+obs-test = Tester
+obs-test-desc = Envoyer l'action à OBS maintenant
+obs-testing = Envoi à OBS…
+obs-test-ok = OBS a exécuté l'action.
+obs-failed = OBS : { $error }
+obs-save-failed = Impossible d'enregistrer les réglages OBS : { $error }
+obs-unreachable = impossible de joindre OBS ({ $error })
+obs-bad-reply = OBS a répondu autre chose qu'obs-websocket 5
+obs-refused = OBS a refusé la requête (code { $code })
+obs-unavailable = cette version n'a pas de client OBS
+round-new = Nouvelle manche
+round-new-desc = Afficher côte à côte une manche de nouveaux mélanges ; une feuille de résolution imprime ceux-ci tant qu'ils sont affichés
+round-close = Fermer la manche
+round-scramble = { $n }. { $moves }
+classroom = Classe
+classroom-desc = N'afficher qu'un grand mélange et son cube, pour projeter dans une salle ; Espace ou → pour le suivant, Échap pour quitter
+classroom-on-key = Suivant sur touche
+classroom-every = Suivant toutes les { $secs } s
+classroom-next = Suivant
+classroom-leave = Quitter
+classroom-number = Mélange { $n }
+classroom-countdown = Suivant dans { $secs } s
+classroom-hint = Espace ou → pour le mélange suivant, Échap pour quitter
+blueprint-export = Plan
+blueprint-export-desc = Enregistrer le cube vu de face depuis le dessus, l'avant et la droite, en schéma SVG dans le dossier de données
+blueprint-exported = Plan enregistré dans { $path }.
+blueprint-top = Dessus
+blueprint-front = Avant
+blueprint-right = Droite
+report-title = Rapport de session
+report-summary = Résumé
+report-solves = Résolutions
+report-best = Meilleur
+report-worst = Pire
+report-mean = Moyenne
+report-average = Moyenne actuelle sur { $n }
+report-best-average = Meilleure moyenne sur { $n }
+report-chart = Temps
+report-best-solve = Meilleure résolution
+report-best-line = { $color } en premier, { $time } : { $n } mouvements, { $tps } mouvements par seconde.
+report-scramble = Mélange
+report-solution = Solution
+report-scrambles = Mélanges
+report-color = Couleur
+report-time = Temps
+report-empty = Aucune résolution d'entraînement dans cette session.
+annotate = Dessiner sur le cube
+annotate-desc = Mode entraîneur : glissez sur la vue du cube pour dessiner à main levée, des flèches ou des cercles
+annotate-pen = Main levée
+annotate-arrow = Flèche
+annotate-circle = Cercle
+annotate-red = Rouge
+annotate-yellow = Jaune
+annotate-blue = Bleu
+annotate-keep = Garder par étape
+annotate-keep-desc = Garder le dessin de chaque état et le réafficher en y revenant, au lieu de l'effacer au mouvement suivant
+annotate-undo = Annuler le tracé
+annotate-clear = Effacer les tracés
+annotate-snapshot = Enregistrer une capture
+annotate-snapshot-desc = Enregistrer la fenêtre, dessin compris, en PNG dans le dossier de données
+
+# accessibilité : description de l'état et libellés des contrôles
+announce = Annoncer l'état
+announce-desc = Décrire les autocollants de chaque face dans la ligne d'état
+share-link = Copier le lien de partage
+qr-code = Code QR
+qr-code-desc = Afficher ou masquer un code QR des autocollants et de l'algorithme, à scanner avec un téléphone
+qr-too-long = Trop long pour un code QR ; raccourcissez l'algorithme.
+share-link-desc = Copier un lien vers cet état du cube, la caméra et l'algorithme (l'ouvrir en le passant à l'application)
+alg-cubing = Ouvrir dans alg.cubing.net
+alg-cubing-desc = Rejouer le mélange et les mouvements faits depuis dans alg.cubing.net (ouvre le navigateur web)
+alg-cubing-opened = Ouvert : { $url }
+alg-cubing-failed = Impossible de lancer un navigateur ({ $error }) ; ouvrez ceci à la main : { $url }
+alg-cubing-unavailable = Les tables du solveur sont encore en chargement ; réessayez dans un instant.
+post-copy = Copier pour un forum
+post-copy-desc = Copier le mélange, les mouvements faits depuis comme solution, leur nombre, le dernier temps du chrono et un lien alg.cubing.net, prêts à coller sur un forum
+post-format-desc = BBCode pour speedsolving.com, Markdown pour Reddit et les messageries
+post-bbcode = BBCode
+post-markdown = Markdown
+post-scramble = Mélange
+post-solution = Solution
+post-moves = Mouvements
+post-moves-value = { $n } HTM
+post-time = Temps
+post-link = Voir sur alg.cubing.net
+post-copied = Résolution copiée pour un forum.
+post-empty = Aucun mouvement depuis le mélange à publier.
+describe-solved = Résolu.
+describe-face = Face { $face } : { $top } / { $bottom }
+color-white = blanc
+color-yellow = jaune
+color-green = vert
+color-blue = bleu
+color-orange = orange
+color-red = rouge
+face-up = du haut
+face-down = du bas
+face-front = avant
+face-back = arrière
+face-left = gauche
+face-right = droite
+turn-cw = dans le sens horaire
+turn-ccw = dans le sens antihoraire
+turn-180 = d'un demi-tour
+move-desc = Tourner la face { $face } { $turn }
+nudge-desc = Pivoter { $axis } de { $deg }°
+angle-input-desc = Angle { $axis } exact en degrés ; Entrée pour appliquer
+named-preset-desc = Placer cette vue à l'angle { $preset }
+look-at = Regarder
+look-at-desc = Tourner cette vue pour voir la face { $face } de face
+preset-left-desc = Placer la vue gauche à 0°, 0°, 0°
+preset-right-desc = Placer la vue droite à 90°, 180°, 0°
+preset-left-mirrored-desc = Placer la vue gauche à 270°, 0°, 0°
+preset-right-mirrored-desc = Placer la vue droite à 0°, 180°, 0°
+mirrored-layout-desc = Pour les gauchers et les leçons en miroir : échanger les deux vues et leurs commandes, aligner les panneaux à droite et refléter les préréglages de caméra
+renderer-desc = Le GPU dessine les cubes en triangles 3D avec une profondeur exacte, pour les vues pleines seulement et sans les surcouches, annotations ni gestes tactiles ; le canevas dessine tout le reste
+reduced-motion-desc = Arrêter la rotation et la lueur de la marche aléatoire et le flash du métronome, et redessiner moins souvent au repos
+reset-cameras-desc = Rétablir les deux caméras par défaut
+apply-seed-desc = Mélanger le cube à partir de la graine
+scramble-desc = Mélanger avec une nouvelle graine aléatoire
+seed-clear-desc = Vider le champ graine
+alg-clear-desc = Vider le champ algorithme
+alg-apply-desc = Tourner le cube selon l'algorithme (Ctrl+Entrée dans le champ)
+alg-watch-desc = Appliquer l'algorithme de ce fichier maintenant, puis à chaque enregistrement, depuis le cube tel qu'il est — pour l'éditer dans un autre éditeur
+reset-cube-desc = Remettre le cube à l'état résolu
+
+# effets sonores (compilés avec la fonctionnalité `sound`)
+sound = Son
+click-sound = Clic
+click-tick = Tic
+click-wood = Bloc de bois
+click-beep = Bip
+cue-move = Mouvements
+cue-countdown = Annonces d'inspection
+cue-solved = Carillon de résolution
+sound-unavailable = Aucune sortie audio ; les effets sonores sont désactivés.
+sound-save-failed = Impossible d'enregistrer les réglages du son : { $error }
+controllers = Boutons de contrôleur
+controllers-unavailable = Cette version n'écoute pas les contrôleurs (compilée sans la fonctionnalité « controllers ») ; les associations restent modifiables.
+controllers-unsupported = Les contrôleurs ne sont lus que sous Linux ; les associations restent modifiables, mais aucun appui n'arrive sur cette plateforme.
+binding-input-placeholder = note 60, cc 20, deck 3
+binding-input-desc = Le bouton : une note MIDI (note N), un contrôle MIDI (cc N) ou une touche de Stream Deck (deck N, à partir de 0 en haut à gauche)
+binding-learn = Apprendre
+binding-learn-cancel = Arrêter l'apprentissage
+binding-learn-desc = Appuyez sur un bouton du contrôleur pour remplir son nom
+binding-command-placeholder = Commande, ex. R U R', preset iso, drill
+binding-command-desc = Toute ligne de la palette de commandes : mouvements, caméra ou préréglage, mélange, entraînement, annuler…
+binding-add = Associer
+binding-remove = Retirer
+binding-learning = Appuyez sur un bouton du contrôleur…
+binding-learned = { $input } appris ; tapez maintenant sa commande.
+binding-unbound = { $input } n'est associé à aucune commande.
+binding-bad-input = « { $input } » n'est pas un bouton de contrôleur (utilisez note N, cc N ou deck N).
+binding-no-command = Tapez la commande à associer.
+binding-added = { $input } associé à « { $command } ».
+bindings-save-failed = Impossible d'enregistrer les associations de contrôleur : { $error }
+gamepad = Manette
+gamepad-prime = Prime
+gamepad-double = Double
+gamepad-speed = Orbite { $speed }°/s
+gamepad-speed-desc = Vitesse d'orbite des vues avec le stick poussé à fond (stick gauche : vue gauche, stick droit : vue droite)
+gamepad-invert = Inverser la verticale
+gamepad-save-failed = Impossible d'enregistrer les réglages de la manette : { $error }
+gamepad-unsupported = Les manettes ne sont pas prises en charge sur cette plateforme
+gamepad-failed = Impossible de lire les manettes : { $error }
+motion-save-failed = Impossible d'enregistrer les réglages d'animation : { $error }
+inspection-call = Inspection : { $seconds } s restantes
+
+# métronome / exercice de rythme
+metronome-start = Lancer le métronome
+metronome-stop = Arrêter le métronome
+metronome-desc = Faire clignoter le contour du cube à chaque temps pour rythmer vos mouvements
+metronome-bpm = { $bpm } BPM
+metronome-click = Clic
+cue-beat = Métronome
+beat-drill-start = Exercice de rythme
+beat-drill-stop = Terminer l'exercice
+beat-drill-desc = Noter la proximité de chaque mouvement avec le temps (lance le métronome)
+beat-drill-live = { $n } mouvements, { $accuracy } % dans le temps
+beat-drill-started = Exercice de rythme à { $bpm } BPM : tournez à chaque éclair.
+beat-drill-empty = Exercice de rythme terminé sans mouvement.
+beat-drill-result = Exercice de rythme : { $accuracy } % dans le temps sur { $n } mouvements (±{ $offset } ms en moyenne, biais { $bias } ms).
+
+# suivi d'une pièce
+track = Suivre
+track-placeholder = Choisir un coin…
+track-desc = Entourer les autocollants de ce coin où qu'il aille, à travers mélanges et solutions
+track-stop = Arrêter le suivi
+track-trail = Parcours
+track-trail-desc = Lister la position atteinte par le coin après chaque mouvement depuis la dernière remise à zéro ou le dernier mélange (+ / - = tourné dans le sens horaire / antihoraire)
+track-at = Actuellement en { $slot }
+track-trail-empty = Parcours : aucun mouvement depuis la dernière remise à zéro ou le dernier mélange.
+track-trail-list = Parcours : { $stops }
+
+# marche aléatoire
+random-walk-start = Marche aléatoire
+random-walk-stop = Arrêter la marche
+random-walk-desc = Enchaîner des mouvements aléatoires pendant que la vue tourne lentement, en affichage d'ambiance
+random-walk-rate = { $rate } mouvements/s
+random-walk-until-solved = Arrêter une fois résolu
+random-walk-until-solved-desc = Expérience : combien de mouvements aléatoires avant que le cube soit de nouveau résolu ? Environ { $states } en moyenne, un par état distinct.
+random-walk-moves = { $n } mouvements
+random-walk-stopped = Marche aléatoire arrêtée après { $n } mouvements.
+random-walk-solved = De nouveau résolu après { $n } mouvements aléatoires !
+
+# entraînement à la notation
+notation-start = Exercice de notation
+notation-stop = Arrêter l'exercice
+notation-desc = S'entraîner à la disposition clavier du cube virtuel : taper chaque mouvement avant la fin du temps imparti
+notation-quiz-keys = Taper le mouvement
+notation-quiz-read = Lire le mouvement
+notation-limit = { $secs } s par mouvement
+notation-started = Exercice de notation lancé : gardez la fenêtre du cube active et tapez avec les touches ci-dessous.
+notation-prompt-read = Quel mouvement joue le petit cube ?
+notation-correct = { $mv } correct ({ $ms } ms).
+notation-wrong = Vous avez tapé { $typed } ; { $mv } se tape { $keys }.
+notation-timeout = Trop lent : { $mv } se tape { $keys }.
+notation-stats = { $quiz } : { $accuracy } % sur { $n } ({ $avg } ms en moyenne, { $timed_out } trop lents)
+notation-stats-none = { $quiz } : aucune tentative
+notation-missed = Les plus ratés : { $moves }
+notation-keymap = Touches : { $keys } (un demi-tour = deux fois la même touche)
+notation-save-failed = Impossible d'enregistrer les statistiques de notation : { $error }
+
+# mémorisation à l'aveugle
+memo = Mémo BLD
+memo-show = Afficher le mémo
+memo-show-desc = Établir le mémo des coins pour le cube affiché (votre schéma de lettres, Old Pochmann depuis le tampon UBL)
+memo-drill = Chronométrer le mémo
+memo-drill-desc = Lancer le chrono, mémoriser les coins, puis taper le mémo pour l'arrêter
+memo-placeholder = lettres, ex. CJ MX
+memo-check = Vérifier
+memo-cancel = Annuler
+memo-drill-started = Mémorisation en cours : tapez le mémo quand vous êtes prêt.
+memo-letters = Coins ({ $n } cibles) : { $memo }
+memo-parity = Coins ({ $n } cibles, parité) : { $memo }
+memo-none = Coins déjà résolus : rien à mémoriser.
+memo-illegal = Ce motif d'autocollants n'est pas un état atteignable du cube : pas de mémo possible.
+memo-correct = Mémo correct en { $time }.
+memo-correct-best = Mémo correct en { $time }, nouveau record !
+memo-wrong = « { $typed } » ne correspond pas au mémo { $memo }.
+memo-best = Record : { $time }
+scheme-edit = Modifier les lettres
+scheme-reset = Speffz
+scheme-reset-desc = Rendre à chaque emplacement sa lettre Speffz
+scheme-duplicates = Utilisées plusieurs fois, les mémos sont donc ambigus : { $letters }
+scheme-save-failed = Impossible d'enregistrer le schéma de lettres : { $error }
+
+# solution du solveur + conseils de reprise en main
+solve = Résoudre
+solve-desc = Trouver une solution pour le cube affiché : le moins de mouvements (R, U, F), ou le moindre coût
+solve-weighted = Moindre coût
+solve-weighted-desc = Minimiser le coût total des mouvements ci-dessous (toutes faces) plutôt que leur nombre
+solving = Recherche…
+solve-found = Solution en { $n } mouvements trouvée.
+solve-unreachable = Impossible à résoudre en tournant seulement { $generators }.
+solve-timeout = Recherche abandonnée après la limite de temps ; essayez moins de restrictions ou d'autres coûts.
+solver-table-bad = Table du solveur inutilisable : { $detail }
+generators = Avec
+generators-placeholder = toutes les faces
+generators-desc = Faces que le solveur peut tourner, ex. « R U » ou « <R,U,F> » (vide = toutes)
+generators-bad = « { $ch } » n'est pas une face (utilisez U D F B L R).
+solve-solved = Déjà résolu.
+solve-result = Solution ({ $n } mouvements, coût { $cost }) : { $alg }
+solve-invalid = Ce motif d'autocollants n'est pas un état de cube atteignable.
+solver-not-ready = Les tables du solveur sont encore en chargement ; réessayez dans un instant.
+watch-solve = Résoudre et regarder
+watch-solve-desc = Trouver une solution et la jouer sur le cube un mouvement à la fois, ses mouvements apparaissant au-dessus des vues
+watch-stop = Arrêter
+watch-pace = Cadence
+watch-pace-desc = Temps entre les mouvements de la résolution regardée
+watch-pace-value = { $secs } s par mouvement
+watch-started = Lecture d'une solution de { $n } mouvements.
+watch-stopped = Lecture arrêtée.
+watch-done = Résolu en { $n } mouvements.
+watch-progress = { $n }/{ $total }
+cost-model = Coûts des mouvements
+cost-model-desc = Coût de chaque mouvement pour la résolution à moindre coût et les conseils de reprise, ex. « B=3 y=1 » (U D F B L R, half, x y z ; les autres gardent leur valeur par défaut)
+cost-bad-token = Coût de mouvement invalide « { $token } » (attendu ex. B=2.5).
+regrip-none = Aucune reprise n'aide (coût { $cost }).
+regrip-hint = Avec reprises : { $alg } (coût { $from } → { $to })
@@ -0,0 +1,104 @@
+// src/i18n/mod.rs
+
+//! Localization: Fluent-style `key = value` bundles (`en.ftl`, `fr.ftl`)
+//! embedded at compile time, a process-wide current language, and the
+//! [`t!`](crate::t) macro for lookups with `{ $name }` placeholders.
+//!
+//! The language is global (like gettext's locale) so every view builder picks
+//! up a switch on the next redraw without threading it through each call.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Supported UI languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::En, Lang::Fr];
+
+    fn source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("en.ftl"),
+            Lang::Fr => include_str!("fr.ftl"),
+        }
+    }
+}
+
+impl Display for Lang {
+    /// Each language names itself in its own tongue.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Lang::En => "English",
+            Lang::Fr => "Français",
+        })
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Switch the UI language; takes effect on the next view rebuild.
+pub fn set_lang(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+pub fn lang() -> Lang {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Lang::Fr,
+        _ => Lang::En,
+    }
+}
+
+type Bundle = HashMap<&'static str, &'static str>;
+
+fn bundle(lang: Lang) -> &'static Bundle {
+    static BUNDLES: OnceLock<[Bundle; 2]> = OnceLock::new();
+    &BUNDLES.get_or_init(|| Lang::ALL.map(|l| parse(l.source())))[lang as usize]
+}
+
+/// Parse `key = value` lines; `#` starts a comment line.
+fn parse(src: &'static str) -> Bundle {
+    src.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| l.split_once('='))
+        .map(|(k, v)| (k.trim(), v.trim()))
+        .collect()
+}
+
+/// Look up `key` in the current language, falling back to English and
+/// finally to the key itself so a missing string is visible but harmless.
+pub fn tr(key: &str) -> String {
+    lookup(key).to_string()
+}
+
+/// Like [`tr`], substituting `{ $name }` placeholders from `args`.
+pub fn tr_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = lookup(key).to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{ ${name} }}"), &value.to_string());
+    }
+    out
+}
+
+fn lookup(key: &str) -> &str {
+    bundle(lang()).get(key)
+        .or_else(|| bundle(Lang::En).get(key))
+        .copied()
+        .unwrap_or(key)
+}
+
+/// Translate a key: `t!("seed")` or `t!("seed-applied", seed = s, seq = q)`.
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => { $crate::i18n::tr($key) };
+    ($key:expr, $($name:ident = $val:expr),+ $(,)?) => {
+        $crate::i18n::tr_args($key, &[$((stringify!($name), &$val as &dyn std::fmt::Display)),+])
+    };
+}
@@ -0,0 +1,281 @@
+// src/leaderboard/mod.rs
+
+//! Online leaderboard for the daily challenge. Nothing leaves the machine
+//! until the user opts in; from then on each day's recorded time waits in
+//! a queue file until the server takes it, so solves made offline go up
+//! once the server can be reached again. The board shows the fastest times
+//! of the day.
+//!
+//! Talking to a server needs the `leaderboard` feature. The client speaks
+//! plain HTTP/1.0 over a TCP socket, so it needs no extra libraries, and
+//! only `http://` endpoints work. The protocol, relative to the endpoint:
+//!
+//! - `POST /times` with a `key=value` body of `day` (`YYYY-MM-DD`),
+//!   `name`, and `millis`;
+//! - `GET /board?day=YYYY-MM-DD`, answered with one `<millis> <name>` line
+//!   per time, fastest first.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::app::daily::Day;
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+/// Whether this build can reach a leaderboard server.
+pub const AVAILABLE: bool = cfg!(feature = "leaderboard");
+
+const HEADER: &str = "rubics leaderboard settings v1";
+const QUEUE_HEADER: &str = "rubics leaderboard queue v1";
+const TIME_HEADER: &str = "rubics daily time v1";
+
+/// How often queued times are retried.
+pub const RETRY: Duration = Duration::from_secs(60);
+/// Connect and read timeout for each request.
+#[cfg_attr(not(feature = "leaderboard"), allow(dead_code))]
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Largest response read, headers included; a board of [`SHOWN`] lines
+/// takes well under a kilobyte.
+#[cfg_attr(not(feature = "leaderboard"), allow(dead_code))]
+const MAX_RESPONSE: u64 = 64 * 1024;
+/// Times shown on the board.
+pub const SHOWN: usize = 10;
+/// Longest name sent.
+pub const NAME_MAX: usize = 24;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaderboardSettings {
+    /// The user agreed to send their daily times, with `name`, to `endpoint`.
+    pub opted_in: bool,
+    pub endpoint: String,
+    pub name: String,
+}
+
+impl LeaderboardSettings {
+    /// Opted in with somewhere to send to.
+    pub fn active(&self) -> bool {
+        AVAILABLE && self.opted_in && !self.endpoint.trim().is_empty()
+    }
+
+    pub fn encode(&self) -> String {
+        write_kv(HEADER, &[
+            ("opted_in", self.opted_in.to_string()),
+            ("endpoint", self.endpoint.clone()),
+            ("name", self.name.clone()),
+        ])
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        Self {
+            opted_in: kv.get("opted_in").is_some_and(|v| v == "true"),
+            endpoint: kv.get("endpoint").cloned().unwrap_or_default(),
+            name: kv.get("name").cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// A daily time waiting to be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub millis: u64,
+}
+
+/// Times not yet taken by the server, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Queue(pub Vec<Submission>);
+
+impl Queue {
+    pub fn encode(&self) -> String {
+        let days: Vec<String> = self.0.iter().map(|s| s.day.to_string()).collect();
+        let pairs: Vec<(&str, String)> =
+            days.iter().zip(&self.0).map(|(d, s)| (d.as_str(), s.millis.to_string())).collect();
+        write_kv(QUEUE_HEADER, &pairs)
+    }
+
+    pub fn decode(text: &str) -> Self {
+        Queue(
+            read_kv(text)
+                .into_iter()
+                .filter_map(|(k, v)| Some(Submission { day: Day::parse(&k)?, millis: v.parse().ok()? }))
+                .collect(),
+        )
+    }
+}
+
+/// One time on the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub name: String,
+    pub millis: u64,
+}
+
+/// The fastest times of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    pub day: Day,
+    pub entries: Vec<Entry>,
+}
+
+/// What a sync did: how many queued times the server took, in queue
+/// order, and the board or why it couldn't be had.
+#[derive(Debug, Clone)]
+pub struct Synced {
+    pub sent: usize,
+    pub board: Result<Board, String>,
+}
+
+/// `name` as sent: one line, trimmed, at most [`NAME_MAX`] characters;
+/// blank names go up as "anonymous".
+fn clean_name(name: &str) -> String {
+    let name: String = name.chars().filter(|c| !c.is_control()).take(NAME_MAX).collect();
+    match name.trim() {
+        "" => "anonymous".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// The board in a `GET /board` answer; lines that don't read are skipped.
+fn parse_board(day: Day, body: &str) -> Board {
+    let entries = body
+        .lines()
+        .filter_map(|l| {
+            let (millis, name) = l.trim().split_once(' ')?;
+            Some(Entry { name: name.trim().to_string(), millis: millis.parse().ok()? })
+        })
+        .take(SHOWN)
+        .collect();
+    Board { day, entries }
+}
+
+/// Send `queue` in order, stopping at the first failure, then fetch the
+/// board for `day`. The requests run on their own thread (see
+/// [`crate::background`]).
+pub async fn sync(settings: LeaderboardSettings, queue: Vec<Submission>, day: Day) -> Synced {
+    crate::background::run(move || sync_blocking(&settings, &queue, day)).await
+}
+
+fn sync_blocking(settings: &LeaderboardSettings, queue: &[Submission], day: Day) -> Synced {
+    let base = settings.endpoint.trim().trim_end_matches('/').to_string();
+    let name = clean_name(&settings.name);
+    let mut sent = 0;
+    for s in queue {
+        let body = write_kv(TIME_HEADER, &[
+            ("day", s.day.to_string()),
+            ("name", name.clone()),
+            ("millis", s.millis.to_string()),
+        ]);
+        if let Err(e) = backend::request("POST", &format!("{base}/times"), &body) {
+            return Synced { sent, board: Err(e) };
+        }
+        sent += 1;
+    }
+    let board = backend::request("GET", &format!("{base}/board?day={day}"), "").map(|body| parse_board(day, &body));
+    Synced { sent, board }
+}
+
+// ----- HTTP -----
+
+/// Host, port, and path of an `http://` URL.
+#[cfg_attr(not(feature = "leaderboard"), allow(dead_code))]
+fn split_url(url: &str) -> Option<(&str, u16, &str)> {
+    let rest = url.strip_prefix("http://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().ok()?),
+        None => (authority, 80),
+    };
+    (!host.is_empty()).then_some((host, port, path))
+}
+
+/// The body of a raw HTTP response, if its status is 2xx.
+#[cfg_attr(not(feature = "leaderboard"), allow(dead_code))]
+fn response_body(raw: &str) -> Result<&str, String> {
+    let (head, body) = raw.split_once("\r\n\r\n").unwrap_or((raw, ""));
+    let code = head.split_whitespace().nth(1).unwrap_or("");
+    if code.starts_with('2') && code.len() == 3 {
+        Ok(body)
+    } else {
+        Err(t!("leaderboard-status", code = code.to_string()))
+    }
+}
+
+#[cfg(feature = "leaderboard")]
+mod backend {
+    use std::io::{Read, Write};
+    use std::net::{TcpStream, ToSocketAddrs};
+
+    use crate::t;
+    use super::{response_body, split_url, MAX_RESPONSE, TIMEOUT};
+
+    /// One request; the response body, or a translated reason it failed.
+    pub fn request(method: &str, url: &str, body: &str) -> Result<String, String> {
+        let (host, port, path) = split_url(url).ok_or_else(|| t!("leaderboard-bad-url", url = url.to_string()))?;
+        let failed = |e: std::io::Error| t!("leaderboard-unreachable", error = e.to_string());
+        let addr = (host, port)
+            .to_socket_addrs()
+            .map_err(failed)?
+            .next()
+            .ok_or_else(|| t!("leaderboard-bad-url", url = url.to_string()))?;
+        let mut stream = TcpStream::connect_timeout(&addr, TIMEOUT).map_err(failed)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+        stream.set_write_timeout(Some(TIMEOUT)).map_err(failed)?;
+        // HTTP/1.0 keeps the answer unchunked and closes the connection after it.
+        let request = format!(
+            "{method} {path} HTTP/1.0\r\nHost: {host}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len(),
+        );
+        stream.write_all(request.as_bytes()).map_err(failed)?;
+        let mut raw = Vec::new();
+        stream.take(MAX_RESPONSE + 1).read_to_end(&mut raw).map_err(failed)?;
+        if raw.len() as u64 > MAX_RESPONSE {
+            return Err(t!("leaderboard-too-large", limit = MAX_RESPONSE / 1024));
+        }
+        response_body(&String::from_utf8_lossy(&raw)).map(str::to_string)
+    }
+}
+
+#[cfg(not(feature = "leaderboard"))]
+mod backend {
+    use crate::t;
+
+    pub fn request(_method: &str, _url: &str, _body: &str) -> Result<String, String> {
+        Err(t!("leaderboard-unavailable"))
+    }
+}
+
+// ----- files -----
+
+/// Location of the leaderboard settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("leaderboard.txt")
+}
+
+/// Location of the queue of unsent times.
+pub fn queue_path() -> PathBuf {
+    data_dir().join("leaderboard-queue.txt")
+}
+
+pub fn load() -> LeaderboardSettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| LeaderboardSettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &LeaderboardSettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
+
+pub fn load_queue() -> Queue {
+    std::fs::read_to_string(queue_path())
+        .map(|t| Queue::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save_queue(queue: &Queue) -> std::io::Result<()> {
+    save_file(&queue_path(), queue.encode())
+}
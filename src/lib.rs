@@ -0,0 +1,15 @@
+// src/lib.rs
+
+//! UI-independent half of the app: cube model, move parsing, puzzle logic,
+//! typed errors, string bundles, event hooks, and a small QR encoder. The
+//! binary (`main.rs`) layers the Iced UI on top; benchmarks and tools link
+//! against this library.
+
+pub mod cube;
+pub mod logic;
+pub mod i18n;
+pub mod qr;
+pub mod events;
+pub mod error;
+
+pub use error::Error;
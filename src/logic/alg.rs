@@ -0,0 +1,85 @@
+// src/logic/alg.rs
+
+//! Move-token parsing and application (`"R U R' U'"` → cube turns).
+//!
+//! Algorithm text may span lines and carry `//` comments running to the
+//! end of the line, as in a pasted reconstruction:
+//!
+//! ```text
+//! R U R' // first layer
+//! U2 F   // orient
+//! ```
+
+use std::ops::Range;
+
+use crate::cube::{Alg, Cube, Move};
+use crate::Error;
+
+/// Apply a space-separated algorithm to the cube, e.g. `"R U R' U'"`.
+///
+/// Returns `Err` at the first unknown token, with the turns before it
+/// already applied.
+pub fn apply_alg(cube: &mut Cube, alg: &str) -> Result<(), Error> {
+    for tok in strip_comments(alg).split_whitespace() {
+        apply_token(cube, tok)?;
+    }
+    Ok(())
+}
+
+/// Apply a single token like `"R"`, `"R'"`, or `"R2"`; returns the move.
+pub fn apply_token(cube: &mut Cube, tok: &str) -> Result<Move, Error> {
+    let m = Move::parse(tok).ok_or_else(|| Error::ParseMove { token: tok.to_string() })?;
+    cube.apply(m);
+    Ok(m)
+}
+
+/// Parse an algorithm once for repeated application via `Cube::apply_alg`.
+pub fn parse_alg(alg: &str) -> Result<Alg, Error> {
+    Alg::parse(&strip_comments(alg))
+}
+
+/// `alg` without its `//` comments, lines joined by spaces.
+pub fn strip_comments(alg: &str) -> String {
+    let lines: Vec<&str> = alg.lines().map(|l| l.split_once("//").map_or(l, |(code, _)| code)).collect();
+    lines.join(" ")
+}
+
+/// What a stretch of algorithm text is, for highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Move,
+    Invalid,
+    Comment,
+}
+
+/// Byte ranges of the tokens and the comment in one line of algorithm text.
+pub fn classify_line(line: &str) -> Vec<(Range<usize>, TokenKind)> {
+    let (code, comment) = match line.find("//") {
+        Some(i) => (&line[..i], Some(i..line.len())),
+        None => (line, None),
+    };
+    let mut out = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices().chain(std::iter::once((code.len(), ' '))) {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                let kind = if Move::parse(&code[s..i]).is_some() { TokenKind::Move } else { TokenKind::Invalid };
+                out.push((s..i, kind));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    out.extend(comment.map(|r| (r, TokenKind::Comment)));
+    out
+}
+
+/// Valid and invalid move tokens in `alg`, comments aside.
+pub fn count_tokens(alg: &str) -> (usize, usize) {
+    alg.lines().flat_map(classify_line).fold((0, 0), |(ok, bad), (_, kind)| match kind {
+        TokenKind::Move => (ok + 1, bad),
+        TokenKind::Invalid => (ok, bad + 1),
+        TokenKind::Comment => (ok, bad),
+    })
+}
@@ -0,0 +1,198 @@
+// src/logic/console.rs
+
+//! Console scripting: [Rhai](https://rhai.rs) scripts for automating
+//! experiments on the cube from inside the app.
+//!
+//! ```text
+//! // How many random scrambles are 10 or more moves from solved?
+//! let deep = 0;
+//! for seed in 0..1000 {
+//!     scramble(seed);
+//!     if depth() >= 10 { deep += 1; }
+//! }
+//! print(`${deep} of 1000`);
+//! ```
+//!
+//! Scripts get the whole Rhai language, plus:
+//!
+//! - `cube.apply("R U")`, `cube.state()` (facelets), `cube.reset()`,
+//!   `cube.solved()`
+//! - `scramble(seed)`: reset and apply the app's scramble for `seed`;
+//!   returns the scramble
+//! - `solve()`: an optimal solution of the cube; `depth()`: its length
+//! - `invert(alg)`: the algorithm undoing `alg`; `order(alg)`: how many
+//!   times `alg` must be repeated to get back where it started
+//! - `random()`: a random non-negative integer, e.g. a seed
+//! - `print(…)`: a line of output
+//!
+//! A run stops after [`MAX_STEPS`] operations, so a runaway loop ends.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use rand::RngCore;
+use rhai::{Engine, EvalAltResult, Scope};
+
+use crate::cube::{Cube, CornerPerm};
+use crate::error::{Error, StateProblem};
+use crate::logic::alg::{apply_alg, parse_alg};
+use crate::logic::scramble::{seeded_alg, SCRAMBLE_LEN};
+use crate::logic::solver::DistanceTable;
+
+/// Operations a run may execute.
+pub const MAX_STEPS: u64 = 10_000_000;
+
+/// Lines of output kept; later `print`s are dropped.
+pub const MAX_OUTPUT: usize = 1_000;
+
+/// Longest string, array, or map a script may build.
+const MAX_SIZE: usize = 1 << 20;
+
+/// A finished run: what it printed, the cube as it left it, and whether it
+/// ran to the end.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub output: Vec<String>,
+    pub cube: Cube,
+    pub result: Result<(), Error>,
+}
+
+/// What a script's functions share.
+#[derive(Default)]
+struct State {
+    cube: Cube,
+    output: Vec<String>,
+}
+
+/// The `cube` a script sees.
+#[derive(Clone)]
+struct CubeHandle(Rc<RefCell<State>>);
+
+/// Run `src` on `cube`. `solve()` and `depth()` need `solver`.
+pub fn run(src: &str, cube: Cube, solver: Option<Arc<DistanceTable>>) -> Run {
+    let state = Rc::new(RefCell::new(State { cube, output: Vec::new() }));
+    let engine = engine(&state, solver);
+    let mut scope = Scope::new();
+    scope.push_constant("cube", CubeHandle(state.clone()));
+    let result = engine.run_with_scope(&mut scope, src).map_err(|e| error(*e));
+    let State { cube, output } = std::mem::take(&mut *state.borrow_mut());
+    Run { output, cube, result }
+}
+
+/// A failed run as a console error; the functions' own messages are shown
+/// as they are.
+fn error(mut e: EvalAltResult) -> Error {
+    let line = e.take_position().line().unwrap_or(0);
+    let detail = match e {
+        EvalAltResult::ErrorRuntime(message, _) => message.to_string(),
+        e => e.to_string(),
+    };
+    Error::Console { line, detail }
+}
+
+fn fail(e: impl ToString) -> Box<EvalAltResult> {
+    e.to_string().into()
+}
+
+/// An engine with the cube and algorithm functions, printing into `state`.
+fn engine(state: &Rc<RefCell<State>>, solver: Option<Arc<DistanceTable>>) -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_STEPS)
+        .set_max_string_size(MAX_SIZE)
+        .set_max_array_size(MAX_SIZE)
+        .set_max_map_size(MAX_SIZE);
+
+    let out = state.clone();
+    engine.on_print(move |line| {
+        let output = &mut out.borrow_mut().output;
+        if output.len() < MAX_OUTPUT {
+            output.push(line.to_string());
+        }
+    });
+
+    engine
+        .register_type_with_name::<CubeHandle>("Cube")
+        .register_fn("apply", |c: CubeHandle, alg: &str| -> Result<(), Box<EvalAltResult>> {
+            apply_alg(&mut c.0.borrow_mut().cube, alg).map_err(fail)
+        })
+        .register_fn("state", |c: CubeHandle| c.0.borrow().cube.to_facelets())
+        .register_fn("reset", |c: CubeHandle| c.0.borrow_mut().cube = Cube::default())
+        .register_fn("solved", |c: CubeHandle| c.0.borrow().cube.is_solved());
+
+    let s = state.clone();
+    engine.register_fn("scramble", move |seed: i64| {
+        let alg = seeded_alg(SCRAMBLE_LEN, seed as u64);
+        let cube = &mut s.borrow_mut().cube;
+        *cube = Cube::default();
+        cube.apply_alg(&alg);
+        alg.to_string()
+    });
+
+    let (s, table) = (state.clone(), solver.clone());
+    engine.register_fn("solve", move || -> Result<String, Box<EvalAltResult>> {
+        let table = table.as_deref().ok_or_else(|| fail("solver tables are still loading"))?;
+        let alg = table.solve(&s.borrow().cube).ok_or(Error::InvalidState(StateProblem::Illegal)).map_err(fail)?;
+        Ok(alg.to_string())
+    });
+
+    let s = state.clone();
+    engine.register_fn("depth", move || -> Result<i64, Box<EvalAltResult>> {
+        let table = solver.as_deref().ok_or_else(|| fail("solver tables are still loading"))?;
+        let d = table.distance(&s.borrow().cube).ok_or(Error::InvalidState(StateProblem::Illegal)).map_err(fail)?;
+        Ok(d.into())
+    });
+
+    engine
+        .register_fn("invert", |alg: &str| -> Result<String, Box<EvalAltResult>> {
+            Ok(parse_alg(alg).map_err(fail)?.inverse().to_string())
+        })
+        .register_fn("order", |alg: &str| -> Result<i64, Box<EvalAltResult>> {
+            Ok(CornerPerm::of_alg(&parse_alg(alg).map_err(fail)?).order().into())
+        })
+        .register_fn("random", || (rand::thread_rng().next_u64() >> 1) as i64);
+
+    engine
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prints_and_turns_the_cube() {
+        let out = run(r#"cube.apply("R U"); print(`${cube.solved()} ${invert("R U")}`);"#, Cube::default(), None);
+        assert!(out.result.is_ok());
+        assert_eq!(out.output, ["false U' R'"]);
+        let mut expected = Cube::default();
+        apply_alg(&mut expected, "R U").unwrap();
+        assert_eq!(out.cube, expected);
+    }
+
+    #[test]
+    fn scramble_matches_the_app() {
+        let out = run("let s = scramble(7); print(s);", Cube::default(), None);
+        assert_eq!(out.output, [seeded_alg(SCRAMBLE_LEN, 7).to_string()]);
+    }
+
+    #[test]
+    fn order_counts_repetitions() {
+        let out = run(r#"print(order("R")); print(order("R U"));"#, Cube::default(), None);
+        assert_eq!(out.output, ["4", "15"]);
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let out = run("let x = 1;\ncube.apply(\"Q\");", Cube::default(), None);
+        assert!(matches!(out.result, Err(Error::Console { line: 2, .. })));
+        let out = run("solve();", Cube::default(), None);
+        assert!(matches!(out.result, Err(Error::Console { line: 1, .. })));
+    }
+
+    #[test]
+    fn runaway_loops_stop() {
+        let out = run("loop { }", Cube::default(), None);
+        assert!(out.result.is_err());
+    }
+}
@@ -0,0 +1,43 @@
+// src/logic/describe.rs
+
+//! Plain-text cube descriptions for screen readers and the status line,
+//! e.g. `U face: white white / green red; D face: …`, optionally with each
+//! sticker position's letter, e.g. `white (D)`.
+
+use crate::cube::{Col, Cube, FaceId};
+use crate::logic::memo::LetterScheme;
+use crate::t;
+
+/// Localized color name.
+pub fn color_name(c: Col) -> String {
+    match c {
+        Col::W => t!("color-white"),
+        Col::Y => t!("color-yellow"),
+        Col::G => t!("color-green"),
+        Col::B => t!("color-blue"),
+        Col::O => t!("color-orange"),
+        Col::R => t!("color-red"),
+    }
+}
+
+/// One sentence per face (U, D, F, B, L, R), rows top to bottom as seen when
+/// looking straight at the face; prefixed with a solved note when solved.
+/// With `letters`, each sticker is followed by its position's letter.
+pub fn describe(cube: &Cube, letters: Option<&LetterScheme>) -> String {
+    use FaceId::*;
+    let faces = [U, D, F, B, L, R].map(|id| {
+        let f = cube.face(id);
+        let sticker = |r: usize, c: usize| match letters {
+            Some(s) => format!("{} ({})", color_name(f[r][c]), s.letter((id, r, c))),
+            None => color_name(f[r][c]),
+        };
+        let row = |r: usize| format!("{} {}", sticker(r, 0), sticker(r, 1));
+        t!("describe-face", face = format!("{id:?}"), top = row(0), bottom = row(1))
+    });
+    let body = faces.join("; ");
+    if cube.is_solved() {
+        format!("{} {body}", t!("describe-solved"))
+    } else {
+        body
+    }
+}
@@ -0,0 +1,120 @@
+// src/logic/diagnose.rs
+
+//! Sanity checks for a sticker pattern, e.g. one loaded from a share link or
+//! a KPuzzle file: does every slot hold a real corner, is each piece there
+//! once, what are the permutation parity and total twist, and so is the
+//! state solvable. A bad total twist can be repaired by twisting any one
+//! corner in place.
+//!
+//! On a 2×2 either permutation parity is solvable (a quarter turn is a
+//! 4-cycle, an odd permutation), so parity is reported but never blocks.
+
+use crate::cube::packed::{home_colors, is_ud, CORNER_SLOTS};
+use crate::cube::{Col, Cube};
+
+/// What one corner slot holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotState {
+    /// A real piece (home slot index) and its twist (1 = counterclockwise).
+    Piece { piece: u8, twist: u8 },
+    /// The colors of `piece`, but in mirror-image order, which no twist fixes.
+    Mirrored(u8),
+    /// Stickers that belong to no corner (e.g. two whites, or no white or
+    /// yellow at all).
+    Unknown([Col; 3]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Indexed by slot, as in [`CORNER_SLOTS`].
+    pub slots: [SlotState; 8],
+    /// Pieces held by more than one slot, and pieces held by none.
+    pub duplicated: Vec<u8>,
+    pub missing: Vec<u8>,
+    /// Whether the corner permutation is odd; `None` unless every slot holds
+    /// a distinct real piece.
+    pub odd_parity: Option<bool>,
+    /// Sum of the real pieces' twists, mod 3 (0 when solvable).
+    pub twist: u8,
+}
+
+impl Diagnosis {
+    pub fn solvable(&self) -> bool {
+        self.odd_parity.is_some() && self.twist == 0
+    }
+
+    /// Whether twisting one corner would make the state solvable.
+    pub fn twist_fixable(&self) -> bool {
+        self.odd_parity.is_some() && self.twist != 0
+    }
+}
+
+/// Check every corner slot of `cube`.
+pub fn diagnose(cube: &Cube) -> Diagnosis {
+    let home = home_colors();
+    let slots = CORNER_SLOTS.map(|pos| {
+        let cols = pos.map(|(f, r, c)| cube.face(f)[r][c]);
+        for o in 0..3 {
+            if !is_ud(cols[o]) {
+                continue;
+            }
+            let rotated = [cols[o], cols[(o + 1) % 3], cols[(o + 2) % 3]];
+            let mirrored = [cols[o], cols[(o + 2) % 3], cols[(o + 1) % 3]];
+            if let Some(p) = home.iter().position(|h| *h == rotated) {
+                return SlotState::Piece { piece: p as u8, twist: o as u8 };
+            }
+            if let Some(p) = home.iter().position(|h| *h == mirrored) {
+                return SlotState::Mirrored(p as u8);
+            }
+        }
+        SlotState::Unknown(cols)
+    });
+
+    let mut count = [0u8; 8];
+    let mut twist = 0;
+    for s in &slots {
+        match *s {
+            SlotState::Piece { piece, twist: t } => {
+                count[piece as usize] += 1;
+                twist = (twist + t) % 3;
+            }
+            SlotState::Mirrored(piece) => count[piece as usize] += 1,
+            SlotState::Unknown(_) => {}
+        }
+    }
+    let duplicated = (0..8).filter(|&p| count[p as usize] > 1).collect();
+    let missing: Vec<u8> = (0..8).filter(|&p| count[p as usize] == 0).collect();
+
+    let pieces: Vec<u8> = slots
+        .iter()
+        .filter_map(|s| match *s {
+            SlotState::Piece { piece, .. } => Some(piece),
+            _ => None,
+        })
+        .collect();
+    let odd_parity = (pieces.len() == 8 && missing.is_empty()).then(|| {
+        // Parity = number of inversions, mod 2.
+        let inversions: usize = (0..8).map(|i| pieces[i + 1..].iter().filter(|&&q| q < pieces[i]).count()).sum();
+        inversions % 2 == 1
+    });
+    Diagnosis { slots, duplicated, missing, odd_parity, twist }
+}
+
+/// `cube` with the corner in `slot` twisted so the total twist is 0;
+/// `None` if the slot holds no real piece or the twist is already fine.
+pub fn fix_twist(cube: &Cube, slot: u8) -> Option<Cube> {
+    let diagnosis = diagnose(cube);
+    if diagnosis.twist == 0 || !matches!(diagnosis.slots[slot as usize], SlotState::Piece { .. }) {
+        return None;
+    }
+    // Moving every sticker k places round the corner adds k to its twist.
+    let by = (3 - diagnosis.twist) as usize;
+    let pos = CORNER_SLOTS[slot as usize];
+    let cols = pos.map(|(f, r, c)| cube.face(f)[r][c]);
+    let mut out = cube.clone();
+    for (k, &col) in cols.iter().enumerate() {
+        let (f, r, c) = pos[(k + by) % 3];
+        out.set_sticker(f, r, c, col);
+    }
+    Some(out)
+}
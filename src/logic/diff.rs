@@ -0,0 +1,106 @@
+// src/logic/diff.rs
+
+//! Compare two cube states: the stickers that differ, the corner slots
+//! holding a different piece or twist, and the shortest algorithm taking
+//! one to the other.
+//!
+//! The solver counts a state as solved in any orientation, but a diff must
+//! land on the target exactly (a whole-cube rotation of it still differs
+//! sticker by sticker), so the algorithm comes from a small IDA* search over
+//! all 18 turns that uses the solver's distance as its lower bound.
+
+use crate::cube::perm::CORNER_NAMES;
+use crate::cube::{Alg, CornerPerm, Cube, FaceId, Move, PackedCube};
+use crate::logic::solver::DistanceTable;
+use crate::logic::tracking::Sticker;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateDiff {
+    /// Positions whose sticker colors differ.
+    pub stickers: Vec<Sticker>,
+    /// Corner slots holding a different piece or twist; `None` unless both
+    /// states are legal.
+    pub slots: Option<Vec<u8>>,
+}
+
+impl StateDiff {
+    pub fn is_empty(&self) -> bool {
+        self.stickers.is_empty()
+    }
+}
+
+/// Sticker- and piece-level differences between `a` and `b`.
+pub fn diff(a: &Cube, b: &Cube) -> StateDiff {
+    let mut stickers = Vec::new();
+    for f in FaceId::ALL {
+        for r in 0..2 {
+            for c in 0..2 {
+                if a.face(f)[r][c] != b.face(f)[r][c] {
+                    stickers.push((f, r, c));
+                }
+            }
+        }
+    }
+    let slots = PackedCube::pack(a).zip(PackedCube::pack(b)).map(|(pa, pb)| {
+        let (pa, pb) = (CornerPerm::from_packed(pa), CornerPerm::from_packed(pb));
+        (0..8u8)
+            .filter(|&s| {
+                let s = s as usize;
+                pa.from[s] != pb.from[s] || pa.twist[s] != pb.twist[s]
+            })
+            .collect()
+    });
+    StateDiff { stickers, slots }
+}
+
+/// Shortest algorithm turning `a` into exactly `b`; `None` if either
+/// state is illegal.
+pub fn transform(a: &Cube, b: &Cube, table: &DistanceTable) -> Option<Alg> {
+    let pa = CornerPerm::from_packed(PackedCube::pack(a)?);
+    let pb = CornerPerm::from_packed(PackedCube::pack(b)?);
+    // a·x = b, so x = a⁻¹·b; the moves that take state x⁻¹ to exactly
+    // solved are x.
+    let start = pa.inverse().then(pb).inverse().to_packed().unpack();
+    let search = ExactSearch { table, goal: Cube::default() };
+    let mut path = Vec::new();
+    (table.distance(&start)?..).find(|&bound| search.run(&start, bound, &mut path)).map(|_| Alg(path))
+}
+
+struct ExactSearch<'a> {
+    table: &'a DistanceTable,
+    goal: Cube,
+}
+
+impl ExactSearch<'_> {
+    /// Depth-first search for `goal` within `left` turns, appending them to
+    /// `path`. The solver's distance ignores orientation, so it never
+    /// overestimates the exact distance.
+    fn run(&self, cube: &Cube, left: u8, path: &mut Vec<Move>) -> bool {
+        if left == 0 {
+            return *cube == self.goal;
+        }
+        if self.table.distance(cube).is_none_or(|d| d > left) {
+            return false;
+        }
+        let last = path.last().map(|m| m.face);
+        for m in Move::ALL {
+            if Some(m.face) == last {
+                continue;
+            }
+            let mut next = cube.clone();
+            next.apply(m);
+            path.push(m);
+            if self.run(&next, left - 1, path) {
+                return true;
+            }
+            path.pop();
+        }
+        false
+    }
+}
+
+/// Slot names for `slots`, e.g. `UFR, DBR`.
+pub fn slot_names(slots: &[u8]) -> String {
+    let names: Vec<&str> = slots.iter().map(|&s| CORNER_NAMES[s as usize]).collect();
+    names.join(", ")
+}
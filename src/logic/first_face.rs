@@ -0,0 +1,83 @@
+// src/logic/first_face.rs
+
+//! First-face analysis: for each of the six colors, the fewest turns that
+//! complete a face of that color, and every optimal way to do it.
+//!
+//! Searches U/R/F turns only: any turn sequence equals a U/R/F sequence of
+//! the same length up to a whole-cube rotation, and "some face is all one
+//! color" doesn't care about rotations.
+
+use crate::cube::{Alg, Col, Cube, FaceId, Move, Turn};
+
+/// Deepest search; every first face on a 2×2 is reachable well within this.
+pub const MAX_DEPTH: usize = 8;
+
+/// Optimal first-face solutions for one color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaceReport {
+    pub color: Col,
+    /// Optimal move count, `None` if deeper than [`MAX_DEPTH`].
+    pub depth: Option<usize>,
+    /// All optimal U/R/F sequences.
+    pub solutions: Vec<Alg>,
+}
+
+const URF_MOVES: [Move; 9] = {
+    use FaceId::*;
+    use Turn::*;
+    [
+        Move::new(U, Cw), Move::new(U, Ccw), Move::new(U, Half),
+        Move::new(R, Cw), Move::new(R, Ccw), Move::new(R, Half),
+        Move::new(F, Cw), Move::new(F, Ccw), Move::new(F, Half),
+    ]
+};
+
+/// Analyze all six colors with one iterative-deepening search.
+pub fn analyze(cube: &Cube) -> Vec<FaceReport> {
+    let mut reports: Vec<FaceReport> = Col::ALL
+        .iter()
+        .map(|&color| FaceReport { color, depth: None, solutions: Vec::new() })
+        .collect();
+    let mut path = Vec::with_capacity(MAX_DEPTH);
+    for depth in 0..=MAX_DEPTH {
+        let pending: Vec<usize> = (0..6).filter(|&i| reports[i].depth.is_none()).collect();
+        if pending.is_empty() {
+            break;
+        }
+        search(cube, depth, None, &mut path, &pending, &mut reports);
+        for &i in &pending {
+            if !reports[i].solutions.is_empty() {
+                reports[i].depth = Some(depth);
+            }
+        }
+    }
+    reports
+}
+
+fn search(
+    cube: &Cube,
+    left: usize,
+    last: Option<FaceId>,
+    path: &mut Vec<Move>,
+    pending: &[usize],
+    reports: &mut [FaceReport],
+) {
+    if left == 0 {
+        for &i in pending {
+            if cube.has_face(reports[i].color) {
+                reports[i].solutions.push(Alg(path.clone()));
+            }
+        }
+        return;
+    }
+    for &m in &URF_MOVES {
+        if Some(m.face) == last {
+            continue;
+        }
+        let mut next = cube.clone();
+        next.apply(m);
+        path.push(m);
+        search(&next, left - 1, Some(m.face), path, pending, reports);
+        path.pop();
+    }
+}
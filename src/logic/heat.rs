@@ -0,0 +1,81 @@
+// src/logic/heat.rs
+
+//! Piece-distance heatmap: for each corner, the fewest turns that would
+//! bring it alone back to its home slot and orientation, for tinting its
+//! stickers on the render. Other pieces are ignored, so this measures one
+//! piece's displacement, not the distance to solved.
+//!
+//! "Home" is taken in whichever of the 24 whole-cube orientations puts the
+//! pieces closest overall, so a rotated but solved cube shows no heat.
+
+use std::sync::OnceLock;
+
+use crate::cube::packed::CORNER_SLOTS;
+use crate::cube::{CornerPerm, Cube, Move, PackedCube};
+use crate::logic::solver::solved_states;
+
+/// Turns to move a lone corner from `[home]` (untwisted) to `[slot][twist]`.
+type SingleCorner = [[[u8; 3]; 8]; 8];
+
+/// Per-sticker distances, indexed like the cube's faces: `[face][row][col]`.
+pub type StickerHeat = [[[u8; 2]; 2]; 6];
+
+/// The distance of the piece in each corner slot from its home.
+pub fn corner_distances(cube: &Cube) -> Option<[u8; 8]> {
+    let state = CornerPerm::from_packed(PackedCube::pack(cube)?);
+    let table = single_corner();
+    solved_states()
+        .into_iter()
+        .map(|goal| {
+            // The state as seen from this orientation's solved cube.
+            let rel = CornerPerm::from_packed(goal).inverse().then(state);
+            let mut d = [0u8; 8];
+            for (s, out) in d.iter_mut().enumerate() {
+                *out = table[rel.from[s] as usize][s][rel.twist[s] as usize];
+            }
+            d
+        })
+        .min_by_key(|d| d.iter().map(|&x| u32::from(x)).sum::<u32>())
+}
+
+/// [`corner_distances`] spread over each corner's three stickers.
+pub fn sticker_heat(cube: &Cube) -> Option<StickerHeat> {
+    let d = corner_distances(cube)?;
+    let mut out = [[[0u8; 2]; 2]; 6];
+    for (slot, stickers) in CORNER_SLOTS.iter().enumerate() {
+        for &(f, r, c) in stickers {
+            out[f as usize][r][c] = d[slot];
+        }
+    }
+    Some(out)
+}
+
+// Breadth-first search over the 24 positions of one corner, from each home.
+fn single_corner() -> &'static SingleCorner {
+    static TABLE: OnceLock<SingleCorner> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let moves = Move::ALL.map(CornerPerm::of_move);
+        let mut table = [[[u8::MAX; 3]; 8]; 8];
+        for (home, dist) in table.iter_mut().enumerate() {
+            dist[home][0] = 0;
+            let mut frontier = vec![(home, 0usize)];
+            let mut depth = 0;
+            while !frontier.is_empty() {
+                depth += 1;
+                let mut next = Vec::new();
+                for (slot, twist) in frontier {
+                    for m in &moves {
+                        let to = m.from.iter().position(|&f| f as usize == slot).expect("a permutation");
+                        let t = (twist + m.twist[to] as usize) % 3;
+                        if dist[to][t] == u8::MAX {
+                            dist[to][t] = depth;
+                            next.push((to, t));
+                        }
+                    }
+                }
+                frontier = next;
+            }
+        }
+        table
+    })
+}
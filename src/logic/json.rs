@@ -0,0 +1,240 @@
+// src/logic/json.rs
+
+//! Minimal JSON reader (objects, arrays, strings, numbers, literals), enough
+//! for the documents the app reads, and string quoting for the ones it
+//! writes.
+
+/// Deepest nesting of arrays and objects read; deeper input is rejected
+/// rather than risking the stack.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug, Clone)]
+pub enum Json {
+    /// `true`, `false`, or `null`; never inspected.
+    Lit,
+    Num(f64),
+    Str(String),
+    Arr(Vec<Json>),
+    Obj(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(src: &str) -> Option<Json> {
+        let mut p = Parser { s: src.as_bytes(), i: 0, depth: 0 };
+        let v = p.value()?;
+        p.ws();
+        (p.i == p.s.len()).then_some(v)
+    }
+
+    /// Field `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Obj(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self { Json::Str(s) => Some(s), _ => None }
+    }
+
+    pub fn as_num(&self) -> Option<f64> {
+        match self { Json::Num(n) => Some(*n), _ => None }
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    i: usize,
+    /// Arrays and objects open around the current position.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn ws(&mut self) {
+        while self.s.get(self.i).is_some_and(u8::is_ascii_whitespace) {
+            self.i += 1;
+        }
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        self.ws();
+        let hit = self.s.get(self.i) == Some(&b);
+        if hit {
+            self.i += 1;
+        }
+        hit
+    }
+
+    fn lit(&mut self, word: &str) -> Option<Json> {
+        self.s[self.i..].starts_with(word.as_bytes()).then(|| {
+            self.i += word.len();
+            Json::Lit
+        })
+    }
+
+    fn value(&mut self) -> Option<Json> {
+        self.ws();
+        match *self.s.get(self.i)? {
+            b'{' | b'[' => {
+                if self.depth == MAX_DEPTH {
+                    return None;
+                }
+                self.depth += 1;
+                let v = self.container();
+                self.depth -= 1;
+                v
+            }
+            b'"' => self.string().map(Json::Str),
+            b't' => self.lit("true"),
+            b'f' => self.lit("false"),
+            b'n' => self.lit("null"),
+            _ => {
+                let start = self.i;
+                while self.s.get(self.i).is_some_and(|b| b"+-.eE0123456789".contains(b)) {
+                    self.i += 1;
+                }
+                std::str::from_utf8(&self.s[start..self.i]).ok()?.parse().ok().map(Json::Num)
+            }
+        }
+    }
+
+    /// An object or array, at its opening bracket.
+    fn container(&mut self) -> Option<Json> {
+        match self.s[self.i] {
+            b'{' => {
+                self.i += 1;
+                let mut fields = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.ws();
+                        let k = self.string()?;
+                        if !self.eat(b':') {
+                            return None;
+                        }
+                        fields.push((k, self.value()?));
+                        if self.eat(b'}') { break; }
+                        if !self.eat(b',') { return None; }
+                    }
+                }
+                Some(Json::Obj(fields))
+            }
+            b'[' => {
+                self.i += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') { break; }
+                        if !self.eat(b',') { return None; }
+                    }
+                }
+                Some(Json::Arr(items))
+            }
+            _ => None,
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.s.get(self.i) != Some(&b'"') {
+            return None;
+        }
+        self.i += 1;
+        let mut out = Vec::new();
+        loop {
+            let b = *self.s.get(self.i)?;
+            self.i += 1;
+            match b {
+                b'"' => return String::from_utf8(out).ok(),
+                b'\\' => {
+                    let e = *self.s.get(self.i)?;
+                    self.i += 1;
+                    match e {
+                        b'n' => out.push(b'\n'),
+                        b't' => out.push(b'\t'),
+                        b'r' => out.push(b'\r'),
+                        b'b' => out.push(0x08),
+                        b'f' => out.push(0x0c),
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            // Outside the BMP: a high surrogate, then `\u` and the low one.
+                            if (0xd800..0xdc00).contains(&code) {
+                                if self.s.get(self.i..self.i + 2)? != b"\\u" {
+                                    return None;
+                                }
+                                self.i += 2;
+                                let low = self.hex4().filter(|l| (0xdc00..0xe000).contains(l))?;
+                                code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            let ch = char::from_u32(code)?;
+                            out.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes());
+                        }
+                        other => out.push(other),
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+    }
+
+    /// The four hex digits of a `\u` escape.
+    fn hex4(&mut self) -> Option<u32> {
+        let hex = std::str::from_utf8(self.s.get(self.i..self.i + 4)?).ok()?;
+        let code = u32::from_str_radix(hex, 16).ok()?;
+        self.i += 4;
+        Some(code)
+    }
+}
+
+/// `s` as a JSON string literal, quotes included.
+pub fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_nested_documents() {
+        let v = Json::parse(r#"{"a": [1, {"b": "c"}], "d": null}"#).unwrap();
+        assert_eq!(v.get("a").map(|a| matches!(a, Json::Arr(items) if items.len() == 2)), Some(true));
+        assert!(Json::parse("[1, 2").is_none());
+    }
+
+    #[test]
+    fn rejects_nesting_past_the_limit() {
+        let nested = |n: usize| format!("{}{}", "[".repeat(n), "]".repeat(n));
+        assert!(Json::parse(&nested(MAX_DEPTH)).is_some());
+        assert!(Json::parse(&nested(MAX_DEPTH + 1)).is_none());
+        assert!(Json::parse(&"[".repeat(1_000_000)).is_none());
+    }
+
+    #[test]
+    fn joins_surrogate_pairs() {
+        let v = Json::parse(r#""smile \ud83d\ude00 \u00e9""#).unwrap();
+        assert_eq!(v.as_str(), Some("smile \u{1f600} \u{e9}"));
+        assert!(Json::parse(r#""\ud83d""#).is_none());
+        assert!(Json::parse(r#""\ud83d\u0041""#).is_none());
+    }
+
+    #[test]
+    fn quoted_strings_read_back() {
+        let s = "say \"hi\"\n\\ \u{1}";
+        assert_eq!(Json::parse(&quote(s)).unwrap().as_str(), Some(s));
+    }
+}
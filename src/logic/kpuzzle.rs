@@ -0,0 +1,89 @@
+// src/logic/kpuzzle.rs
+
+//! Import from cubing.js KPuzzle JSON, so patterns made in the JS tools
+//! load here.
+//!
+//! Accepted shapes (pasted as one object):
+//! - a pattern `{"CORNERS": {"pieces": […], "orientation": […]}}`
+//! - the older state/transformation form with `permutation` and
+//!   `orientation`/`orientationDelta` (applied to solved, it is a pattern)
+//! - either of those under `pattern`, `patternData`, `state`, or `stateData`,
+//!   optionally next to an `"alg": "R U R'"` string.
+//!
+//! Only the `CORNERS` orbit is read, so a 3×3×3 pattern imports its corners.
+//! cubing.js numbers the corners UFR, URB, UBL, ULF, DRF, DFL, DLB, DBR and
+//! lists each one's stickers in that name order, as `CORNER_SLOTS` does.
+//! Its orientation counts the other way round from ours.
+
+use crate::cube::packed::{ori_rank, perm_rank};
+use crate::cube::{Cube, PackedCube};
+use crate::error::{Error, StateProblem};
+use super::json::Json;
+
+/// Our slot index for each cubing.js corner slot.
+const SLOT_MAP: [usize; 8] = [1, 2, 3, 0, 5, 4, 7, 6];
+
+const PATTERN_KEYS: [&str; 4] = ["pattern", "patternData", "state", "stateData"];
+
+/// What an import carried; at least one field is set.
+#[derive(Debug, Clone, Default)]
+pub struct Import {
+    pub cube: Option<Cube>,
+    pub alg: Option<String>,
+}
+
+/// Parse KPuzzle JSON (see the module docs).
+pub fn import(src: &str) -> Result<Import, Error> {
+    let json = Json::parse(src).ok_or(Error::BadJson)?;
+    let pattern = std::iter::once(&json)
+        .chain(PATTERN_KEYS.iter().filter_map(|k| json.get(k)))
+        .find_map(|obj| obj.get("CORNERS"));
+    let cube = pattern.map(corners_to_cube).transpose()?;
+    let alg = json.get("alg").and_then(Json::as_str).map(str::to_string);
+    if cube.is_none() && alg.is_none() {
+        return Err(Error::NothingToImport);
+    }
+    Ok(Import { cube, alg })
+}
+
+fn corners_to_cube(orbit: &Json) -> Result<Cube, Error> {
+    let bad = Error::InvalidState(StateProblem::BadCorners);
+    let pieces = ["pieces", "permutation"]
+        .iter()
+        .find_map(|k| orbit.get(k))
+        .and_then(|v| small_ints(v, 8))
+        .ok_or_else(|| bad.clone())?;
+    let twists = match ["orientation", "orientationDelta"].iter().find_map(|k| orbit.get(k)) {
+        Some(v) => small_ints(v, 3).ok_or_else(|| bad.clone())?,
+        None => [0; 8],
+    };
+
+    let mut perm = [0u8; 8];
+    let mut ori = [0u8; 8];
+    let mut seen = 0u8;
+    for (s, (&p, &o)) in pieces.iter().zip(&twists).enumerate() {
+        if seen & (1 << p) != 0 {
+            return Err(bad);
+        }
+        seen |= 1 << p;
+        perm[SLOT_MAP[s]] = SLOT_MAP[p as usize] as u8;
+        ori[SLOT_MAP[s]] = (3 - o) % 3;
+    }
+    if ori.iter().map(|&o| o as u32).sum::<u32>() % 3 != 0 {
+        return Err(Error::InvalidState(StateProblem::Twisted));
+    }
+    Ok(PackedCube::from_indices(perm_rank(&perm), ori_rank(&ori)).unpack())
+}
+
+// Eight integers, each below `bound`.
+fn small_ints(v: &Json, bound: u8) -> Option<[u8; 8]> {
+    let Json::Arr(items) = v else { return None };
+    let vals: Vec<u8> = items
+        .iter()
+        .map(|x| match x {
+            Json::Num(n) if n.fract() == 0.0 && *n >= 0.0 && *n < bound as f64 => Some(*n as u8),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    vals.try_into().ok()
+}
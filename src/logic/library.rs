@@ -0,0 +1,91 @@
+// src/logic/library.rs
+
+//! Built-in algorithm library: the named algorithms a 2×2 solver reaches
+//! for, so the algorithm field can expand a typed name into its moves.
+//!
+//! Each last-layer algorithm leaves the D layer solved; the permutations
+//! keep every corner's twist.
+
+use std::fmt;
+
+use crate::t;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedAlg {
+    pub name: &'static str,
+    pub moves: &'static str,
+    pub set: AlgSet,
+}
+
+const fn named(set: AlgSet, name: &'static str, moves: &'static str) -> NamedAlg {
+    NamedAlg { name, moves, set }
+}
+
+/// Algorithms learned together. Every entry is in one of the sets after
+/// `All`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlgSet {
+    #[default]
+    All,
+    Oll,
+    Perm,
+    Trigger,
+}
+
+impl AlgSet {
+    pub const ALL: [AlgSet; 4] = [AlgSet::All, AlgSet::Oll, AlgSet::Perm, AlgSet::Trigger];
+
+    /// The library entries in the set, in library order.
+    pub fn algs(self) -> impl Iterator<Item = &'static NamedAlg> {
+        LIBRARY.iter().filter(move |a| self == AlgSet::All || a.set == self)
+    }
+}
+
+impl fmt::Display for AlgSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            AlgSet::All => t!("alg-set-all"),
+            AlgSet::Oll => t!("alg-set-oll"),
+            AlgSet::Perm => t!("alg-set-perm"),
+            AlgSet::Trigger => t!("alg-set-trigger"),
+        })
+    }
+}
+
+impl fmt::Display for NamedAlg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Last-layer orientation cases, then permutations, then triggers.
+pub const LIBRARY: [NamedAlg; 12] = [
+    named(AlgSet::Oll, "Sune", "R U R' U R U2 R'"),
+    named(AlgSet::Oll, "Anti-Sune", "R U2 R' U' R U' R'"),
+    named(AlgSet::Oll, "H-OLL", "R2 U2 R U2 R2"),
+    named(AlgSet::Oll, "Pi-OLL", "F R U R' U' R U R' U' F'"),
+    named(AlgSet::Oll, "U-OLL", "F R U R' U' F'"),
+    named(AlgSet::Oll, "T-OLL", "R U R' U' R' F R F'"),
+    named(AlgSet::Oll, "L-OLL", "F R' F' R U R U' R'"),
+    named(AlgSet::Perm, "T-perm", "R U R' U' R' F R2 U' R' U' R U R' F'"),
+    named(AlgSet::Perm, "Y-perm", "F R U' R' U' R U R' F' R U R' U' R' F R F'"),
+    named(AlgSet::Perm, "A-perm", "R' F R' B2 R F' R' B2 R2"),
+    named(AlgSet::Trigger, "Sexy move", "R U R' U'"),
+    named(AlgSet::Trigger, "Sledgehammer", "R' F R F'"),
+];
+
+/// Letters and digits only, lowercased, so "t perm", "tperm" and "T-perm"
+/// all match.
+fn fold(s: &str) -> String {
+    s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Library entries whose name starts with `prefix` (at least two letters,
+/// ignoring case and punctuation).
+pub fn complete(prefix: &str) -> Vec<&'static NamedAlg> {
+    let prefix = fold(prefix);
+    if prefix.chars().count() < 2 {
+        return Vec::new();
+    }
+    LIBRARY.iter().filter(|a| fold(a.name).starts_with(&prefix)).collect()
+}
@@ -0,0 +1,137 @@
+// src/logic/memo.rs
+
+//! Blindfolded memorization: a letter scheme naming every corner sticker
+//! position (Speffz by default) and the corner memo for a state, traced the
+//! Old Pochmann way from the UBL buffer.
+//!
+//! The cube is memorized as it is held: a sticker's target is where its
+//! color sits on the default solved cube, so a state that is only a
+//! whole-cube rotation away still needs a (long) memo.
+
+use std::fmt;
+
+use crate::cube::packed::CORNER_SLOTS;
+use crate::cube::{CornerPerm, Cube, FaceId, PackedCube};
+use crate::logic::tracking::Sticker;
+
+/// A letter for each sticker position, indexed like `Cube::face`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterScheme {
+    letters: [[[char; 2]; 2]; 6],
+}
+
+impl LetterScheme {
+    /// Speffz: A–D on U, then E–H L, I–L F, M–P R, Q–T B, U–X D, each face
+    /// clockwise from its top-left sticker.
+    pub const SPEFFZ: LetterScheme = LetterScheme {
+        letters: [
+            [['D', 'C'], ['A', 'B']], // U (row 0 along F)
+            [['X', 'W'], ['U', 'V']], // D (row 0 along B)
+            [['I', 'J'], ['L', 'K']], // F
+            [['Q', 'R'], ['T', 'S']], // B
+            [['E', 'F'], ['H', 'G']], // L
+            [['M', 'N'], ['P', 'O']], // R
+        ],
+    };
+
+    pub fn letter(&self, (f, r, c): Sticker) -> char {
+        self.letters[f as usize][r][c]
+    }
+
+    pub fn set(&mut self, (f, r, c): Sticker, letter: char) {
+        self.letters[f as usize][r][c] = letter;
+    }
+
+    /// The face's four letters, for drawing over its stickers.
+    pub fn face(&self, f: FaceId) -> [[char; 2]; 2] {
+        self.letters[f as usize]
+    }
+
+    /// Letters given to more than one position (case-insensitive), which
+    /// make a memo ambiguous.
+    pub fn duplicates(&self) -> Vec<char> {
+        let mut all: Vec<char> = self.letters.iter().flatten().flatten().flat_map(|c| c.to_uppercase()).collect();
+        all.sort_unstable();
+        let mut out: Vec<char> = all.windows(2).filter(|w| w[0] == w[1]).map(|w| w[0]).collect();
+        out.dedup();
+        out
+    }
+}
+
+impl Default for LetterScheme {
+    fn default() -> Self {
+        Self::SPEFFZ
+    }
+}
+
+/// Corner slot of the buffer (UBL; its U sticker is A in Speffz).
+const BUFFER: usize = 3;
+
+/// A corner memo: one letter per target, with parity when the count is odd.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Memo {
+    pub letters: Vec<char>,
+}
+
+impl Memo {
+    pub fn parity(&self) -> bool {
+        self.letters.len() % 2 == 1
+    }
+
+    /// Whether `typed` spells this memo (case, spaces and punctuation ignored).
+    pub fn matches(&self, typed: &str) -> bool {
+        let typed: Vec<char> = typed.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_uppercase).collect();
+        let want: Vec<char> = self.letters.iter().flat_map(|c| c.to_uppercase()).collect();
+        typed == want
+    }
+}
+
+/// Letter pairs, e.g. `CJ MX E`.
+impl fmt::Display for Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, pair) in self.letters.chunks(2).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            pair.iter().try_for_each(|c| write!(f, "{c}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Corner memo for `cube` under `scheme`; `None` for an illegal cube.
+///
+/// Each target is where the sticker in the buffer belongs; when the buffer
+/// holds its own piece, the cycle is broken into the first unsolved corner
+/// (twisted corners included) at its U/D sticker.
+pub fn corner_memo(cube: &Cube, scheme: &LetterScheme) -> Option<Memo> {
+    let perm = CornerPerm::from_packed(PackedCube::pack(cube)?);
+    // content[s][k]: the home (slot, sticker) of what sits at sticker k of slot s.
+    let mut content = [[(0u8, 0u8); 3]; 8];
+    for (s, stickers) in content.iter_mut().enumerate() {
+        for (k, home) in stickers.iter_mut().enumerate() {
+            *home = (perm.from[s], ((k + 3 - perm.twist[s] as usize) % 3) as u8);
+        }
+    }
+    let solved = |content: &[[(u8, u8); 3]; 8], s: usize| content[s][0] == (s as u8, 0);
+
+    let mut letters = Vec::new();
+    // Every shoot solves a piece or breaks into one, so this bounds the trace.
+    for _ in 0..24 {
+        let (slot, k) = match content[BUFFER][0] {
+            (s, k) if s as usize != BUFFER => (s as usize, k as usize),
+            _ => match (0..8).find(|&s| s != BUFFER && !solved(&content, s)) {
+                Some(s) => (s, 0),
+                None => break,
+            },
+        };
+        letters.push(scheme.letter(CORNER_SLOTS[slot][k]));
+        // Swap the buffer piece with the target, sticker k landing in the buffer's U/D spot.
+        for j in 0..3 {
+            let t = content[slot][(k + j) % 3];
+            content[slot][(k + j) % 3] = content[BUFFER][j];
+            content[BUFFER][j] = t;
+        }
+    }
+    Some(Memo { letters })
+}
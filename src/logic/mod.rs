@@ -1,4 +1,24 @@
 // src/logic/mod.rs
 
-//! High-level puzzle logic (scramble, future solving aides, etc.).
-pub mod scramble;
\ No newline at end of file
+//! High-level puzzle logic (scramble, solver, etc.).
+pub mod scramble;
+pub mod alg;
+pub mod describe;
+pub mod solver;
+pub mod first_face;
+pub mod verify;
+pub mod json;
+pub mod kpuzzle;
+pub mod regrip;
+pub mod subgroup;
+pub mod heat;
+pub mod tracking;
+pub mod memo;
+pub mod diagnose;
+pub mod diff;
+pub mod script;
+pub mod phases;
+pub mod pauses;
+pub mod library;
+pub mod console;
+pub mod stats;
@@ -0,0 +1,208 @@
+// src/logic/pauses.rs
+
+//! Pause analysis: where recorded solves stopped for longer than a
+//! threshold, what the cube looked like then, and, over a whole session,
+//! which kinds of case the hesitations came on. A pause is put down to the
+//! step the cube was in (see [`super::phases`]): building the face, an OLL
+//! case named from the algorithm library, or the PBL case, each layer
+//! solved, with an adjacent swap, or with a diagonal one.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::cube::{Alg, Cube, FaceId, Move, Turn};
+use crate::logic::library::AlgSet;
+use crate::logic::phases::Phase;
+use crate::t;
+
+/// Shortest gap between moves counted as a pause, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threshold(pub u16);
+
+impl Threshold {
+    pub const ALL: [Threshold; 4] = [Threshold(500), Threshold(1000), Threshold(1500), Threshold(2000)];
+
+    pub fn duration(self) -> Duration {
+        Duration::from_millis(u64::from(self.0))
+    }
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Threshold(1000)
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&t!("pause-threshold-value", secs = format!("{}", f32::from(self.0) / 1000.0)))
+    }
+}
+
+/// How one layer's corners sit once the layer is oriented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LayerPerm {
+    Solved,
+    Adjacent,
+    Diagonal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Case {
+    Face,
+    /// The library's name for the OLL case, if it has one.
+    Oll(Option<&'static str>),
+    /// The two layers, the more solved first.
+    Pbl(LayerPerm, LayerPerm),
+}
+
+impl fmt::Display for LayerPerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            LayerPerm::Solved => t!("layer-solved"),
+            LayerPerm::Adjacent => t!("layer-adjacent"),
+            LayerPerm::Diagonal => t!("layer-diagonal"),
+        })
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Case::Face => Phase::Face.to_string(),
+            Case::Oll(Some(name)) => t!("case-named", step = Phase::Oll.to_string(), name = *name),
+            Case::Oll(None) => t!("case-other", step = Phase::Oll.to_string()),
+            Case::Pbl(a, b) => t!("case-pbl", step = Phase::Pbl.to_string(), first = a.to_string(), second = b.to_string()),
+        })
+    }
+}
+
+/// Whole-cube turns as pairs of opposite face turns: on a 2×2, `x` is
+/// `R L'`.
+const X: [Move; 2] = [Move::new(FaceId::R, Turn::Cw), Move::new(FaceId::L, Turn::Ccw)];
+const Z: [Move; 2] = [Move::new(FaceId::F, Turn::Cw), Move::new(FaceId::B, Turn::Ccw)];
+
+/// The cube turned each of the six ways that bring a different face down.
+fn faces_down(cube: &Cube) -> impl Iterator<Item = Cube> + '_ {
+    let turns: [&[[Move; 2]]; 6] = [&[], &[X], &[X, X], &[X, X, X], &[Z], &[Z, Z, Z]];
+    turns.into_iter().map(|t| {
+        let mut c = cube.clone();
+        t.iter().flatten().for_each(|&m| c.apply(m));
+        c
+    })
+}
+
+fn uniform(cube: &Cube, f: FaceId) -> bool {
+    cube.face(f).iter().flatten().all(|&c| c == cube.face(f)[0][0])
+}
+
+/// A layer's corners from the bars on its four sides: every side a bar
+/// when solved, one when two corners are swapped side by side, none when
+/// swapped across.
+fn layer(cube: &Cube, row: usize) -> LayerPerm {
+    let bars = [FaceId::F, FaceId::R, FaceId::B, FaceId::L]
+        .into_iter()
+        .filter(|&f| cube.face(f)[row][0] == cube.face(f)[row][1])
+        .count();
+    match bars {
+        4 => LayerPerm::Solved,
+        0 => LayerPerm::Diagonal,
+        _ => LayerPerm::Adjacent,
+    }
+}
+
+/// The OLL algorithm in the library that, after some turn of the top,
+/// orients a cube with its face done on the bottom.
+fn oll_name(cube: &Cube) -> Option<&'static str> {
+    let u = Move::new(FaceId::U, Turn::Cw);
+    AlgSet::Oll.algs().find_map(|a| {
+        let alg = Alg::parse(a.moves).ok()?;
+        let mut c = cube.clone();
+        (0..4).any(|_| {
+            c.apply(u);
+            let mut t = c.clone();
+            t.apply_alg(&alg);
+            uniform(&t, FaceId::U) && uniform(&t, FaceId::D)
+        })
+        .then_some(a.name)
+    })
+}
+
+/// The case `cube` is at; `None` when solved.
+pub fn classify(cube: &Cube) -> Option<Case> {
+    if cube.is_solved() {
+        return None;
+    }
+    if !Phase::Face.done(cube) {
+        return Some(Case::Face);
+    }
+    if !Phase::Oll.done(cube) {
+        // Any face done may be the one the solver built.
+        let named = faces_down(cube).filter(|c| uniform(c, FaceId::D)).find_map(|c| oll_name(&c));
+        return Some(Case::Oll(named));
+    }
+    let c = faces_down(cube).find(|c| uniform(c, FaceId::U) && uniform(c, FaceId::D))?;
+    let (top, bottom) = (layer(&c, 0), layer(&c, 1));
+    Some(Case::Pbl(top.min(bottom), top.max(bottom)))
+}
+
+/// One pause in a solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pause {
+    /// The solve it was in, from 0, and the move it came before, from 1.
+    pub solve: usize,
+    pub before: usize,
+    pub length: Duration,
+    /// The cube during the pause, and its case.
+    pub state: Cube,
+    pub case: Option<Case>,
+}
+
+/// The pauses of `threshold` or longer in a solve of `moves` from
+/// `start`, the move `i` made `times[i]` after the scramble. The wait
+/// before the first move is inspection, not a pause.
+pub fn find(solve: usize, start: &Cube, moves: &[Move], times: &[Duration], threshold: Duration) -> Vec<Pause> {
+    let mut cube = start.clone();
+    let mut out = Vec::new();
+    for (i, (&m, pair)) in moves.iter().zip(times.windows(2).map(Some).chain([None])).enumerate() {
+        cube.apply(m);
+        let Some(&[made, next]) = pair else { continue };
+        let length = next.saturating_sub(made);
+        if length >= threshold {
+            out.push(Pause { solve, before: i + 2, length, state: cube.clone(), case: classify(&cube) });
+        }
+    }
+    out
+}
+
+/// Pauses on one kind of case, over a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaseStats {
+    pub case: Option<Case>,
+    pub count: usize,
+    pub total: Duration,
+    pub longest: Duration,
+}
+
+impl CaseStats {
+    pub fn mean(&self) -> Duration {
+        self.total / self.count.max(1) as u32
+    }
+}
+
+/// `pauses` grouped by case, the longest hesitation in all first.
+pub fn by_case(pauses: &[Pause]) -> Vec<CaseStats> {
+    let mut out: Vec<CaseStats> = Vec::new();
+    for p in pauses {
+        match out.iter_mut().find(|s| s.case == p.case) {
+            Some(s) => {
+                s.count += 1;
+                s.total += p.length;
+                s.longest = s.longest.max(p.length);
+            }
+            None => out.push(CaseStats { case: p.case, count: 1, total: p.length, longest: p.length }),
+        }
+    }
+    out.sort_by(|a, b| b.total.cmp(&a.total).then(b.longest.cmp(&a.longest)));
+    out
+}
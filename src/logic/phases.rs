@@ -0,0 +1,148 @@
+// src/logic/phases.rs
+
+//! Step-by-step breakdown of a timed solve, as reconstruction sites show
+//! it. The solve is cut into the Ortega steps (a first face, the opposite
+//! face oriented, then both layers permuted) by recognizing on the cube
+//! when each is done, and each step gets its moves, time, the pause before
+//! its first move (recognition), and turns per second. Pauses anywhere in
+//! the solve are listed too.
+//!
+//! A step done by the same move as the one before is a skip: no moves, no
+//! time.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::cube::{Cube, FaceId, Move};
+use crate::logic::script::Step;
+use crate::t;
+
+/// Gaps between moves this long or longer count as pauses.
+pub const PAUSE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Any face one color.
+    Face,
+    /// That face and the opposite one each one color.
+    Oll,
+    /// Solved.
+    Pbl,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Face, Phase::Oll, Phase::Pbl];
+
+    /// Whether the step is done on `cube`.
+    pub fn done(self, cube: &Cube) -> bool {
+        let uniform = |f: FaceId| cube.face(f).iter().flatten().all(|&c| c == cube.face(f)[0][0]);
+        let pairs = [(FaceId::U, FaceId::D), (FaceId::F, FaceId::B), (FaceId::L, FaceId::R)];
+        match self {
+            Phase::Face => FaceId::ALL.into_iter().any(uniform),
+            Phase::Oll => pairs.into_iter().any(|(a, b)| uniform(a) && uniform(b)),
+            Phase::Pbl => cube.is_solved(),
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            Phase::Face => t!("phase-face"),
+            Phase::Oll => t!("phase-oll"),
+            Phase::Pbl => t!("phase-pbl"),
+        })
+    }
+}
+
+/// One step of the solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Split {
+    pub phase: Phase,
+    pub moves: usize,
+    /// From the end of the step before (or the start of the solve) to the
+    /// step's last move.
+    pub time: Duration,
+    /// From the end of the step before to the step's first move.
+    pub recognition: Duration,
+    /// Whether the step was finished; the solve may stop short.
+    pub done: bool,
+}
+
+impl Split {
+    /// Turns per second over the whole step.
+    pub fn tps(&self) -> f32 {
+        per_second(self.moves, self.time)
+    }
+
+    /// Turns per second once recognized.
+    pub fn execution_tps(&self) -> f32 {
+        per_second(self.moves, self.time.saturating_sub(self.recognition))
+    }
+}
+
+fn per_second(moves: usize, time: Duration) -> f32 {
+    if time.is_zero() { 0.0 } else { moves as f32 / time.as_secs_f32() }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakdown {
+    pub splits: Vec<Split>,
+    /// Time of the last move.
+    pub total: Duration,
+    pub moves: usize,
+    /// Each pause: the move it came before (from 1) and how long it was.
+    pub pauses: Vec<(usize, Duration)>,
+}
+
+impl Breakdown {
+    pub fn tps(&self) -> f32 {
+        per_second(self.moves, self.total)
+    }
+
+    /// Time spent in pauses.
+    pub fn paused(&self) -> Duration {
+        self.pauses.iter().map(|&(_, d)| d).sum()
+    }
+}
+
+/// Break down the solve of `steps` from `start`; `None` unless every move
+/// is a turn with its time recorded.
+pub fn breakdown(start: &Cube, steps: &[Step]) -> Option<Breakdown> {
+    let mut cube = start.clone();
+    let mut phases = Phase::ALL.into_iter().peekable();
+    let mut splits = Vec::new();
+    let new_split = |phase| Split { phase, moves: 0, time: Duration::ZERO, recognition: Duration::ZERO, done: false };
+    // Steps already done before the first move.
+    while let Some(phase) = phases.next_if(|p| p.done(&cube)) {
+        splits.push(Split { done: true, ..new_split(phase) });
+    }
+    let mut current = phases.next().map(new_split);
+    let (mut split_start, mut prev) = (Duration::ZERO, Duration::ZERO);
+    let mut pauses = Vec::new();
+    for (i, step) in steps.iter().enumerate() {
+        let at = step.at?;
+        cube.apply(Move::parse(&step.token)?);
+        // The wait before the first move is inspection, not a pause.
+        let gap = at.saturating_sub(prev);
+        if i > 0 && gap >= PAUSE {
+            pauses.push((i + 1, gap));
+        }
+        prev = at;
+        let Some(split) = current.as_mut() else { continue };
+        if split.moves == 0 {
+            split.recognition = at.saturating_sub(split_start);
+        }
+        split.moves += 1;
+        // One move can finish several steps; the later ones are skips.
+        while let Some(split) = current.take_if(|s| s.phase.done(&cube)) {
+            splits.push(Split { time: at.saturating_sub(split_start), done: true, ..split });
+            split_start = at;
+            current = phases.next().map(new_split);
+        }
+    }
+    if let Some(split) = current.filter(|s| s.moves > 0) {
+        splits.push(Split { time: prev.saturating_sub(split_start), ..split });
+    }
+    Some(Breakdown { splits, total: prev, moves: steps.len(), pauses })
+}
@@ -0,0 +1,274 @@
+// src/logic/regrip.rs
+
+//! Regrip hints: rewrite an algorithm with whole-cube rotations (x, y, z)
+//! where they make it cheaper to execute under a [`MoveCosts`] model, e.g.
+//! `y` before a run of B turns so they become R turns.
+//!
+//! The search tracks which way the cube is being held (24 orientations).
+//! Before each turn it may rotate (cheapest rotation path between
+//! orientations), then performs the turn under whatever face name it now
+//! has. Plain HTM optimality is the special case where rotations are too
+//! expensive to pay off.
+
+use std::fmt;
+
+use crate::cube::{Alg, FaceId, Move, Turn};
+use crate::Error;
+
+/// Per-face turn costs, a half-turn multiplier, and rotation costs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveCosts {
+    /// Indexed by `FaceId as usize` (U, D, F, B, L, R).
+    pub face: [f32; 6],
+    /// Multiplier for half turns (face turns and rotations alike).
+    pub half: f32,
+    /// Quarter rotation costs about x, y, z.
+    pub rotation: [f32; 3],
+}
+
+impl Default for MoveCosts {
+    fn default() -> Self {
+        Self { face: [1.0, 1.6, 1.5, 2.5, 1.4, 1.0], half: 1.3, rotation: [1.6, 1.2, 2.2] }
+    }
+}
+
+const FACE_KEYS: [(&str, FaceId); 6] = [
+    ("U", FaceId::U), ("D", FaceId::D), ("F", FaceId::F),
+    ("B", FaceId::B), ("L", FaceId::L), ("R", FaceId::R),
+];
+
+impl MoveCosts {
+    /// Every turn costs 1: plain move count.
+    pub const HTM: MoveCosts = MoveCosts { face: [1.0; 6], half: 1.0, rotation: [1.0; 3] };
+
+    /// Parse `key=cost` pairs (`U`…`R`, `half`, `x`, `y`, `z`); keys not
+    /// given keep their defaults.
+    pub fn parse(s: &str) -> Result<MoveCosts, Error> {
+        let mut out = MoveCosts::default();
+        for tok in s.split_whitespace() {
+            let bad = || Error::BadCost { token: tok.to_string() };
+            let (k, v) = tok.split_once('=').ok_or_else(bad)?;
+            let v: f32 = v.parse().ok().filter(|v: &f32| v.is_finite() && *v >= 0.0).ok_or_else(bad)?;
+            // Free turns would let the weighted solver search forever.
+            if v == 0.0 && !matches!(k, "x" | "y" | "z") {
+                return Err(bad());
+            }
+            let slot = match k {
+                "half" => &mut out.half,
+                "x" => &mut out.rotation[0],
+                "y" => &mut out.rotation[1],
+                "z" => &mut out.rotation[2],
+                _ => {
+                    let (_, f) = FACE_KEYS.iter().find(|(name, _)| *name == k).ok_or_else(bad)?;
+                    &mut out.face[*f as usize]
+                }
+            };
+            *slot = v;
+        }
+        Ok(out)
+    }
+
+    pub fn turn(&self, m: Move) -> f32 {
+        self.face[m.face as usize] * self.turn_factor(m.turn)
+    }
+
+    pub fn rotate(&self, r: Rotation) -> f32 {
+        self.rotation[r.axis as usize] * self.turn_factor(r.turn)
+    }
+
+    /// Cost of `alg` as written, with no rotations.
+    pub fn alg(&self, alg: &Alg) -> f32 {
+        alg.0.iter().map(|&m| self.turn(m)).sum()
+    }
+
+    fn turn_factor(&self, t: Turn) -> f32 {
+        if t == Turn::Half { self.half } else { 1.0 }
+    }
+}
+
+impl fmt::Display for MoveCosts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, face) in FACE_KEYS {
+            write!(f, "{name}={} ", self.face[face as usize])?;
+        }
+        write!(f, "half={} x={} y={} z={}", self.half, self.rotation[0], self.rotation[1], self.rotation[2])
+    }
+}
+
+/// Whole-cube rotation axis: x follows R, y follows U, z follows F.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis { X, Y, Z }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub axis: Axis,
+    pub turn: Turn,
+}
+
+impl Rotation {
+    const ALL: [Rotation; 9] = {
+        use Axis::*;
+        use Turn::*;
+        [
+            Rotation { axis: X, turn: Cw }, Rotation { axis: X, turn: Ccw }, Rotation { axis: X, turn: Half },
+            Rotation { axis: Y, turn: Cw }, Rotation { axis: Y, turn: Ccw }, Rotation { axis: Y, turn: Half },
+            Rotation { axis: Z, turn: Cw }, Rotation { axis: Z, turn: Ccw }, Rotation { axis: Z, turn: Half },
+        ]
+    };
+
+    // Where each face position ends up after a clockwise quarter rotation.
+    fn quarter(axis: Axis, f: FaceId) -> FaceId {
+        use FaceId::*;
+        match (axis, f) {
+            (Axis::X, F) => U, (Axis::X, U) => B, (Axis::X, B) => D, (Axis::X, D) => F,
+            (Axis::Y, F) => L, (Axis::Y, L) => B, (Axis::Y, B) => R, (Axis::Y, R) => F,
+            (Axis::Z, U) => R, (Axis::Z, R) => D, (Axis::Z, D) => L, (Axis::Z, L) => U,
+            (_, f) => f,
+        }
+    }
+
+    fn map(self, f: FaceId) -> FaceId {
+        let quarters = match self.turn { Turn::Cw => 1, Turn::Half => 2, Turn::Ccw => 3 };
+        (0..quarters).fold(f, |f, _| Self::quarter(self.axis, f))
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.axis { Axis::X => "x", Axis::Y => "y", Axis::Z => "z" })?;
+        match self.turn {
+            Turn::Cw => Ok(()),
+            Turn::Ccw => f.write_str("'"),
+            Turn::Half => f.write_str("2"),
+        }
+    }
+}
+
+/// One step of a regripped algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Turn(Move),
+    Rotate(Rotation),
+}
+
+/// An algorithm rewritten with rotations, and its cost before and after.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regripped {
+    pub steps: Vec<Step>,
+    pub cost: f32,
+    pub plain_cost: f32,
+}
+
+impl Regripped {
+    pub fn rotations(&self) -> usize {
+        self.steps.iter().filter(|s| matches!(s, Step::Rotate(_))).count()
+    }
+}
+
+impl fmt::Display for Regripped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, s) in self.steps.iter().enumerate() {
+            if i > 0 { f.write_str(" ")?; }
+            match s {
+                Step::Turn(m) => write!(f, "{m}")?,
+                Step::Rotate(r) => write!(f, "{r}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// ----- search -----
+
+/// A grip: the name each original face position is turned by now.
+type Grip = [FaceId; 6];
+
+const IDENTITY: Grip = [FaceId::U, FaceId::D, FaceId::F, FaceId::B, FaceId::L, FaceId::R];
+
+/// The cheapest way to execute `alg` under `costs`, inserting rotations.
+pub fn regrip(alg: &Alg, costs: &MoveCosts) -> Regripped {
+    let (grips, paths) = rotation_paths(costs);
+    let n = grips.len();
+
+    // best[g] = (cost, back-pointer) after the moves so far, holding grip g.
+    let mut best: Vec<Option<f32>> = vec![None; n];
+    best[0] = Some(0.0);
+    let mut back: Vec<Vec<usize>> = Vec::with_capacity(alg.len());
+    for &m in &alg.0 {
+        let mut next = vec![None; n];
+        let mut from = vec![0; n];
+        for (g2, grip) in grips.iter().enumerate() {
+            let turn = costs.turn(Move::new(grip[m.face as usize], m.turn));
+            for (g1, c1) in best.iter().enumerate() {
+                let Some(c1) = c1 else { continue };
+                let c = c1 + paths[g1][g2].0 + turn;
+                if next[g2].is_none_or(|b| c < b) {
+                    next[g2] = Some(c);
+                    from[g2] = g1;
+                }
+            }
+        }
+        best = next;
+        back.push(from);
+    }
+
+    let (mut g, cost) = best
+        .iter()
+        .enumerate()
+        .filter_map(|(g, c)| c.map(|c| (g, c)))
+        .fold((0, f32::INFINITY), |acc, (g, c)| if c < acc.1 { (g, c) } else { acc });
+    let mut steps = Vec::new();
+    for (i, &m) in alg.0.iter().enumerate().rev() {
+        steps.push(Step::Turn(Move::new(grips[g][m.face as usize], m.turn)));
+        let prev = back[i][g];
+        steps.extend(paths[prev][g].1.iter().rev().map(|&r| Step::Rotate(r)));
+        g = prev;
+    }
+    steps.reverse();
+    let cost = if alg.is_empty() { 0.0 } else { cost };
+    Regripped { steps, cost, plain_cost: costs.alg(alg) }
+}
+
+/// All 24 grips (identity first) and the cheapest rotation sequence
+/// between every pair.
+#[allow(clippy::type_complexity)]
+fn rotation_paths(costs: &MoveCosts) -> (Vec<Grip>, Vec<Vec<(f32, Vec<Rotation>)>>) {
+    let mut grips = vec![IDENTITY];
+    let mut i = 0;
+    while i < grips.len() {
+        for r in Rotation::ALL {
+            let g = grips[i].map(|f| r.map(f));
+            if !grips.contains(&g) {
+                grips.push(g);
+            }
+        }
+        i += 1;
+    }
+    let n = grips.len();
+    let index = |g: &Grip| grips.iter().position(|x| x == g).unwrap();
+
+    let mut paths: Vec<Vec<(f32, Vec<Rotation>)>> =
+        (0..n).map(|a| (0..n).map(|b| (if a == b { 0.0 } else { f32::INFINITY }, Vec::new())).collect()).collect();
+    for (a, grip) in grips.iter().enumerate() {
+        for r in Rotation::ALL {
+            let b = index(&grip.map(|f| r.map(f)));
+            if costs.rotate(r) < paths[a][b].0 {
+                paths[a][b] = (costs.rotate(r), vec![r]);
+            }
+        }
+    }
+    // Floyd–Warshall over 24 nodes.
+    for k in 0..n {
+        for a in 0..n {
+            for b in 0..n {
+                let via = paths[a][k].0 + paths[k][b].0;
+                if via < paths[a][b].0 {
+                    let mut seq = paths[a][k].1.clone();
+                    seq.extend_from_slice(&paths[k][b].1);
+                    paths[a][b] = (via, seq);
+                }
+            }
+        }
+    }
+    (grips, paths)
+}
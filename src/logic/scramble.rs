@@ -4,21 +4,78 @@
 
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use rand::rngs::StdRng;
-use rand::SeedableRng;
 
-const MOVES: &[&str] = &[
-    "U","U'","U2","D","D'","D2","R","R'","R2","L","L'","L2","F","F'","F2","B","B'","B2",
-];
+use crate::cube::{Alg, Move};
+
+/// Default length for generated scrambles.
+pub const SCRAMBLE_LEN: usize = 15;
 
 /// Generate a random scramble of `len` tokens using thread RNG.
 pub fn random_scramble(len: usize) -> String {
     let mut rng = thread_rng();
-    (0..len).map(|_| *MOVES.choose(&mut rng).unwrap()).collect::<Vec<_>>().join(" ")
+    Alg((0..len).map(|_| *Move::ALL.choose(&mut rng).unwrap()).collect()).to_string()
 }
 
 /// Generate a deterministic scramble of `len` tokens from a `seed`.
 pub fn scramble_with_seed(len: usize, seed: u64) -> String {
-    let mut rng = StdRng::seed_from_u64(seed);
-    (0..len).map(|_| *MOVES.choose(&mut rng).unwrap()).collect::<Vec<_>>().join(" ")
-}
\ No newline at end of file
+    seeded_alg(len, seed).to_string()
+}
+
+/// A seed as typed in the seed field; `None` unless it is a whole number.
+pub fn parse_seed(s: &str) -> Option<u64> {
+    s.trim().parse().ok()
+}
+
+/// Pre-parsed form of `scramble_with_seed` (same sequence for the same seed).
+///
+/// Seeded scrambles are shared (the daily challenge, seeds typed in or
+/// passed in links), so they are drawn with SplitMix64 and a fixed way of
+/// picking a move rather than a library RNG, whose stream may change
+/// between versions or platforms.
+pub fn seeded_alg(len: usize, seed: u64) -> Alg {
+    let mut rng = SplitMix64(seed);
+    Alg((0..len).map(|_| Move::ALL[rng.below(Move::ALL.len() as u64) as usize]).collect())
+}
+
+/// SplitMix64 (Steele, Lea and Flood), whose output is fixed by its
+/// definition.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, rejecting draws from the uneven top of the range.
+    fn below(&mut self, n: u64) -> u64 {
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitmix_matches_reference_output() {
+        let mut rng = SplitMix64(0);
+        assert_eq!(rng.next(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next(), 0x6e78_9e6a_a1b9_65f4);
+    }
+
+    #[test]
+    fn seeded_scrambles_repeat() {
+        assert_eq!(seeded_alg(SCRAMBLE_LEN, 42), seeded_alg(SCRAMBLE_LEN, 42));
+        assert_ne!(seeded_alg(SCRAMBLE_LEN, 42), seeded_alg(SCRAMBLE_LEN, 43));
+    }
+}
@@ -0,0 +1,208 @@
+// src/logic/script.rs
+
+//! Annotated solve scripts: a solve written out as Markdown, with the
+//! scramble, a note on it, and one line per move carrying the time since the
+//! scramble and an optional comment. Scripts read back in to be replayed.
+//!
+//! ```text
+//! # Solve script
+//!
+//! ## Scramble
+//!
+//! `R U2 F' R2 U'`
+//!
+//! > white on the bottom
+//!
+//! ## Solution
+//!
+//! 1. [0:02.40] `R` — first pair
+//! 2. [0:02.95] `U'`
+//! ```
+//!
+//! The scramble may also be the 24 facelet letters of the starting state
+//! (`Cube::to_facelets` order) when no scramble algorithm is known. Times
+//! are optional; reading is lenient about list markers, the dash before a
+//! comment, and anything outside the two sections.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::cube::{Cube, Move};
+use crate::logic::alg::parse_alg;
+use crate::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub token: String,
+    /// Time since the scramble, if recorded.
+    pub at: Option<Duration>,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveScript {
+    /// Scramble algorithm or starting facelets, as written.
+    pub scramble: String,
+    /// The state the solution starts from.
+    pub start: Cube,
+    /// Comment on the scramble itself.
+    pub note: String,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Other,
+    Scramble,
+    Solution,
+}
+
+impl SolveScript {
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Solve script\n\n## Scramble\n\n");
+        let _ = writeln!(out, "`{}`", self.scramble);
+        if !self.note.is_empty() {
+            out.push('\n');
+            for line in self.note.lines() {
+                let _ = writeln!(out, "> {line}");
+            }
+        }
+        out.push_str("\n## Solution\n\n");
+        for (i, step) in self.steps.iter().enumerate() {
+            let _ = write!(out, "{}. ", i + 1);
+            if let Some(at) = step.at {
+                let _ = write!(out, "[{}] ", fmt_time(at));
+            }
+            let _ = write!(out, "`{}`", step.token);
+            if !step.comment.is_empty() {
+                let _ = write!(out, " — {}", step.comment);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Read a script written by [`to_markdown`](Self::to_markdown) or by
+    /// hand.
+    pub fn parse(text: &str) -> Result<SolveScript, Error> {
+        let mut section = Section::Other;
+        let mut start = None;
+        let mut note = Vec::new();
+        let mut steps = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if let Some(heading) = line.strip_prefix("##") {
+                section = match heading.trim().to_ascii_lowercase().as_str() {
+                    "scramble" => Section::Scramble,
+                    "solution" => Section::Solution,
+                    _ => Section::Other,
+                };
+                continue;
+            }
+            match section {
+                Section::Scramble => {
+                    if let Some(quote) = line.strip_prefix('>') {
+                        note.push(quote.trim().to_string());
+                    } else if let Some((code, _)) = code_span(line).filter(|_| start.is_none()) {
+                        start = Some((code.to_string(), parse_start(code)?));
+                    }
+                }
+                Section::Solution if !line.is_empty() => {
+                    steps.push(parse_step(line).ok_or_else(|| Error::ScriptLine { line: n + 1, text: line.to_string() })?);
+                }
+                _ => {}
+            }
+        }
+        let (scramble, start) = start.ok_or(Error::NoScramble)?;
+        Ok(SolveScript { scramble, start, note: note.join("\n"), steps })
+    }
+}
+
+/// `m:ss.cc`, e.g. `1:02.35`.
+pub fn fmt_time(d: Duration) -> String {
+    let cs = d.as_millis() / 10;
+    format!("{}:{:02}.{:02}", cs / 6000, cs / 100 % 60, cs % 100)
+}
+
+/// [`fmt_time`] without the minutes under one, e.g. `4.56`: how forums
+/// write times.
+pub fn fmt_time_short(d: Duration) -> String {
+    let long = fmt_time(d);
+    match long.strip_prefix("0:") {
+        Some(secs) => secs.strip_prefix('0').filter(|s| !s.starts_with('.')).unwrap_or(secs).to_string(),
+        None => long,
+    }
+}
+
+/// `m:ss.cc` or plain seconds; `None` if malformed, negative, or too long
+/// for a [`Duration`].
+pub fn parse_time(s: &str) -> Option<Duration> {
+    let (min, sec) = match s.split_once(':') {
+        Some((m, s)) => (m.parse::<u64>().ok()?, s),
+        None => (0, s),
+    };
+    let sec = Duration::try_from_secs_f64(sec.parse().ok()?).ok()?;
+    Duration::from_secs(min.checked_mul(60)?).checked_add(sec)
+}
+
+/// The first `` `code` `` in `line` and the text after it.
+fn code_span(line: &str) -> Option<(&str, &str)> {
+    let (_, rest) = line.split_once('`')?;
+    rest.split_once('`')
+}
+
+fn parse_start(code: &str) -> Result<Cube, Error> {
+    let letters: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(cube) = Cube::from_facelets(&letters) {
+        return Ok(cube);
+    }
+    let alg = parse_alg(code)?;
+    let mut cube = Cube::default();
+    cube.apply_alg(&alg);
+    Ok(cube)
+}
+
+/// `1. [0:02.40] `R` — comment`; the number, time, and comment are optional.
+fn parse_step(line: &str) -> Option<Step> {
+    let line = line.trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '.' | ')' | '-' | '*' | ' '));
+    let at = match line.strip_prefix('[') {
+        Some(rest) => {
+            let (time, _) = rest.split_once(']')?;
+            Some(parse_time(time.trim())?)
+        }
+        None => None,
+    };
+    let (token, rest) = code_span(line)?;
+    Move::parse(token.trim())?;
+    let comment = rest.trim().trim_start_matches(['—', '–', '-', ':']).trim();
+    Some(Step { token: token.trim().to_string(), at, comment: comment.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_round_trip() {
+        let d = Duration::from_millis(62_350);
+        assert_eq!(fmt_time(d), "1:02.35");
+        assert_eq!(parse_time("1:02.35"), Some(d));
+        assert_eq!(parse_time("62.35"), Some(d));
+    }
+
+    #[test]
+    fn short_times_drop_zero_minutes() {
+        let ms = |n| fmt_time_short(Duration::from_millis(n));
+        assert_eq!(ms(500), "0.50");
+        assert_eq!(ms(4_567), "4.56");
+        assert_eq!(ms(12_340), "12.34");
+        assert_eq!(ms(62_350), "1:02.35");
+    }
+
+    #[test]
+    fn bad_times_are_refused() {
+        for s in ["", "x", "-1", "NaN", "inf", "1:-2", "1e30", "99999999999999999999:00", "307445734561825861:00"] {
+            assert_eq!(parse_time(s), None, "{s}");
+        }
+    }
+}
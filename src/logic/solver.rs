@@ -0,0 +1,451 @@
+// src/logic/solver.rs
+
+//! Optimal solver backed by a full distance table.
+//!
+//! Any 2×2 position can be solved without turning the D, L, or B faces
+//! (turning L is turning R plus a whole-cube rotation). The solver first
+//! recolors the cube so the piece in the DLB slot reads as the home DLB
+//! corner, then works in the U/R/F subgroup where that corner never moves:
+//! 7! × 3^6 = 3,674,160 states, one byte of distance each.
+
+use std::cell::Cell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::cube::packed::{CORNER_SLOTS, ORI_COUNT, PERM_COUNT, perm_unrank};
+use crate::cube::{Alg, Col, Cube, FaceId, Move, MoveTables, PackedCube, Turn};
+use crate::error::{Error, StateProblem};
+use crate::logic::regrip::MoveCosts;
+
+/// Number of states in the U/R/F subgroup (7! × 3^6).
+pub const STATE_COUNT: usize = 5_040 * 729;
+
+/// Bumped whenever the table layout or indexing changes; cached tables with
+/// another version are discarded.
+pub const TABLE_VERSION: u32 = 1;
+
+const MAGIC: &[u8; 4] = b"RBDT";
+const HEADER_LEN: usize = 4 + 4 + 8;
+const UNSEEN: u8 = u8::MAX;
+
+/// The nine turns the table is built from.
+const URF_MOVES: [Move; 9] = {
+    use FaceId::*;
+    use Turn::*;
+    [
+        Move::new(U, Cw), Move::new(U, Ccw), Move::new(U, Half),
+        Move::new(R, Cw), Move::new(R, Ccw), Move::new(R, Half),
+        Move::new(F, Cw), Move::new(F, Ccw), Move::new(F, Half),
+    ]
+};
+
+/// Distance-to-solved for every normalized state.
+pub struct DistanceTable {
+    dist: Vec<u8>,
+    index: Indexing,
+}
+
+impl fmt::Debug for DistanceTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DistanceTable({} states)", self.dist.len())
+    }
+}
+
+impl DistanceTable {
+    /// Breadth-first search from solved over the U/R/F turns.
+    pub fn build() -> Self {
+        let index = Indexing::new();
+        let tables = MoveTables::get();
+        let mut dist = vec![UNSEEN; STATE_COUNT];
+        dist[index.dense(PackedCube::SOLVED)] = 0;
+
+        let mut depth = 0u8;
+        let mut found = true;
+        while found {
+            found = false;
+            for i in 0..STATE_COUNT {
+                if dist[i] != depth {
+                    continue;
+                }
+                let state = index.packed(i);
+                for &m in &URF_MOVES {
+                    let j = index.dense(tables.apply(state, m));
+                    if dist[j] == UNSEEN {
+                        dist[j] = depth + 1;
+                        found = true;
+                    }
+                }
+            }
+            depth += 1;
+        }
+        Self { dist, index }
+    }
+
+    /// Optimal move count for `cube`, or `None` if it isn't a legal state.
+    pub fn distance(&self, cube: &Cube) -> Option<u8> {
+        let state = normalize(cube)?;
+        Some(self.dist[self.index.dense(state)])
+    }
+
+    /// An optimal solution using U, R, and F turns only.
+    pub fn solve(&self, cube: &Cube) -> Option<Alg> {
+        let tables = MoveTables::get();
+        let mut state = normalize(cube)?;
+        let mut d = self.dist[self.index.dense(state)];
+        let mut moves = Vec::with_capacity(d as usize);
+        while d > 0 {
+            let (m, next) = URF_MOVES
+                .iter()
+                .map(|&m| (m, tables.apply(state, m)))
+                .find(|&(_, s)| self.dist[self.index.dense(s)] == d - 1)?;
+            moves.push(m);
+            state = next;
+            d -= 1;
+        }
+        Some(Alg(moves))
+    }
+
+    /// A solution of least total cost under `costs` (face turns only; the
+    /// rotation costs are ignored), using all 18 turns: an expensive face
+    /// may be swapped for its opposite, since solved is solved whichever
+    /// way the cube ends up held.
+    pub fn solve_weighted(&self, cube: &Cube, costs: &MoveCosts, budget: Duration) -> Result<Alg, Error> {
+        self.solve_restricted(cube, costs, &FaceId::ALL, budget)
+    }
+
+    /// Least-cost solution turning only `faces` (e.g. ⟨R, U⟩), giving up
+    /// with [`Error::SolverTimeout`] once `budget` is spent. Check
+    /// [`reachable`] first: an unreachable search runs until the budget.
+    ///
+    /// IDA* with the table as heuristic: a state `d` turns from solved
+    /// costs at least `d` times the cheapest turn (restricting the moves
+    /// only makes solutions longer). Face costs must be positive, which
+    /// `MoveCosts::parse` ensures.
+    pub fn solve_restricted(&self, cube: &Cube, costs: &MoveCosts, faces: &[FaceId], budget: Duration) -> Result<Alg, Error> {
+        let moves: Vec<Move> = Move::ALL.into_iter().filter(|m| faces.contains(&m.face)).collect();
+        let search = WeightedSearch {
+            table: self,
+            costs,
+            cheapest: moves.iter().map(|&m| costs.turn(m)).fold(f32::INFINITY, f32::min),
+            moves: &moves,
+            deadline: Instant::now() + budget,
+            timed_out: Cell::new(false),
+        };
+        let d = self.distance(cube).ok_or(Error::InvalidState(StateProblem::Illegal))?;
+        let mut bound = f32::from(d) * search.cheapest;
+        let mut path = Vec::new();
+        loop {
+            match search.run(cube, 0.0, bound, None, &mut path) {
+                Ok(()) => return Ok(Alg(path)),
+                Err(_) if search.timed_out.get() => return Err(Error::SolverTimeout),
+                Err(next) if next.is_finite() => bound = next,
+                Err(_) => return Err(Error::Unreachable { faces: faces.to_vec() }),
+            }
+        }
+    }
+
+    /// Deepest state in the table (God's number for this metric).
+    pub fn max_depth(&self) -> u8 {
+        self.dist.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Serialize as `RBDT`, version, checksum (FNV-1a of the body), body.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.dist.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&TABLE_VERSION.to_le_bytes());
+        out.extend_from_slice(&checksum(&self.dist).to_le_bytes());
+        out.extend_from_slice(&self.dist);
+        out
+    }
+
+    /// Inverse of [`DistanceTable::to_bytes`]; rejects other versions,
+    /// truncated files, and checksum mismatches.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != HEADER_LEN + STATE_COUNT || &bytes[..4] != MAGIC {
+            return Err(Error::CorruptTable("not a distance table".into()));
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != TABLE_VERSION {
+            return Err(Error::CorruptTable(format!("table version {version}, expected {TABLE_VERSION}")));
+        }
+        let sum = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let dist = bytes[HEADER_LEN..].to_vec();
+        if checksum(&dist) != sum {
+            return Err(Error::CorruptTable("checksum mismatch".into()));
+        }
+        Ok(Self { dist, index: Indexing::new() })
+    }
+}
+
+// ----- weighted search -----
+
+/// Slack for float cost comparisons.
+const COST_EPS: f32 = 1e-4;
+
+struct WeightedSearch<'a> {
+    table: &'a DistanceTable,
+    costs: &'a MoveCosts,
+    cheapest: f32,
+    moves: &'a [Move],
+    deadline: Instant,
+    /// Set when a pass stopped at the deadline.
+    timed_out: Cell<bool>,
+}
+
+impl WeightedSearch<'_> {
+    /// Depth-first pass under `bound`; `Err` carries the smallest total
+    /// estimate that exceeded it (the next bound).
+    fn run(&self, cube: &Cube, spent: f32, bound: f32, last: Option<FaceId>, path: &mut Vec<Move>) -> Result<(), f32> {
+        if self.timed_out.get() || Instant::now() >= self.deadline {
+            self.timed_out.set(true);
+            return Err(f32::INFINITY);
+        }
+        let Some(d) = self.table.distance(cube) else { return Err(f32::INFINITY) };
+        let estimate = spent + f32::from(d) * self.cheapest;
+        if estimate > bound + COST_EPS {
+            return Err(estimate);
+        }
+        if d == 0 {
+            return Ok(());
+        }
+        let mut next = f32::INFINITY;
+        for &m in self.moves {
+            // Same face twice is one turn; opposite faces commute, so
+            // only try them in one order.
+            if last.is_some_and(|l| l == m.face || (opposite_face(l) == m.face && (m.face as usize) < (l as usize))) {
+                continue;
+            }
+            let mut c = cube.clone();
+            c.apply(m);
+            path.push(m);
+            match self.run(&c, spent + self.costs.turn(m), bound, Some(m.face), path) {
+                Ok(()) => return Ok(()),
+                Err(t) => next = next.min(t),
+            }
+            path.pop();
+        }
+        Err(next)
+    }
+}
+
+// ----- generator subsets -----
+
+/// Parse a generator set such as `R U`, `RUF`, or `⟨R, U⟩` (brackets,
+/// commas, and spaces are ignored).
+pub fn parse_generators(s: &str) -> Result<Vec<FaceId>, Error> {
+    let mut faces = Vec::new();
+    for ch in s.chars().filter(|c| !matches!(c, '<' | '>' | '⟨' | '⟩' | ',') && !c.is_whitespace()) {
+        let f = match ch.to_ascii_uppercase() {
+            'U' => FaceId::U, 'D' => FaceId::D, 'F' => FaceId::F,
+            'B' => FaceId::B, 'L' => FaceId::L, 'R' => FaceId::R,
+            _ => return Err(Error::BadGenerator { ch }),
+        };
+        if !faces.contains(&f) {
+            faces.push(f);
+        }
+    }
+    Ok(faces)
+}
+
+/// `⟨R, U⟩`
+pub fn fmt_generators(faces: &[FaceId]) -> String {
+    let names: Vec<String> = faces.iter().map(|f| format!("{f:?}")).collect();
+    format!("⟨{}⟩", names.join(", "))
+}
+
+/// Whether `cube` can be solved (in some orientation) turning only `faces`.
+///
+/// One face from each axis (e.g. ⟨R, U, F⟩) reaches every state, as the
+/// table solver shows, and so does an opposite pair plus any other face:
+/// R L' turns the whole cube, so ⟨R, L, U⟩ can also turn F, B, and D.
+/// The remaining subsets (⟨R, U⟩, ⟨R, L⟩, one face) have orbits of at
+/// most 29,160 states, which are searched outright.
+pub fn reachable(cube: &Cube, faces: &[FaceId]) -> bool {
+    let Some(start) = PackedCube::pack(cube) else { return false };
+    let axis = |f: FaceId| match f { FaceId::U | FaceId::D => 0, FaceId::F | FaceId::B => 1, FaceId::L | FaceId::R => 2 };
+    let axes = (0..3).filter(|&a| faces.iter().any(|&f| axis(f) == a)).count();
+    let has_pair = faces.iter().any(|&f| faces.contains(&opposite_face(f)));
+    if axes == 3 || (axes == 2 && has_pair) {
+        return true;
+    }
+
+    let moves: Vec<Move> = Move::ALL.into_iter().filter(|m| faces.contains(&m.face)).collect();
+    orbit_reaches_solved(start, &moves)
+}
+
+/// Depth-first search of the orbit of `start` under `moves` for a solved
+/// state; only for subgroups small enough to enumerate.
+pub(crate) fn orbit_reaches_solved(start: PackedCube, moves: &[Move]) -> bool {
+    let tables = MoveTables::get();
+    let goals = solved_states();
+    let mut seen = std::collections::HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(s) = stack.pop() {
+        if goals.contains(&s) {
+            return true;
+        }
+        for &m in moves {
+            let next = tables.apply(s, m);
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    false
+}
+
+/// The solved cube held in each of its 24 orientations.
+pub(crate) fn solved_states() -> Vec<PackedCube> {
+    use FaceId::*;
+    let tables = MoveTables::get();
+    // x, y, z as paired face turns (R L', U D', F B').
+    let rotations = [(R, L), (U, D), (F, B)];
+    let mut states = vec![PackedCube::SOLVED];
+    let mut i = 0;
+    while i < states.len() {
+        for (a, b) in rotations {
+            let s = tables.apply(tables.apply(states[i], Move::new(a, Turn::Cw)), Move::new(b, Turn::Ccw));
+            if !states.contains(&s) {
+                states.push(s);
+            }
+        }
+        i += 1;
+    }
+    states
+}
+
+pub(crate) fn opposite_face(f: FaceId) -> FaceId {
+    use FaceId::*;
+    match f { U => D, D => U, F => B, B => F, L => R, R => L }
+}
+
+// ----- normalization -----
+
+fn opposite(c: Col) -> Col {
+    match c {
+        Col::W => Col::Y, Col::Y => Col::W,
+        Col::G => Col::B, Col::B => Col::G,
+        Col::O => Col::R, Col::R => Col::O,
+    }
+}
+
+/// Recolor so the DLB piece is home, then pack.
+fn normalize(cube: &Cube) -> Option<PackedCube> {
+    let [(df, dr, dc), (lf, lr, lc), (bf, br, bc)] = CORNER_SLOTS[7];
+    let (d, l, b) = (cube.face(df)[dr][dc], cube.face(lf)[lr][lc], cube.face(bf)[br][bc]);
+    let mut map = [None; 6];
+    for (from, to) in [(d, Col::Y), (l, Col::O), (b, Col::B)] {
+        for (f, t) in [(from, to), (opposite(from), opposite(to))] {
+            if map[f as usize].is_some_and(|x| x != t) {
+                return None;
+            }
+            map[f as usize] = Some(t);
+        }
+    }
+    let map = map.map(|c| c.unwrap_or(Col::W));
+    PackedCube::pack(&cube.recolored(&map))
+}
+
+// ----- dense indexing -----
+
+/// Maps packed states (DLB fixed) to `0..STATE_COUNT` and back.
+struct Indexing {
+    perm_dense: Vec<u16>,
+    perm_packed: Vec<u16>,
+    ori_packed: Vec<u16>,
+}
+
+impl Indexing {
+    fn new() -> Self {
+        let mut perm_dense = vec![u16::MAX; PERM_COUNT];
+        let mut perm_packed = Vec::with_capacity(5_040);
+        for (i, slot) in perm_dense.iter_mut().enumerate() {
+            if perm_unrank(i as u16)[7] == 7 {
+                *slot = perm_packed.len() as u16;
+                perm_packed.push(i as u16);
+            }
+        }
+        // With the last corner untwisted, the 7th digit is implied by the
+        // first six, so dropping it gives a dense index.
+        let ori_packed = (0..ORI_COUNT as u16).filter(|o| o % 3 == implied_digit(o / 3)).collect();
+        Self { perm_dense, perm_packed, ori_packed }
+    }
+
+    #[inline]
+    fn dense(&self, s: PackedCube) -> usize {
+        self.perm_dense[s.perm_index() as usize] as usize * 729 + (s.ori_index() / 3) as usize
+    }
+
+    #[inline]
+    fn packed(&self, i: usize) -> PackedCube {
+        PackedCube::from_indices(self.perm_packed[i / 729], self.ori_packed[i % 729])
+    }
+}
+
+/// 7th orientation digit that makes the first seven sum to 0 mod 3.
+fn implied_digit(mut six: u16) -> u16 {
+    let mut sum = 0;
+    for _ in 0..6 {
+        sum += six % 3;
+        six /= 3;
+    }
+    (3 - sum % 3) % 3
+}
+
+fn checksum(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |h, &b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use super::*;
+    use crate::logic::alg::apply_alg;
+    use crate::logic::scramble::{random_scramble, SCRAMBLE_LEN};
+
+    /// God's number for the 2×2 in the half-turn metric.
+    const GODS_NUMBER: u8 = 11;
+
+    fn table() -> &'static DistanceTable {
+        static TABLE: OnceLock<DistanceTable> = OnceLock::new();
+        TABLE.get_or_init(DistanceTable::build)
+    }
+
+    #[test]
+    fn deepest_state_is_gods_number() {
+        assert_eq!(table().max_depth(), GODS_NUMBER);
+    }
+
+    #[test]
+    fn solves_random_scrambles_optimally() {
+        for _ in 0..50 {
+            let scramble = random_scramble(SCRAMBLE_LEN);
+            let mut cube = Cube::default();
+            apply_alg(&mut cube, &scramble).unwrap();
+            let solution = table().solve(&cube).unwrap();
+            assert!(solution.len() <= usize::from(GODS_NUMBER), "{scramble}: {solution}");
+            assert_eq!(Some(solution.len() as u8), table().distance(&cube));
+            cube.apply_alg(&solution);
+            assert!(cube.is_solved(), "{scramble}: {solution}");
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let bytes = table().to_bytes();
+        let back = DistanceTable::from_bytes(&bytes).unwrap();
+        assert_eq!(back.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn bad_checksum_is_rejected() {
+        let mut bytes = table().to_bytes();
+        bytes[HEADER_LEN + 1000] ^= 1;
+        assert!(matches!(DistanceTable::from_bytes(&bytes), Err(Error::CorruptTable(_))));
+        let mut bytes = table().to_bytes();
+        bytes[8] ^= 1;
+        assert!(matches!(DistanceTable::from_bytes(&bytes), Err(Error::CorruptTable(_))));
+    }
+}
@@ -0,0 +1,116 @@
+// src/logic/stats.rs
+
+//! Two-sample comparison of timings: Welch's t-test, which doesn't assume
+//! the two samples vary alike, with its two-sided p-value from Student's t
+//! distribution.
+
+/// Significance level below which a difference is called real.
+pub const ALPHA: f64 = 0.05;
+
+/// Outcome of a Welch's t-test of `a` against `b`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Welch {
+    pub mean_a: f64,
+    pub mean_b: f64,
+    /// Positive when `a` has the larger mean.
+    pub t: f64,
+    /// Welch–Satterthwaite degrees of freedom.
+    pub df: f64,
+    /// Chance of a difference at least this large if the means were equal.
+    pub p: f64,
+}
+
+impl Welch {
+    pub fn significant(&self) -> bool {
+        self.p < ALPHA
+    }
+}
+
+pub fn mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+/// Sample variance (divided by n − 1).
+fn variance(xs: &[f64], mean: f64) -> f64 {
+    xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (xs.len() - 1) as f64
+}
+
+/// Welch's t-test; `None` with fewer than two values on a side, or when
+/// neither side varies at all.
+pub fn welch(a: &[f64], b: &[f64]) -> Option<Welch> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    let va = variance(a, mean_a) / a.len() as f64;
+    let vb = variance(b, mean_b) / b.len() as f64;
+    let se2 = va + vb;
+    if se2 <= 0.0 {
+        return None;
+    }
+    let t = (mean_a - mean_b) / se2.sqrt();
+    let df = se2.powi(2) / (va.powi(2) / (a.len() - 1) as f64 + vb.powi(2) / (b.len() - 1) as f64);
+    let p = inc_beta(df / (df + t * t), df / 2.0, 0.5);
+    Some(Welch { mean_a, mean_b, t, df, p })
+}
+
+/// ln Γ(x) for x > 0, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const G: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.120_865_097_386_617_9e-2,
+        -0.539_523_938_495_3e-5,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut y = x;
+    let series = G.iter().fold(1.000_000_000_190_015, |s, g| {
+        y += 1.0;
+        s + g / y
+    });
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+/// The regularized incomplete beta function I_x(a, b).
+fn inc_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges fast only on this side of the mean.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Continued fraction for the incomplete beta function, by Lentz's method.
+fn beta_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-30;
+    let clamp = |v: f64| if v.abs() < TINY { TINY } else { v };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..200 {
+        let m = f64::from(m);
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / clamp(1.0 + even * d);
+        c = clamp(1.0 + even / c);
+        h *= d * c;
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / clamp(1.0 + odd * d);
+        c = clamp(1.0 + odd / c);
+        let step = d * c;
+        h *= step;
+        if (step - 1.0).abs() < 1e-12 {
+            break;
+        }
+    }
+    h
+}
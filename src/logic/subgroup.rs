@@ -0,0 +1,113 @@
+// src/logic/subgroup.rs
+
+//! Subgroup explorer: which standard subgroups of the cube group the
+//! current state lies in (one face, half turns, two generators, orientation
+//! or permutation solved), and the order of the state as a group element,
+//! i.e. how often the moves that reached it must be repeated to undo them.
+//!
+//! Subgroups count a state as solved in any orientation, as the solver
+//! does; the order is exact, so a state that is a whole-cube rotation away
+//! from solved may return to solved before it returns to itself.
+
+use std::fmt;
+
+use crate::cube::{CornerPerm, Cube, FaceId, Move, PackedCube, Turn};
+use crate::logic::solver::{opposite_face, orbit_reaches_solved, reachable, solved_states};
+use crate::t;
+
+/// A standard subgroup containing the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subgroup {
+    Solved,
+    /// One turn of this face (or its opposite) from solved.
+    OneFace(FaceId),
+    /// ⟨U2, D2, F2, B2, L2, R2⟩.
+    HalfTurns,
+    /// Two adjacent faces, e.g. ⟨R, U⟩.
+    TwoGen(FaceId, FaceId),
+    /// Every corner's U/D sticker on the U/D axis (of some orientation).
+    Oriented,
+    /// Every corner in its home slot (of some orientation), maybe twisted.
+    Permuted,
+}
+
+impl fmt::Display for Subgroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match *self {
+            Subgroup::Solved => t!("subgroup-solved"),
+            Subgroup::OneFace(face) => t!("subgroup-one-face",
+                face = format!("{face:?}"),
+                opposite = format!("{:?}", opposite_face(face))),
+            Subgroup::HalfTurns => t!("subgroup-half-turns"),
+            Subgroup::TwoGen(a, b) => t!("subgroup-two-gen", a = format!("{a:?}"), b = format!("{b:?}")),
+            Subgroup::Oriented => t!("subgroup-oriented"),
+            Subgroup::Permuted => t!("subgroup-permuted"),
+        })
+    }
+}
+
+/// What the explorer found for one state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubgroupReport {
+    /// Smallest first; empty when only the whole group contains the state.
+    pub subgroups: Vec<Subgroup>,
+    /// Repetitions until the state is exactly the start again.
+    pub order: u32,
+    /// Repetitions until it is solved in some orientation (≤ `order`).
+    pub reps_to_solved: u32,
+}
+
+/// Explore `cube`; `None` if it isn't a legal state.
+pub fn explore(cube: &Cube) -> Option<SubgroupReport> {
+    let state = PackedCube::pack(cube)?;
+    let (order, reps_to_solved) = order(state);
+    Some(SubgroupReport { subgroups: subgroups(cube, state), order, reps_to_solved })
+}
+
+fn subgroups(cube: &Cube, state: PackedCube) -> Vec<Subgroup> {
+    let goals = solved_states();
+    if goals.contains(&state) {
+        return vec![Subgroup::Solved];
+    }
+    let mut out = Vec::new();
+    if let Some(&f) = FaceId::ALL.iter().find(|&&f| reachable(cube, &[f])) {
+        out.push(Subgroup::OneFace(f));
+    }
+    let halves: Vec<Move> = FaceId::ALL.map(|f| Move::new(f, Turn::Half)).to_vec();
+    if orbit_reaches_solved(state, &halves) {
+        out.push(Subgroup::HalfTurns);
+    }
+    // Any one-face state is also in every pair containing that face.
+    if !out.iter().any(|s| matches!(s, Subgroup::OneFace(_))) {
+        for (i, &a) in FaceId::ALL.iter().enumerate() {
+            for &b in &FaceId::ALL[i + 1..] {
+                if b != opposite_face(a) && reachable(cube, &[a, b]) {
+                    out.push(Subgroup::TwoGen(a, b));
+                }
+            }
+        }
+    }
+    if goals.iter().any(|g| g.ori_index() == state.ori_index()) {
+        out.push(Subgroup::Oriented);
+    }
+    if goals.iter().any(|g| g.perm_index() == state.perm_index()) {
+        out.push(Subgroup::Permuted);
+    }
+    out
+}
+
+/// `(order, reps_to_solved)` of `state` as a group element: repeatedly
+/// apply the permutation and twist that take solved to `state`.
+fn order(state: PackedCube) -> (u32, u32) {
+    let goals = solved_states();
+    let g = CornerPerm::from_packed(state);
+    let order = g.order();
+    let mut now = g;
+    for n in 1..order {
+        if goals.contains(&now.to_packed()) {
+            return (order, n);
+        }
+        now = now.then(g);
+    }
+    (order, order)
+}
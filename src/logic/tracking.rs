@@ -0,0 +1,84 @@
+// src/logic/tracking.rs
+
+//! Piece tracking: where one corner piece is now, and the slots it passed
+//! through over a move sequence. A piece is identified by its colors (and
+//! named after its home slot), so it can be followed through any scramble.
+
+use std::fmt;
+
+use crate::cube::packed::CORNER_SLOTS;
+use crate::cube::perm::CORNER_NAMES;
+use crate::cube::{Col, CornerPerm, Cube, FaceId, Move, PackedCube};
+use crate::logic::describe::color_name;
+
+/// Sticker position: face, row, col.
+pub type Sticker = (FaceId, usize, usize);
+
+/// A corner piece, by the slot it belongs in on a solved cube.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece(pub u8);
+
+impl Piece {
+    pub const ALL: [Piece; 8] = [Piece(0), Piece(1), Piece(2), Piece(3), Piece(4), Piece(5), Piece(6), Piece(7)];
+
+    pub fn colors(self) -> [Col; 3] {
+        let solved = Cube::default();
+        CORNER_SLOTS[self.0 as usize].map(|(f, r, c)| solved.face(f)[r][c])
+    }
+
+    /// Slot and twist (0–2) of this piece in `cube`; `None` for an illegal cube.
+    pub fn locate(self, cube: &Cube) -> Option<(u8, u8)> {
+        let perm = CornerPerm::from_packed(PackedCube::pack(cube)?);
+        let slot = perm.from.iter().position(|&p| p == self.0)?;
+        Some((slot as u8, perm.twist[slot]))
+    }
+
+    /// The three stickers this piece shows in `cube`.
+    pub fn stickers(self, cube: &Cube) -> Option<[Sticker; 3]> {
+        self.locate(cube).map(|(slot, _)| CORNER_SLOTS[slot as usize])
+    }
+
+    /// Where the piece sits after each of `moves`, which led to `cube`.
+    pub fn trail(self, cube: &Cube, moves: &[Move]) -> Vec<Stop> {
+        let mut c = cube.clone();
+        for m in moves.iter().rev() {
+            c.apply(m.inverse());
+        }
+        moves
+            .iter()
+            .filter_map(|&m| {
+                c.apply(m);
+                self.locate(&c).map(|(slot, twist)| Stop { after: m, slot, twist })
+            })
+            .collect()
+    }
+}
+
+/// `White / Green / Orange (ULF)`.
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.colors().map(color_name);
+        write!(f, "{} ({})", names.join(" / "), CORNER_NAMES[self.0 as usize])
+    }
+}
+
+/// One step of a trail: the slot a piece is in after a move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stop {
+    pub after: Move,
+    pub slot: u8,
+    pub twist: u8,
+}
+
+/// `R→URB+`: the move, then the slot with `+`/`-` for a clockwise or
+/// counterclockwise twist away from home orientation.
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}→{}", self.after, CORNER_NAMES[self.slot as usize])?;
+        match self.twist {
+            1 => f.write_str("-"),
+            2 => f.write_str("+"),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// src/logic/verify.rs
+
+//! Compare the app's cube with facelet colors read off a physical cube.
+//! Input uses the `Cube::to_facelets` order (U, D, F, B, L, R faces, each
+//! row-major); whitespace is ignored so faces can be typed in groups. The
+//! same letters can also be read as a whole state.
+
+use crate::cube::{Col, Cube, FaceId};
+use crate::Error;
+
+/// One sticker that differs from the expected state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch {
+    pub face: FaceId,
+    pub row: usize,
+    pub col: usize,
+    pub expected: Col,
+    pub found: Col,
+}
+
+const FACE_ORDER: [FaceId; 6] = [FaceId::U, FaceId::D, FaceId::F, FaceId::B, FaceId::L, FaceId::R];
+
+/// The cube whose stickers `entered` lists.
+pub fn parse_facelets(entered: &str) -> Result<Cube, Error> {
+    let letters: String = entered.chars().filter(|c| !c.is_whitespace()).collect();
+    let n = letters.chars().count();
+    if n != 24 {
+        return Err(Error::FaceletCount { n });
+    }
+    if let Some(letter) = letters.chars().find(|&c| Col::from_letter(c).is_none()) {
+        return Err(Error::FaceletColor { letter });
+    }
+    Cube::from_facelets(&letters).ok_or(Error::FaceletCount { n })
+}
+
+/// Stickers of `entered` that differ from `expected`; empty when they match.
+pub fn compare(expected: &Cube, entered: &str) -> Result<Vec<Mismatch>, Error> {
+    let letters: Vec<char> = entered.chars().filter(|c| !c.is_whitespace()).collect();
+    if letters.len() != 24 {
+        return Err(Error::FaceletCount { n: letters.len() });
+    }
+    let mut out = Vec::new();
+    for (i, &ch) in letters.iter().enumerate() {
+        let found = Col::from_letter(ch).ok_or(Error::FaceletColor { letter: ch })?;
+        let (face, row, col) = (FACE_ORDER[i / 4], (i / 2) % 2, i % 2);
+        let expected = expected.face(face)[row][col];
+        if found != expected {
+            out.push(Mismatch { face, row, col, expected, found });
+        }
+    }
+    Ok(out)
+}
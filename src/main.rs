@@ -8,14 +8,26 @@
 
 mod render;
 mod ui;
-mod logic;
-pub mod cube;
 mod app;
+mod storage;
+mod audio;
+mod controllers;
+mod gamepad;
+mod hotkey;
+mod cli;
+mod leaderboard;
+mod obs;
+mod background;
+mod serve;
+
+// Model, logic, and strings come from the library half of the crate.
+use rubics::{cube, events, logic, i18n, t, Error};
 
 use iced::widget::{
     column, row, text, slider, text_input, button, container, Space, Canvas, checkbox,
 };
-use iced::{Alignment, Length, Element, Application, Settings, Command, Theme};
+use iced::{Alignment, Length, Element, Command, Theme};
+use iced::multi_window::Application;
 
 use render::{CubeCanvas, ViewParams, RotZ, RotX, RotY};
 
@@ -23,7 +35,13 @@ use rand::seq::SliceRandom;
 use rand::thread_rng;
 use crate::app::{App, Msg};
 
-/// Launch the app.
+/// Run a command-line tool if one is named, else launch the app.
 fn main() -> iced::Result {
-    App::run(Settings::default())
+    if let Some(code) = cli::run() {
+        std::process::exit(code);
+    }
+    storage::set_read_only(app::demo::Demo::from_args().is_some());
+    // Failing to note the profile for next time doesn't stop this run.
+    let _ = app::profiles::at_startup().activate();
+    App::run(app::settings())
 }
\ No newline at end of file
@@ -0,0 +1,220 @@
+// src/obs/mod.rs
+
+//! OBS Studio trigger for streamers: when a timed solve ends, the app asks
+//! OBS to save its replay buffer or to switch to a chosen scene, through
+//! obs-websocket (built into OBS 28 and later, Tools → WebSocket Server
+//! Settings). Off until turned on; the password is kept in the settings
+//! file as typed, like the rest of the profile.
+//!
+//! Talking to OBS needs the `obs` feature. The client is a minimal
+//! WebSocket over a plain TCP socket, so it needs no extra libraries, and
+//! speaks obs-websocket 5: read `Hello`, answer `Identify` (with the
+//! salted SHA-256 response when OBS asks for a password), wait for
+//! `Identified`, send one request, and read its response.
+
+#[cfg(feature = "obs")]
+mod digest;
+#[cfg(feature = "obs")]
+mod ws;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::storage::{data_dir, read_kv, save_file, write_kv};
+use crate::t;
+
+/// Whether this build can reach OBS.
+pub const AVAILABLE: bool = cfg!(feature = "obs");
+
+const HEADER: &str = "rubics obs settings v1";
+
+/// obs-websocket's default port.
+pub const DEFAULT_PORT: u16 = 4455;
+/// Connect and read timeout for a trigger.
+#[cfg_attr(not(feature = "obs"), allow(dead_code))]
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What OBS is asked to do when a solve ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObsAction {
+    /// Save the replay buffer, which must already be running.
+    #[default]
+    SaveReplay,
+    /// Make the chosen scene the live one.
+    SwitchScene,
+}
+
+impl ObsAction {
+    pub const ALL: [ObsAction; 2] = [ObsAction::SaveReplay, ObsAction::SwitchScene];
+}
+
+impl std::fmt::Display for ObsAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            ObsAction::SaveReplay => t!("obs-save-replay"),
+            ObsAction::SwitchScene => t!("obs-switch-scene"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsSettings {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    /// Empty when OBS has authentication turned off.
+    pub password: String,
+    pub action: ObsAction,
+    /// Scene switched to by [`ObsAction::SwitchScene`].
+    pub scene: String,
+}
+
+impl Default for ObsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "localhost".to_string(),
+            port: DEFAULT_PORT,
+            password: String::new(),
+            action: ObsAction::default(),
+            scene: String::new(),
+        }
+    }
+}
+
+impl ObsSettings {
+    /// Turned on with somewhere to send to.
+    pub fn active(&self) -> bool {
+        AVAILABLE && self.enabled && !self.host.trim().is_empty()
+    }
+
+    /// The obs-websocket request for the action: its type and, if it takes
+    /// any, its data as a JSON object.
+    #[cfg_attr(not(feature = "obs"), allow(dead_code))]
+    fn request(&self) -> (&'static str, Option<String>) {
+        match self.action {
+            ObsAction::SaveReplay => ("SaveReplayBuffer", None),
+            ObsAction::SwitchScene => (
+                "SetCurrentProgramScene",
+                Some(format!("{{\"sceneName\":{}}}", crate::logic::json::quote(self.scene.trim()))),
+            ),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        write_kv(HEADER, &[
+            ("enabled", self.enabled.to_string()),
+            ("host", self.host.clone()),
+            ("port", self.port.to_string()),
+            ("password", self.password.clone()),
+            ("action", format!("{:?}", self.action)),
+            ("scene", self.scene.clone()),
+        ])
+    }
+
+    pub fn decode(text: &str) -> Self {
+        let kv = read_kv(text);
+        let default = Self::default();
+        Self {
+            enabled: kv.get("enabled").is_some_and(|v| v == "true"),
+            host: kv.get("host").cloned().unwrap_or(default.host),
+            port: kv.get("port").and_then(|v| v.parse().ok()).unwrap_or(default.port),
+            password: kv.get("password").cloned().unwrap_or_default(),
+            action: ObsAction::ALL
+                .into_iter()
+                .find(|a| kv.get("action").is_some_and(|v| *v == format!("{a:?}")))
+                .unwrap_or_default(),
+            scene: kv.get("scene").cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// Send the action to OBS; a translated reason if it didn't take. The
+/// exchange runs on its own thread (see [`crate::background`]).
+pub async fn trigger(settings: ObsSettings) -> Result<(), String> {
+    crate::background::run(move || backend::trigger(&settings)).await
+}
+
+#[cfg(feature = "obs")]
+mod backend {
+    use crate::logic::json::{quote, Json};
+    use crate::t;
+    use super::digest::{base64, sha256};
+    use super::ws::Socket;
+    use super::{ObsSettings, TIMEOUT};
+
+    /// obs-websocket's status code for a request that succeeded.
+    const SUCCESS: f64 = 100.0;
+
+    pub fn trigger(settings: &ObsSettings) -> Result<(), String> {
+        let failed = |e: std::io::Error| t!("obs-unreachable", error = e.to_string());
+        let mut socket = Socket::connect(settings.host.trim(), settings.port, "obswebsocket.json", TIMEOUT).map_err(failed)?;
+
+        let hello = expect(&mut socket, 0)?;
+        let auth = hello.get("authentication").map(|a| {
+            let field = |k: &str| a.get(k).and_then(Json::as_str).unwrap_or("");
+            let secret = base64(&sha256(format!("{}{}", settings.password, field("salt")).as_bytes()));
+            let response = base64(&sha256(format!("{secret}{}", field("challenge")).as_bytes()));
+            format!(",\"authentication\":{}", quote(&response))
+        });
+        let identify = format!("{{\"op\":1,\"d\":{{\"rpcVersion\":1{}}}}}", auth.unwrap_or_default());
+        socket.send(&identify).map_err(failed)?;
+        expect(&mut socket, 2)?;
+
+        let (kind, data) = settings.request();
+        let data = data.map(|d| format!(",\"requestData\":{d}")).unwrap_or_default();
+        let request = format!("{{\"op\":6,\"d\":{{\"requestType\":\"{kind}\",\"requestId\":\"rubics\"{data}}}}}");
+        socket.send(&request).map_err(failed)?;
+        let response = expect(&mut socket, 7)?;
+        socket.close();
+
+        let status = response.get("requestStatus");
+        match status.and_then(|s| s.get("code")).and_then(Json::as_num) {
+            Some(SUCCESS) => Ok(()),
+            code => Err(status
+                .and_then(|s| s.get("comment"))
+                .and_then(Json::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| t!("obs-refused", code = code.unwrap_or_default()))),
+        }
+    }
+
+    /// The data of the next message, which must have opcode `op`. OBS
+    /// closes the connection on a wrong password, which reads as an error
+    /// with its reason.
+    fn expect(socket: &mut Socket, op: u8) -> Result<Json, String> {
+        let text = socket.recv().map_err(|e| t!("obs-unreachable", error = e.to_string()))?;
+        let msg = Json::parse(&text).ok_or_else(|| t!("obs-bad-reply"))?;
+        if msg.get("op").and_then(Json::as_num) != Some(f64::from(op)) {
+            return Err(t!("obs-bad-reply"));
+        }
+        msg.get("d").cloned().ok_or_else(|| t!("obs-bad-reply"))
+    }
+}
+
+#[cfg(not(feature = "obs"))]
+mod backend {
+    use crate::t;
+    use super::ObsSettings;
+
+    pub fn trigger(_settings: &ObsSettings) -> Result<(), String> {
+        Err(t!("obs-unavailable"))
+    }
+}
+
+// ----- files -----
+
+/// Location of the OBS settings file.
+pub fn settings_path() -> PathBuf {
+    data_dir().join("obs.txt")
+}
+
+pub fn load() -> ObsSettings {
+    std::fs::read_to_string(settings_path())
+        .map(|t| ObsSettings::decode(&t))
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &ObsSettings) -> std::io::Result<()> {
+    save_file(&settings_path(), settings.encode())
+}
@@ -0,0 +1,151 @@
+// src/obs/ws.rs
+
+//! Just enough of a WebSocket client (RFC 6455) for a short exchange of
+//! text messages: the HTTP upgrade, masked text frames out, and text
+//! frames in, answering pings along the way. No TLS, no extensions.
+
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use super::digest::base64;
+
+const TEXT: u8 = 0x1;
+const CONTINUATION: u8 = 0x0;
+const CLOSE: u8 = 0x8;
+const PING: u8 = 0x9;
+const PONG: u8 = 0xA;
+
+/// Longest message read, against a peer sending nonsense lengths.
+const MAX_MESSAGE: u64 = 1 << 20;
+
+pub struct Socket {
+    stream: TcpStream,
+}
+
+impl Socket {
+    /// Open a socket to `ws://host:port/` speaking sub-protocol `protocol`.
+    pub fn connect(host: &str, port: u16, protocol: &str, timeout: Duration) -> io::Result<Self> {
+        let addr = (host, port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, host.to_string()))?;
+        let mut stream = TcpStream::connect_timeout(&addr, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        let key = base64(&rand::random::<[u8; 16]>());
+        write!(
+            stream,
+            "GET / HTTP/1.1\r\nHost: {host}:{port}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+             Sec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: 13\r\nSec-WebSocket-Protocol: {protocol}\r\n\r\n",
+        )?;
+        // The response head, a byte at a time so no frame data is read with it.
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            let mut b = [0u8];
+            stream.read_exact(&mut b)?;
+            head.push(b[0]);
+            if head.len() > 8192 {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "handshake response too long"));
+            }
+        }
+        let head = String::from_utf8_lossy(&head);
+        let status = head.lines().next().unwrap_or_default();
+        if status.split_whitespace().nth(1) != Some("101") {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, status.to_string()));
+        }
+        Ok(Self { stream })
+    }
+
+    /// Send one text message.
+    pub fn send(&mut self, text: &str) -> io::Result<()> {
+        self.frame(TEXT, text.as_bytes())
+    }
+
+    /// The next text message. A close from the peer is an error carrying
+    /// its code and reason.
+    pub fn recv(&mut self) -> io::Result<String> {
+        let mut message = Vec::new();
+        loop {
+            let (fin, opcode, payload) = self.read_frame()?;
+            match opcode {
+                TEXT | CONTINUATION => {
+                    message.extend_from_slice(&payload);
+                    if message.len() as u64 > MAX_MESSAGE {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, "message too long"));
+                    }
+                    if fin {
+                        return String::from_utf8(message).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+                    }
+                }
+                PING => self.frame(PONG, &payload)?,
+                CLOSE => {
+                    let code = payload.get(..2).map_or(1005, |c| u16::from_be_bytes([c[0], c[1]]));
+                    let reason = String::from_utf8_lossy(payload.get(2..).unwrap_or_default());
+                    return Err(io::Error::new(io::ErrorKind::ConnectionAborted, format!("{code} {reason}").trim().to_string()));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Say goodbye; the peer may already be gone.
+    pub fn close(mut self) {
+        let _ = self.frame(CLOSE, &1000u16.to_be_bytes());
+    }
+
+    /// Write one final frame; a client's frames are always masked.
+    fn frame(&mut self, opcode: u8, payload: &[u8]) -> io::Result<()> {
+        let mut out = vec![0x80 | opcode];
+        match payload.len() {
+            n if n < 126 => out.push(0x80 | n as u8),
+            n if n <= usize::from(u16::MAX) => {
+                out.push(0x80 | 126);
+                out.extend_from_slice(&(n as u16).to_be_bytes());
+            }
+            n => {
+                out.push(0x80 | 127);
+                out.extend_from_slice(&(n as u64).to_be_bytes());
+            }
+        }
+        let mask = rand::random::<[u8; 4]>();
+        out.extend_from_slice(&mask);
+        out.extend(payload.iter().zip(mask.iter().cycle()).map(|(b, m)| b ^ m));
+        self.stream.write_all(&out)
+    }
+
+    /// One frame: whether it ends its message, its opcode, and its payload.
+    fn read_frame(&mut self) -> io::Result<(bool, u8, Vec<u8>)> {
+        let mut head = [0u8; 2];
+        self.stream.read_exact(&mut head)?;
+        let len = match head[1] & 0x7f {
+            126 => {
+                let mut b = [0u8; 2];
+                self.stream.read_exact(&mut b)?;
+                u64::from(u16::from_be_bytes(b))
+            }
+            127 => {
+                let mut b = [0u8; 8];
+                self.stream.read_exact(&mut b)?;
+                u64::from_be_bytes(b)
+            }
+            n => u64::from(n),
+        };
+        if len > MAX_MESSAGE {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "message too long"));
+        }
+        let mask = if head[1] & 0x80 != 0 {
+            let mut m = [0u8; 4];
+            self.stream.read_exact(&mut m)?;
+            Some(m)
+        } else {
+            None
+        };
+        let mut payload = vec![0u8; len as usize];
+        self.stream.read_exact(&mut payload)?;
+        if let Some(m) = mask {
+            payload.iter_mut().zip(m.iter().cycle()).for_each(|(b, m)| *b ^= m);
+        }
+        Ok((head[0] & 0x80 != 0, head[0] & 0x0f, payload))
+    }
+}
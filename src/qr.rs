@@ -0,0 +1,416 @@
+// src/qr.rs
+
+//! Minimal QR code encoder: byte mode, error correction level M, versions
+//! 1–10 (up to 213 bytes) — enough for a facelet string plus a scramble.
+//! Follows ISO/IEC 18004; the smallest fitting version and the mask with
+//! the lowest penalty score are chosen automatically.
+
+/// A square grid of modules; `true` is dark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+}
+
+/// Largest supported version.
+pub const MAX_VERSION: usize = 10;
+
+// Level-M error-correction codewords per block and block count, by version.
+const ECC_PER_BLOCK: [usize; MAX_VERSION + 1] = [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+const NUM_BLOCKS: [usize; MAX_VERSION + 1] = [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5];
+
+/// Format-info bits for level M (ECC level indicator `00`).
+const ECL_M_BITS: u32 = 0;
+
+impl QrCode {
+    /// Encode `data` as bytes; `None` if it doesn't fit in version 10.
+    pub fn encode(data: &[u8]) -> Option<QrCode> {
+        let version = (1..=MAX_VERSION).find(|&v| {
+            let count_bits = if v < 10 { 8 } else { 16 };
+            4 + count_bits + data.len() * 8 <= data_codewords(v) * 8
+        })?;
+
+        // Mode indicator, length, payload, terminator, padding.
+        let mut bits = BitBuf::default();
+        bits.push(0b0100, 4);
+        bits.push(data.len() as u32, if version < 10 { 8 } else { 16 });
+        for &b in data {
+            bits.push(u32::from(b), 8);
+        }
+        let capacity = data_codewords(version) * 8;
+        bits.push(0, (capacity - bits.len).min(4));
+        bits.push(0, (8 - bits.len % 8) % 8);
+        let mut codewords = bits.bytes;
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if codewords.len() >= data_codewords(version) {
+                break;
+            }
+            codewords.push(pad);
+        }
+
+        let all = add_ecc_and_interleave(&codewords, version);
+        let mut qr = Grid::new(version);
+        qr.draw_function_patterns();
+        qr.draw_codewords(&all);
+
+        let best = (0..8)
+            .min_by_key(|&mask| {
+                let mut trial = qr.clone();
+                trial.apply_mask(mask);
+                trial.draw_format_bits(mask);
+                trial.penalty()
+            })
+            .unwrap();
+        qr.apply_mask(best);
+        qr.draw_format_bits(best);
+        Some(QrCode { size: qr.size, modules: qr.modules })
+    }
+
+    /// Modules per side (without quiet zone).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Module at column `x`, row `y`.
+    pub fn dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+}
+
+// ----- bit buffer -----
+
+#[derive(Default)]
+struct BitBuf {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitBuf {
+    fn push(&mut self, value: u32, n: usize) {
+        for i in (0..n).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+}
+
+// ----- capacity -----
+
+fn raw_data_modules(ver: usize) -> usize {
+    let mut result = (16 * ver + 128) * ver + 64;
+    if ver >= 2 {
+        let num_align = ver / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if ver >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn data_codewords(ver: usize) -> usize {
+    raw_data_modules(ver) / 8 - ECC_PER_BLOCK[ver] * NUM_BLOCKS[ver]
+}
+
+// ----- Reed–Solomon over GF(256), polynomial 0x11D -----
+
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((u32::from(y) >> i) & 1) * u32::from(x);
+    }
+    z as u8
+}
+
+fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &b in data {
+        let factor = b ^ result.remove(0);
+        result.push(0);
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_mul(d, factor);
+        }
+    }
+    result
+}
+
+fn add_ecc_and_interleave(data: &[u8], ver: usize) -> Vec<u8> {
+    let num_blocks = NUM_BLOCKS[ver];
+    let ecc_len = ECC_PER_BLOCK[ver];
+    let raw = raw_data_modules(ver) / 8;
+    let num_short = num_blocks - raw % num_blocks;
+    let short_len = raw / num_blocks;
+    let divisor = rs_divisor(ecc_len);
+
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut k = 0;
+    for i in 0..num_blocks {
+        let len = short_len - ecc_len + usize::from(i >= num_short);
+        let mut block = data[k..k + len].to_vec();
+        k += len;
+        let ecc = rs_remainder(&block, &divisor);
+        if i < num_short {
+            block.push(0); // placeholder, skipped when interleaving
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut out = Vec::with_capacity(raw);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_len - ecc_len || j >= num_short {
+                out.push(block[i]);
+            }
+        }
+    }
+    out
+}
+
+// ----- module grid -----
+
+#[derive(Clone)]
+struct Grid {
+    version: usize,
+    size: usize,
+    modules: Vec<bool>,
+    function: Vec<bool>,
+}
+
+impl Grid {
+    fn new(version: usize) -> Self {
+        let size = version * 4 + 17;
+        Self { version, size, modules: vec![false; size * size], function: vec![false; size * size] }
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let i = y * self.size + x;
+        self.modules[i] = dark;
+        self.function[i] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+
+        let pos = alignment_positions(self.version, size);
+        let n = pos.len();
+        for i in 0..n {
+            for j in 0..n {
+                let corner = (i == 0 && (j == 0 || j == n - 1)) || (i == n - 1 && j == 0);
+                if !corner {
+                    self.draw_alignment(pos[i], pos[j]);
+                }
+            }
+        }
+
+        self.draw_format_bits(0); // reserve the area; redrawn once the mask is chosen
+        self.draw_version();
+    }
+
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let dist = dx.abs().max(dy.abs());
+                let (xx, yy) = (x as i32 + dx, y as i32 + dy);
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    self.set_function(xx as usize, yy as usize, dist != 2 && dist != 4);
+                }
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+        let size = self.size;
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true); // always-dark module
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_bits(self.version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let size = self.size;
+        let mut i = 0;
+        let mut right = size as i32 - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vert in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vert } else { vert };
+                    let idx = y * size + x;
+                    if !self.function[idx] && i < data.len() * 8 {
+                        self.modules[idx] = (data[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let i = y * self.size + x;
+                if invert && !self.function[i] {
+                    self.modules[i] ^= true;
+                }
+            }
+        }
+    }
+
+    /// Penalty rules 1 (runs), 2 (2×2 blocks), and 4 (dark balance); the
+    /// finder-lookalike rule is skipped, which only affects mask choice.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let at = |x: usize, y: usize| self.modules[y * size + x];
+        let mut score = 0;
+        for line in 0..size {
+            for horizontal in [true, false] {
+                let mut run = 1;
+                for k in 1..size {
+                    let (a, b) = if horizontal {
+                        (at(k, line), at(k - 1, line))
+                    } else {
+                        (at(line, k), at(line, k - 1))
+                    };
+                    if a == b {
+                        run += 1;
+                        if run == 5 {
+                            score += 3;
+                        } else if run > 5 {
+                            score += 1;
+                        }
+                    } else {
+                        run = 1;
+                    }
+                }
+            }
+        }
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = at(x, y);
+                if c == at(x + 1, y) && c == at(x, y + 1) && c == at(x + 1, y + 1) {
+                    score += 3;
+                }
+            }
+        }
+        let dark = self.modules.iter().filter(|&&m| m).count();
+        let total = size * size;
+        let k = (dark * 20).abs_diff(total * 10).div_ceil(total).saturating_sub(1);
+        score + k * 10
+    }
+}
+
+fn alignment_positions(ver: usize, size: usize) -> Vec<usize> {
+    if ver == 1 {
+        return Vec::new();
+    }
+    let num_align = ver / 7 + 2;
+    let step = (ver * 4 + num_align * 2 + 1) / (num_align * 2 - 2) * 2;
+    let mut result = vec![6; num_align];
+    let mut pos = size - 7;
+    for slot in result.iter_mut().skip(1).rev() {
+        *slot = pos;
+        pos -= step;
+    }
+    result
+}
+
+/// 15-bit format info (level M + mask) with BCH check bits, XOR-masked.
+fn format_bits(mask: u32) -> u32 {
+    let data = (ECL_M_BITS << 3) | mask;
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    ((data << 10) | rem) ^ 0x5412
+}
+
+/// 18-bit version info with BCH check bits (versions 7+).
+fn version_bits(ver: usize) -> u32 {
+    let mut rem = ver as u32;
+    for _ in 0..12 {
+        rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+    }
+    ((ver as u32) << 12) | rem
+}
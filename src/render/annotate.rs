@@ -0,0 +1,143 @@
+// src/render/annotate.rs
+
+//! Coach annotations drawn over the cube view: freehand lines, arrows, and
+//! circles, in canvas coordinates. `CubeCanvas` collects the drag of the
+//! mark being drawn and reports the finished mark; the app keeps them.
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point};
+
+use crate::t;
+
+const WIDTH: f32 = 3.0;
+/// Arrow head: side length and half-angle.
+const HEAD: f32 = 12.0;
+const HEAD_ANGLE: f32 = 0.45;
+/// Freehand points closer than this to the previous one are skipped.
+const MIN_STEP: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tool {
+    #[default]
+    Pen,
+    Arrow,
+    Circle,
+}
+
+impl Tool {
+    pub const ALL: [Tool; 3] = [Tool::Pen, Tool::Arrow, Tool::Circle];
+}
+
+impl std::fmt::Display for Tool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Tool::Pen => t!("annotate-pen"),
+            Tool::Arrow => t!("annotate-arrow"),
+            Tool::Circle => t!("annotate-circle"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ink {
+    #[default]
+    Red,
+    Yellow,
+    Blue,
+}
+
+impl Ink {
+    pub const ALL: [Ink; 3] = [Ink::Red, Ink::Yellow, Ink::Blue];
+
+    fn color(self) -> Color {
+        match self {
+            Ink::Red => Color::from_rgb(0.9, 0.15, 0.15),
+            Ink::Yellow => Color::from_rgb(1.0, 0.85, 0.1),
+            Ink::Blue => Color::from_rgb(0.15, 0.45, 1.0),
+        }
+    }
+}
+
+impl std::fmt::Display for Ink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Ink::Red => t!("annotate-red"),
+            Ink::Yellow => t!("annotate-yellow"),
+            Ink::Blue => t!("annotate-blue"),
+        })
+    }
+}
+
+/// One drawn mark. A freehand line keeps every point of the drag; an arrow
+/// or a circle keeps where the drag started and where it is now.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mark {
+    pub tool: Tool,
+    pub ink: Ink,
+    pub points: Vec<Point>,
+}
+
+impl Mark {
+    pub fn start(tool: Tool, ink: Ink, at: Point) -> Self {
+        Mark { tool, ink, points: vec![at] }
+    }
+
+    /// Follow the drag to `at`.
+    pub fn extend(&mut self, at: Point) {
+        match self.tool {
+            Tool::Pen => {
+                if self.points.last().is_none_or(|p| p.distance(at) >= MIN_STEP) {
+                    self.points.push(at);
+                }
+            }
+            Tool::Arrow | Tool::Circle => {
+                self.points.truncate(1);
+                self.points.push(at);
+            }
+        }
+    }
+}
+
+/// Draw `marks` in order, later ones on top.
+pub fn draw_marks<'m>(fr: &mut Frame, marks: impl IntoIterator<Item = &'m Mark>) {
+    for mark in marks {
+        let stroke = Stroke::default().with_color(mark.ink.color()).with_width(WIDTH);
+        let (Some(&first), Some(&last)) = (mark.points.first(), mark.points.last()) else { continue };
+        match mark.tool {
+            // A click without a drag leaves a dot.
+            Tool::Pen if mark.points.len() == 1 => fr.fill(&Path::circle(first, WIDTH), mark.ink.color()),
+            Tool::Pen => {
+                let line = Path::new(|b| {
+                    b.move_to(first);
+                    for &p in &mark.points[1..] {
+                        b.line_to(p);
+                    }
+                });
+                fr.stroke(&line, stroke);
+            }
+            Tool::Arrow => {
+                if first.distance(last) < MIN_STEP {
+                    continue;
+                }
+                let back = (first.y - last.y).atan2(first.x - last.x);
+                let wing = |a: f32| Point::new(last.x + HEAD * a.cos(), last.y + HEAD * a.sin());
+                let arrow = Path::new(|b| {
+                    b.move_to(first);
+                    b.line_to(last);
+                    b.move_to(wing(back - HEAD_ANGLE));
+                    b.line_to(last);
+                    b.line_to(wing(back + HEAD_ANGLE));
+                });
+                fr.stroke(&arrow, stroke);
+            }
+            // The drag spans the circle's diameter.
+            Tool::Circle => {
+                let center = Point::new(0.5 * (first.x + last.x), 0.5 * (first.y + last.y));
+                let radius = 0.5 * first.distance(last);
+                if radius >= MIN_STEP {
+                    fr.stroke(&Path::circle(center, radius), stroke);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// src/render/blueprint.rs
+
+//! Blueprint diagram: the cube seen straight on from the top, the front,
+//! and the right, side by side as an SVG drawing in white on blue. Each
+//! view has its own orthographic camera, fixed whatever the interactive
+//! views show, so the diagram always reads the same way.
+
+use std::fmt::Write as _;
+
+use iced::Color;
+
+use crate::cube::{Cube, FaceId};
+use crate::t;
+use super::face::{base_color, cell_quad_raw};
+use super::geom::{face_normal, inset_polygon};
+use super::markup::escape;
+
+type P3 = (f32, f32, f32);
+
+/// Pixels per cube unit (half a cube side).
+const UNIT: f32 = 60.0;
+/// Width given to each view, and the margins around the views.
+const VIEW_W: f32 = 220.0;
+const MARGIN: f32 = 40.0;
+/// Room under each view for its label.
+const LABEL_H: f32 = 36.0;
+/// Sticker inset from its cell.
+const INSET: f32 = 0.1;
+
+const PAPER: &str = "#1d4e89";
+const INK: &str = "#e8f1ff";
+
+/// An orthographic camera: the object-space directions that map to
+/// screen right and screen up. It looks along `up × right`.
+struct Ortho {
+    right: P3,
+    up: P3,
+}
+
+impl Ortho {
+    /// Top: looking down, the back edge up. Front: looking at F, U up.
+    /// Right: looking at R, U up.
+    const TOP: Ortho = Ortho { right: (1.0, 0.0, 0.0), up: (0.0, 1.0, 0.0) };
+    const FRONT: Ortho = Ortho { right: (1.0, 0.0, 0.0), up: (0.0, 0.0, 1.0) };
+    const RIGHT: Ortho = Ortho { right: (0.0, 1.0, 0.0), up: (0.0, 0.0, 1.0) };
+
+    /// Toward the viewer.
+    fn toward(&self) -> P3 {
+        cross(self.right, self.up)
+    }
+
+    /// Screen position of `p` in cube units, y down, with the cube's
+    /// center at the origin.
+    fn project(&self, p: P3) -> (f32, f32) {
+        let c = (p.0 - 1.0, p.1 - 1.0, p.2 - 1.0);
+        (dot(c, self.right), -dot(c, self.up))
+    }
+}
+
+fn dot(a: P3, b: P3) -> f32 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+fn cross(a: P3, b: P3) -> P3 {
+    (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+}
+
+pub(super) fn hex(c: Color) -> String {
+    let [r, g, b, _] = c.into_rgba8();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn points(q: &[(f32, f32); 4]) -> String {
+    q.iter().map(|(x, y)| format!("{x:.1},{y:.1}")).collect::<Vec<_>>().join(" ")
+}
+
+/// One view centered at `(cx, cy)`: the faces turned toward its camera,
+/// plastic then stickers, and the outline.
+fn view(svg: &mut String, cube: &Cube, cam: &Ortho, cx: f32, cy: f32) {
+    let at = |p: P3| {
+        let (x, y) = cam.project(p);
+        (cx + x * UNIT, cy + y * UNIT)
+    };
+    for face in FaceId::ALL {
+        if dot(face_normal(face), cam.toward()) <= 0.0 {
+            continue;
+        }
+        for r in 0..2 {
+            for c in 0..2 {
+                let cell = cell_quad_raw(face, r, c).map(at);
+                let sticker = inset_polygon(&cell, INSET);
+                let _ = write!(svg, r##"<polygon points="{}" fill="#111"/>"##, points(&cell));
+                let _ = write!(
+                    svg,
+                    r#"<polygon points="{}" fill="{}"/>"#,
+                    points(&sticker),
+                    hex(base_color(cube.face(face)[r][c])),
+                );
+            }
+        }
+    }
+    let side = 2.0 * UNIT;
+    let _ = write!(
+        svg,
+        r#"<rect x="{:.1}" y="{:.1}" width="{side}" height="{side}" fill="none" stroke="{INK}" stroke-width="2"/>"#,
+        cx - UNIT,
+        cy - UNIT,
+    );
+}
+
+/// The three views of `cube` as a standalone SVG document, with `desc` as
+/// its description.
+pub fn svg(cube: &Cube, desc: &str) -> String {
+    let views = [
+        (Ortho::TOP, t!("blueprint-top")),
+        (Ortho::FRONT, t!("blueprint-front")),
+        (Ortho::RIGHT, t!("blueprint-right")),
+    ];
+    let width = 2.0 * MARGIN + VIEW_W * views.len() as f32;
+    let height = 2.0 * MARGIN + 2.0 * UNIT + LABEL_H;
+    let cy = MARGIN + UNIT;
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    let _ = write!(svg, "<desc>{}</desc>", escape(desc));
+    let _ = write!(svg, r#"<rect width="{width}" height="{height}" fill="{PAPER}"/>"#);
+    // Projection lines carrying the top and bottom edges across the views.
+    for y in [cy - UNIT, cy + UNIT] {
+        let _ = write!(
+            svg,
+            r#"<line x1="{MARGIN}" y1="{y}" x2="{}" y2="{y}" stroke="{INK}" stroke-opacity="0.35" stroke-dasharray="4 4"/>"#,
+            width - MARGIN,
+        );
+    }
+    for (i, (cam, label)) in views.iter().enumerate() {
+        let cx = MARGIN + VIEW_W * (i as f32 + 0.5);
+        view(&mut svg, cube, cam, cx, cy);
+        let _ = write!(
+            svg,
+            r#"<text x="{cx}" y="{:.1}" fill="{INK}" font-family="monospace" font-size="14" text-anchor="middle">{}</text>"#,
+            cy + UNIT + LABEL_H - 8.0,
+            escape(&label.to_uppercase()),
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
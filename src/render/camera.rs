@@ -0,0 +1,170 @@
+// src/render/camera.rs
+
+//! Named camera poses (isometric, dimetric, front, top) expressed as the
+//! (Rz, Rx, Ry) slider angles that produce them under `geom::project`.
+//!
+//! A pose is described as an orbit around the cube: start looking straight at
+//! F with U up, yaw about the screen's up axis, then pitch about its right
+//! axis. The resulting orientation is decomposed into the renderer's Euler
+//! order (Rz first, then Ry, then Rx).
+//!
+//! Face-on views are built from the face's normal instead: it is turned
+//! toward the viewer with a neighbouring face's direction up.
+
+use crate::cube::FaceId;
+use crate::t;
+use super::geom::face_normal;
+
+type Mat3 = [[f32; 3]; 3];
+
+/// Canonical camera angles offered as one-click presets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraPreset {
+    /// True isometric: 45° yaw, 35.264° (atan 1/√2) elevation; U/F/R equal.
+    Isometric,
+    /// 2:1 dimetric: 45° yaw, 30° elevation.
+    Dimetric,
+    /// Front orthographic: F face square-on, U up.
+    Front,
+    /// Top-down: U face square-on, F at the bottom.
+    TopDown,
+}
+
+impl CameraPreset {
+    pub const ALL: [CameraPreset; 4] = [
+        CameraPreset::Isometric, CameraPreset::Dimetric, CameraPreset::Front, CameraPreset::TopDown,
+    ];
+
+    /// Localized button label.
+    pub fn label(self) -> String {
+        match self {
+            CameraPreset::Isometric => t!("preset-isometric"),
+            CameraPreset::Dimetric  => t!("preset-dimetric"),
+            CameraPreset::Front     => t!("preset-front"),
+            CameraPreset::TopDown   => t!("preset-top"),
+        }
+    }
+
+    /// Orbit (yaw, pitch) in degrees relative to the front view.
+    fn orbit(self) -> (f32, f32) {
+        match self {
+            CameraPreset::Isometric => (-45.0, (1.0f32 / 2.0f32.sqrt()).atan().to_degrees()),
+            CameraPreset::Dimetric  => (-45.0, 30.0),
+            CameraPreset::Front     => (0.0, 0.0),
+            CameraPreset::TopDown   => (0.0, 90.0),
+        }
+    }
+
+    /// Slider angles `(rz, rx, ry)` in `[0, 360)` degrees for this pose.
+    pub fn angles(self) -> (f32, f32, f32) {
+        let (yaw, pitch) = self.orbit();
+        orbit_angles(yaw, pitch)
+    }
+}
+
+/// Screen basis of the isometric projection, in world coordinates:
+/// right, up, and toward the viewer (columns).
+fn screen() -> Mat3 {
+    let (s2, s3, s6) = (2.0f32.sqrt(), 3.0f32.sqrt(), 6.0f32.sqrt());
+    [
+        [ 1.0 / s2, 1.0 / s6, -1.0 / s3],
+        [-1.0 / s2, 1.0 / s6, -1.0 / s3],
+        [ 0.0,      2.0 / s6,  1.0 / s3],
+    ]
+}
+
+/// Convert an orbit (yaw, pitch in degrees) into `(rz, rx, ry)` degrees.
+pub fn orbit_angles(yaw: f32, pitch: f32) -> (f32, f32, f32) {
+    let screen = screen();
+    // Front view in screen coordinates: +X right, -Y (F) toward viewer, +Z up.
+    let front: Mat3 = [
+        [1.0, 0.0,  0.0],
+        [0.0, 0.0,  1.0],
+        [0.0, -1.0, 0.0],
+    ];
+    let m = mul(&mul(&mul(&screen, &rot_x(pitch)), &rot_y(yaw)), &front);
+    let (rz, rx, ry) = decompose(&m);
+    (wrap(rz), wrap(rx), wrap(ry))
+}
+
+/// Slider angles `(rz, rx, ry)` looking squarely at `face`: U up for the
+/// side faces, F at the bottom for U and at the top for D.
+pub fn face_angles(face: FaceId) -> (f32, f32, f32) {
+    let n = face_normal(face);
+    let up = match face {
+        FaceId::U => (0.0, 1.0, 0.0),
+        FaceId::D => (0.0, -1.0, 0.0),
+        _ => face_normal(FaceId::U),
+    };
+    let right = cross(up, n);
+    // Rows take the face's right, up, and normal to the standard axes;
+    // `screen` takes those to screen right, up, and toward the viewer.
+    let basis: Mat3 = [[right.0, right.1, right.2], [up.0, up.1, up.2], [n.0, n.1, n.2]];
+    let (rz, rx, ry) = decompose(&mul(&screen(), &basis));
+    (wrap(rz), wrap(rx), wrap(ry))
+}
+
+/// The pose whose picture is the left–right mirror image of the pose
+/// `(rz, rx, ry)`, cube included: a view of U, F, and R becomes one of U,
+/// F, and L seen from the other side. Mirroring twice gives the pose back.
+pub fn mirror_angles(rz: f32, rx: f32, ry: f32) -> (f32, f32, f32) {
+    // Flip the screen's right axis and the cube's L–R axis (world X);
+    // the two reflections make a rotation again.
+    let flip: Mat3 = [[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    let screen = screen();
+    let flip_screen = mul(&mul(&screen, &flip), &transpose(&screen));
+    let m = mul(&mul(&rot_x(rx), &rot_y(ry)), &rot_z(rz));
+    let (rz, rx, ry) = decompose(&mul(&mul(&flip_screen, &m), &flip));
+    (wrap(rz), wrap(rx), wrap(ry))
+}
+
+/// Split `m = Rx(a)·Ry(b)·Rz(c)` into degrees `(c, a, b)`.
+fn decompose(m: &Mat3) -> (f32, f32, f32) {
+    let b = m[0][2].clamp(-1.0, 1.0).asin();
+    let (a, c) = if b.cos().abs() > 1e-5 {
+        ((-m[1][2]).atan2(m[2][2]), (-m[0][1]).atan2(m[0][0]))
+    } else {
+        // Gimbal lock: fold the Z turn into X.
+        (m[2][1].atan2(m[1][1]), 0.0)
+    };
+    (c.to_degrees(), a.to_degrees(), b.to_degrees())
+}
+
+fn rot_x(deg: f32) -> Mat3 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]]
+}
+
+fn rot_y(deg: f32) -> Mat3 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]]
+}
+
+fn rot_z(deg: f32) -> Mat3 {
+    let (s, c) = deg.to_radians().sin_cos();
+    [[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]]
+}
+
+fn cross(a: (f32, f32, f32), b: (f32, f32, f32)) -> (f32, f32, f32) {
+    (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+}
+
+fn transpose(m: &Mat3) -> Mat3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| m[j][i]))
+}
+
+fn mul(a: &Mat3, b: &Mat3) -> Mat3 {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+/// Wrap into `[0, 360)` and drop float dust (e.g. 359.9999 → 0, -0 → 0).
+fn wrap(d: f32) -> f32 {
+    let d = (d * 1000.0).round() / 1000.0;
+    d.rem_euclid(360.0) % 360.0 + 0.0
+}
@@ -1,67 +1,328 @@
 // src/render/canvas.rs
 
-//! Iced `Canvas` program that draws two cube views with depth sorting.
+//! Iced `Canvas` program that draws one or two cube views with depth sorting.
+//! The scene is cached between draws and instrumented for the F12 profiler.
+//! Annotations are drawn on top, uncached; while a pen is given, left drags
+//! draw a new one, reported as a message when the button is released.
+//! A file held over the window outlines the canvas as its drop target.
+//! Touch gestures on the views turn faces, orbit, and zoom. With the
+//! trackball camera and no pen, left drags roll the views. During a turn in
+//! playback the cube is drawn block by block instead of face by face.
 
-use iced::widget::canvas::{self, Frame, Program};
-use iced::{Theme, Rectangle};
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Instant;
 
-use super::types::ViewParams;
-use super::face::{draw_face};
-use super::layout::{layout_origins, fit_vertically};
+use iced::mouse;
+use iced::widget::canvas::{self, event, Cache, Event, Frame, Program};
+use iced::{Point, Theme, Rectangle, Size};
+
+use super::types::{CameraModel, ViewParams, RenderSettings};
+use super::face::{draw_cube, draw_turning, FaceOverlay};
+use super::layer::LayerTurn;
+use super::layout::place;
+use super::overlay::{draw_axes, draw_drop_target, draw_grid};
+use super::profile::{RenderStats, draw_profiler};
+use super::annotate::{draw_marks, Ink, Mark, Tool};
+use super::touch::{Gesture, Touches};
+use super::quat::Quat;
+use super::trackball::{center, roll};
 use crate::cube::{Cube, FaceId};
-use crate::render::geom::face_depth;
+use crate::logic::heat::sticker_heat;
+use crate::logic::tracking::Sticker;
+use crate::logic::memo::LetterScheme;
 
 pub struct CubeCanvas<'a> {
     pub cube: &'a Cube,
     pub left: ViewParams,
-    pub right: ViewParams,
+    /// Second view; `None` draws `left` alone, centered.
+    pub right: Option<ViewParams>,
+    pub settings: RenderSettings,
+    /// Face to outline and how strongly (0–1), e.g. the face just turned.
+    pub glow: Option<(FaceId, f32)>,
+    /// Stickers of a tracked piece, outlined.
+    pub tracked: Option<[Sticker; 3]>,
+    /// Letters drawn on the stickers when `settings.show_letters` is on.
+    pub scheme: &'a LetterScheme,
+    /// Stickers that differ from a compared state, outlined.
+    pub diff: &'a [Sticker],
+    /// Annotations shown over the views.
+    pub marks: &'a [Mark],
+    /// Tool and ink for drawing with the mouse; `None` leaves the mouse alone.
+    pub pen: Option<(Tool, Ink)>,
+    /// A face turn partway through, drawn with its layer turned.
+    pub turning: Option<LayerTurn>,
+    /// A file is held over the window: highlight the canvas as where it
+    /// can be dropped.
+    pub drop_target: bool,
+    /// Fingerprint of everything the cached scene depends on, set by
+    /// [`stamped`](Self::stamped) when the view is built (0 until then).
+    pub revision: u64,
+}
+/// Persistent canvas state: the cached scene, the revision and size it was
+/// built for, and the render stats.
+#[derive(Default)]
+pub struct CanvasState {
+    cache: Cache,
+    built: Cell<Option<(u64, Size)>>,
+    stats: RefCell<RenderStats>,
+    /// The mark being dragged out.
+    drawing: Option<Mark>,
+    touches: Touches,
+    /// The trackball drag under way: whether it rolls the right view, and
+    /// the cursor at the last step.
+    rolling: Option<(bool, Point)>,
 }
-impl<'a> Program<()> for CubeCanvas<'a> {
-    type State = ();
 
-    /// Draw both views into the provided canvas bounds. Auto-places and
-    /// vertically fits both views to keep them within margins.
+/// What the canvas reports.
+#[derive(Debug, Clone)]
+pub enum CanvasEvent {
+    /// An annotation was drawn.
+    Mark(Mark),
+    Gesture(Gesture),
+    /// A trackball drag step: turn the left or right view by `turn`, in
+    /// world space.
+    Roll { right: bool, turn: Quat },
+}
+
+impl<'a> Program<CanvasEvent> for CubeCanvas<'a> {
+    type State = CanvasState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<CanvasEvent>) {
+        if let Event::Touch(touch) = event {
+            let touch = relative(touch, bounds);
+            if let iced::touch::Event::FingerPressed { position, .. } = touch
+                && !Rectangle::with_size(bounds.size()).contains(position)
+            {
+                return (event::Status::Ignored, None);
+            }
+            let (left, right) = place(self.left, self.right, bounds);
+            let gesture = state.touches.update(touch, (left, self.right.map(|_| right)));
+            return (event::Status::Captured, gesture.map(CanvasEvent::Gesture));
+        }
+        if self.pen.is_none() && self.settings.camera == CameraModel::Trackball {
+            state.drawing = None;
+            return self.roll(state, event, bounds, cursor);
+        }
+        state.rolling = None;
+        let Some((tool, ink)) = self.pen else {
+            state.drawing = None;
+            return (event::Status::Ignored, None);
+        };
+        let Event::Mouse(event) = event else { return (event::Status::Ignored, None) };
+        let (status, mark) = match event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) => match cursor.position_in(bounds) {
+                Some(at) => {
+                    state.drawing = Some(Mark::start(tool, ink, at));
+                    (event::Status::Captured, None)
+                }
+                None => (event::Status::Ignored, None),
+            },
+            mouse::Event::CursorMoved { .. } => match (state.drawing.as_mut(), cursor.position_from(bounds.position())) {
+                (Some(mark), Some(at)) => {
+                    mark.extend(at);
+                    (event::Status::Captured, None)
+                }
+                _ => (event::Status::Ignored, None),
+            },
+            mouse::Event::ButtonReleased(mouse::Button::Left) => match state.drawing.take() {
+                Some(mark) => (event::Status::Captured, Some(mark)),
+                None => (event::Status::Ignored, None),
+            },
+            _ => (event::Status::Ignored, None),
+        };
+        (status, mark.map(CanvasEvent::Mark))
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if !cursor.is_over(bounds) {
+            mouse::Interaction::default()
+        } else if self.pen.is_some() {
+            mouse::Interaction::Crosshair
+        } else if self.settings.camera == CameraModel::Trackball {
+            if state.rolling.is_some() { mouse::Interaction::Grabbing } else { mouse::Interaction::Grab }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    /// Draw the views, reusing the cached scene when nothing that affects it
+    /// changed, and record timing/cache stats.
     fn draw(
         &self,
-        _state: &Self::State,
+        state: &Self::State,
         renderer: &iced::Renderer,
         _theme: &Theme,
         bounds: iced::Rectangle,
         _cursor: iced::mouse::Cursor,
     ) -> Vec<canvas::Geometry> {
-        let mut frame = Frame::new(renderer, bounds.size());
+        let start = Instant::now();
+
+        // Redraws that change nothing (the cursor moving, a relayout) only
+        // compare the revision and size.
+        let key = Some((self.revision, bounds.size()));
+        if state.built.get() != key {
+            state.cache.clear();
+            state.built.set(key);
+        }
 
-        // 1) Start with your incoming params
-        let mut left  = self.left;
-        let mut right = self.right;
+        let mut rebuilt = None;
+        let scene = state.cache.draw(renderer, bounds.size(), |frame| {
+            rebuilt = Some(self.draw_scene(frame, bounds));
+        });
 
-        // 2) If origins are NaN (our signal to auto-place), give them a first pass
-        if left.origin.0.is_nan() || right.origin.0.is_nan() {
-            let (ol, or) = layout_origins(bounds, left.size.min(right.size));
-            if left.origin.0.is_nan()  { left.origin  = ol; }
-            if right.origin.0.is_nan() { right.origin = or; }
+        let mut stats = state.stats.borrow_mut();
+        stats.record(start.elapsed(), rebuilt);
+
+        let mut layers = vec![scene];
+        if !self.marks.is_empty() || state.drawing.is_some() {
+            let mut ink = Frame::new(renderer, bounds.size());
+            draw_marks(&mut ink, self.marks.iter().chain(&state.drawing));
+            layers.push(ink.into_geometry());
+        }
+        if self.drop_target {
+            let mut hint = Frame::new(renderer, bounds.size());
+            draw_drop_target(&mut hint, bounds.size());
+            layers.push(hint.into_geometry());
+        }
+        if self.settings.show_profiler {
+            let mut hud = Frame::new(renderer, bounds.size());
+            draw_profiler(&mut hud, &stats);
+            layers.push(hud.into_geometry());
         }
+        layers
+    }
+}
 
-        // 3) Nudge both views so the pair is vertically centered *and*
-        //    still respects top/bottom margins for the current size.
-        fit_vertically(bounds, &mut left, &mut right);
+/// `event` with its position relative to the canvas.
+fn relative(event: iced::touch::Event, bounds: Rectangle) -> iced::touch::Event {
+    use iced::touch::Event::*;
+    let at = |p: Point| Point::new(p.x - bounds.x, p.y - bounds.y);
+    match event {
+        FingerPressed { id, position } => FingerPressed { id, position: at(position) },
+        FingerMoved { id, position } => FingerMoved { id, position: at(position) },
+        FingerLifted { id, position } => FingerLifted { id, position: at(position) },
+        FingerLost { id, position } => FingerLost { id, position: at(position) },
+    }
+}
 
-        // 4) Depth-sorted render with the adjusted origins
-        let mut render = |vp: ViewParams| {
-            let ViewParams { rz, rx, ry, origin, size } = vp;
+impl<'a> CubeCanvas<'a> {
+    /// A trackball drag: a left drag rolls the view nearer where it began.
+    fn roll(
+        &self,
+        state: &mut CanvasState,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<CanvasEvent>) {
+        let Event::Mouse(event) = event else { return (event::Status::Ignored, None) };
+        let (left, right) = place(self.left, self.right, bounds);
+        match event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) => match cursor.position_in(bounds) {
+                Some(at) => {
+                    let on_right = self.right.is_some() && at.distance(center(&right)) < at.distance(center(&left));
+                    state.rolling = Some((on_right, at));
+                    (event::Status::Captured, None)
+                }
+                None => (event::Status::Ignored, None),
+            },
+            mouse::Event::CursorMoved { .. } => match (state.rolling.as_mut(), cursor.position_from(bounds.position())) {
+                (Some((on_right, last)), Some(at)) => {
+                    let turn = roll(if *on_right { &right } else { &left }, *last, at);
+                    *last = at;
+                    (event::Status::Captured, Some(CanvasEvent::Roll { right: *on_right, turn }))
+                }
+                _ => (event::Status::Ignored, None),
+            },
+            mouse::Event::ButtonReleased(mouse::Button::Left) => match state.rolling.take() {
+                Some(_) => (event::Status::Captured, None),
+                None => (event::Status::Ignored, None),
+            },
+            _ => (event::Status::Ignored, None),
+        }
+    }
 
-            let mut faces = [FaceId::U, FaceId::R, FaceId::F, FaceId::D, FaceId::L, FaceId::B];
-            faces.sort_by(|a, b| face_depth(*a, rz, rx, ry)
-                .partial_cmp(&face_depth(*b, rz, rx, ry)).unwrap());
+    /// This canvas with its [`revision`](Self::revision) set. Taken once per
+    /// view rather than on every redraw.
+    pub fn stamped(self) -> Self {
+        // Everything the scene depends on (the profiler flag excluded).
+        let key = format!(
+            "{}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+            self.cube.to_facelets(), self.left, self.right,
+            RenderSettings { show_profiler: false, ..self.settings }, self.glow, self.tracked, self.scheme, self.diff,
+            self.turning,
+        );
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self { revision: hasher.finish(), ..self }
+    }
 
-            for f in faces {
-                draw_face(&mut frame, self.cube.face(f), f, origin, size, rz, rx, ry);
+    /// Draw both views into the provided canvas bounds. Auto-places and
+    /// vertically fits both views to keep them within margins.
+    /// Returns the number of polygons emitted.
+    fn draw_scene(&self, frame: &mut Frame, bounds: Rectangle) -> usize {
+        let mut polygons = 0;
+
+        if self.settings.show_grid {
+            draw_grid(frame, bounds.size());
+        }
+
+        let (left, right) = place(self.left, self.right, bounds);
+
+        // 4) Depth-sorted render with the adjusted origins
+        let heat = if self.settings.show_heat { sticker_heat(self.cube) } else { None };
+        let overlays = FaceId::ALL.map(|f| {
+            let mut o = FaceOverlay {
+                glow: self.glow.filter(|(g, _)| *g == f).map_or(0.0, |(_, k)| k),
+                heat: heat.map(|h| h[f as usize]),
+                letters: self.settings.show_letters.then(|| self.scheme.face(f)),
+                ..Default::default()
+            };
+            for &(tf, r, c) in self.tracked.iter().flatten() {
+                if tf == f {
+                    o.tracked[r][c] = true;
+                }
+            }
+            for &(df, r, c) in self.diff {
+                if df == f {
+                    o.diff[r][c] = true;
+                }
             }
+            o
+        });
+        let mut render = |vp: ViewParams| {
+            if let Some(turn) = &self.turning {
+                polygons += draw_turning(frame, self.cube, turn, &vp, &self.settings);
+                return;
+            }
+            polygons += draw_cube(frame, self.cube, &vp, &self.settings, &overlays);
         };
 
         render(left);
-        render(right);
+        if self.right.is_some() { render(right); }
+
+        // 5) Axis triads in the bottom corners (left view left, right view right)
+        if self.settings.show_axes {
+            const INSET: f32 = 34.0;
+            let y = bounds.height - INSET;
+            draw_axes(frame, &left, (INSET, y));
+            if self.right.is_some() {
+                draw_axes(frame, &right, (bounds.width - INSET, y));
+            }
+        }
 
-        vec![frame.into_geometry()]
+        polygons
     }
 }
\ No newline at end of file
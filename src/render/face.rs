@@ -1,15 +1,22 @@
 // src/render/face.rs
 
-//! Draw a single cube face (plastic base + 2×2 stickers) into a canvas frame.
+//! Draw a single cube face (plastic base + 2×2 stickers) into a canvas frame,
+//! or the whole cube with a layer partway through a turn. Detail follows the
+//! cube size: small cubes get plain stickers without seams, large ones
+//! rounded sticker corners, and faces entirely off the frame are skipped.
 
 use iced::Color;
 use iced::widget::canvas::{self, Frame};
 
-use crate::cube::{Col, Face, FaceId};
-use super::types::{RotZ, RotX, RotY};
-use super::geom::{project, face_outer, inset_polygon, face_visible, rotate_pt_all};
+use crate::cube::{Col, Cube, Face, FaceId};
+use super::types::{ViewParams, RenderSettings, RenderMode};
+use super::hinting::{hint_width, snap_half};
+use super::geom::{
+    project, face_depth, face_normal, face_outer, inset_polygon, face_visible, face_shade, normal_shade, rotate_pt_all, rotate_vec_all,
+};
+use super::layer::{facing, LayerTurn};
 
-fn base_color(c: Col) -> Color {
+pub(super) fn base_color(c: Col) -> Color {
     match c {
         Col::W => Color::from_rgb(1.0, 1.0, 1.0),
         Col::Y => Color::from_rgb(1.0, 0.90, 0.00),
@@ -20,6 +27,80 @@ fn base_color(c: Col) -> Color {
     }
 }
 
+/// Outline color for a highlighted face.
+const GLOW_COLOR: Color = Color { r: 1.0, g: 0.85, b: 0.3, a: 1.0 };
+
+/// Outline color for the stickers of a tracked piece.
+const TRACK_COLOR: Color = Color { r: 0.85, g: 0.1, b: 0.95, a: 1.0 };
+
+/// Outline color for stickers that differ from a compared state.
+const DIFF_COLOR: Color = Color { r: 0.1, g: 0.9, b: 0.95, a: 1.0 };
+
+/// Below this size stickers fill their cells, without inset or seams.
+const LOW_DETAIL_BELOW: f32 = 20.0;
+/// From this size sticker corners are rounded.
+const HIGH_DETAIL_FROM: f32 = 32.0;
+/// Share of each sticker edge taken by a rounded corner.
+const CORNER_ROUND: f32 = 0.2;
+
+/// How much detail a cube of a given size gets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Detail {
+    Low,
+    Normal,
+    High,
+}
+
+impl Detail {
+    fn for_size(size: f32) -> Self {
+        if size < LOW_DETAIL_BELOW {
+            Detail::Low
+        } else if size >= HIGH_DETAIL_FROM {
+            Detail::High
+        } else {
+            Detail::Normal
+        }
+    }
+}
+
+/// Extras drawn over one face's stickers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FaceOverlay {
+    /// Face outline opacity in [`GLOW_COLOR`] (0 = none).
+    pub glow: f32,
+    /// Piece distance per sticker, tinted by [`heat_tint`].
+    pub heat: Option<[[u8; 2]; 2]>,
+    /// Stickers of the tracked piece, outlined in [`TRACK_COLOR`].
+    pub tracked: [[bool; 2]; 2],
+    /// Stickers differing from a compared state, outlined in [`DIFF_COLOR`].
+    pub diff: [[bool; 2]; 2],
+    /// Letter-scheme label per sticker.
+    pub letters: Option<[[char; 2]; 2]>,
+}
+
+/// Label ink that stays readable on sticker color `c`.
+fn letter_ink(c: Col) -> Color {
+    match c {
+        Col::W | Col::Y | Col::O => Color::from_rgb(0.05, 0.05, 0.05),
+        Col::R | Col::B | Col::G => Color::WHITE,
+    }
+}
+
+/// Translucent overlay for a piece `d` turns from home; none when home.
+fn heat_tint(d: u8) -> Option<Color> {
+    match d {
+        0 => None,
+        1 => Some(Color::from_rgba(1.0, 0.95, 0.2, 0.35)),
+        2 => Some(Color::from_rgba(1.0, 0.5, 0.0, 0.5)),
+        _ => Some(Color::from_rgba(0.85, 0.0, 0.25, 0.6)),
+    }
+}
+
+/// Scale a color's RGB channels by `k` (alpha untouched).
+fn shaded(c: Color, k: f32) -> Color {
+    Color::from_rgba(c.r * k, c.g * k, c.b * k, c.a)
+}
+
 fn path_polygon(points: &[[f32; 2]]) -> canvas::Path {
     canvas::Path::new(|b| {
         if let Some(first) = points.first() {
@@ -32,9 +113,49 @@ fn path_polygon(points: &[[f32; 2]]) -> canvas::Path {
     })
 }
 
+/// A quad with its corners rounded off by quadratic curves, each taking
+/// [`CORNER_ROUND`] of the edges meeting there.
+fn rounded_polygon(q: &[(f32, f32); 4]) -> canvas::Path {
+    let toward = |from: (f32, f32), to: (f32, f32)| {
+        iced::Point::new(from.0 + (to.0 - from.0) * CORNER_ROUND, from.1 + (to.1 - from.1) * CORNER_ROUND)
+    };
+    canvas::Path::new(|b| {
+        b.move_to(toward(q[0], q[1]));
+        for i in 1..=4 {
+            let (prev, at, next) = (q[i - 1], q[i % 4], q[(i + 1) % 4]);
+            b.line_to(toward(at, prev));
+            b.quadratic_curve_to(iced::Point::new(at.0, at.1), toward(at, next));
+        }
+        b.close();
+    })
+}
+
+/// The sticker in cell `cell` (projected corners) at `detail`, with the
+/// corners it is drawn within.
+fn sticker_path(cell: &[(f32, f32); 4], detail: Detail, inset_k: f32, scale: f32) -> ([(f32, f32); 4], canvas::Path) {
+    let corners = match detail {
+        Detail::Low => cell.map(|p| snap_half(p, scale)),
+        _ => inset_polygon(cell, inset_k).map(|p| snap_half(p, scale)),
+    };
+    let path = match detail {
+        Detail::High => rounded_polygon(&corners),
+        _ => path_polygon(&corners.map(|(x, y)| [x, y])),
+    };
+    (corners, path)
+}
+
+/// Whether the projected quad lies wholly to one side of the frame.
+fn off_frame(fr: &Frame, q: &[(f32, f32); 4]) -> bool {
+    let size = fr.size();
+    q.iter().all(|p| p.0 < 0.0)
+        || q.iter().all(|p| p.1 < 0.0)
+        || q.iter().all(|p| p.0 > size.width)
+        || q.iter().all(|p| p.1 > size.height)
+}
+
 /// Local copy of the raw 3D quad for a given face cell (row `r`, col `c`).
 /// This avoids importing `face_cell_raw` in case your build/module layout differs.
-fn cell_quad_raw(face: FaceId, r: usize, c: usize) -> [(f32, f32, f32); 4] {
+pub(super) fn cell_quad_raw(face: FaceId, r: usize, c: usize) -> [(f32, f32, f32); 4] {
     let r = r as f32;
     let c = c as f32;
     let p = |x: f32, y: f32, z: f32| (x, y, z);
@@ -92,23 +213,35 @@ fn cell_quad_raw(face: FaceId, r: usize, c: usize) -> [(f32, f32, f32); 4] {
 
 /// Draw one face of the cube with plastic edges and sticker seams.
 /// Skips rendering if the face is back-facing for the given orientation.
+/// Sticker colors are shaded by the face normal against `settings.light`,
+/// then `overlay` is drawn on top (filled mode only).
+/// Returns the number of polygons emitted (for render stats).
 pub fn draw_face(
     fr: &mut Frame,
     face: &Face,
     which: FaceId,
-    origin: (f32, f32),
-    size: f32,
-    rz: RotZ,
-    rx: RotX,
-    ry: RotY,
-) {
+    vp: &ViewParams,
+    settings: &RenderSettings,
+    overlay: &FaceOverlay,
+) -> usize {
+    let ViewParams { rz, rx, ry, origin, size, mode } = *vp;
     if !face_visible(which, rz, rx, ry) {
-        return;
+        return 0;
     }
-
-    // 1) plastic base
     let outer = face_outer(which).map(|p| rotate_pt_all(p, rz, ry, rx));
     let outer_xy = outer.map(|(x, y, z)| project(x, y, z, size, origin));
+    if off_frame(fr, &outer_xy) {
+        return 0;
+    }
+    let scale = settings.scale_factor;
+    if mode == RenderMode::Wireframe {
+        draw_face_wire(fr, which, vp, scale);
+        return 5; // outline + 4 cells
+    }
+    let shade = face_shade(which, rz, rx, ry, &settings.light);
+    let detail = Detail::for_size(size);
+
+    // 1) plastic base
     let outer_path = path_polygon(&[
         [outer_xy[0].0, outer_xy[0].1],
         [outer_xy[1].0, outer_xy[1].1],
@@ -116,8 +249,8 @@ pub fn draw_face(
         [outer_xy[3].0, outer_xy[3].1],
     ]);
 
-    let plastic_w = (size * 0.070).clamp(0.9, 2.4);
-    let seam_w = (size * 0.030).clamp(0.4, 1.2);
+    let plastic_w = hint_width((size * 0.070).clamp(0.9, 2.4), scale);
+    let seam_w = hint_width((size * 0.030).clamp(0.4, 1.2), scale);
     let inset_k = (0.09 + (size - 24.0) * 0.002).clamp(0.09, 0.14);
 
     fr.fill(&outer_path, Color::from_rgb(0.05, 0.05, 0.05));
@@ -136,30 +269,183 @@ pub fn draw_face(
             let q3 = cell_quad_raw(which, r, c).map(|p| rotate_pt_all(p, rz, ry, rx));
             let pts = q3.map(|(x, y, z)| project(x, y, z, size, origin));
 
-            let raw2d = [
-                (pts[0].0, pts[0].1),
-                (pts[1].0, pts[1].1),
-                (pts[2].0, pts[2].1),
-                (pts[3].0, pts[3].1),
-            ];
-            let inset = inset_polygon(&raw2d, inset_k);
-
-            let poly = path_polygon(&[
-                [inset[0].0, inset[0].1],
-                [inset[1].0, inset[1].1],
-                [inset[2].0, inset[2].1],
-                [inset[3].0, inset[3].1],
-            ]);
-
-            fr.fill(&poly, base_color(face[r][c]));
+            let (inset, poly) = sticker_path(&pts, detail, inset_k, scale);
+
+            fr.fill(&poly, shaded(base_color(face[r][c]), shade));
+            if let Some(tint) = overlay.heat.and_then(|h| heat_tint(h[r][c])) {
+                fr.fill(&poly, tint);
+            }
+            let outline = if overlay.tracked[r][c] {
+                Some(TRACK_COLOR)
+            } else if overlay.diff[r][c] {
+                Some(DIFF_COLOR)
+            } else {
+                None
+            };
+            if let Some(color) = outline {
+                fr.stroke(
+                    &poly,
+                    canvas::Stroke {
+                        width: plastic_w * 1.6,
+                        style: canvas::stroke::Style::Solid(color),
+                        ..Default::default()
+                    },
+                );
+            }
+            if detail != Detail::Low {
+                fr.stroke(
+                    &poly,
+                    canvas::Stroke {
+                        width: seam_w,
+                        style: canvas::stroke::Style::Solid(Color::from_rgb(0.04, 0.04, 0.04)),
+                        ..Default::default()
+                    },
+                );
+            }
+            if let Some(letters) = overlay.letters {
+                let cx = inset.iter().map(|p| p.0).sum::<f32>() / 4.0;
+                let cy = inset.iter().map(|p| p.1).sum::<f32>() / 4.0;
+                fr.fill_text(canvas::Text {
+                    content: letters[r][c].to_string(),
+                    position: iced::Point::new(cx, cy),
+                    color: letter_ink(face[r][c]),
+                    size: (size * 0.45).into(),
+                    horizontal_alignment: iced::alignment::Horizontal::Center,
+                    vertical_alignment: iced::alignment::Vertical::Center,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // 3) highlight outline
+    if overlay.glow > 0.0 {
+        fr.stroke(
+            &outer_path,
+            canvas::Stroke {
+                width: plastic_w * 2.0,
+                style: canvas::stroke::Style::Solid(Color { a: overlay.glow, ..GLOW_COLOR }),
+                ..Default::default()
+            },
+        );
+    }
+
+    // plastic base + 4 stickers
+    5
+}
+
+/// Outline-only variant of [`draw_face`]: face border plus the sticker grid
+/// (un-inset cells, so seams become single lines). No fills are emitted.
+fn draw_face_wire(fr: &mut Frame, which: FaceId, vp: &ViewParams, scale: f32) {
+    let ViewParams { rz, rx, ry, origin, size, .. } = *vp;
+    let to_2d = |q: [(f32, f32, f32); 4]| {
+        let pts = q
+            .map(|p| rotate_pt_all(p, rz, ry, rx))
+            .map(|(x, y, z)| snap_half(project(x, y, z, size, origin), scale));
+        path_polygon(&pts.map(|(x, y)| [x, y]))
+    };
+
+    let edge_w = hint_width((size * 0.060).clamp(0.8, 2.0), scale);
+    let seam_w = hint_width((size * 0.030).clamp(0.4, 1.2), scale);
+    let ink = Color::from_rgb(0.10, 0.10, 0.10);
+
+    for r in 0..2 {
+        for c in 0..2 {
             fr.stroke(
-                &poly,
+                &to_2d(cell_quad_raw(which, r, c)),
                 canvas::Stroke {
                     width: seam_w,
-                    style: canvas::stroke::Style::Solid(Color::from_rgb(0.04, 0.04, 0.04)),
+                    style: canvas::stroke::Style::Solid(ink),
                     ..Default::default()
                 },
             );
         }
     }
-}
\ No newline at end of file
+    fr.stroke(
+        &to_2d(face_outer(which)),
+        canvas::Stroke {
+            width: edge_w,
+            style: canvas::stroke::Style::Solid(ink),
+            ..Default::default()
+        },
+    );
+}
+
+/// Draw the whole of `cube` in one view, far faces first, each with its
+/// overlay. Returns the number of polygons emitted.
+pub fn draw_cube(fr: &mut Frame, cube: &Cube, vp: &ViewParams, settings: &RenderSettings, overlays: &[FaceOverlay; 6]) -> usize {
+    let ViewParams { rz, rx, ry, .. } = *vp;
+    let mut faces = [FaceId::U, FaceId::R, FaceId::F, FaceId::D, FaceId::L, FaceId::B];
+    faces.sort_by(|a, b| face_depth(*a, rz, rx, ry)
+        .partial_cmp(&face_depth(*b, rz, rx, ry)).unwrap());
+    faces.into_iter().map(|f| draw_face(fr, cube.face(f), f, vp, settings, &overlays[f as usize])).sum()
+}
+
+/// Draw `cube` with `turn`'s layer turned partway: the farther of the two
+/// blocks first, each as its visible cells (plastic, then the inset sticker)
+/// and the plastic of its cut face. Overlays are left out while turning.
+/// Returns the number of polygons emitted.
+pub fn draw_turning(fr: &mut Frame, cube: &Cube, turn: &LayerTurn, vp: &ViewParams, settings: &RenderSettings) -> usize {
+    let ViewParams { rz, rx, ry, origin, size, mode } = *vp;
+    let scale = settings.scale_factor;
+    let to_2d = |q: [(f32, f32, f32); 4]| {
+        q.map(|p| rotate_pt_all(p, rz, ry, rx))
+            .map(|(x, y, z)| snap_half(project(x, y, z, size, origin), scale))
+    };
+    let seam_w = hint_width((size * 0.030).clamp(0.4, 1.2), scale);
+    let inset_k = (0.09 + (size - 24.0) * 0.002).clamp(0.09, 0.14);
+    let detail = Detail::for_size(size);
+    let plastic = Color::from_rgb(0.05, 0.05, 0.05);
+    let ink = Color::from_rgb(0.10, 0.10, 0.10);
+    let seam = |fr: &mut Frame, path: &canvas::Path, color: Color| {
+        fr.stroke(path, canvas::Stroke { width: seam_w, style: canvas::stroke::Style::Solid(color), ..Default::default() });
+    };
+
+    let mut polygons = 0;
+    let front = turn.layer_in_front(rz, rx, ry);
+    for layer in [!front, front] {
+        let place = |q: [(f32, f32, f32); 4]| if layer { q.map(|p| turn.turn_pt(p)) } else { q };
+        let normal = |n| rotate_vec_all(if layer { turn.turn_vec(n) } else { n }, rz, ry, rx);
+
+        let (cut, n) = turn.cut(layer);
+        if facing(normal(n)) {
+            let path = path_polygon(&to_2d(place(cut)).map(|(x, y)| [x, y]));
+            match mode {
+                RenderMode::Filled => fr.fill(&path, plastic),
+                RenderMode::Wireframe => seam(fr, &path, ink),
+            }
+            polygons += 1;
+        }
+        for which in FaceId::ALL {
+            let n = normal(face_normal(which));
+            if !facing(n) {
+                continue;
+            }
+            let shade = normal_shade(n, &settings.light);
+            for r in 0..2 {
+                for c in 0..2 {
+                    let cell = cell_quad_raw(which, r, c);
+                    let mid = cell.iter().fold((0.0, 0.0, 0.0), |m, p| (m.0 + p.0 / 4.0, m.1 + p.1 / 4.0, m.2 + p.2 / 4.0));
+                    if turn.holds(mid) != layer {
+                        continue;
+                    }
+                    let pts = to_2d(place(cell));
+                    let outer = path_polygon(&pts.map(|(x, y)| [x, y]));
+                    if mode == RenderMode::Wireframe {
+                        seam(fr, &outer, ink);
+                        polygons += 1;
+                        continue;
+                    }
+                    fr.fill(&outer, plastic);
+                    let (_, sticker) = sticker_path(&pts, detail, inset_k, scale);
+                    fr.fill(&sticker, shaded(base_color(cube.face(which)[r][c]), shade));
+                    if detail != Detail::Low {
+                        seam(fr, &sticker, Color::from_rgb(0.04, 0.04, 0.04));
+                    }
+                    polygons += 2;
+                }
+            }
+        }
+    }
+    polygons
+}
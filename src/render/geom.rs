@@ -6,7 +6,8 @@
 use iced::Point;
 
 use crate::cube::FaceId;
-use super::types::{RotZ, RotX, RotY};
+use super::quat::Quat;
+use super::types::{RotZ, RotX, RotY, Lighting};
 
 /// Classic isometric projection of `(x,y,z)` with a per-view size and origin.
 #[inline]
@@ -22,30 +23,18 @@ pub fn project(x: f32, y: f32, z: f32, size: f32, origin: (f32, f32)) -> (f32, f
 // rotate about cube center (1,1,1)
 const CEN: (f32, f32, f32) = (1.0, 1.0, 1.0);
 
+/// Rotate a point about the cube's center by the camera angles, composed
+/// as one quaternion (Rz first, then Ry, then Rx).
 #[inline]
-fn rot_z_point(p: (f32,f32,f32), deg: f32) -> (f32,f32,f32) {
-    let (x,y,z) = p; let (x0,y0,z0) = (x-CEN.0, y-CEN.1, z-CEN.2);
-    let r = deg.to_radians(); let (c,s) = (r.cos(), r.sin());
-    (c*x0 - s*y0 + CEN.0, s*x0 + c*y0 + CEN.1, z0 + CEN.2)
-}
-#[inline]
-fn rot_y_point(p: (f32,f32,f32), deg: f32) -> (f32,f32,f32) {
-    let (x,y,z) = p; let (x0,y0,z0) = (x-CEN.0, y-CEN.1, z-CEN.2);
-    let r = deg.to_radians(); let (c,s) = (r.cos(), r.sin());
-    (c*x0 + s*z0 + CEN.0, y0 + CEN.1, -s*x0 + c*z0 + CEN.2)
-}
-#[inline]
-fn rot_x_point(p: (f32,f32,f32), deg: f32) -> (f32,f32,f32) {
-    let (x,y,z) = p; let (x0,y0,z0) = (x-CEN.0, y-CEN.1, z-CEN.2);
-    let r = deg.to_radians(); let (c,s) = (r.cos(), r.sin());
-    (x0 + CEN.0, c*y0 - s*z0 + CEN.1, s*y0 + c*z0 + CEN.2)
+pub fn rotate_pt_all(p: (f32,f32,f32), rz: RotZ, ry: RotY, rx: RotX) -> (f32,f32,f32) {
+    let v = rotate_vec_all((p.0 - CEN.0, p.1 - CEN.1, p.2 - CEN.2), rz, ry, rx);
+    (v.0 + CEN.0, v.1 + CEN.1, v.2 + CEN.2)
 }
 
+/// Rotate a direction vector (not a point) with the same camera rotation.
 #[inline]
-pub fn rotate_pt_all(p: (f32,f32,f32), rz: RotZ, ry: RotY, rx: RotX) -> (f32,f32,f32) {
-    let pz = rot_z_point(p, rz.0);
-    let py = rot_y_point(pz, ry.0);
-    rot_x_point(py, rx.0)
+pub fn rotate_vec_all(v: (f32,f32,f32), rz: RotZ, ry: RotY, rx: RotX) -> (f32,f32,f32) {
+    Quat::from_euler(rz, ry, rx).rotate(v)
 }
 
 // Outer polygon of each face in CCW order w.r.t. OUTWARD normal.
@@ -106,6 +95,38 @@ pub fn face_depth(face: FaceId, rz: RotZ, rx: RotX, ry: RotY) -> f32 {
     -py
 }
 
+/// Outward unit normal of each face in object space.
+pub fn face_normal(face: FaceId) -> (f32,f32,f32) {
+    match face {
+        FaceId::U => ( 0.0,  0.0,  1.0),
+        FaceId::D => ( 0.0,  0.0, -1.0),
+        FaceId::F => ( 0.0, -1.0,  0.0),
+        FaceId::B => ( 0.0,  1.0,  0.0),
+        FaceId::L => (-1.0,  0.0,  0.0),
+        FaceId::R => ( 1.0,  0.0,  0.0),
+    }
+}
+
+/// Unit vector pointing from the cube toward the light.
+pub fn light_dir(light: &Lighting) -> (f32,f32,f32) {
+    let (az, el) = (light.azimuth.0, light.elevation.0);
+    (el.cos() * az.cos(), el.cos() * az.sin(), el.sin())
+}
+
+/// Brightness multiplier for a face: Lambert term of the rotated normal
+/// against the light, blended with flat shading by `light.intensity`.
+pub fn face_shade(face: FaceId, rz: RotZ, rx: RotX, ry: RotY, light: &Lighting) -> f32 {
+    normal_shade(rotate_vec_all(face_normal(face), rz, ry, rx), light)
+}
+
+/// [`face_shade`] for a normal already rotated by the camera.
+pub fn normal_shade(n: (f32,f32,f32), light: &Lighting) -> f32 {
+    let l = light_dir(light);
+    let lambert = (n.0 * l.0 + n.1 * l.1 + n.2 * l.2).max(0.0);
+    let k = light.intensity.clamp(0.0, 1.0);
+    1.0 - k + k * lambert
+}
+
 /// 8 cube corners in object space (2x2x2 cube)
 #[inline]
 pub fn cube_corners() -> [(f32,f32,f32); 8] {
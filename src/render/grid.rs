@@ -0,0 +1,63 @@
+// src/render/grid.rs
+
+//! Canvas program that draws many small cubes at once, each its own state
+//! seen its own way, laid out in a grid and numbered in reading order: the
+//! scrambles of a round side by side, or a set of cases.
+
+use iced::alignment::Horizontal;
+use iced::widget::canvas::{self, Frame, Program, Text};
+use iced::{Color, Point, Rectangle, Theme};
+
+use crate::cube::Cube;
+use super::face::{draw_cube, FaceOverlay};
+use super::layout::{grid_shape, place_grid};
+use super::types::{RenderSettings, ViewParams};
+
+/// Size of the cell numbers, and their inset from a cell's top-left corner.
+const NUMBER_SIZE: f32 = 13.0;
+const NUMBER_INSET: f32 = 6.0;
+
+pub struct CubeGrid<'a> {
+    /// Each cell's state and view; the view's origin is ignored and its
+    /// size is shrunk to fit the cell.
+    pub cells: Vec<(&'a Cube, ViewParams)>,
+    pub settings: RenderSettings,
+}
+
+impl<'a, Message> Program<Message> for CubeGrid<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        if self.cells.is_empty() {
+            return vec![frame.into_geometry()];
+        }
+        let area = Rectangle::with_size(bounds.size());
+        let views: Vec<ViewParams> = self.cells.iter().map(|&(_, v)| v).collect();
+        let (cols, rows) = grid_shape(area, views.len());
+        let (cell_w, cell_h) = (area.width / cols as f32, area.height / rows as f32);
+        let overlays = [FaceOverlay::default(); 6];
+        for (i, (&(cube, _), vp)) in self.cells.iter().zip(place_grid(&views, area)).enumerate() {
+            draw_cube(&mut frame, cube, &vp, &self.settings, &overlays);
+            frame.fill_text(Text {
+                content: (i + 1).to_string(),
+                position: Point::new(
+                    cell_w * (i % cols) as f32 + NUMBER_INSET,
+                    cell_h * (i / cols) as f32 + NUMBER_INSET,
+                ),
+                color: Color::from_rgb(0.55, 0.55, 0.55),
+                size: NUMBER_SIZE.into(),
+                horizontal_alignment: Horizontal::Left,
+                ..Text::default()
+            });
+        }
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,73 @@
+// src/render/heatmap.rs
+
+//! Canvas program that draws how often each move is turned: one row per
+//! face, one column per direction, each cell labelled with its move and
+//! its share of all turns and tinted from cool to hot by its count
+//! against the busiest cell.
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::canvas::{self, Frame, Program, Text};
+use iced::{Color, Point, Rectangle, Size, Theme};
+
+use crate::cube::{FaceId, Move, Turn};
+
+/// Directions in column order, matching the counts' second index.
+const TURNS: [Turn; 3] = [Turn::Cw, Turn::Ccw, Turn::Half];
+/// Gap between cells, and the size of their labels.
+const GAP: f32 = 3.0;
+const LABEL_SIZE: f32 = 13.0;
+
+const COOL: Color = Color::from_rgb(0.16, 0.2, 0.3);
+const HOT: Color = Color::from_rgb(0.9, 0.25, 0.1);
+
+pub struct MoveHeatmap {
+    /// Turns made, by face (as [`FaceId`] indices) and direction.
+    pub counts: [[u64; 3]; 6],
+}
+
+/// `COOL` to `HOT` through amber, for `k` from 0 to 1.
+fn ramp(k: f32) -> Color {
+    let amber = Color::from_rgb(0.95, 0.7, 0.15);
+    let (a, b, t) = if k < 0.5 { (COOL, amber, k * 2.0) } else { (amber, HOT, k * 2.0 - 1.0) };
+    Color::from_rgb(a.r + (b.r - a.r) * t, a.g + (b.g - a.g) * t, a.b + (b.b - a.b) * t)
+}
+
+impl<Message> Program<Message> for MoveHeatmap {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let total: u64 = self.counts.iter().flatten().sum();
+        let max = self.counts.iter().flatten().copied().max().unwrap_or(0).max(1);
+        let cell = Size::new(bounds.width / TURNS.len() as f32, bounds.height / FaceId::ALL.len() as f32);
+        for (row, face) in FaceId::ALL.into_iter().enumerate() {
+            for (col, turn) in TURNS.into_iter().enumerate() {
+                let n = self.counts[face as usize][col];
+                let at = Point::new(cell.width * col as f32, cell.height * row as f32);
+                frame.fill_rectangle(
+                    at,
+                    Size::new(cell.width - GAP, cell.height - GAP),
+                    ramp(n as f32 / max as f32),
+                );
+                let share = if total == 0 { 0.0 } else { 100.0 * n as f32 / total as f32 };
+                frame.fill_text(Text {
+                    content: format!("{}  {share:.0}%", Move { face, turn }),
+                    position: Point::new(at.x + (cell.width - GAP) / 2.0, at.y + (cell.height - GAP) / 2.0),
+                    color: Color::WHITE,
+                    size: LABEL_SIZE.into(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    ..Text::default()
+                });
+            }
+        }
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,21 @@
+// src/render/hinting.rs
+
+//! Pixel hinting for crisp strokes on HiDPI and fractional-scale displays.
+//!
+//! Canvas coordinates are logical pixels; the renderer multiplies them by the
+//! display scale factor. Widths are rounded to whole device pixels and points
+//! snapped to device half-pixels so 1-px lines land on a single pixel row.
+
+/// Round a logical stroke width to a whole number of device pixels
+/// (at least one), returned in logical units.
+#[inline]
+pub fn hint_width(w: f32, scale: f32) -> f32 {
+    (w * scale).round().max(1.0) / scale
+}
+
+/// Snap a logical point to the nearest device half-pixel center.
+#[inline]
+pub fn snap_half(p: (f32, f32), scale: f32) -> (f32, f32) {
+    let s = |v: f32| ((v * scale - 0.5).round() + 0.5) / scale;
+    (s(p.0), s(p.1))
+}
@@ -0,0 +1,122 @@
+// src/render/history.rs
+
+//! Canvas program that draws the move history tree: one dot per state, left
+//! to right by move number, with each branch on its own lane below the line
+//! it split from. Clicking a dot reports that node's index.
+
+use iced::mouse;
+use iced::widget::canvas::{self, event, Event, Frame, Path, Program, Stroke};
+use iced::{Color, Point, Rectangle, Theme};
+
+/// Widest spacing between dots; long histories are squeezed to fit.
+const STEP: f32 = 14.0;
+const DOT: f32 = 3.0;
+/// How far from a dot a click still selects it.
+const HIT: f32 = 7.0;
+
+const LINE_COLOR: Color = Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+const PATH_COLOR: Color = Color { r: 0.2, g: 0.6, b: 1.0, a: 1.0 };
+const CURRENT_COLOR: Color = Color { r: 1.0, g: 0.85, b: 0.3, a: 1.0 };
+
+/// One history state as laid out for drawing.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphNode {
+    pub parent: Option<usize>,
+    /// Moves from the root, and the branch lane (0 = the first line).
+    pub depth: usize,
+    pub lane: usize,
+    /// Whether the node lies between the root and the current state.
+    pub on_path: bool,
+}
+
+pub struct HistoryGraph {
+    pub nodes: Vec<GraphNode>,
+    pub current: usize,
+}
+
+impl HistoryGraph {
+    /// Height that fits every lane.
+    pub fn height(&self) -> f32 {
+        let lanes = self.nodes.iter().map(|n| n.lane).max().unwrap_or(0) + 1;
+        STEP * lanes as f32
+    }
+
+    fn step(&self, bounds: Rectangle) -> f32 {
+        let deepest = self.nodes.iter().map(|n| n.depth).max().unwrap_or(0);
+        (bounds.width / (deepest + 1) as f32).min(STEP)
+    }
+
+    fn position(&self, n: &GraphNode, step: f32) -> Point {
+        Point::new(step * (n.depth as f32 + 0.5), STEP * (n.lane as f32 + 0.5))
+    }
+}
+
+impl Program<usize> for HistoryGraph {
+    type State = ();
+
+    fn update(
+        &self,
+        _state: &mut (),
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<usize>) {
+        let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event else {
+            return (event::Status::Ignored, None);
+        };
+        let Some(p) = cursor.position_in(bounds) else {
+            return (event::Status::Ignored, None);
+        };
+        let step = self.step(bounds);
+        let hit = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (i, self.position(n, step).distance(p)))
+            .filter(|&(_, d)| d <= HIT)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+        match hit {
+            Some(i) => (event::Status::Captured, Some(i)),
+            None => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let step = self.step(bounds);
+
+        for n in &self.nodes {
+            let Some(parent) = n.parent.and_then(|p| self.nodes.get(p)) else { continue };
+            let (from, to) = (self.position(parent, step), self.position(n, step));
+            // Branches drop straight down from the parent, then run right.
+            let line = Path::new(|b| {
+                b.move_to(from);
+                b.line_to(Point::new(from.x, to.y));
+                b.line_to(to);
+            });
+            let color = if n.on_path { PATH_COLOR } else { LINE_COLOR };
+            frame.stroke(&line, Stroke::default().with_color(color).with_width(1.5));
+        }
+        for (i, n) in self.nodes.iter().enumerate() {
+            let at = self.position(n, step);
+            let color = if i == self.current {
+                CURRENT_COLOR
+            } else if n.on_path {
+                PATH_COLOR
+            } else {
+                LINE_COLOR
+            };
+            let radius = if i == self.current { DOT * 1.6 } else { DOT };
+            frame.fill(&Path::circle(at, radius), color);
+        }
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,87 @@
+// src/render/layer.rs
+
+//! A face turn caught partway, for smooth playback. The cube given is the
+//! state after the turn; the turning layer is drawn turned back by the part
+//! of the turn not yet made, so the picture runs from the state before to
+//! the state after as `progress` goes from 0 to 1. The cube then splits into
+//! two blocks, the layer and the still half, each drawn as its own cells
+//! with plastic showing on the cut between them.
+
+use crate::cube::{Move, Turn};
+use super::geom::{face_normal, face_outer, rotate_vec_all};
+use super::types::{RotX, RotY, RotZ};
+
+type P3 = (f32, f32, f32);
+
+const CEN: P3 = (1.0, 1.0, 1.0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerTurn {
+    pub mv: Move,
+    /// How much of the turn is done, 0–1.
+    pub progress: f32,
+}
+
+impl LayerTurn {
+    /// Degrees the layer is drawn turned by, about its face's outward
+    /// normal (counter-clockwise seen from outside is positive).
+    fn angle(&self) -> f32 {
+        let full = match self.mv.turn {
+            Turn::Cw => -90.0,
+            Turn::Ccw => 90.0,
+            Turn::Half => -180.0,
+        };
+        -(1.0 - self.progress.clamp(0.0, 1.0)) * full
+    }
+
+    /// Whether the object-space point `p` (a cell's center) is in the layer.
+    pub fn holds(&self, p: P3) -> bool {
+        let n = face_normal(self.mv.face);
+        (p.0 - CEN.0) * n.0 + (p.1 - CEN.1) * n.1 + (p.2 - CEN.2) * n.2 > 0.0
+    }
+
+    /// Direction `v` turned with the layer.
+    pub fn turn_vec(&self, v: P3) -> P3 {
+        let n = face_normal(self.mv.face);
+        let r = self.angle().to_radians();
+        let (c, s) = (r.cos(), r.sin());
+        let dot = n.0 * v.0 + n.1 * v.1 + n.2 * v.2;
+        let cross = (n.1 * v.2 - n.2 * v.1, n.2 * v.0 - n.0 * v.2, n.0 * v.1 - n.1 * v.0);
+        (
+            v.0 * c + cross.0 * s + n.0 * dot * (1.0 - c),
+            v.1 * c + cross.1 * s + n.1 * dot * (1.0 - c),
+            v.2 * c + cross.2 * s + n.2 * dot * (1.0 - c),
+        )
+    }
+
+    /// Point `p` turned with the layer, about the cube's center.
+    pub fn turn_pt(&self, p: P3) -> P3 {
+        let v = self.turn_vec((p.0 - CEN.0, p.1 - CEN.1, p.2 - CEN.2));
+        (v.0 + CEN.0, v.1 + CEN.1, v.2 + CEN.2)
+    }
+
+    /// The cut face of the layer (`true`) or of the still half, in object
+    /// space and unturned, with its outward normal.
+    pub fn cut(&self, layer: bool) -> ([P3; 4], P3) {
+        let n = face_normal(self.mv.face);
+        let mut q = face_outer(self.mv.face).map(|p| (p.0 - n.0, p.1 - n.1, p.2 - n.2));
+        if layer {
+            q.reverse();
+            (q, (-n.0, -n.1, -n.2))
+        } else {
+            (q, n)
+        }
+    }
+
+    /// Whether the layer is nearer the viewer than the still half, so
+    /// drawn second.
+    pub fn layer_in_front(&self, rz: RotZ, rx: RotX, ry: RotY) -> bool {
+        facing(rotate_vec_all(face_normal(self.mv.face), rz, ry, rx))
+    }
+}
+
+/// Whether a camera-space normal points toward the viewer of the
+/// isometric projection.
+pub fn facing(n: P3) -> bool {
+    -n.0 - n.1 + n.2 > 0.0
+}
@@ -1,6 +1,7 @@
 // src/render/layout.rs
 
-//! Compute canvas layout/origins for the two cube views and keep them visible.
+//! Compute canvas layout/origins for the cube views and keep them visible:
+//! the usual one or two views side by side, or any number in a grid.
 
 use iced::Rectangle;
 
@@ -16,20 +17,26 @@ fn cube_corners() -> [(f32,f32,f32); 8] {
     ]
 }
 
-fn min_projected_y(vp: &ViewParams) -> f32 {
+fn projected(vp: &ViewParams) -> [(f32,f32); 8] {
     cube_corners()
         .map(|p| rotate_pt_all(p, vp.rz, vp.ry, vp.rx))
-        .map(|(x,y,z)| project(x, y, z, vp.size, vp.origin).1)
-        .into_iter()
-        .fold(f32::INFINITY, f32::min)
+        .map(|(x,y,z)| project(x, y, z, vp.size, vp.origin))
+}
+
+fn min_projected_y(vp: &ViewParams) -> f32 {
+    projected(vp).into_iter().map(|p| p.1).fold(f32::INFINITY, f32::min)
 }
 
 fn max_projected_y(vp: &ViewParams) -> f32 {
-    cube_corners()
-        .map(|p| rotate_pt_all(p, vp.rz, vp.ry, vp.rx))
-        .map(|(x,y,z)| project(x, y, z, vp.size, vp.origin).1)
-        .into_iter()
-        .fold(f32::NEG_INFINITY, f32::max)
+    projected(vp).into_iter().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max)
+}
+
+/// Screen bounding box of the cube, as (min x, min y, max x, max y).
+fn projected_box(vp: &ViewParams) -> (f32, f32, f32, f32) {
+    projected(vp).into_iter().fold(
+        (f32::INFINITY, f32::INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+        |(x0, y0, x1, y1), (x, y)| (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+    )
 }
 
 /// Initial horizontal placement + vertical center line.
@@ -45,13 +52,36 @@ pub fn layout_origins(bounds: Rectangle, size: f32) -> ((f32,f32),(f32,f32)) {
     ((mid_x - gap * 0.5, center_y), (mid_x + gap * 0.5, center_y))
 }
 
-/// Shift both origins vertically so the pair stays centered and within margins.
-pub fn fit_vertically(bounds: Rectangle, left: &mut ViewParams, right: &mut ViewParams) {
-    // Combined vertical bounding box (screen Y) for both cubes
-    let (min_l, max_l) = (min_projected_y(left),  max_projected_y(left));
-    let (min_r, max_r) = (min_projected_y(right), max_projected_y(right));
-    let min_all = min_l.min(min_r);
-    let max_all = max_l.max(max_r);
+/// Where both views go in `bounds`: auto-placed where their origins are
+/// unset, then nudged to fit vertically. The right one is `left` again
+/// when there is no second view.
+pub fn place(left: ViewParams, right: Option<ViewParams>, bounds: Rectangle) -> (ViewParams, ViewParams) {
+    // 1) Start with your incoming params
+    let lone = right.is_none();
+    let mut left  = left;
+    let mut right = right.unwrap_or(left);
+
+    // 2) If origins are NaN (our signal to auto-place), give them a first pass
+    if left.origin.0.is_nan() || right.origin.0.is_nan() {
+        let (ol, or) = layout_origins(bounds, left.size.min(right.size));
+        if left.origin.0.is_nan()  { left.origin  = ol; }
+        if right.origin.0.is_nan() { right.origin = or; }
+        // A lone view sits in the middle instead of the left slot.
+        if lone { left.origin.0 = 0.5 * (ol.0 + or.0); }
+    }
+
+    // 3) Nudge both views so the pair is vertically centered *and*
+    //    still respects top/bottom margins for the current size.
+    let mut pair = [left, right];
+    fit_vertically(bounds, &mut pair);
+    (pair[0], pair[1])
+}
+
+/// Shift all origins vertically so the views stay centered and within margins.
+pub fn fit_vertically(bounds: Rectangle, views: &mut [ViewParams]) {
+    // Combined vertical bounding box (screen Y) for all cubes
+    let min_all = views.iter().map(min_projected_y).fold(f32::INFINITY, f32::min);
+    let max_all = views.iter().map(max_projected_y).fold(f32::NEG_INFINITY, f32::max);
     let center_all = 0.5 * (min_all + max_all);
 
     // Target vertical center (slightly above exact middle so UI has air)
@@ -66,7 +96,41 @@ pub fn fit_vertically(bounds: Rectangle, left: &mut ViewParams, right: &mut View
     dy = dy.clamp(top_margin - min_all, bottom_margin - max_all);
 
     if dy.abs() > 0.01 {
-        left.origin.1  += dy;
-        right.origin.1 += dy;
+        for v in views {
+            v.origin.1 += dy;
+        }
     }
+}
+
+/// Share of a grid cell's width and height a cube may take.
+const GRID_FILL: f32 = 0.85;
+
+/// Columns and rows for `count` cells in `bounds`, keeping the cells as
+/// near square as the count allows.
+pub fn grid_shape(bounds: Rectangle, count: usize) -> (usize, usize) {
+    let aspect = (bounds.width / bounds.height.max(1.0)).max(0.01);
+    let cols = ((count as f32 * aspect).sqrt().round() as usize).clamp(1, count.max(1));
+    (cols, count.div_ceil(cols).max(1))
+}
+
+/// Where `views` go as a grid over `bounds`, in reading order: each cube
+/// centered in its cell and shrunk, never grown, to fit it.
+pub fn place_grid(views: &[ViewParams], bounds: Rectangle) -> Vec<ViewParams> {
+    let (cols, rows) = grid_shape(bounds, views.len());
+    let cell_w = bounds.width / cols as f32;
+    let cell_h = bounds.height / rows as f32;
+    views
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            // The box at unit size and origin scales with the size.
+            let (x0, y0, x1, y1) = projected_box(&ViewParams { size: 1.0, origin: (0.0, 0.0), ..v });
+            let fit = (GRID_FILL * cell_w / (x1 - x0)).min(GRID_FILL * cell_h / (y1 - y0));
+            let size = v.size.min(fit);
+            let cx = bounds.x + cell_w * ((i % cols) as f32 + 0.5);
+            let cy = bounds.y + cell_h * ((i / cols) as f32 + 0.5);
+            let origin = (cx - size * 0.5 * (x0 + x1), cy - size * 0.5 * (y0 + y1));
+            ViewParams { size, origin, ..v }
+        })
+        .collect()
 }
\ No newline at end of file
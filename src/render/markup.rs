@@ -0,0 +1,46 @@
+// src/render/markup.rs
+
+//! Text in generated HTML and SVG: escaping for element content and
+//! attribute values (either quote style), and reading it back.
+
+/// Escape `s` for HTML or SVG text and attribute values.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Inverse of [`escape`].
+pub fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup_and_quotes() {
+        assert_eq!(escape(r#"<a href="x">R U' & more</a>"#), "&lt;a href=&quot;x&quot;&gt;R U&#39; &amp; more&lt;/a&gt;");
+    }
+
+    #[test]
+    fn round_trip() {
+        for s in ["R U R' U'", "&amp; is not &", "<desc>\"'</desc>", ""] {
+            assert_eq!(unescape(&escape(s)), s);
+        }
+    }
+}
@@ -0,0 +1,370 @@
+// src/render/mesh.rs
+
+//! GPU renderer: the cube views as real 3D triangles drawn through iced's
+//! shader widget with a depth buffer, so overlapping geometry is resolved
+//! per pixel rather than by painter's-algorithm face order, and large cubes
+//! redraw every frame cheaply. Views are placed and lit as on the canvas.
+//!
+//! Needs the `gpu` feature; without it [`AVAILABLE`] is false and
+//! [`cube_mesh`] gives nothing, leaving the canvas in charge. It draws the
+//! filled cubes only: the grid, axes, outlines, letters, heat tints,
+//! annotations, and touch gestures stay with the canvas renderer.
+
+use iced::{Color, Element, Rectangle};
+
+use crate::cube::{Cube, FaceId};
+use super::face::{base_color, cell_quad_raw};
+use super::geom::{face_normal, face_outer, normal_shade, project, rotate_pt_all, rotate_vec_all};
+use super::layer::LayerTurn;
+use super::layout::place;
+use super::types::{RenderSettings, ViewParams};
+
+/// Whether this build has the GPU renderer.
+pub const AVAILABLE: bool = cfg!(feature = "gpu");
+
+/// Sticker inset from its cell, and lift off the plastic, in cube units.
+const INSET: f32 = 0.1;
+const LIFT: f32 = 0.002;
+
+const PLASTIC: Color = Color { r: 0.05, g: 0.05, b: 0.05, a: 1.0 };
+
+/// One vertex: position in the widget (logical pixels) with its depth
+/// (0 nearest, 1 farthest), and linear RGB.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(feature = "gpu"), allow(dead_code))]
+struct Vertex {
+    pos: [f32; 3],
+    color: [f32; 3],
+}
+
+/// The views of `cube` drawn in the shader widget, filling its space; `None`
+/// without the `gpu` feature.
+pub fn cube_mesh<'a, Message: 'a>(
+    cube: &'a Cube,
+    left: ViewParams,
+    right: Option<ViewParams>,
+    settings: RenderSettings,
+    turning: Option<LayerTurn>,
+) -> Option<Element<'a, Message>> {
+    backend::cube_mesh(cube, left, right, settings, turning)
+}
+
+/// Triangles for both views, placed in `bounds` as the canvas places them.
+/// While `turning`, the plastic goes cell by cell, plus the two cut faces,
+/// so the layer can turn away from the still half.
+#[cfg_attr(not(feature = "gpu"), allow(dead_code))]
+fn triangles(
+    cube: &Cube,
+    left: ViewParams,
+    right: Option<ViewParams>,
+    settings: &RenderSettings,
+    turning: Option<LayerTurn>,
+    bounds: Rectangle,
+) -> Vec<Vertex> {
+    let (l, r) = place(left, right, bounds);
+    // The layer's turn, for the points and normals of cells it holds.
+    let turned = |held: bool, p| match turning {
+        Some(t) if held => t.turn_pt(p),
+        _ => p,
+    };
+    let mut out = Vec::new();
+    for vp in std::iter::once(l).chain(right.map(|_| r)) {
+        if let Some(t) = turning {
+            for layer in [false, true] {
+                quad(&mut out, &vp, t.cut(layer).0.map(|p| turned(layer, p)), PLASTIC);
+            }
+        } else {
+            for face in FaceId::ALL {
+                quad(&mut out, &vp, face_outer(face), PLASTIC);
+            }
+        }
+        for face in FaceId::ALL {
+            let n = face_normal(face);
+            let stickers = cube.face(face);
+            for (row, cols) in stickers.iter().enumerate() {
+                for (col, &c) in cols.iter().enumerate() {
+                    let cell = cell_quad_raw(face, row, col);
+                    let mid = cell.iter().fold((0.0, 0.0, 0.0), |m, p| (m.0 + p.0 / 4.0, m.1 + p.1 / 4.0, m.2 + p.2 / 4.0));
+                    let held = turning.is_some_and(|t| t.holds(mid));
+                    if turning.is_some() {
+                        quad(&mut out, &vp, cell.map(|p| turned(held, p)), PLASTIC);
+                    }
+                    let sticker = cell.map(|p| turned(held, (
+                        mid.0 + (p.0 - mid.0) * (1.0 - INSET) + n.0 * LIFT,
+                        mid.1 + (p.1 - mid.1) * (1.0 - INSET) + n.1 * LIFT,
+                        mid.2 + (p.2 - mid.2) * (1.0 - INSET) + n.2 * LIFT,
+                    )));
+                    let n = match turning {
+                        Some(t) if held => t.turn_vec(n),
+                        _ => n,
+                    };
+                    let shade = normal_shade(rotate_vec_all(n, vp.rz, vp.ry, vp.rx), &settings.light);
+                    let base = base_color(c);
+                    quad(&mut out, &vp, sticker, Color::from_rgb(base.r * shade, base.g * shade, base.b * shade));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Two triangles for the object-space quad `q` seen through `vp`.
+fn quad(out: &mut Vec<Vertex>, vp: &ViewParams, q: [(f32, f32, f32); 4], color: Color) {
+    let [r, g, b, _] = color.into_linear();
+    let v = q.map(|p| {
+        let (x, y, z) = rotate_pt_all(p, vp.rz, vp.ry, vp.rx);
+        let (sx, sy) = project(x, y, z, vp.size, vp.origin);
+        // Distance toward the viewer of the isometric projection, from the
+        // cube's center; within ±√3 however the cube is turned.
+        let toward = (-(x - 1.0) - (y - 1.0) + (z - 1.0)) / 3.0f32.sqrt();
+        Vertex { pos: [sx, sy, 0.5 - toward / 6.0], color: [r, g, b] }
+    });
+    out.extend([v[0], v[1], v[2], v[0], v[2], v[3]]);
+}
+
+#[cfg(feature = "gpu")]
+mod backend {
+    use iced::widget::shader::{self, wgpu, Primitive, Storage};
+    use iced::widget::shader::wgpu::util::DeviceExt;
+    use iced::{mouse, Element, Length, Rectangle, Size};
+
+    use crate::cube::Cube;
+    use super::super::layer::LayerTurn;
+    use super::super::types::{RenderSettings, ViewParams};
+    use super::{triangles, Vertex};
+
+    const SHADER: &str = "
+        struct Out {
+            @builtin(position) pos: vec4<f32>,
+            @location(0) color: vec3<f32>,
+        };
+
+        @vertex
+        fn vs_main(@location(0) pos: vec3<f32>, @location(1) color: vec3<f32>) -> Out {
+            var out: Out;
+            out.pos = vec4<f32>(pos, 1.0);
+            out.color = color;
+            return out;
+        }
+
+        @fragment
+        fn fs_main(in: Out) -> @location(0) vec4<f32> {
+            return vec4<f32>(in.color, 1.0);
+        }
+    ";
+
+    /// Floats per vertex: position, then color.
+    const FLOATS: usize = 6;
+    /// Meshes kept uploaded: enough for every cube shown in one frame.
+    const KEPT: usize = 4;
+
+    pub fn cube_mesh<'a, Message: 'a>(
+        cube: &'a Cube,
+        left: ViewParams,
+        right: Option<ViewParams>,
+        settings: RenderSettings,
+        turning: Option<LayerTurn>,
+    ) -> Option<Element<'a, Message>> {
+        let mesh = CubeMesh { cube, left, right, settings, turning };
+        Some(shader::Shader::new(mesh).width(Length::Fill).height(Length::Fill).into())
+    }
+
+    struct CubeMesh<'a> {
+        cube: &'a Cube,
+        left: ViewParams,
+        right: Option<ViewParams>,
+        settings: RenderSettings,
+        turning: Option<LayerTurn>,
+    }
+
+    impl<Message> shader::Program<Message> for CubeMesh<'_> {
+        type State = ();
+        type Primitive = Mesh;
+
+        fn draw(&self, _state: &(), _cursor: mouse::Cursor, bounds: Rectangle) -> Mesh {
+            Mesh { bounds, vertices: triangles(self.cube, self.left, self.right, &self.settings, self.turning, bounds) }
+        }
+    }
+
+    #[derive(Debug)]
+    struct Mesh {
+        /// Where the widget is; tells apart meshes drawn in the same frame.
+        bounds: Rectangle,
+        vertices: Vec<Vertex>,
+    }
+
+    impl Mesh {
+        fn key(&self) -> [u32; 4] {
+            [self.bounds.x, self.bounds.y, self.bounds.width, self.bounds.height].map(f32::to_bits)
+        }
+    }
+
+    impl Primitive for Mesh {
+        fn prepare(
+            &self,
+            format: wgpu::TextureFormat,
+            device: &wgpu::Device,
+            _queue: &wgpu::Queue,
+            bounds: Rectangle,
+            target_size: Size<u32>,
+            scale_factor: f32,
+            storage: &mut Storage,
+        ) {
+            if !storage.has::<Pipeline>() {
+                storage.store(Pipeline::new(device, format));
+            }
+            let Some(pipeline) = storage.get_mut::<Pipeline>() else { return };
+            // Widget pixels to clip space over the whole target, since the
+            // viewport must stay inside it; the scissor does the clipping.
+            let (w, h) = (target_size.width as f32, target_size.height as f32);
+            let data: Vec<f32> = self
+                .vertices
+                .iter()
+                .flat_map(|v| {
+                    let x = (bounds.x + v.pos[0]) * scale_factor;
+                    let y = (bounds.y + v.pos[1]) * scale_factor;
+                    [2.0 * x / w - 1.0, 1.0 - 2.0 * y / h, v.pos[2], v.color[0], v.color[1], v.color[2]]
+                })
+                .collect();
+            let bytes: Vec<u8> = data.iter().flat_map(|f| f.to_ne_bytes()).collect();
+            let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("rubics cube mesh vertices"),
+                contents: &bytes,
+                usage: wgpu::BufferUsages::VERTEX,
+            });
+            let key = self.key();
+            pipeline.meshes.retain(|(k, ..)| *k != key);
+            pipeline.meshes.push((key, buffer, self.vertices.len() as u32));
+            if pipeline.meshes.len() > KEPT {
+                pipeline.meshes.remove(0);
+            }
+            if pipeline.depth.as_ref().is_none_or(|(size, _)| *size != target_size) {
+                pipeline.depth = Some((target_size, depth_view(device, target_size)));
+            }
+        }
+
+        fn render(
+            &self,
+            storage: &Storage,
+            target: &wgpu::TextureView,
+            target_size: Size<u32>,
+            viewport: Rectangle<u32>,
+            encoder: &mut wgpu::CommandEncoder,
+        ) {
+            let Some(pipeline) = storage.get::<Pipeline>() else { return };
+            let key = self.key();
+            let Some((_, vertices, count)) = pipeline.meshes.iter().find(|(k, ..)| *k == key) else { return };
+            let Some((_, depth)) = &pipeline.depth else { return };
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("rubics cube mesh"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: depth,
+                    depth_ops: Some(wgpu::Operations { load: wgpu::LoadOp::Clear(1.0), store: wgpu::StoreOp::Discard }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&pipeline.pipeline);
+            pass.set_viewport(0.0, 0.0, target_size.width as f32, target_size.height as f32, 0.0, 1.0);
+            pass.set_scissor_rect(viewport.x, viewport.y, viewport.width, viewport.height);
+            pass.set_vertex_buffer(0, vertices.slice(..));
+            pass.draw(0..*count, 0..1);
+        }
+    }
+
+    /// The pipeline, kept in the renderer's storage, the last meshes
+    /// uploaded (vertex buffer and count, by widget), and the depth buffer
+    /// they are drawn against.
+    struct Pipeline {
+        pipeline: wgpu::RenderPipeline,
+        meshes: Vec<([u32; 4], wgpu::Buffer, u32)>,
+        depth: Option<(Size<u32>, wgpu::TextureView)>,
+    }
+
+    impl Pipeline {
+        fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("rubics cube mesh shader"),
+                source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+            });
+            let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("rubics cube mesh layout"),
+                bind_group_layouts: &[],
+                push_constant_ranges: &[],
+            });
+            let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("rubics cube mesh pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &module,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: (FLOATS * std::mem::size_of::<f32>()) as u64,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3],
+                    }],
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                fragment: Some(wgpu::FragmentState {
+                    module: &module,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                multiview: None,
+            });
+            Self { pipeline, meshes: Vec::new(), depth: None }
+        }
+    }
+
+    fn depth_view(device: &wgpu::Device, size: Size<u32>) -> wgpu::TextureView {
+        device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("rubics cube mesh depth"),
+                size: wgpu::Extent3d { width: size.width.max(1), height: size.height.max(1), depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth32Float,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default())
+    }
+}
+
+#[cfg(not(feature = "gpu"))]
+mod backend {
+    use iced::Element;
+
+    use crate::cube::Cube;
+    use super::super::layer::LayerTurn;
+    use super::super::types::{RenderSettings, ViewParams};
+
+    pub fn cube_mesh<'a, Message: 'a>(
+        _cube: &'a Cube,
+        _left: ViewParams,
+        _right: Option<ViewParams>,
+        _settings: RenderSettings,
+        _turning: Option<LayerTurn>,
+    ) -> Option<Element<'a, Message>> {
+        None
+    }
+}
@@ -1,8 +1,33 @@
 pub mod types;
 pub mod geom;
+pub mod quat;
 pub mod face;
 pub mod layout;
 pub mod canvas;
+pub mod overlay;
+pub mod camera;
+pub mod hinting;
+pub mod profile;
+pub mod qr;
+pub mod history;
+pub mod annotate;
+pub mod touch;
+pub mod layer;
+pub mod blueprint;
+pub mod markup;
+pub mod net;
+pub mod thumb;
+pub mod mesh;
+pub mod renderer;
+pub mod grid;
+pub mod heatmap;
+pub mod trackball;
 
-pub use types::{RotZ, RotX, RotY, ViewParams};
-pub use canvas::CubeCanvas;
\ No newline at end of file
+pub use types::{Radians, RotZ, RotX, RotY, ViewParams, RenderMode, CameraModel, Backend, Lighting, RenderSettings, SCALE_FACTORS};
+pub use canvas::{CanvasEvent, CubeCanvas};
+pub use renderer::Scene;
+pub use camera::CameraPreset;
+pub use qr::QrCanvas;
+pub use grid::CubeGrid;
+pub use heatmap::MoveHeatmap;
+pub use history::{GraphNode, HistoryGraph};
@@ -0,0 +1,57 @@
+// src/render/net.rs
+
+//! The cube unfolded into a cross-shaped net (U above L F R B, D below) as
+//! SVG, for printed pages. Each face is laid out as seen from outside with
+//! its edges meeting the faces beside it in the net.
+
+use std::fmt::Write as _;
+
+use crate::cube::{Cube, FaceId};
+use super::blueprint::hex;
+use super::face::{base_color, cell_quad_raw};
+
+/// Gap between stickers, in pixels.
+const GAP: f32 = 2.0;
+
+/// Where a face sits in the net (top-left, in stickers) and where an
+/// object-space point on it lands within the face (in cube units).
+fn place(face: FaceId, (x, y, z): (f32, f32, f32)) -> ((f32, f32), (f32, f32)) {
+    match face {
+        FaceId::U => ((2.0, 0.0), (x, 2.0 - y)),
+        FaceId::L => ((0.0, 2.0), (2.0 - y, 2.0 - z)),
+        FaceId::F => ((2.0, 2.0), (x, 2.0 - z)),
+        FaceId::R => ((4.0, 2.0), (y, 2.0 - z)),
+        FaceId::B => ((6.0, 2.0), (2.0 - x, 2.0 - z)),
+        FaceId::D => ((2.0, 4.0), (x, y)),
+    }
+}
+
+/// The net of `cube` with stickers `unit` pixels wide.
+pub fn svg(cube: &Cube, unit: f32) -> String {
+    let (width, height) = (8.0 * unit, 6.0 * unit);
+    let mut out = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    for face in FaceId::ALL {
+        for r in 0..2 {
+            for c in 0..2 {
+                let q = cell_quad_raw(face, r, c);
+                let mid = q.iter().fold((0.0, 0.0, 0.0), |m, p| (m.0 + p.0 / 4.0, m.1 + p.1 / 4.0, m.2 + p.2 / 4.0));
+                let ((fx, fy), (u, v)) = place(face, mid);
+                // The cell's center is half a sticker in from its corner.
+                let (x, y) = ((fx + u - 0.5) * unit, (fy + v - 0.5) * unit);
+                let _ = write!(
+                    out,
+                    r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}" stroke="#222" stroke-width="1"/>"##,
+                    x + GAP / 2.0,
+                    y + GAP / 2.0,
+                    unit - GAP,
+                    unit - GAP,
+                    hex(base_color(cube.face(face)[r][c])),
+                );
+            }
+        }
+    }
+    out.push_str("</svg>");
+    out
+}
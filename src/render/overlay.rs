@@ -0,0 +1,94 @@
+// src/render/overlay.rs
+
+//! Canvas overlays: faint background grid, a per-view XYZ axis triad
+//! that follows the camera rotation, and the drop-target highlight.
+
+use iced::{Color, Point, Size};
+use iced::widget::canvas::{self, Frame, Path, Stroke};
+
+use super::types::ViewParams;
+use super::geom::{project, rotate_vec_all};
+
+/// Spacing of the background grid in pixels.
+const GRID_STEP: f32 = 20.0;
+/// Length of each triad axis in pixels.
+const AXIS_LEN: f32 = 22.0;
+
+/// Drop-target highlight color.
+const DROP: Color = Color { r: 0.3, g: 0.6, b: 1.0, a: 1.0 };
+/// Dash and gap of the drop-target outline, in pixels.
+const DROP_DASH: [f32; 2] = [10.0, 6.0];
+
+/// Tint the frame and outline it with a dashed border, showing that a
+/// file held over the window can be dropped there.
+pub fn draw_drop_target(fr: &mut Frame, size: Size) {
+    let inset = 3.0;
+    let area = Path::rectangle(Point::new(inset, inset), Size::new(size.width - 2.0 * inset, size.height - 2.0 * inset));
+    fr.fill(&area, Color { a: 0.12, ..DROP });
+    fr.stroke(&area, Stroke {
+        width: 3.0,
+        style: canvas::stroke::Style::Solid(DROP),
+        line_dash: canvas::LineDash { segments: &DROP_DASH, offset: 0 },
+        ..Default::default()
+    });
+}
+
+/// Draw a faint screen-space grid covering the whole frame.
+pub fn draw_grid(fr: &mut Frame, size: Size) {
+    let stroke = Stroke {
+        width: 1.0,
+        style: canvas::stroke::Style::Solid(Color::from_rgba(0.5, 0.5, 0.5, 0.15)),
+        ..Default::default()
+    };
+    let grid = Path::new(|b| {
+        let mut x = 0.0;
+        while x <= size.width {
+            b.move_to(Point::new(x, 0.0));
+            b.line_to(Point::new(x, size.height));
+            x += GRID_STEP;
+        }
+        let mut y = 0.0;
+        while y <= size.height {
+            b.move_to(Point::new(0.0, y));
+            b.line_to(Point::new(size.width, y));
+            y += GRID_STEP;
+        }
+    });
+    fr.stroke(&grid, stroke);
+}
+
+/// Draw an XYZ triad at `anchor` using the rotation of `vp`.
+///
+/// Axes are drawn back-to-front so the one pointing at the viewer stays on top.
+pub fn draw_axes(fr: &mut Frame, vp: &ViewParams, anchor: (f32, f32)) {
+    let axes = [
+        ("X", (1.0, 0.0, 0.0), Color::from_rgb(0.85, 0.15, 0.15)),
+        ("Y", (0.0, 1.0, 0.0), Color::from_rgb(0.10, 0.60, 0.20)),
+        ("Z", (0.0, 0.0, 1.0), Color::from_rgb(0.15, 0.35, 0.90)),
+    ];
+
+    // Project each rotated unit vector. The projection looks along (1,1,-1),
+    // so `x + y - z` grows with distance from the viewer.
+    let mut tips = axes.map(|(label, v, color)| {
+        let (x, y, z) = rotate_vec_all(v, vp.rz, vp.ry, vp.rx);
+        let tip = project(x, y, z, AXIS_LEN, anchor);
+        (label, tip, color, x + y - z)
+    });
+    tips.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap());
+
+    for (label, tip, color, _) in tips {
+        let line = Path::line(Point::new(anchor.0, anchor.1), Point::new(tip.0, tip.1));
+        fr.stroke(&line, Stroke {
+            width: 2.0,
+            style: canvas::stroke::Style::Solid(color),
+            ..Default::default()
+        });
+        fr.fill_text(canvas::Text {
+            content: label.into(),
+            position: Point::new(tip.0 + 2.0, tip.1 - 6.0),
+            color,
+            size: 11.0.into(),
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,69 @@
+// src/render/profile.rs
+
+//! Render instrumentation: per-draw timing, polygon counts, and geometry
+//! cache hit rate, plus the debug overlay that displays them (F12).
+
+use std::time::Duration;
+
+use iced::{Color, Point, Size};
+use iced::widget::canvas::{self, Frame, Path};
+
+/// Running counters updated by `CubeCanvas::draw`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RenderStats {
+    /// Draw calls seen.
+    pub frames: u64,
+    /// Last draw time.
+    pub last: Duration,
+    /// Exponential moving average of the draw time, in milliseconds.
+    pub avg_ms: f32,
+    /// Polygons emitted by the last scene rebuild.
+    pub polygons: usize,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+impl RenderStats {
+    /// Record one draw. `rebuilt` carries the polygon count when the cached
+    /// scene had to be regenerated (a miss), `None` on a cache hit.
+    pub fn record(&mut self, took: Duration, rebuilt: Option<usize>) {
+        self.frames += 1;
+        self.last = took;
+        let ms = took.as_secs_f32() * 1000.0;
+        self.avg_ms = if self.frames == 1 { ms } else { self.avg_ms * 0.9 + ms * 0.1 };
+        match rebuilt {
+            Some(n) => { self.cache_misses += 1; self.polygons = n; }
+            None => self.cache_hits += 1,
+        }
+    }
+
+    /// Share of draws served from the cache, in `[0, 1]`.
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.cache_hits + self.cache_misses;
+        if total == 0 { 0.0 } else { self.cache_hits as f32 / total as f32 }
+    }
+}
+
+/// Paint the stats box in the top-left corner of the frame.
+pub fn draw_profiler(fr: &mut Frame, stats: &RenderStats) {
+    let lines = [
+        format!("draw  {:.2} ms  (avg {:.2})", stats.last.as_secs_f32() * 1000.0, stats.avg_ms),
+        format!("polys {}", stats.polygons),
+        format!(
+            "cache {:.0}%  ({} hit / {} miss)",
+            stats.hit_rate() * 100.0, stats.cache_hits, stats.cache_misses,
+        ),
+    ];
+    let bg = Path::rectangle(Point::new(4.0, 4.0), Size::new(230.0, 50.0));
+    fr.fill(&bg, Color::from_rgba(0.0, 0.0, 0.0, 0.65));
+    for (i, line) in lines.into_iter().enumerate() {
+        fr.fill_text(canvas::Text {
+            content: line,
+            position: Point::new(10.0, 8.0 + i as f32 * 14.0),
+            color: Color::from_rgb(0.6, 1.0, 0.6),
+            size: 12.0.into(),
+            font: iced::Font::MONOSPACE,
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,52 @@
+// src/render/qr.rs
+
+//! Canvas program that draws a [`QrCode`] as dark squares on white, with
+//! the standard four-module quiet zone, scaled to fit and pixel-snapped.
+
+use iced::widget::canvas::{self, Frame, Path, Program};
+use iced::{Color, Point, Rectangle, Size, Theme};
+
+use rubics::qr::QrCode;
+
+/// Blank modules around the symbol, as the spec requires for scanning.
+const QUIET_ZONE: usize = 4;
+
+pub struct QrCanvas {
+    pub code: QrCode,
+}
+
+impl Program<()> for QrCanvas {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        renderer: &iced::Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let n = self.code.size() + 2 * QUIET_ZONE;
+        // Whole-pixel modules keep edges crisp for camera scanners.
+        let module = (bounds.width.min(bounds.height) / n as f32).floor().max(1.0);
+        let side = module * n as f32;
+        frame.fill_rectangle(Point::ORIGIN, Size::new(side, side), Color::WHITE);
+
+        let dark = Path::new(|b| {
+            for y in 0..self.code.size() {
+                for x in 0..self.code.size() {
+                    if self.code.dark(x, y) {
+                        let p = Point::new(
+                            (x + QUIET_ZONE) as f32 * module,
+                            (y + QUIET_ZONE) as f32 * module,
+                        );
+                        b.rectangle(p, Size::new(module, module));
+                    }
+                }
+            }
+        });
+        frame.fill(&dark, Color::BLACK);
+        vec![frame.into_geometry()]
+    }
+}
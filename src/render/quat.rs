@@ -0,0 +1,143 @@
+// src/render/quat.rs
+
+//! Unit quaternions for camera orientation. The three slider angles compose
+//! into one quaternion (Rz first, then Ry, then Rx), so an orientation can
+//! be turned and interpolated as a whole. Easing the angles one by one goes
+//! wrong near Ry = ±90°, where Rz and Rx turn about the same axis: a small
+//! change of view can mean large, opposite changes to both, and the cube
+//! swings the long way round on its way there.
+
+use super::types::{Radians, RotX, RotY, RotZ};
+
+type P3 = (f32, f32, f32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat { w: 1.0, x: 0.0, y: 0.0, z: 0.0 };
+
+    /// A turn by `angle` about the unit vector `axis`, counter-clockwise
+    /// looking down the axis.
+    pub fn about(axis: P3, angle: Radians) -> Self {
+        let (s, c) = (angle.0 * 0.5).sin_cos();
+        Self { w: c, x: axis.0 * s, y: axis.1 * s, z: axis.2 * s }
+    }
+
+    /// The shortest turn taking unit vector `a` to unit vector `b`.
+    pub fn between(a: P3, b: P3) -> Self {
+        let (x, y, z) = cross(a, b);
+        let w = 1.0 + a.0 * b.0 + a.1 * b.1 + a.2 * b.2;
+        Self { w, x, y, z }.normalized()
+    }
+
+    /// The camera rotation of the renderer's Euler angles.
+    pub fn from_euler(rz: RotZ, ry: RotY, rx: RotX) -> Self {
+        Self::about((1.0, 0.0, 0.0), rx.0)
+            * Self::about((0.0, 1.0, 0.0), ry.0)
+            * Self::about((0.0, 0.0, 1.0), rz.0)
+    }
+
+    /// Split back into the renderer's angles `(rz, rx, ry)`, each in
+    /// `(-π, π]`. At Ry = ±90° the Z turn is folded into X.
+    pub fn to_euler(self) -> (RotZ, RotX, RotY) {
+        let m = self.matrix();
+        // cos(Ry) from the row's other entries rather than from asin, which
+        // loses the precision needed to see the lock coming.
+        let cb = m[0][0].hypot(m[0][1]);
+        let b = m[0][2].atan2(cb);
+        let (a, c) = if cb > 1e-4 {
+            ((-m[1][2]).atan2(m[2][2]), (-m[0][1]).atan2(m[0][0]))
+        } else {
+            (m[2][1].atan2(m[1][1]), 0.0)
+        };
+        (RotZ(Radians(c)), RotX(Radians(a)), RotY(Radians(b)))
+    }
+
+    /// Rotation matrix, rows by columns.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        let Quat { w, x, y, z } = self;
+        [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+            [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+            [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+        ]
+    }
+
+    pub fn dot(self, o: Quat) -> f32 {
+        self.w * o.w + self.x * o.x + self.y * o.y + self.z * o.z
+    }
+
+    /// Rescaled to unit length, against drift after many products.
+    pub fn normalized(self) -> Self {
+        let n = self.dot(self).sqrt();
+        if n < f32::EPSILON {
+            return Self::IDENTITY;
+        }
+        Self { w: self.w / n, x: self.x / n, y: self.y / n, z: self.z / n }
+    }
+
+    /// Vector `v` turned by this rotation.
+    pub fn rotate(self, v: P3) -> P3 {
+        // v + 2w(q×v) + 2q×(q×v), with q the vector part.
+        let q = (self.x, self.y, self.z);
+        let t = cross(q, v);
+        let t = (2.0 * t.0, 2.0 * t.1, 2.0 * t.2);
+        let u = cross(q, t);
+        (
+            v.0 + self.w * t.0 + u.0,
+            v.1 + self.w * t.1 + u.1,
+            v.2 + self.w * t.2 + u.2,
+        )
+    }
+
+    /// The orientation a fraction `t` (0–1) of the way to `to`, turning
+    /// about a single axis by the shorter way round.
+    pub fn slerp(self, to: Quat, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        // q and −q are the same orientation; pick the one nearer.
+        let (to, d) = match self.dot(to) {
+            d if d < 0.0 => (Quat { w: -to.w, x: -to.x, y: -to.y, z: -to.z }, -d),
+            d => (to, d),
+        };
+        let (k0, k1) = if d > 0.9995 {
+            // Nearly the same: a straight blend is as good and stays finite.
+            (1.0 - t, t)
+        } else {
+            let theta = d.min(1.0).acos();
+            let s = theta.sin();
+            (((1.0 - t) * theta).sin() / s, (t * theta).sin() / s)
+        };
+        Quat {
+            w: k0 * self.w + k1 * to.w,
+            x: k0 * self.x + k1 * to.x,
+            y: k0 * self.y + k1 * to.y,
+            z: k0 * self.z + k1 * to.z,
+        }
+        .normalized()
+    }
+}
+
+impl std::ops::Mul for Quat {
+    type Output = Quat;
+
+    /// The turn `rhs` followed by `self`.
+    fn mul(self, rhs: Quat) -> Quat {
+        let (a, b) = (self, rhs);
+        Quat {
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        }
+    }
+}
+
+fn cross(a: P3, b: P3) -> P3 {
+    (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+}
@@ -0,0 +1,105 @@
+// src/render/renderer.rs
+
+//! Cube renderers. Views describe what to show as a [`Scene`] and
+//! [`draw`] hands it to the renderer picked in the settings, falling back
+//! to the canvas for scenes the picked one can't draw. Another backend (an
+//! SVG exporter, a net view) implements [`CubeRenderer`] and gets a
+//! [`Backend`] variant.
+
+use iced::widget::Canvas;
+use iced::{Element, Length};
+
+use super::annotate::{Ink, Mark, Tool};
+use super::canvas::{CanvasEvent, CubeCanvas};
+use super::layer::LayerTurn;
+use super::mesh::cube_mesh;
+use super::types::{Backend, CameraModel, RenderMode, RenderSettings, ViewParams};
+use crate::cube::{Cube, FaceId};
+use crate::logic::memo::LetterScheme;
+use crate::logic::tracking::Sticker;
+
+/// Everything drawn in one cube area: one or two views of a cube and what
+/// goes over them.
+#[derive(Clone, Copy)]
+pub struct Scene<'a> {
+    pub cube: &'a Cube,
+    pub left: ViewParams,
+    /// Second view; `None` draws `left` alone, centered.
+    pub right: Option<ViewParams>,
+    pub settings: RenderSettings,
+    /// Face to outline and how strongly (0–1), e.g. the face just turned.
+    pub glow: Option<(FaceId, f32)>,
+    /// Stickers of a tracked piece, outlined.
+    pub tracked: Option<[Sticker; 3]>,
+    /// Letters drawn on the stickers when `settings.show_letters` is on.
+    pub scheme: &'a LetterScheme,
+    /// Stickers that differ from a compared state, outlined.
+    pub diff: &'a [Sticker],
+    /// Annotations shown over the views.
+    pub marks: &'a [Mark],
+    /// Tool and ink for drawing with the mouse; `None` leaves the mouse alone.
+    pub pen: Option<(Tool, Ink)>,
+    /// A face turn partway through, drawn with its layer turned.
+    pub turning: Option<LayerTurn>,
+    /// Highlight the area as the target of a file being dragged in.
+    pub drop_target: bool,
+}
+
+impl Scene<'_> {
+    fn views(&self) -> impl Iterator<Item = ViewParams> {
+        std::iter::once(self.left).chain(self.right)
+    }
+}
+
+/// Something that draws cube scenes as a widget filling its space.
+pub trait CubeRenderer {
+    /// The widget for `scene`, or `None` if this renderer can't draw it.
+    fn view<'a>(&self, scene: Scene<'a>) -> Option<Element<'a, CanvasEvent>>;
+}
+
+/// The iced canvas painter; draws every scene.
+pub struct CanvasRenderer;
+
+impl CubeRenderer for CanvasRenderer {
+    fn view<'a>(&self, scene: Scene<'a>) -> Option<Element<'a, CanvasEvent>> {
+        Some(canvas(scene))
+    }
+}
+
+fn canvas(s: Scene<'_>) -> Element<'_, CanvasEvent> {
+    let program = CubeCanvas {
+        cube: s.cube, left: s.left, right: s.right, settings: s.settings, glow: s.glow, tracked: s.tracked,
+        scheme: s.scheme, diff: s.diff, marks: s.marks, pen: s.pen, turning: s.turning, drop_target: s.drop_target,
+        revision: 0,
+    };
+    Canvas::new(program.stamped()).width(Length::Fill).height(Length::Fill).into()
+}
+
+/// The GPU mesh renderer; filled views only, and only with the `gpu`
+/// feature. It takes no mouse input and draws no highlight, so the
+/// trackball camera and a file held over the window fall back to the
+/// canvas.
+pub struct MeshRenderer;
+
+impl CubeRenderer for MeshRenderer {
+    fn view<'a>(&self, s: Scene<'a>) -> Option<Element<'a, CanvasEvent>> {
+        if s.views().any(|v| v.mode != RenderMode::Filled) || s.settings.camera == CameraModel::Trackball || s.drop_target {
+            return None;
+        }
+        cube_mesh(s.cube, s.left, s.right, s.settings, s.turning)
+    }
+}
+
+impl Backend {
+    pub fn renderer(self) -> &'static dyn CubeRenderer {
+        match self {
+            Backend::Canvas => &CanvasRenderer,
+            Backend::Gpu => &MeshRenderer,
+        }
+    }
+}
+
+/// `scene` drawn by the renderer its settings pick, or else the canvas.
+pub fn draw(scene: Scene<'_>) -> Element<'_, CanvasEvent> {
+    scene.settings.backend.renderer().view(scene).unwrap_or_else(|| canvas(scene))
+}
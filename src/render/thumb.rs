@@ -0,0 +1,70 @@
+// src/render/thumb.rs
+
+//! Last-layer case thumbnail: the U face seen from above, back edge up,
+//! with the top row of each side face as a strip beside it, as SVG. The
+//! usual diagram in trainer sheets and flash cards.
+
+use std::fmt::Write as _;
+
+use crate::cube::{Cube, FaceId};
+use super::blueprint::hex;
+use super::face::{base_color, cell_quad_raw};
+use super::geom::face_normal;
+
+/// Depth of the side strips and their gap from the U face, in stickers.
+const STRIP: f32 = 0.3;
+const GAP: f32 = 0.08;
+
+/// Where one sticker lands, as (x0, y0, x1, y1) in stickers from the U
+/// face's top-left corner; `None` for stickers not in the diagram.
+fn sticker_box(face: FaceId, r: usize, c: usize) -> Option<(f32, f32, f32, f32)> {
+    let q = cell_quad_raw(face, r, c);
+    if q.iter().map(|p| p.2).sum::<f32>() / 4.0 <= 1.0 {
+        return None;
+    }
+    // Seen from above: x to the right, y (toward B) up the page.
+    let xs = q.map(|p| p.0);
+    let ys = q.map(|p| 2.0 - p.1);
+    let lo = |v: [f32; 4]| v.into_iter().fold(f32::INFINITY, f32::min);
+    let hi = |v: [f32; 4]| v.into_iter().fold(f32::NEG_INFINITY, f32::max);
+    let (x0, x1, y0, y1) = (lo(xs), hi(xs), lo(ys), hi(ys));
+    // A side sticker is an edge of the square seen end on; push it out
+    // into a strip along that edge.
+    let out = |n: f32, a: f32, b: f32| match n {
+        n if n > 0.5 => (b + GAP, b + GAP + STRIP),
+        n if n < -0.5 => (a - GAP - STRIP, a - GAP),
+        _ => (a, b),
+    };
+    let n = face_normal(face);
+    let (x0, x1) = out(n.0, x0, x1);
+    let (y0, y1) = out(-n.1, y0, y1);
+    Some((x0, y0, x1, y1))
+}
+
+/// The last-layer diagram of `cube` with stickers `unit` pixels wide.
+pub fn svg(cube: &Cube, unit: f32) -> String {
+    let margin = (STRIP + GAP) * unit;
+    let side = 2.0 * unit + 2.0 * margin;
+    let mut out = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{side}" height="{side}" viewBox="0 0 {side} {side}">"#
+    );
+    for face in FaceId::ALL {
+        for r in 0..2 {
+            for c in 0..2 {
+                let Some((x0, y0, x1, y1)) = sticker_box(face, r, c) else { continue };
+                let _ = write!(
+                    out,
+                    r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" rx="{:.1}" fill="{}" stroke="#222" stroke-width="1.5"/>"##,
+                    margin + x0 * unit,
+                    margin + y0 * unit,
+                    (x1 - x0) * unit,
+                    (y1 - y0) * unit,
+                    unit * 0.06,
+                    hex(base_color(cube.face(face)[r][c])),
+                );
+            }
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
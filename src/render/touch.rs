@@ -0,0 +1,174 @@
+// src/render/touch.rs
+
+//! Touch gestures on the cube views: a one-finger swipe across a face turns
+//! that face the way the finger went around its center (a swipe over more
+//! than half a turn is a double turn), two fingers dragged together orbit
+//! the view they started on, and a pinch zooms.
+
+use std::collections::HashMap;
+
+use iced::touch::{self, Finger};
+use iced::{Point, Vector};
+
+use crate::cube::{FaceId, Move, Turn};
+use super::geom::{face_outer, face_visible, project, rotate_pt_all};
+use super::types::ViewParams;
+
+/// Shortest swipe that turns a face, as a fraction of the sticker size.
+const MIN_SWIPE: f32 = 0.6;
+/// Swept angle past which a swipe is a double turn.
+const HALF_TURN: f32 = 135.0;
+/// Orbit per pixel of two-finger drag, in degrees.
+const ORBIT_PER_PX: f32 = 0.5;
+
+/// What a gesture asks for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    Turn(Move),
+    /// Two fingers moved: orbit a view by `yaw` (about the vertical axis)
+    /// and `pitch` degrees, and scale the cube size by `zoom`.
+    Pinch { right: bool, yaw: f32, pitch: f32, zoom: f32 },
+}
+
+/// A one-finger swipe that started on a face.
+#[derive(Debug, Clone, Copy)]
+struct Swipe {
+    finger: Finger,
+    face: FaceId,
+    /// Face center and the swipe's start, in canvas coordinates.
+    center: Point,
+    start: Point,
+    size: f32,
+}
+
+/// Fingers down and the gesture they are making.
+#[derive(Debug, Default)]
+pub struct Touches {
+    fingers: HashMap<Finger, Point>,
+    swipe: Option<Swipe>,
+    /// Two-finger gesture: the view it orbits, and the fingers' midpoint
+    /// and spread at the last event.
+    pinch: Option<(bool, Point, f32)>,
+}
+
+impl Touches {
+    /// Take in a touch event, positions already relative to the canvas.
+    /// `views` are the placed left view and, if shown, the right one.
+    pub fn update(&mut self, event: touch::Event, views: (ViewParams, Option<ViewParams>)) -> Option<Gesture> {
+        match event {
+            touch::Event::FingerPressed { id, position } => {
+                self.fingers.insert(id, position);
+                self.swipe = None;
+                match self.fingers.len() {
+                    1 => {
+                        self.swipe = hit_face(position, views).map(|(face, center, size)| Swipe {
+                            finger: id,
+                            face,
+                            center,
+                            start: position,
+                            size,
+                        });
+                    }
+                    2 => {
+                        let (mid, spread) = self.spread()?;
+                        let right = views.1.is_some_and(|r| mid.distance(origin(&r)) < mid.distance(origin(&views.0)));
+                        self.pinch = Some((right, mid, spread));
+                    }
+                    _ => self.pinch = None,
+                }
+                None
+            }
+            touch::Event::FingerMoved { id, position } => {
+                *self.fingers.get_mut(&id)? = position;
+                let (right, last_mid, last_spread) = self.pinch?;
+                let (mid, spread) = self.spread()?;
+                self.pinch = Some((right, mid, spread));
+                let pan = mid - last_mid;
+                Some(Gesture::Pinch {
+                    right,
+                    yaw: pan.x * ORBIT_PER_PX,
+                    pitch: -pan.y * ORBIT_PER_PX,
+                    zoom: if last_spread > 0.0 { spread / last_spread } else { 1.0 },
+                })
+            }
+            touch::Event::FingerLifted { id, position } => {
+                self.fingers.remove(&id);
+                self.pinch = None;
+                let swipe = self.swipe.take().filter(|s| s.finger == id)?;
+                swipe_turn(&swipe, position).map(Gesture::Turn)
+            }
+            touch::Event::FingerLost { id, .. } => {
+                self.fingers.remove(&id);
+                self.swipe = None;
+                self.pinch = None;
+                None
+            }
+        }
+    }
+
+    /// Midpoint and distance of the two fingers down.
+    fn spread(&self) -> Option<(Point, f32)> {
+        let mut it = self.fingers.values();
+        let (a, b) = (*it.next()?, *it.next()?);
+        Some((Point::new(0.5 * (a.x + b.x), 0.5 * (a.y + b.y)), a.distance(b)))
+    }
+}
+
+fn origin(vp: &ViewParams) -> Point {
+    Point::new(vp.origin.0, vp.origin.1)
+}
+
+/// The visible face under `p`, with its projected center and sticker size.
+fn hit_face(p: Point, views: (ViewParams, Option<ViewParams>)) -> Option<(FaceId, Point, f32)> {
+    for vp in std::iter::once(views.0).chain(views.1) {
+        for face in FaceId::ALL {
+            if !face_visible(face, vp.rz, vp.rx, vp.ry) {
+                continue;
+            }
+            let quad = face_outer(face)
+                .map(|q| rotate_pt_all(q, vp.rz, vp.ry, vp.rx))
+                .map(|(x, y, z)| Point::from(project(x, y, z, vp.size, vp.origin)));
+            if inside(p, &quad) {
+                let center = Point::new(
+                    quad.iter().map(|q| q.x).sum::<f32>() / 4.0,
+                    quad.iter().map(|q| q.y).sum::<f32>() / 4.0,
+                );
+                return Some((face, center, vp.size));
+            }
+        }
+    }
+    None
+}
+
+/// Whether `p` lies inside the convex quad `q` (either winding).
+fn inside(p: Point, q: &[Point; 4]) -> bool {
+    let sides: [f32; 4] = std::array::from_fn(|i| {
+        let (a, b) = (q[i], q[(i + 1) % 4]);
+        (b - a).x * (p - a).y - (b - a).y * (p - a).x
+    });
+    sides.iter().all(|&s| s >= 0.0) || sides.iter().all(|&s| s <= 0.0)
+}
+
+/// The turn a swipe ending at `end` makes: its direction around the face
+/// center (clockwise on screen is a clockwise turn, the face being seen
+/// from outside), doubled when it sweeps far enough.
+fn swipe_turn(swipe: &Swipe, end: Point) -> Option<Move> {
+    if swipe.start.distance(end) < MIN_SWIPE * swipe.size {
+        return None;
+    }
+    let (a, b): (Vector, Vector) = (swipe.start - swipe.center, end - swipe.center);
+    // Screen y points down, so a positive cross product is clockwise.
+    let cross = a.x * b.y - a.y * b.x;
+    let dot = a.x * b.x + a.y * b.y;
+    let swept = cross.atan2(dot).to_degrees();
+    let turn = if swept.abs() > HALF_TURN {
+        Turn::Half
+    } else if swept > 0.0 {
+        Turn::Cw
+    } else if swept < 0.0 {
+        Turn::Ccw
+    } else {
+        return None;
+    };
+    Some(Move::new(swipe.face, turn))
+}
@@ -0,0 +1,55 @@
+// src/render/trackball.rs
+
+//! Trackball camera: a mouse drag over a view rolls the cube like a ball
+//! under the cursor. The cursor is lifted onto a sphere around the cube
+//! (and onto a hyperbolic sheet past its rim, so a drag never falls off),
+//! and each step of the drag turns the view by the rotation taking the
+//! last point on the sphere to the new one. The axis comes from the drag,
+//! not from a fixed Euler order, so there are no locked directions.
+
+use iced::Point;
+
+use super::geom::project;
+use super::quat::Quat;
+use super::types::ViewParams;
+
+type P3 = (f32, f32, f32);
+
+/// Ball radius in cube units: through the cube's corners.
+const RADIUS: f32 = 1.732_050_8;
+/// Screen pixels per cube unit, per unit of view size, under the
+/// isometric projection (√(3/2)).
+const SCALE: f32 = 1.224_744_9;
+
+/// The world-space turn of a drag step from `from` to `to` (canvas
+/// coordinates) over the placed view `vp`.
+pub fn roll(vp: &ViewParams, from: Point, to: Point) -> Quat {
+    Quat::between(lift(vp, from), lift(vp, to))
+}
+
+/// The screen center of `vp`'s cube.
+pub fn center(vp: &ViewParams) -> Point {
+    let (x, y) = project(1.0, 1.0, 1.0, vp.size, vp.origin);
+    Point::new(x, y)
+}
+
+/// `p` lifted onto the ball, as a world-space unit vector from the cube's
+/// center.
+fn lift(vp: &ViewParams, p: Point) -> P3 {
+    let c = center(vp);
+    let r = RADIUS * SCALE * vp.size;
+    let (x, y) = ((p.x - c.x) / r, (c.y - p.y) / r);
+    let d2 = x * x + y * y;
+    // Sphere inside, hyperbola outside; they meet smoothly at d² = ½.
+    let z = if d2 <= 0.5 { (1.0 - d2).sqrt() } else { 0.5 / d2.sqrt() };
+    // Screen right, up, and toward the viewer, in world coordinates.
+    let (s2, s3, s6) = (2.0f32.sqrt(), 3.0f32.sqrt(), 6.0f32.sqrt());
+    let (right, up, toward) = ((1.0 / s2, -1.0 / s2, 0.0), (1.0 / s6, 1.0 / s6, 2.0 / s6), (-1.0 / s3, -1.0 / s3, 1.0 / s3));
+    let v = (
+        x * right.0 + y * up.0 + z * toward.0,
+        x * right.1 + y * up.1 + z * toward.1,
+        x * right.2 + y * up.2 + z * toward.2,
+    );
+    let n = (v.0 * v.0 + v.1 * v.1 + v.2 * v.2).sqrt();
+    (v.0 / n, v.1 / n, v.2 / n)
+}
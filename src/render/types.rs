@@ -1,13 +1,35 @@
 // src/render/types.rs
 
-//! Basic render types: typed angle wrappers and per-view parameters.
+//! Basic render types: typed angle wrappers, per-view parameters, and
+//! render settings shared by both views.
+//!
+//! Render math works in radians. Degrees belong to the UI (sliders, typed
+//! angles, saved settings) and are converted once, where a view's
+//! parameters are built.
 
+use crate::t;
+
+/// An angle in radians.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f32);
+impl Radians {
+    pub fn from_degrees(deg: f32) -> Self {
+        Self(deg.to_radians())
+    }
+    pub fn degrees(self) -> f32 {
+        self.0.to_degrees()
+    }
+}
+
+/// Camera turn about the cube's Z axis, applied first.
 #[derive(Copy, Clone, Debug)]
-pub struct RotZ(pub f32);
+pub struct RotZ(pub Radians);
+/// Camera turn about X, applied last.
 #[derive(Copy, Clone, Debug)]
-pub struct RotX(pub f32);
+pub struct RotX(pub Radians);
+/// Camera turn about Y, applied between Z and X.
 #[derive(Copy, Clone, Debug)]
-pub struct RotY(pub f32);
+pub struct RotY(pub Radians);
 
 #[derive(Copy, Clone, Debug)]
 pub struct ViewParams {
@@ -16,4 +38,121 @@ pub struct ViewParams {
     pub ry: RotY,
     pub origin: (f32, f32),
     pub size: f32,
-}
\ No newline at end of file
+    pub mode: RenderMode,
+}
+
+/// How a view draws its faces.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Plastic base plus colored stickers.
+    #[default]
+    Filled,
+    /// Face outlines and sticker seams only, no fills (print/low-ink).
+    Wireframe,
+}
+impl RenderMode {
+    pub const ALL: [RenderMode; 2] = [RenderMode::Filled, RenderMode::Wireframe];
+}
+impl std::fmt::Display for RenderMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            RenderMode::Filled => t!("mode-filled"),
+            RenderMode::Wireframe => t!("mode-wireframe"),
+        })
+    }
+}
+
+/// How mouse drags over the views move the cameras.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CameraModel {
+    /// Drags do nothing; the Rz/Rx/Ry sliders set the camera.
+    #[default]
+    Euler,
+    /// Drags roll the view like a ball under the cursor, about whatever
+    /// axis the drag implies; the sliders follow. See [`super::trackball`].
+    Trackball,
+}
+impl CameraModel {
+    pub const ALL: [CameraModel; 2] = [CameraModel::Euler, CameraModel::Trackball];
+}
+impl std::fmt::Display for CameraModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            CameraModel::Euler => t!("camera-euler"),
+            CameraModel::Trackball => t!("camera-trackball"),
+        })
+    }
+}
+
+/// Which renderer draws the cube views; see [`super::renderer`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Backend {
+    /// Iced canvas, painter's algorithm; draws everything.
+    #[default]
+    Canvas,
+    /// GPU triangles with a depth buffer (`gpu` feature); filled views only.
+    Gpu,
+}
+impl Backend {
+    /// The renderers this build has.
+    pub fn available() -> Vec<Backend> {
+        [Backend::Canvas, Backend::Gpu]
+            .into_iter()
+            .filter(|b| *b != Backend::Gpu || super::mesh::AVAILABLE)
+            .collect()
+    }
+}
+impl std::fmt::Display for Backend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&match self {
+            Backend::Canvas => t!("backend-canvas"),
+            Backend::Gpu => t!("backend-gpu"),
+        })
+    }
+}
+
+/// Fixed directional light (world space, i.e. after the camera rotation).
+#[derive(Copy, Clone, Debug)]
+pub struct Lighting {
+    /// Angle around world Z (225° points toward the viewer).
+    pub azimuth: Radians,
+    /// Angle above the XY plane.
+    pub elevation: Radians,
+    /// Shading strength in `[0, 1]`; `0` keeps the flat sticker colors.
+    pub intensity: f32,
+}
+impl Default for Lighting {
+    fn default() -> Self {
+        Self { azimuth: Radians::from_degrees(240.0), elevation: Radians::from_degrees(50.0), intensity: 0.35 }
+    }
+}
+
+/// Settings applied to every view drawn by the canvas.
+#[derive(Copy, Clone, Debug)]
+pub struct RenderSettings {
+    pub light: Lighting,
+    /// Faint screen-space grid behind the cubes.
+    pub show_grid: bool,
+    /// XYZ triad in the canvas corner under each view.
+    pub show_axes: bool,
+    /// Tint each sticker by how far its corner is from home.
+    pub show_heat: bool,
+    /// Letter-scheme label on every sticker position.
+    pub show_letters: bool,
+    /// Display scale factor (device px per logical px) used for hinting.
+    pub scale_factor: f32,
+    /// Frame-time / polygon / cache overlay (toggled with F12).
+    pub show_profiler: bool,
+    /// Renderer drawing the cube views.
+    pub backend: Backend,
+    /// What mouse drags over the views do.
+    pub camera: CameraModel,
+}
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self { light: Lighting::default(), show_grid: false, show_axes: false, show_heat: false, show_letters: false, scale_factor: 1.0, show_profiler: false, backend: Backend::Canvas, camera: CameraModel::Euler }
+    }
+}
+
+/// Display scale choices offered in the UI.
+pub const SCALE_FACTORS: [f32; 5] = [1.0, 1.25, 1.5, 1.75, 2.0];
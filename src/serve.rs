@@ -0,0 +1,228 @@
+// src/serve.rs
+
+//! Local diagram server, for blogs and documentation pages that want a
+//! picture of a cube state by URL. `rubics serve [port]` answers
+//!
+//! - `GET /state.svg?alg=R+U+R'` with an SVG of the state the algorithm
+//!   leaves a solved cube in. `view=` picks the drawing: `net` (the
+//!   default, every face unfolded), `blueprint` (top, front, and right), or
+//!   `ll` (the last-layer thumbnail); `case=1` draws the state the
+//!   algorithm solves instead.
+//!
+//! Diagrams are drawn without a window, so there is no PNG; `/state.png`
+//! says so. The server speaks plain HTTP/1.0 on the loopback interface
+//! only, one request at a time, and runs until stopped. Requests with an
+//! overlong line or too many headers get a 400 rather than being read on.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use crate::app::share::percent_decode;
+use crate::cube::Cube;
+use crate::logic::alg::parse_alg;
+use crate::render::{blueprint, net, thumb};
+use crate::t;
+
+/// Port used when none is given.
+pub const DEFAULT_PORT: u16 = 8642;
+/// Time a client has to send its whole request, so a slow or stalled one
+/// can't hold the server.
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest request or header line read, in bytes.
+const MAX_LINE: u64 = 8 * 1024;
+/// Most header lines read.
+const MAX_HEADERS: usize = 100;
+/// Sticker size in the net and thumbnail, in pixels.
+const UNIT: f32 = 40.0;
+
+/// An answer: status line, content type, and body.
+struct Response {
+    status: &'static str,
+    kind: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn svg(body: String) -> Self {
+        Self { status: "200 OK", kind: "image/svg+xml", body }
+    }
+
+    fn text(status: &'static str, body: String) -> Self {
+        Self { status, kind: "text/plain; charset=utf-8", body }
+    }
+}
+
+/// Listen on `port` and answer requests until the process is stopped;
+/// returns only if the port can't be had.
+pub fn run(port: u16) -> Result<(), String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|e| t!("serve-bind-failed", port = port, error = e.to_string()))?;
+    println!("{}", t!("serve-listening", url = format!("http://{}:{port}/state.svg?alg=R+U+R'", Ipv4Addr::LOCALHOST)));
+    for stream in listener.incoming() {
+        // One bad client is no reason to stop serving the next.
+        if let Err(e) = stream.and_then(handle) {
+            eprintln!("{e}");
+        }
+    }
+    Ok(())
+}
+
+/// Reads from a client, each read waiting only as long as is left before
+/// `until`.
+struct Deadline<'a> {
+    stream: &'a TcpStream,
+    until: Instant,
+}
+
+impl Read for Deadline<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.until.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        let mut stream = self.stream;
+        stream.read(buf)
+    }
+}
+
+fn handle(mut stream: TcpStream) -> io::Result<()> {
+    let until = Instant::now() + TIMEOUT;
+    let response = match read_request(&mut BufReader::new(Deadline { stream: &stream, until }))? {
+        Some(request) => answer(request.trim_end()),
+        None => Response::text("400 Bad Request", t!("serve-too-long")),
+    };
+    write!(
+        stream,
+        "HTTP/1.0 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nAccess-Control-Allow-Origin: *\r\n\r\n{}",
+        response.status,
+        response.kind,
+        response.body.len(),
+        response.body,
+    )
+}
+
+/// The request line, once the headers after it are read (and ignored);
+/// `None` if a line runs past [`MAX_LINE`] or the headers past
+/// [`MAX_HEADERS`].
+fn read_request(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut request = String::new();
+    if !read_line(reader, &mut request)? {
+        return Ok(None);
+    }
+    let mut header = String::new();
+    for _ in 0..MAX_HEADERS {
+        header.clear();
+        if !read_line(reader, &mut header)? {
+            return Ok(None);
+        }
+        if header.len() <= 2 {
+            return Ok(Some(request));
+        }
+    }
+    Ok(None)
+}
+
+/// Read one line into `line`; `false` if it doesn't end within
+/// [`MAX_LINE`] bytes.
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> io::Result<bool> {
+    let n = reader.take(MAX_LINE).read_line(line)?;
+    Ok((n as u64) < MAX_LINE || line.ends_with('\n'))
+}
+
+/// The answer to a request line (`GET /state.svg?alg=… HTTP/1.1`).
+fn answer(request: &str) -> Response {
+    let mut words = request.split_whitespace();
+    let (Some(method), Some(target)) = (words.next(), words.next()) else {
+        return Response::text("400 Bad Request", t!("serve-bad-request"));
+    };
+    if method != "GET" {
+        return Response::text("405 Method Not Allowed", t!("serve-get-only"));
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    match path {
+        "/state.svg" => state_svg(query),
+        "/state.png" => Response::text("501 Not Implemented", t!("serve-no-png")),
+        _ => Response::text("404 Not Found", t!("serve-not-found", path = path.to_string())),
+    }
+}
+
+fn state_svg(query: &str) -> Response {
+    let param = |key: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('=').or(Some((pair, ""))))
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| percent_decode(v))
+    };
+    let alg = match parse_alg(&param("alg").unwrap_or_default()) {
+        Ok(alg) => alg,
+        Err(e) => return Response::text("400 Bad Request", e.to_string()),
+    };
+    let mut cube = Cube::default();
+    if param("case").is_some_and(|v| v == "1" || v == "true") {
+        cube.apply_alg(&alg.inverse());
+    } else {
+        cube.apply_alg(&alg);
+    }
+    match param("view").as_deref().unwrap_or("net") {
+        "net" => Response::svg(net::svg(&cube, UNIT)),
+        "blueprint" => Response::svg(blueprint::svg(&cube, &alg.to_string())),
+        "ll" => Response::svg(thumb::svg(&cube, UNIT)),
+        view => Response::text("400 Bad Request", t!("serve-bad-view", view = view.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(raw: &str) -> Option<String> {
+        read_request(&mut io::Cursor::new(raw)).unwrap()
+    }
+
+    #[test]
+    fn reads_the_request_line() {
+        let raw = "GET /state.svg?alg=R HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        assert_eq!(read(raw).as_deref(), Some("GET /state.svg?alg=R HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn refuses_overlong_lines() {
+        let long = "R+".repeat(MAX_LINE as usize);
+        assert_eq!(read(&format!("GET /state.svg?alg={long} HTTP/1.1\r\n\r\n")), None);
+        assert_eq!(read(&format!("GET / HTTP/1.1\r\nX: {long}\r\n\r\n")), None);
+    }
+
+    #[test]
+    fn slow_clients_run_out_of_time() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            // A byte at a time, each well within any per-read timeout.
+            for b in b"GET / HTTP/1.1\r\nX-Slow: ".iter().chain(std::iter::repeat_n(&b'y', 40)) {
+                if stream.write_all(&[*b]).is_err() {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(25));
+            }
+        });
+        let (stream, _) = listener.accept().unwrap();
+        let started = Instant::now();
+        let until = started + Duration::from_millis(200);
+        let err = read_request(&mut BufReader::new(Deadline { stream: &stream, until })).unwrap_err();
+        // A socket read timing out reports WouldBlock on Unix.
+        assert!(matches!(err.kind(), io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock), "{err}");
+        assert!(started.elapsed() < Duration::from_millis(500));
+        drop(stream);
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn refuses_endless_headers() {
+        let headers = "X: y\r\n".repeat(MAX_HEADERS + 1);
+        assert_eq!(read(&format!("GET / HTTP/1.1\r\n{headers}\r\n")), None);
+    }
+}
@@ -0,0 +1,135 @@
+// src/storage/mod.rs
+
+//! On-disk locations and the small `key=value` text format used for the
+//! app's own files (recovery checkpoints, settings). Data files belong to
+//! the active profile (see `app::profiles`).
+
+pub mod zip;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+/// The active profile's name; `None` is the default profile.
+static PROFILE: RwLock<Option<String>> = RwLock::new(None);
+/// Set for a demo run, which reads the profile's files but never writes
+/// them.
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Per-user data root (`$XDG_DATA_HOME/rubics`, `~/.local/share/rubics`,
+/// or `%APPDATA%\rubics`), falling back to `./.rubics`. It holds the
+/// default profile's files and the other profiles' folders.
+pub fn root_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")));
+    match base {
+        Some(b) => b.join("rubics"),
+        None => PathBuf::from(".rubics"),
+    }
+}
+
+/// Where the other profiles' folders go, one per profile name.
+pub fn profiles_dir() -> PathBuf {
+    root_dir().join("profiles")
+}
+
+/// The active profile's data directory: the root for the default profile.
+pub fn data_dir() -> PathBuf {
+    match active_profile() {
+        Some(name) => profiles_dir().join(name),
+        None => root_dir(),
+    }
+}
+
+pub fn active_profile() -> Option<String> {
+    PROFILE.read().map_or(None, |p| p.clone())
+}
+
+/// Point [`data_dir`] at profile `name`, or the default profile.
+pub fn set_profile(name: Option<String>) {
+    if let Ok(mut p) = PROFILE.write() {
+        *p = name;
+    }
+}
+
+pub fn read_only() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+pub fn set_read_only(on: bool) {
+    READ_ONLY.store(on, Ordering::Relaxed);
+}
+
+/// Write one of the app's own files, making its folder first; in a
+/// read-only run, keep nothing and report success.
+pub fn save_file(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    if read_only() {
+        return Ok(());
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, contents)
+}
+
+/// Per-user cache directory (`$XDG_CACHE_HOME/rubics`, `~/.cache/rubics`,
+/// or `%LOCALAPPDATA%\rubics`), falling back to `./.rubics/cache`. Anything
+/// here can be regenerated.
+pub fn cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")));
+    match base {
+        Some(b) => b.join("rubics"),
+        None => PathBuf::from(".rubics/cache"),
+    }
+}
+
+/// Render `pairs` as `key=value` lines under a `# header` comment.
+/// Newlines in values are escaped as `\n`.
+pub fn write_kv(header: &str, pairs: &[(&str, String)]) -> String {
+    let mut out = format!("# {header}\n");
+    for (k, v) in pairs {
+        out.push_str(k);
+        out.push('=');
+        out.push_str(&escape(v));
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse `key=value` lines, skipping blanks and `#` comments.
+pub fn read_kv(text: &str) -> BTreeMap<String, String> {
+    text.lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .filter_map(|l| l.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), unescape(v)))
+        .collect()
+}
+
+/// `v` on one line: backslashes doubled, newlines as `\n`.
+pub fn escape(v: &str) -> String {
+    v.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Inverse of [`escape`].
+pub fn unescape(v: &str) -> String {
+    let mut out = String::with_capacity(v.len());
+    let mut chars = v.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
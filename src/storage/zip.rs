@@ -0,0 +1,168 @@
+// src/storage/zip.rs
+
+//! Just enough of the ZIP format for profile backups: archives of whole
+//! files stored without compression, readable by any unzip tool, and read
+//! back the same way. Compressed entries from other tools are refused
+//! rather than inflated.
+
+use crate::Error;
+
+const LOCAL: u32 = 0x0403_4b50;
+const CENTRAL: u32 = 0x0201_4b50;
+const END: u32 = 0x0605_4b50;
+/// Version 2.0, the first with folders, is all stored entries need.
+const VERSION: u16 = 20;
+/// General-purpose flag: names are UTF-8.
+const UTF8: u16 = 0x0800;
+/// DOS date of 1980-01-01, the earliest there is; times aren't kept.
+const DATE: u16 = 0x0021;
+const END_LEN: usize = 22;
+
+/// CRC-32 (IEEE), bit by bit; backups are small.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn put16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+/// An archive holding `files` (name, contents), in order.
+pub fn write(files: &[(String, Vec<u8>)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let header = |out: &mut Vec<u8>| {
+            put16(out, UTF8);
+            put16(out, 0); // stored
+            put16(out, 0); // time
+            put16(out, DATE);
+            put32(out, crc);
+            put32(out, data.len() as u32);
+            put32(out, data.len() as u32);
+            put16(out, name.len() as u16);
+            put16(out, 0); // extra field
+        };
+        put32(&mut out, LOCAL);
+        put16(&mut out, VERSION);
+        header(&mut out);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        put32(&mut central, CENTRAL);
+        put16(&mut central, VERSION); // made by
+        put16(&mut central, VERSION); // needed
+        header(&mut central);
+        put16(&mut central, 0); // comment
+        put16(&mut central, 0); // disk
+        put16(&mut central, 0); // internal attributes
+        put32(&mut central, 0); // external attributes
+        put32(&mut central, offset);
+        central.extend_from_slice(name.as_bytes());
+    }
+    let (start, size) = (out.len() as u32, central.len() as u32);
+    out.extend_from_slice(&central);
+    put32(&mut out, END);
+    put16(&mut out, 0);
+    put16(&mut out, 0);
+    put16(&mut out, files.len() as u16);
+    put16(&mut out, files.len() as u16);
+    put32(&mut out, size);
+    put32(&mut out, start);
+    put16(&mut out, 0);
+    out
+}
+
+fn get16(b: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(b.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn get32(b: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(b.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// The files in an archive of stored entries, in order.
+pub fn read(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, Error> {
+    entries(bytes).ok_or(Error::BadArchive)
+}
+
+fn entries(b: &[u8]) -> Option<Vec<(String, Vec<u8>)>> {
+    // The end record sits last, before a comment of up to 64 KiB.
+    let end = (0..=b.len().checked_sub(END_LEN)?)
+        .rev()
+        .take(u16::MAX as usize + 1)
+        .find(|&i| get32(b, i) == Some(END))?;
+    let count = get16(b, end + 10)? as usize;
+    let mut at = get32(b, end + 16)? as usize;
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        if get32(b, at)? != CENTRAL || get16(b, at + 10)? != 0 {
+            return None;
+        }
+        let crc = get32(b, at + 16)?;
+        let size = get32(b, at + 20)? as usize;
+        let name_len = get16(b, at + 28)? as usize;
+        let skip = name_len + get16(b, at + 30)? as usize + get16(b, at + 32)? as usize;
+        let local = get32(b, at + 42)? as usize;
+        let name = String::from_utf8(b.get(at + 46..at + 46 + name_len)?.to_vec()).ok()?;
+        at += 46 + skip;
+
+        if get32(b, local)? != LOCAL {
+            return None;
+        }
+        let data_at = local + 30 + get16(b, local + 26)? as usize + get16(b, local + 28)? as usize;
+        let data = b.get(data_at..data_at.checked_add(size)?)?.to_vec();
+        if crc32(&data) != crc {
+            return None;
+        }
+        out.push((name, data));
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files() -> Vec<(String, Vec<u8>)> {
+        vec![
+            ("stats.txt".to_string(), b"12.34\n9.87\n".to_vec()),
+            ("empty.txt".to_string(), Vec::new()),
+            ("théorie.txt".to_string(), (0..=255).collect()),
+        ]
+    }
+
+    #[test]
+    fn crc_matches_the_standard() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(read(&write(&files())).unwrap(), files());
+        assert!(read(&write(&[])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn damage_is_refused() {
+        let zip = write(&files());
+        let mut flipped = zip.clone();
+        flipped[30 + "stats.txt".len()] ^= 1;
+        assert!(matches!(read(&flipped), Err(Error::BadArchive)));
+        assert!(matches!(read(&zip[..zip.len() - 1]), Err(Error::BadArchive)));
+        assert!(matches!(read(b"not a zip"), Err(Error::BadArchive)));
+    }
+}
@@ -8,6 +8,7 @@ use iced::{
     widget::{row, column, text, text_input, button},
 };
 use crate::app::Msg;
+use crate::t;
 
 /// Bottom section: seed + Apply/Scramble/Reset + move buttons.
 ///
@@ -35,13 +36,13 @@ where
 {
     // Seed row: label + input + 3 buttons
     let seed_row = row![
-        text("Seed"),
-        text_input("Enter seed (e.g., 12345)", seed)
+        text(t!("seed")),
+        text_input(&t!("seed-placeholder"), seed)
             .on_input(on_seed_change)
             .width(Length::Fixed(220.0)),
-        button("Apply").on_press(on_apply),
-        button("Scramble").on_press(on_scramble),
-        button("Reset").on_press(on_reset),
+        button(text(t!("apply"))).on_press(on_apply),
+        button(text(t!("scramble"))).on_press(on_scramble),
+        button(text(t!("reset"))).on_press(on_reset),
     ]
         .spacing(8)
         .align_items(Alignment::Center)
@@ -65,7 +66,7 @@ where
         .spacing(8)
         .align_items(Alignment::Center);
 
-    column![seed_row, text("Moves"), moves_top, moves_bot]
+    column![seed_row, text(t!("moves")), moves_top, moves_bot]
         .spacing(10)
         .width(Length::Fill)
         .into()
@@ -0,0 +1,58 @@
+// src/ui/highlight.rs
+
+//! Syntax highlighting for the algorithm editor: move tokens in the accent
+//! color, anything that isn't a move in red, `//` comments dimmed.
+
+use std::ops::Range;
+
+use iced::advanced::text::highlighter::{Format, Highlighter};
+use iced::{Color, Font, Theme};
+
+use crate::logic::alg::{classify_line, TokenKind};
+
+const MOVE_COLOR: Color = Color { r: 0.25, g: 0.55, b: 1.0, a: 1.0 };
+const INVALID_COLOR: Color = Color { r: 0.9, g: 0.15, b: 0.15, a: 1.0 };
+const COMMENT_COLOR: Color = Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+
+/// Each line is classified on its own, so the highlighter only tracks which
+/// line comes next.
+pub struct AlgHighlighter {
+    line: usize,
+}
+
+impl Highlighter for AlgHighlighter {
+    type Settings = ();
+    type Highlight = TokenKind;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, TokenKind)>;
+
+    fn new(_settings: &()) -> Self {
+        Self { line: 0 }
+    }
+
+    fn update(&mut self, _new_settings: &()) {
+        self.line = 0;
+    }
+
+    fn change_line(&mut self, line: usize) {
+        self.line = self.line.min(line);
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        self.line += 1;
+        classify_line(line).into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.line
+    }
+}
+
+/// Editor text format for a token kind.
+pub fn format(kind: &TokenKind, _theme: &Theme) -> Format<Font> {
+    let color = match kind {
+        TokenKind::Move => MOVE_COLOR,
+        TokenKind::Invalid => INVALID_COLOR,
+        TokenKind::Comment => COMMENT_COLOR,
+    };
+    Format { color: Some(color), font: None }
+}
@@ -0,0 +1,50 @@
+// src/ui/labels.rs
+
+//! Descriptive labels for controls: a hover tooltip carrying the spoken-style
+//! description of what the control does, plus helpers for move names.
+
+use iced::{Element, theme, widget::{container, text, tooltip}};
+use crate::app::Msg;
+use crate::cube::FaceId;
+use crate::t;
+
+/// Wrap `content` with a tooltip describing it.
+pub fn labeled<'a>(content: impl Into<Element<'a, Msg>>, label: String) -> Element<'a, Msg> {
+    tooltip(
+        content,
+        container(text(label).size(13)).padding(4).style(theme::Container::Box),
+        tooltip::Position::Bottom,
+    )
+        .into()
+}
+
+/// Long name of a face, e.g. "Right".
+pub fn face_name(face: FaceId) -> String {
+    match face {
+        FaceId::U => t!("face-up"),
+        FaceId::D => t!("face-down"),
+        FaceId::F => t!("face-front"),
+        FaceId::B => t!("face-back"),
+        FaceId::L => t!("face-left"),
+        FaceId::R => t!("face-right"),
+    }
+}
+
+/// Long description of a move token, e.g. `R'` → "Turn the Right face counter-clockwise".
+pub fn move_label(tok: &str) -> String {
+    let face = match tok.chars().next() {
+        Some('U') => FaceId::U,
+        Some('D') => FaceId::D,
+        Some('F') => FaceId::F,
+        Some('B') => FaceId::B,
+        Some('L') => FaceId::L,
+        Some('R') => FaceId::R,
+        _ => return tok.to_string(),
+    };
+    let turn = match &tok[1..] {
+        "'" => t!("turn-ccw"),
+        "2" => t!("turn-180"),
+        _ => t!("turn-cw"),
+    };
+    t!("move-desc", face = face_name(face), turn = turn)
+}
@@ -0,0 +1,196 @@
+// src/ui/menu.rs
+
+//! UI: menu bar (File, Edit, View, Help) across the top of the main window.
+//! Each menu is a drop-down list whose entries send the same messages as
+//! the buttons and palette commands they stand for, so the menus are a map
+//! of what the app can do rather than a second set of features.
+
+use std::fmt;
+
+use iced::{
+    Alignment, Element, Length,
+    widget::{button, column, container, pick_list, row, text, Space},
+    widget::pick_list::Handle,
+    theme, window,
+};
+use crate::app::{backup, screenshot, Msg};
+use crate::app::debug::Field;
+use super::labels::labeled;
+use crate::render::RenderSettings;
+use crate::t;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuItem {
+    // File
+    OpenSession,
+    SaveSession,
+    ExportImage,
+    ExportScript,
+    ImportScript,
+    BackUp,
+    Quit,
+    // Edit
+    Undo,
+    Redo,
+    ResetCube,
+    Palette,
+    // View; toggles carry whether they are on now.
+    Panels(bool),
+    Grid(bool),
+    Axes(bool),
+    Heatmap(bool),
+    Letters(bool),
+    Qr(bool),
+    Profiler(bool),
+    ResetCameras,
+    // Help
+    Diagnostics,
+    About,
+}
+
+impl MenuItem {
+    /// The message the entry stands for.
+    pub fn msg(self) -> Msg {
+        match self {
+            MenuItem::OpenSession => Msg::ReplayTrace,
+            MenuItem::SaveSession => Msg::SaveTrace,
+            MenuItem::ExportImage => Msg::ExportPng(screenshot::default_path()),
+            MenuItem::ExportScript => Msg::ExportScript,
+            MenuItem::ImportScript => Msg::ImportScript,
+            MenuItem::BackUp => Msg::ExportBackup(backup::default_path()),
+            MenuItem::Quit => Msg::CloseRequested(window::Id::MAIN),
+            MenuItem::Undo => Msg::Undo,
+            MenuItem::Redo => Msg::Redo,
+            MenuItem::ResetCube => Msg::ResetCube,
+            MenuItem::Palette => Msg::TogglePalette,
+            MenuItem::Panels(_) => Msg::TogglePanels,
+            MenuItem::Grid(on) => Msg::ToggleGrid(!on),
+            MenuItem::Axes(on) => Msg::ToggleAxes(!on),
+            MenuItem::Heatmap(on) => Msg::ToggleHeat(!on),
+            MenuItem::Letters(on) => Msg::ToggleLetters(!on),
+            MenuItem::Qr(_) => Msg::ToggleQr,
+            MenuItem::Profiler(_) => Msg::ToggleProfiler,
+            MenuItem::ResetCameras => Msg::ResetCameras,
+            MenuItem::Diagnostics => Msg::ToggleDebugInfo,
+            MenuItem::About => Msg::ToggleAbout,
+        }
+    }
+}
+
+impl fmt::Display for MenuItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (label, on) = match *self {
+            MenuItem::OpenSession => (t!("menu-open-session"), None),
+            MenuItem::SaveSession => (t!("menu-save-session"), None),
+            MenuItem::ExportImage => (t!("menu-export-image"), None),
+            MenuItem::ExportScript => (t!("menu-export-script"), None),
+            MenuItem::ImportScript => (t!("menu-import-script"), None),
+            MenuItem::BackUp => (t!("menu-back-up"), None),
+            MenuItem::Quit => (t!("menu-quit"), None),
+            MenuItem::Undo => (t!("menu-undo"), None),
+            MenuItem::Redo => (t!("menu-redo"), None),
+            MenuItem::ResetCube => (t!("menu-reset-cube"), None),
+            MenuItem::Palette => (t!("menu-palette"), None),
+            MenuItem::Panels(on) => (t!("menu-panels"), Some(on)),
+            MenuItem::Grid(on) => (t!("grid"), Some(on)),
+            MenuItem::Axes(on) => (t!("axes"), Some(on)),
+            MenuItem::Heatmap(on) => (t!("heatmap"), Some(on)),
+            MenuItem::Letters(on) => (t!("letters"), Some(on)),
+            MenuItem::Qr(on) => (t!("menu-qr"), Some(on)),
+            MenuItem::Profiler(on) => (t!("menu-profiler"), Some(on)),
+            MenuItem::ResetCameras => (t!("menu-reset-cameras"), None),
+            MenuItem::Diagnostics => (t!("menu-diagnostics"), None),
+            MenuItem::About => (t!("menu-about"), None),
+        };
+        // Toggles line up with a check mark when on, a blank when off.
+        match on {
+            Some(true) => write!(f, "✓ {label}"),
+            Some(false) => write!(f, "    {label}"),
+            None => f.write_str(&label),
+        }
+    }
+}
+
+/// One menu: its title, opening the entries as a drop-down.
+fn menu(title: String, items: Vec<MenuItem>) -> Element<'static, Msg> {
+    pick_list(items, None::<MenuItem>, MenuItem::msg)
+        .placeholder(title)
+        .handle(Handle::None)
+        .padding([4, 10])
+        .into()
+}
+
+/// The menu bar; `panels` and `qr` say whether the panels and the QR code
+/// are shown, for their check marks.
+pub fn build_menu_bar(render: &RenderSettings, panels: bool, qr: bool) -> Element<'static, Msg> {
+    row![
+        menu(t!("menu-file"), vec![
+            MenuItem::OpenSession,
+            MenuItem::SaveSession,
+            MenuItem::ExportImage,
+            MenuItem::ExportScript,
+            MenuItem::ImportScript,
+            MenuItem::BackUp,
+            MenuItem::Quit,
+        ]),
+        menu(t!("menu-edit"), vec![MenuItem::Undo, MenuItem::Redo, MenuItem::ResetCube, MenuItem::Palette]),
+        menu(t!("menu-view"), vec![
+            MenuItem::Panels(panels),
+            MenuItem::Grid(render.show_grid),
+            MenuItem::Axes(render.show_axes),
+            MenuItem::Heatmap(render.show_heat),
+            MenuItem::Letters(render.show_letters),
+            MenuItem::Qr(qr),
+            MenuItem::Profiler(render.show_profiler),
+            MenuItem::ResetCameras,
+        ]),
+        menu(t!("menu-help"), vec![MenuItem::Diagnostics, MenuItem::About]),
+    ]
+        .spacing(2)
+        .width(Length::Fill)
+        .into()
+}
+
+/// Help → Diagnostics: each field with its value, and a button copying
+/// the debug bundle.
+pub fn build_debug_panel(fields: Vec<(Field, String)>) -> Element<'static, Msg> {
+    let mut col = column![text(t!("debug-title")).size(18)].spacing(4);
+    for (field, value) in fields {
+        col = col.push(row![
+            text(field.to_string()).width(Length::Fixed(DEBUG_LABEL_W)),
+            text(value).size(14).width(Length::Fill),
+        ]);
+    }
+    col = col.push(
+        row![
+            labeled(button(text(t!("debug-copy"))).on_press(Msg::CopyDebugBundle), t!("debug-copy-desc")),
+            Space::with_width(Length::Fill),
+            button(text(t!("about-close"))).on_press(Msg::ToggleDebugInfo),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center),
+    );
+    container(col).padding(10).width(Length::Fixed(DEBUG_W)).style(theme::Container::Box).into()
+}
+
+/// Width of the diagnostics box, and of its labels.
+const DEBUG_W: f32 = 620.0;
+const DEBUG_LABEL_W: f32 = 150.0;
+
+/// Help → About: the app's name, version, and license.
+pub fn build_about_panel() -> Element<'static, Msg> {
+    container(
+        column![
+            text(t!("about-title", version = env!("CARGO_PKG_VERSION"))).size(18),
+            text(t!("about-body")),
+            text(t!("about-license")).size(14),
+            row![Space::with_width(Length::Fill), button(text(t!("about-close"))).on_press(Msg::ToggleAbout)]
+                .align_items(Alignment::Center),
+        ]
+            .spacing(6),
+    )
+        .padding(10)
+        .width(Length::Fixed(420.0))
+        .style(theme::Container::Box)
+        .into()
+}
@@ -1,9 +1,22 @@
 // src/ui/mod.rs
 
-//! UI module: panels, moves, and bottom control strip.
+//! UI module: panels, moves, bottom control strip, and editor highlighting.
 
 pub mod moves;
 pub mod panels;
+pub mod labels;
+pub mod highlight;
+pub mod menu;
 pub(crate) mod bottom;
 
-pub use panels::{build_algorithm_panel, build_angle_block, build_presets_row, build_seed_panel};
\ No newline at end of file
+pub use panels::{
+    build_alg_order, build_alg_watch_row, build_algorithm_panel, build_angle_block, build_custom_scramble_panel, build_face_analysis, build_trainer_panel, build_timer_row, build_ab_panel, build_usage_panel, build_pauses_panel, build_daily_panel, build_profile_row, build_leaderboard_panel, build_obs_panel, build_gamepad_panel, build_controllers_panel, build_video_sync_panel, build_annotate_panel, build_lighting_block, build_mode_row,
+    build_presets_row, build_motion_row, build_recovery_banner, build_palette, build_reset_row, build_checkpoints_row, build_history_panel, build_script_panel, build_breakdown_panel, build_trace_panel, build_console_panel, build_seed_panel, build_sound_panel,
+    build_metronome_panel, build_solution_panel, build_watch_row, build_watch_strip, build_subgroup_panel,
+    build_random_walk_panel,
+    build_notation_panel,
+    build_memo_panel,
+    build_diagnostics_panel,
+    build_compare_panel, build_tracking_panel,
+};
+pub use menu::{build_about_panel, build_debug_panel, build_menu_bar};
\ No newline at end of file
@@ -7,13 +7,17 @@ use iced::{
     widget::{button, row, column, text, scrollable},
 };
 use crate::app::Msg;
-
-/// Create a compact button for a single move token (e.g., `"R'"`).
-fn btn(tok: &'static str) -> iced::widget::Button<'static, Msg> {
-    button(text(tok).size(14))      // smaller label
-        .padding([4, 8])            // tighter padding
+use crate::t;
+use super::labels::{labeled, move_label};
+
+/// Create a compact button for a single move token (e.g., `"R'"`),
+/// labeled with the move's long description.
+fn btn(tok: &'static str) -> Element<'static, Msg> {
+    let b = button(text(tok).size(14))  // smaller label
+        .padding([4, 8])                // tighter padding
         .on_press(Msg::Move(tok.to_string()))
-        .width(Length::Shrink)
+        .width(Length::Shrink);
+    labeled(b, move_label(tok))
 }
 
 /// Two compact horizontal scrollers so they don’t explode at small widths.
@@ -38,7 +42,7 @@ pub fn build_moves_scroller() -> Element<'static, Msg> {
         .direction(scrollable::Direction::Horizontal(props))
         .height(Length::Shrink);
 
-    column![text("Moves").size(16), sc1, sc2]
+    column![text(t!("moves")).size(16), sc1, sc2]
         .spacing(6)
         .into()
 }
\ No newline at end of file
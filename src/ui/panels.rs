@@ -2,71 +2,1763 @@
 
 //! UI: parameter panels (angles, presets, seed & algorithm).
 
+use std::time::Duration;
+
 use iced::{
-    Alignment, Element, Length,
-    widget::{row, column, text, text_input, button, checkbox, slider},
+    Alignment, Color, Element, Length,
+    widget::{row, column, text, text_input, text_editor, button, checkbox, slider, pick_list, Canvas, Space},
 };
-use crate::app::Msg;
-
-/// Builds a labeled trio of angle sliders (Rz, Rx, Ry) with live values.
-pub fn build_angle_block<
-    Fz: Fn(f32) -> Msg + 'static + Copy,
-    Fx: Fn(f32) -> Msg + 'static + Copy,
-    Fy: Fn(f32) -> Msg + 'static + Copy,
->(
+use crate::t;
+use super::labels::{face_name, labeled};
+use super::highlight::{self, AlgHighlighter};
+use crate::app::{Msg, NamedCheckpoint, ViewSide, Axis, ViewUI, angle_msg};
+use crate::render::{Backend, CameraModel, CameraPreset, CubeGrid, GraphNode, HistoryGraph, Lighting, MoveHeatmap, RenderMode, RenderSettings, ViewParams};
+use crate::logic::alg::count_tokens;
+use crate::logic::library::{AlgSet, NamedAlg};
+use crate::logic::scramble::parse_seed;
+use crate::logic::describe::color_name;
+use crate::logic::first_face::{FaceReport, MAX_DEPTH};
+use crate::logic::subgroup::SubgroupReport;
+use crate::logic::diagnose::{Diagnosis, SlotState};
+use crate::logic::diff::slot_names;
+use crate::app::compare::{DiffPair, DiffReport};
+use crate::app::history_tree::HistoryTree;
+use crate::app::script::{self, ScriptReplay, VideoSync};
+use crate::app::trace::{self, TraceReplay};
+use crate::app::{anki, backup, blueprint, palette, report, solve_sheet};
+use crate::app::annotate::{self, Annotations};
+use crate::app::motion::{FpsCap, MotionSettings};
+use crate::render::annotate::{Ink, Tool};
+use crate::logic::memo::{LetterScheme, Memo};
+use crate::cube::{Alg, Col, CornerPerm, Cube, FaceId};
+use crate::cube::perm::CORNER_NAMES;
+use crate::logic::tracking::{Piece, Stop};
+use crate::logic::regrip::{regrip, MoveCosts};
+use crate::logic::phases::{Breakdown, PAUSE};
+use crate::logic::pauses::{by_case, Pause, Threshold};
+use crate::logic::verify::Mismatch;
+use crate::app::trainer::NeutralityStats;
+use crate::app::usage::MoveUsage;
+use crate::app::ab_test::{self, AbTest, Side, Trials};
+use crate::app::solve_timer::SolveTimer;
+use crate::app::inspection::{InspectionMode, InspectionRules, InspectionSecs};
+use crate::hotkey::{self, HotkeySettings, Key as HotKey};
+use crate::app::daily::{DailyLog, Day};
+use crate::app::profiles::Profile;
+use crate::leaderboard::{Board, LeaderboardSettings};
+use crate::obs::{ObsAction, ObsSettings};
+use crate::app::classroom::Interval;
+use crate::app::watch::{Pace, Watch};
+use crate::app::forum::PostFormat;
+use crate::app::support::fmt_secs;
+use crate::storage::read_only;
+use crate::audio::{ClickSound, Cue, SoundSettings};
+use crate::controllers::{self, Bindings};
+use crate::gamepad::{self, Button as PadButton, GamepadSettings};
+use crate::app::metronome::{Metronome, BPM_RANGE};
+use crate::app::random_walk::{RandomWalk, RATE_RANGE};
+use crate::app::notation::{keys_for, NotationDrill, NotationStats, Quiz, KEYMAP, LIMIT_RANGE};
+use crate::logic::solver::STATE_COUNT;
+
+/// First-face report lines list at most this many example solutions.
+const SHOWN_SOLUTIONS: usize = 3;
+
+/// Text color for input that won't parse.
+const INVALID_COLOR: Color = Color { r: 0.9, g: 0.15, b: 0.15, a: 1.0 };
+
+/// The tracking trail shows only this many most recent stops.
+const SHOWN_TRAIL: usize = 16;
+
+/// Format an angle for its text box: up to 3 decimals, trailing zeros trimmed.
+fn fmt_angle(v: f32) -> String {
+    let s = format!("{v:.3}");
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// A button that writes a file, with its tooltip; left out of a read-only
+/// (demo) run, which saves nothing.
+fn export_button(label: String, msg: Msg, desc: String) -> Option<Element<'static, Msg>> {
+    (!read_only()).then(|| labeled(button(text(label)).on_press(msg), desc))
+}
+
+/// Builds a labeled trio of angle rows (Rz, Rx, Ry): slider, exact-value
+/// text box (commit with Enter), and ±1°/±15° nudge buttons.
+///
+/// `edit` is the draft currently being typed, shown instead of the live value
+/// for the box it belongs to.
+pub fn build_angle_block(
     title: &str,
-    rz: f32, rx: f32, ry: f32,
-    on_rz: Fz, on_rx: Fx, on_ry: Fy,
+    side: ViewSide,
+    view: ViewUI,
+    edit: Option<&(ViewSide, Axis, String)>,
 ) -> Element<'static, Msg> {
+    let angle_row = |label: &'static str, axis: Axis| {
+        let v = view.get(axis);
+        let shown = match edit {
+            Some((s, a, draft)) if *s == side && *a == axis => draft.clone(),
+            _ => fmt_angle(v),
+        };
+        let nudge = |d: f32| {
+            let b = button(text(format!("{d:+}")).size(12))
+                .padding([2, 6])
+                .on_press(Msg::AngleNudge(side, axis, d));
+            labeled(b, t!("nudge-desc", axis = label, deg = format!("{d:+}")))
+        };
+        row![
+            text(label),
+            slider(0.0..=360.0, v, move |x| angle_msg(side, axis, x)).step(1.0),
+            labeled(
+                text_input(&t!("angle-placeholder"), &shown)
+                    .on_input(move |s| Msg::AngleTextChanged(side, axis, s))
+                    .on_submit(Msg::AngleTextSubmit)
+                    .width(Length::Fixed(64.0)),
+                t!("angle-input-desc", axis = label),
+            ),
+            nudge(-15.0), nudge(-1.0), nudge(1.0), nudge(15.0),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center)
+    };
+
     column![
         text(title),
-        row![ text("Rz"), slider(0.0..=360.0, rz, on_rz).step(1.0), text(format!("{:.0}°", rz)) ].spacing(8),
-        row![ text("Rx"), slider(0.0..=360.0, rx, on_rx).step(1.0), text(format!("{:.0}°", rx)) ].spacing(8),
-        row![ text("Ry"), slider(0.0..=360.0, ry, on_ry).step(1.0), text(format!("{:.0}°", ry)) ].spacing(8),
+        angle_row("Rz", Axis::Rz),
+        angle_row("Rx", Axis::Rx),
+        angle_row("Ry", Axis::Ry),
+        build_named_presets(side),
+        build_look_at(side),
     ]
         .spacing(6)
         .into()
 }
 
-/// Preset camera buttons + Snap-90° toggle row.
-pub fn build_presets_row(snap90: bool) -> Element<'static, Msg> {
+/// Light direction (azimuth/elevation) and shading intensity sliders.
+pub fn build_lighting_block(light: Lighting) -> Element<'static, Msg> {
+    row![
+        text(t!("light")),
+        text(t!("light-az")),
+        slider(0.0..=360.0, light.azimuth.degrees(), Msg::LightAzimuthChanged).step(1.0),
+        text(format!("{:.0}°", light.azimuth.degrees())),
+        text(t!("light-el")),
+        slider(-90.0..=90.0, light.elevation.degrees(), Msg::LightElevationChanged).step(1.0),
+        text(format!("{:.0}°", light.elevation.degrees())),
+        text(t!("light-shade")),
+        slider(0.0..=1.0, light.intensity, Msg::LightIntensityChanged).step(0.05),
+        text(format!("{:.0}%", light.intensity * 100.0)),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Per-view render mode pickers (filled vs. wireframe) and the camera model.
+pub fn build_mode_row(left: RenderMode, right: RenderMode, camera: CameraModel) -> Element<'static, Msg> {
+    row![
+        text(t!("left-render")),
+        pick_list(&RenderMode::ALL[..], Some(left), Msg::LeftModeChanged),
+        text(t!("right-render")),
+        pick_list(&RenderMode::ALL[..], Some(right), Msg::RightModeChanged),
+        text(t!("camera-model")),
+        labeled(pick_list(&CameraModel::ALL[..], Some(camera), Msg::CameraModelChanged), t!("camera-model-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// One button per named camera pose, applied to `side`.
+pub fn build_named_presets(side: ViewSide) -> Element<'static, Msg> {
+    CameraPreset::ALL
+        .into_iter()
+        .fold(row![], |r, p| {
+            let b = button(text(p.label()).size(12))
+                .padding([2, 6])
+                .on_press(Msg::NamedPreset(side, p));
+            r.push(labeled(b, t!("named-preset-desc", preset = p.label())))
+        })
+        .spacing(6)
+        .into()
+}
+
+/// One button per face, turning `side` to look squarely at it.
+pub fn build_look_at(side: ViewSide) -> Element<'static, Msg> {
+    FaceId::ALL
+        .into_iter()
+        .fold(row![text(t!("look-at")).size(12)], |r, face| {
+            let b = button(text(format!("{face:?}")).size(12))
+                .padding([2, 6])
+                .on_press(Msg::LookAtFace(side, face));
+            r.push(labeled(b, t!("look-at-desc", face = face_name(face))))
+        })
+        .spacing(6)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Preset camera buttons + Snap-90° and mirrored-layout toggles; the
+/// presets are labeled as mirrored when the layout is.
+pub fn build_presets_row(snap90: bool, mirrored: bool) -> Element<'static, Msg> {
+    let (left, right) = if mirrored {
+        ((t!("preset-left-mirrored"), t!("preset-left-mirrored-desc")), (t!("preset-right-mirrored"), t!("preset-right-mirrored-desc")))
+    } else {
+        ((t!("preset-left"), t!("preset-left-desc")), (t!("preset-right"), t!("preset-right-desc")))
+    };
     row![
-        button("Preset Left U/F/R").on_press(Msg::PresetLeft),
-        button("Preset Right D/L/B").on_press(Msg::PresetRight),
-        button("Reset Cameras").on_press(Msg::ResetCameras),
-        checkbox("Snap 90°", snap90).on_toggle(Msg::ToggleSnap90),
+        labeled(button(text(left.0)).on_press(Msg::PresetLeft), left.1),
+        labeled(button(text(right.0)).on_press(Msg::PresetRight), right.1),
+        labeled(button(text(t!("reset-cameras"))).on_press(Msg::ResetCameras), t!("reset-cameras-desc")),
+        checkbox(t!("snap-90"), snap90).on_toggle(Msg::ToggleSnap90),
+        labeled(checkbox(t!("mirrored-layout"), mirrored).on_toggle(Msg::ToggleMirrored), t!("mirrored-layout-desc")),
     ]
+        .push_maybe(export_button(
+            t!("blueprint-export"),
+            Msg::ExportBlueprint(blueprint::default_path()),
+            t!("blueprint-export-desc"),
+        ))
         .spacing(12)
         .align_items(Alignment::Center)
         .into()
 }
 
-/// Seed input + Apply/Scramble/Reset buttons (same actions used elsewhere).
-pub fn build_seed_panel(seed: &str) -> Element<'static, Msg> {
+/// Seed input (Enter applies) + Apply/Scramble/Clear buttons; Apply waits
+/// for a whole number.
+/// Choices for the minimum-depth scramble filter (0 = off, 11 = God's number).
+pub const MIN_DEPTHS: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+pub fn build_seed_panel(seed: &str, min_depth: u8) -> Element<'static, Msg> {
+    let valid = parse_seed(seed).is_some();
+    let mut input = text_input(&t!("seed-placeholder"), seed)
+        .on_input(Msg::SeedChanged)
+        .width(Length::Fixed(220.0));
+    let mut apply = button(text(t!("apply")));
+    if valid {
+        input = input.on_submit(Msg::ApplySeed);
+        apply = apply.on_press(Msg::ApplySeed);
+    }
+    let check: Element<'static, Msg> = if valid || seed.trim().is_empty() {
+        Space::with_width(0).into()
+    } else {
+        text(t!("seed-invalid")).size(14).style(INVALID_COLOR).into()
+    };
     row![
-        text("Seed"),
-        text_input("Enter seed (e.g., 12345)", seed)
-            .on_input(Msg::SeedChanged)
-            .width(Length::Fixed(220.0)),
-        button("Apply").on_press(Msg::ApplySeed),
-        button("Scramble").on_press(Msg::Scramble),
-        button("Reset").on_press(Msg::Reset),
+        text(t!("seed")),
+        input,
+        check,
+        labeled(apply, t!("apply-seed-desc")),
+        labeled(button(text(t!("scramble"))).on_press(Msg::Scramble), t!("scramble-desc")),
+        labeled(button(text(t!("clear"))).on_press(Msg::ClearSeed), t!("seed-clear-desc")),
+        labeled(
+            row![
+                text(t!("min-depth")),
+                pick_list(&MIN_DEPTHS[..], Some(min_depth), Msg::MinDepthChanged),
+            ]
+                .spacing(6)
+                .align_items(Alignment::Center),
+            t!("min-depth-desc"),
+        ),
     ]
         .spacing(8)
         .align_items(Alignment::Center)
         .into()
 }
 
-/// Text field for an algorithm string (e.g., `R U R' U'`).
-/// Currently wires to `Msg::SeedChanged` as a placeholder emitter.
-pub fn build_algorithm_panel(alg: &str) -> Element<'static, Msg> {
-    // If/when you add a dedicated AlgChanged, swap the .on_input handler.
+/// Reset-cube button and the ways to share the cube, plus Confirm/Cancel
+/// while a reset awaits confirmation and Restore when the trash slot holds
+/// a discarded state.
+pub fn build_reset_row(confirming: bool, can_restore: bool, post_format: PostFormat) -> Element<'static, Msg> {
+    let mut r = row![
+        labeled(button(text(t!("reset-cube"))).on_press(Msg::ResetCube), t!("reset-cube-desc")),
+        labeled(button(text(t!("announce"))).on_press(Msg::AnnounceState), t!("announce-desc")),
+        labeled(button(text(t!("share-link"))).on_press(Msg::CopyShareLink), t!("share-link-desc")),
+        labeled(button(text(t!("qr-code"))).on_press(Msg::ToggleQr), t!("qr-code-desc")),
+        labeled(button(text(t!("alg-cubing"))).on_press(Msg::OpenAlgCubing), t!("alg-cubing-desc")),
+        labeled(button(text(t!("post-copy"))).on_press(Msg::CopyForumPost), t!("post-copy-desc")),
+        labeled(pick_list(&PostFormat::ALL[..], Some(post_format), Msg::PostFormatChanged), t!("post-format-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if confirming {
+        r = r
+            .push(button(text(t!("confirm-reset"))).on_press(Msg::ConfirmReset))
+            .push(button(text(t!("cancel"))).on_press(Msg::CancelReset));
+    }
+    if can_restore {
+        r = r.push(button(text(t!("restore"))).on_press(Msg::RestoreTrash));
+    }
+    r.into()
+}
+
+/// Name box and "Checkpoint" button, then one jump button (and a remove
+/// button) per checkpoint, oldest first.
+pub fn build_checkpoints_row(checkpoints: &[NamedCheckpoint], name: &str) -> Element<'static, Msg> {
+    let mut r = row![
+        text(t!("checkpoints")),
+        text_input(&t!("checkpoint-placeholder"), name)
+            .on_input(Msg::CheckpointNameChanged)
+            .on_submit(Msg::AddCheckpoint)
+            .width(Length::Fixed(160.0)),
+        labeled(button(text(t!("checkpoint-add"))).on_press(Msg::AddCheckpoint), t!("checkpoint-add-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    for (i, cp) in checkpoints.iter().enumerate() {
+        let moves = cp.state.history.join(" ");
+        r = r.push(labeled(
+            button(text(cp.name.clone())).on_press(Msg::JumpToCheckpoint(i)),
+            t!("checkpoint-jump-desc", n = cp.state.history.len(), moves = moves),
+        ));
+        r = r.push(button(text("×").size(12)).padding([2, 6]).on_press(Msg::RemoveCheckpoint(i)));
+    }
+    r.into()
+}
+
+/// Undo/redo and the graph of every line of moves explored, clickable to
+/// jump to any state in it.
+pub fn build_history_panel(tree: &HistoryTree) -> Element<'static, Msg> {
+    let mut undo = button(text(t!("history-undo")));
+    if tree.can_undo() {
+        undo = undo.on_press(Msg::Undo);
+    }
+    let mut redo = button(text(t!("history-redo")));
+    if tree.can_redo() {
+        redo = redo.on_press(Msg::Redo);
+    }
+    let header = row![
+        text(t!("history")),
+        labeled(undo, t!("history-undo-desc")),
+        labeled(redo, t!("history-redo-desc")),
+        text(t!("history-summary", states = tree.nodes().len(), branches = tree.tips().len())).size(14),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if tree.nodes().len() < 2 {
+        return header.into();
+    }
+
+    let mut on_path = vec![false; tree.nodes().len()];
+    let mut n = Some(tree.current());
+    while let Some(i) = n {
+        on_path[i] = true;
+        n = tree.nodes()[i].parent;
+    }
+    let nodes = tree
+        .layout()
+        .into_iter()
+        .zip(tree.nodes())
+        .enumerate()
+        .map(|(i, ((depth, lane), node))| GraphNode { parent: node.parent, depth, lane, on_path: on_path[i] })
+        .collect();
+    let graph = HistoryGraph { nodes, current: tree.current() };
+    let height = graph.height();
+    let canvas: Element<usize> = Canvas::new(graph).width(Length::Fill).height(Length::Fixed(height)).into();
+    column![header, labeled(canvas.map(Msg::JumpToHistory), t!("history-graph-desc"))].spacing(4).into()
+}
+
+/// Comment on the current move, and export/replay of annotated solve
+/// scripts; while replaying, its progress and the latest comment.
+pub fn build_script_panel(comment: &str, path: &str, replay: Option<&ScriptReplay>) -> Element<'static, Msg> {
+    let default = script::default_path().display().to_string();
+    let controls = row![
+        text(t!("script")),
+        labeled(
+            text_input(&t!("script-comment-placeholder"), comment)
+                .on_input(Msg::MoveCommentChanged)
+                .width(Length::Fill),
+            t!("script-comment-desc"),
+        ),
+        text_input(&t!("script-path-placeholder", path = default), path)
+            .on_input(Msg::ScriptPathChanged)
+            .width(Length::Fixed(220.0)),
+    ]
+        .push_maybe(export_button(t!("script-export"), Msg::ExportScript, t!("script-export-desc")))
+        .push(labeled(button(text(t!("script-import"))).on_press(Msg::ImportScript), t!("script-import-desc")))
+        .push(labeled(button(text(t!("video-sync"))).on_press(Msg::SyncScriptToVideo), t!("video-sync-desc")))
+        .push(labeled(button(text(t!("breakdown"))).on_press(Msg::BreakDownSolve), t!("breakdown-desc")))
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let Some(replay) = replay else { return controls.into() };
+    let mut progress = row![
+        text(t!("script-progress", n = replay.next, total = replay.script.steps.len())),
+        button(text(t!("script-stop"))).on_press(Msg::StopScriptReplay),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if let Some(note) = &replay.note {
+        progress = progress.push(text(format!("“{note}”")).size(18));
+    }
+    column![controls, progress].spacing(4).into()
+}
+
+/// Breakdown table column widths: the step name, then the figures.
+const STEP_W: f32 = 110.0;
+const FIGURE_W: f32 = 90.0;
+
+/// The solve's steps, one line each with moves, time, recognition and
+/// turns per second, then the pauses, and a Close button.
+pub fn build_breakdown_panel(b: &Breakdown) -> Element<'static, Msg> {
+    let cell = |s: String, w: f32| text(s).width(Length::Fixed(w));
+    let secs = |d: Duration| fmt_secs(d.as_millis() as u64);
+    let head = [t!("breakdown-moves"), t!("breakdown-time"), t!("breakdown-recognition"), t!("breakdown-tps"), t!("breakdown-exec-tps")];
+    let mut table = column![head.into_iter().fold(row![cell(t!("breakdown-step"), STEP_W)], |r, h| r.push(cell(h, FIGURE_W)))]
+        .spacing(2);
+    for s in &b.splits {
+        let name = match (s.done, s.moves) {
+            (false, _) => t!("breakdown-unfinished", step = s.phase.to_string()),
+            (true, 0) => t!("breakdown-skip", step = s.phase.to_string()),
+            (true, _) => s.phase.to_string(),
+        };
+        table = table.push(row![
+            cell(name, STEP_W),
+            cell(s.moves.to_string(), FIGURE_W),
+            cell(secs(s.time), FIGURE_W),
+            cell(secs(s.recognition), FIGURE_W),
+            cell(format!("{:.2}", s.tps()), FIGURE_W),
+            cell(format!("{:.2}", s.execution_tps()), FIGURE_W),
+        ]);
+    }
+    let longest = b.pauses.iter().max_by_key(|&&(_, d)| d);
+    let pauses = match longest {
+        None => t!("breakdown-no-pauses", min = secs(PAUSE)),
+        Some(&(at, longest)) => t!("breakdown-pauses",
+            n = b.pauses.len(),
+            min = secs(PAUSE),
+            total = secs(b.paused()),
+            longest = secs(longest),
+            at = at),
+    };
+    column![
+        row![
+            text(t!("breakdown-total", moves = b.moves, time = secs(b.total), tps = format!("{:.2}", b.tps()))),
+            button(text(t!("breakdown-close"))).on_press(Msg::CloseBreakdown),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center),
+        table,
+        text(pauses),
+    ]
+        .spacing(4)
+        .into()
+}
+
+/// Video sync: where the solve starts on the video, the video time shown
+/// (typed, or scrubbed with the slider), and a Close button.
+pub fn build_video_sync_panel(sync: &VideoSync) -> Element<'static, Msg> {
+    let end = sync.end().as_secs_f32().max(0.01);
     row![
-        text_input("Algorithm (e.g., R U R' U')", alg)
-            .on_input(Msg::SeedChanged) // placeholder to keep compiling
+        text(t!("video-sync-offset")),
+        labeled(
+            text_input("0:00.00", &sync.offset_input)
+                .on_input(Msg::VideoOffsetChanged)
+                .width(Length::Fixed(80.0)),
+            t!("video-sync-offset-desc"),
+        ),
+        text(t!("video-sync-time")),
+        labeled(
+            text_input("0:00.00", &sync.time_input)
+                .on_input(Msg::VideoTimeChanged)
+                .on_submit(Msg::ApplyVideoTime)
+                .width(Length::Fixed(80.0)),
+            t!("video-sync-time-desc"),
+        ),
+        slider(0.0..=end, sync.position.as_secs_f32().min(end), Msg::VideoScrubbed)
+            .step(0.01)
             .width(Length::Fill),
+        text(t!("script-progress", n = sync.steps_at(sync.position), total = sync.script.steps.len())),
+        button(text(t!("video-sync-close"))).on_press(Msg::CloseVideoSync),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Session trace controls: how much has been recorded, save it to a file,
+/// or replay a saved one (with its progress and a Stop button while it
+/// runs).
+pub fn build_trace_panel(path: &str, recorded: usize, replay: Option<&TraceReplay>) -> Element<'static, Msg> {
+    let default = trace::default_path().display().to_string();
+    let mut controls = row![
+        text(t!("trace")),
+        text(t!("trace-recorded", n = recorded)),
+        text_input(&t!("trace-path-placeholder", path = default), path)
+            .on_input(Msg::TracePathChanged)
+            .width(Length::Fixed(220.0)),
+    ]
+        .push_maybe(export_button(t!("trace-save"), Msg::SaveTrace, t!("trace-save-desc")))
+        .push(labeled(button(text(t!("trace-replay"))).on_press(Msg::ReplayTrace), t!("trace-replay-desc")))
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if let Some(replay) = replay {
+        controls = controls
+            .push(text(t!("trace-progress", n = replay.next, total = replay.actions.len())))
+            .push(button(text(t!("script-stop"))).on_press(Msg::StopTraceReplay));
+    }
+    controls.into()
+}
+
+/// Lines of console output shown; older ones scroll away.
+const CONSOLE_LINES: usize = 12;
+
+/// Script console: an editor for a console script, Run (also Ctrl+Enter)
+/// and Clear, and the latest output.
+pub fn build_console_panel<'a>(script: &'a text_editor::Content, output: &[String], running: bool) -> Element<'a, Msg> {
+    let run = button(text(t!("console-run")))
+        .on_press_maybe((!running && !script.text().trim().is_empty()).then_some(Msg::RunConsole));
+    let header = row![
+        text(t!("console")),
+        labeled(run, t!("console-run-desc")),
+        labeled(button(text(t!("clear"))).on_press(Msg::ClearConsole), t!("console-clear-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let editor = labeled(
+        text_editor(script).on_action(Msg::ConsoleEdited).height(Length::Fixed(120.0)),
+        t!("console-editor-desc"),
+    );
+    let shown = &output[output.len().saturating_sub(CONSOLE_LINES)..];
+    let lines = shown.iter().fold(column![].spacing(2), |col, line| col.push(text(line.clone()).size(14)));
+    column![header, editor, lines].spacing(4).into()
+}
+
+/// Command palette: the command line, with the usages of the commands
+/// matching what has been typed.
+pub fn build_palette(input: &str) -> Element<'static, Msg> {
+    let field = text_input(&t!("palette-placeholder"), input)
+        .id(palette::input_id())
+        .on_input(Msg::PaletteInputChanged)
+        .on_submit(Msg::RunPaletteCommand)
+        .width(Length::Fixed(420.0));
+    let hints = palette::matching(input).join(" · ");
+    column![field, text(hints).size(14)].spacing(2).into()
+}
+
+/// Banner offering to restore a session that ended unexpectedly.
+pub fn build_recovery_banner() -> Element<'static, Msg> {
+    row![
+        text(t!("restore-session-prompt")),
+        button(text(t!("restore"))).on_press(Msg::RestoreSession),
+        button(text(t!("discard"))).on_press(Msg::DiscardRecovery),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Multi-line editor for an algorithm (e.g., `R U R' U'`, with `//`
+/// comments), highlighted as typed, with its live move count and "Order"
+/// button, followed by the optimal depth of the last generated scramble
+/// when known. Library algorithms matching the name being typed are
+/// offered below it.
+pub fn build_algorithm_panel<'a>(
+    alg: &'a text_editor::Content,
+    completions: &[&'static NamedAlg],
+    depth: Option<u8>,
+    anki_set: AlgSet,
+) -> Element<'a, Msg> {
+    let editor = text_editor(alg)
+        .on_action(Msg::AlgEdited)
+        .height(Length::Shrink)
+        .highlight::<AlgHighlighter>((), highlight::format);
+    let (moves, invalid) = count_tokens(&alg.text());
+    let count = if invalid > 0 {
+        text(t!("alg-invalid-count", n = moves, bad = invalid)).style(INVALID_COLOR)
+    } else {
+        text(t!("alg-move-count", n = moves))
+    };
+    let mut apply = button(text(t!("apply")));
+    if moves > 0 && invalid == 0 {
+        apply = apply.on_press(Msg::ApplyAlg);
+    }
+    let mut r = row![
+        labeled(editor, t!("alg-editor-desc")),
+        count.size(14),
+        labeled(apply, t!("alg-apply-desc")),
+        labeled(button(text(t!("clear"))).on_press(Msg::ClearAlg), t!("alg-clear-desc")),
+        labeled(button(text(t!("alg-order-button"))).on_press(Msg::ShowAlgOrder), t!("alg-order-desc")),
+        labeled(pick_list(&AlgSet::ALL[..], Some(anki_set), Msg::AnkiSetChanged), t!("anki-set-desc")),
+    ]
+        .push_maybe(export_button(t!("anki-export"), Msg::ExportAnki(anki::default_path()), t!("anki-export-desc")))
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if let Some(n) = depth {
+        r = r.push(text(t!("scramble-depth", n = n)));
+    }
+    if completions.is_empty() {
+        return r.into();
+    }
+    let mut offers = row![text(t!("alg-complete")).size(14)].spacing(6).align_items(Alignment::Center);
+    for &named in completions {
+        offers = offers.push(labeled(
+            button(text(named.name).size(14)).padding([2, 8]).on_press(Msg::CompleteAlg(named)),
+            named.moves.to_string(),
+        ));
+    }
+    column![r, offers].spacing(4).into()
+}
+/// An algorithm file to watch, applied to the cube each time it's saved,
+/// and Watch (or Stop while watching).
+pub fn build_alg_watch_row(path: &str, watching: bool) -> Element<'static, Msg> {
+    let toggle: Element<'static, Msg> = if watching {
+        button(text(t!("alg-watch-stop"))).on_press(Msg::StopAlgWatch).into()
+    } else {
+        labeled(
+            button(text(t!("alg-watch"))).on_press_maybe((!path.trim().is_empty()).then_some(Msg::StartAlgWatch)),
+            t!("alg-watch-desc"),
+        )
+    };
+    row![
+        text(t!("alg-watch-file")),
+        text_input(&t!("alg-watch-placeholder"), path)
+            .on_input(Msg::AlgWatchPathChanged)
+            .on_submit(Msg::StartAlgWatch)
+            .width(Length::Fixed(260.0)),
+        toggle,
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Contents of the algorithm-order popup: the order, as the lcm of the
+/// corner cycle orders, and the cycles themselves.
+pub fn build_alg_order(alg: &Alg) -> Element<'static, Msg> {
+    let perm = CornerPerm::of_alg(alg);
+    let cycles = perm.cycles();
+    let shown = if alg.is_empty() { t!("alg-empty") } else { alg.to_string() };
+    let mut col = column![text(shown).size(18)].spacing(6);
+    if cycles.is_empty() {
+        return col.push(text(t!("alg-order-identity"))).into();
+    }
+    let parts: Vec<String> = cycles.iter().map(|c| c.order().to_string()).collect();
+    let list: Vec<String> = cycles.iter().map(|c| c.to_string()).collect();
+    col = col
+        .push(text(t!("alg-order", n = perm.order(), parts = parts.join(", "))))
+        .push(text(t!("alg-cycles", list = list.join(" "))));
+    if cycles.iter().any(|c| c.twist != 0) {
+        col = col.push(text(t!("alg-cycles-legend")));
+    }
+    col.into()
+}
+
+/// "Analyze first faces" button, followed by one line per color when a
+/// report for the current cube is available.
+pub fn build_face_analysis(reports: Option<&[FaceReport]>) -> Element<'static, Msg> {
+    let mut col = column![
+        labeled(button(text(t!("analyze-faces"))).on_press(Msg::AnalyzeFirstFaces), t!("analyze-faces-desc")),
+    ]
+        .spacing(4);
+    for r in reports.unwrap_or_default() {
+        let color = color_name(r.color);
+        let line = match r.depth {
+            Some(0) => t!("face-report-built", color = color),
+            Some(n) => {
+                let algs: Vec<String> = r.solutions.iter().take(SHOWN_SOLUTIONS).map(|a| a.to_string()).collect();
+                let more = if r.solutions.len() > SHOWN_SOLUTIONS { ", …" } else { "" };
+                t!("face-report", color = color, n = n, ways = r.solutions.len(), algs = format!("{}{more}", algs.join(", ")))
+            }
+            None => t!("face-report-deep", color = color, n = MAX_DEPTH),
+        };
+        col = col.push(text(line));
+    }
+    col.into()
+}
+
+/// "Explore subgroups" button, followed by the subgroups containing the
+/// current cube and its order when a report for it is available.
+pub fn build_subgroup_panel(report: Option<&SubgroupReport>) -> Element<'static, Msg> {
+    let mut col = column![
+        labeled(button(text(t!("explore-subgroups"))).on_press(Msg::ExploreSubgroups), t!("explore-subgroups-desc")),
+    ]
+        .spacing(4);
+    let Some(r) = report else { return col.into() };
+    col = col.push(text(if r.subgroups.is_empty() {
+        t!("subgroup-none")
+    } else {
+        let names: Vec<String> = r.subgroups.iter().map(|s| s.to_string()).collect();
+        t!("subgroup-list", list = names.join("; "))
+    }));
+    col = col.push(text(if r.reps_to_solved < r.order {
+        t!("element-order-rotated", order = r.order, reps = r.reps_to_solved)
+    } else {
+        t!("element-order", order = r.order)
+    }));
+    col.into()
+}
+
+/// BLD memo: reveal the corner memo, or time a memorization and check the
+/// memo typed back; then the letter scheme editor when open.
+pub fn build_memo_panel(
+    memo: Option<&Memo>,
+    drilling: bool,
+    input: &str,
+    best: Option<Duration>,
+    scheme: &LetterScheme,
+    editing: bool,
+) -> Element<'static, Msg> {
+    let mut r = row![text(t!("memo"))].spacing(8).align_items(Alignment::Center);
+    r = if drilling {
+        r.push(
+            text_input(&t!("memo-placeholder"), input)
+                .on_input(Msg::MemoInputChanged)
+                .on_submit(Msg::SubmitMemo)
+                .width(Length::Fixed(220.0)),
+        )
+            .push(button(text(t!("memo-check"))).on_press(Msg::SubmitMemo))
+            .push(button(text(t!("memo-cancel"))).on_press(Msg::CancelMemoDrill))
+    } else {
+        r.push(labeled(button(text(t!("memo-show"))).on_press(Msg::ShowMemo), t!("memo-show-desc")))
+            .push(labeled(button(text(t!("memo-drill"))).on_press(Msg::StartMemoDrill), t!("memo-drill-desc")))
+    };
+    if let Some(best) = best {
+        r = r.push(text(t!("memo-best", time = fmt_secs(best.as_millis() as u64))));
+    }
+    r = r.push(checkbox(t!("scheme-edit"), editing).on_toggle(Msg::ToggleSchemeEditor));
+    let mut col = column![r].spacing(4);
+    if let Some(memo) = memo {
+        col = col.push(text(if memo.letters.is_empty() {
+            t!("memo-none")
+        } else if memo.parity() {
+            t!("memo-parity", memo = memo.to_string(), n = memo.letters.len())
+        } else {
+            t!("memo-letters", memo = memo.to_string(), n = memo.letters.len())
+        }));
+    }
+    if editing {
+        col = col.push(build_scheme_editor(scheme));
+    }
+    col.into()
+}
+
+/// One 2×2 grid of letter boxes per face, each face as seen looking at it
+/// (U with B at the top, D with F at the top), plus a reset and a warning
+/// for letters used twice.
+fn build_scheme_editor(scheme: &LetterScheme) -> Element<'static, Msg> {
+    let mut faces = row![].spacing(16);
+    for f in FaceId::ALL {
+        // U's row 0 runs along F and D's along B, so flip them to read naturally.
+        let rows = if matches!(f, FaceId::U | FaceId::D) { [1, 0] } else { [0, 1] };
+        let letters = scheme.face(f);
+        let mut grid = column![text(format!("{f:?}"))].spacing(2).align_items(Alignment::Center);
+        for r in rows {
+            let cell = |c: usize| {
+                text_input("", &letters[r][c].to_string())
+                    .on_input(move |s| Msg::SchemeLetterChanged((f, r, c), s))
+                    .width(Length::Fixed(32.0))
+            };
+            grid = grid.push(row![cell(0), cell(1)].spacing(2));
+        }
+        faces = faces.push(grid);
+    }
+    let mut col = column![
+        row![
+            faces,
+            labeled(button(text(t!("scheme-reset"))).on_press(Msg::ResetScheme), t!("scheme-reset-desc")),
+        ]
+            .spacing(16)
+            .align_items(Alignment::Center),
+    ]
+        .spacing(4);
+    let dups = scheme.duplicates();
+    if !dups.is_empty() {
+        let list: Vec<String> = dups.iter().map(char::to_string).collect();
+        col = col.push(text(t!("scheme-duplicates", letters = list.join(", "))));
+    }
+    col.into()
+}
+
+/// Diagnostics toggle; when on, each problem corner, the permutation parity,
+/// the total twist and whether the state is solvable, with a button per
+/// corner that repairs a bad twist by twisting that corner.
+pub fn build_diagnostics_panel(diagnosis: Option<&Diagnosis>) -> Element<'static, Msg> {
+    let toggle = labeled(
+        checkbox(t!("diagnostics"), diagnosis.is_some()).on_toggle(Msg::ToggleDiagnostics),
+        t!("diagnostics-desc"),
+    );
+    let Some(d) = diagnosis else { return toggle };
+    let mut col = column![toggle].spacing(4);
+
+    let piece_name = |p: u8| CORNER_NAMES[p as usize];
+    for (slot, s) in d.slots.iter().enumerate() {
+        let problem = match *s {
+            SlotState::Piece { .. } => continue,
+            SlotState::Mirrored(p) => t!("diag-mirrored", piece = piece_name(p)),
+            SlotState::Unknown(cols) => t!("diag-unknown", colors = cols.map(color_name).join(" / ")),
+        };
+        col = col.push(text(t!("diag-slot", slot = CORNER_NAMES[slot], problem = problem)));
+    }
+    let names = |ps: &[u8]| ps.iter().map(|&p| piece_name(p)).collect::<Vec<_>>().join(", ");
+    if !d.duplicated.is_empty() {
+        col = col.push(text(t!("diag-duplicated", pieces = names(&d.duplicated))));
+    }
+    if !d.missing.is_empty() {
+        col = col.push(text(t!("diag-missing", pieces = names(&d.missing))));
+    }
+
+    col = col.push(text(match d.odd_parity {
+        Some(true) => t!("diag-parity-odd"),
+        Some(false) => t!("diag-parity-even"),
+        None => t!("diag-parity-unknown"),
+    }));
+    col = col.push(text(match d.twist {
+        0 => t!("diag-twist-ok"),
+        1 => t!("diag-twist-ccw"),
+        _ => t!("diag-twist-cw"),
+    }));
+    col = col.push(text(if d.solvable() { t!("diag-solvable") } else { t!("diag-unsolvable") }));
+
+    if d.twist_fixable() {
+        let mut fixes = row![text(t!("diag-fix-twist"))].spacing(6).align_items(Alignment::Center);
+        for (slot, name) in CORNER_NAMES.iter().enumerate() {
+            fixes = fixes.push(labeled(
+                button(text(*name).size(12)).padding([2, 6]).on_press(Msg::FixTwist(slot as u8)),
+                t!("diag-fix-twist-desc", slot = *name),
+            ));
+        }
+        col = col.push(fixes);
+    }
+    col.into()
+}
+
+/// Save the cube as comparison state A or B, pick the pair (or type a
+/// pattern), compare; then what differs and the shortest algorithm between
+/// them.
+pub fn build_compare_panel(
+    slots: &[Option<Cube>; 2],
+    pair: DiffPair,
+    pattern: &str,
+    report: Option<&DiffReport>,
+) -> Element<'static, Msg> {
+    let save = |i: usize, name: &str| {
+        let saved = if slots[i].is_some() { t!("diff-slot-full") } else { t!("diff-slot-empty", slot = name) };
+        labeled(button(text(t!("diff-save", slot = name))).on_press(Msg::SaveDiffSlot(i)), saved)
+    };
+    let mut r = row![
+        text(t!("diff")),
+        save(0, "A"),
+        save(1, "B"),
+        pick_list(&DiffPair::ALL[..], Some(pair), Msg::DiffPairChanged),
     ]
         .spacing(8)
+        .align_items(Alignment::Center);
+    if pair == DiffPair::CurrentPattern {
+        r = r.push(labeled(
+            text_input(&t!("diff-pattern-placeholder"), pattern)
+                .on_input(Msg::DiffPatternChanged)
+                .on_submit(Msg::CompareStates)
+                .width(Length::Fixed(260.0)),
+            t!("diff-pattern-desc"),
+        ));
+    }
+    r = r.push(labeled(button(text(t!("diff-compare"))).on_press(Msg::CompareStates), t!("diff-compare-desc")));
+    let Some(report) = report else { return r.into() };
+    r = r.push(button(text(t!("diff-clear"))).on_press(Msg::ClearDiff));
+
+    let mut col = column![r].spacing(4);
+    if report.diff.is_empty() {
+        return col.push(text(t!("diff-identical"))).into();
+    }
+    col = col.push(text(t!("diff-stickers", n = report.diff.stickers.len())));
+    col = col.push(text(match &report.diff.slots {
+        Some(slots) if slots.is_empty() => t!("diff-slots-none"),
+        Some(slots) => t!("diff-slots", n = slots.len(), slots = slot_names(slots)),
+        None => t!("diff-illegal"),
+    }));
+    if let Some(alg) = &report.alg {
+        col = col.push(text(t!("diff-alg", alg = alg.to_string(), n = alg.len())));
+    }
+    col.into()
+}
+
+/// "Solve" button, least-cost toggle, generator set, and cost model, then the
+/// solution and its regripped form when rotations make it cheaper to execute.
+pub fn build_solution_panel(
+    solution: Option<&Alg>,
+    cost_input: &str,
+    costs: &MoveCosts,
+    generators: &str,
+    weighted: bool,
+    solving: bool,
+) -> Element<'static, Msg> {
+    let mut col = column![row![
+        labeled(button(text(t!("solve"))).on_press_maybe((!solving).then_some(Msg::SolveCube)), t!("solve-desc")),
+        labeled(
+            checkbox(t!("solve-weighted"), weighted).on_toggle(Msg::ToggleWeightedSolve),
+            t!("solve-weighted-desc"),
+        ),
+        text(t!("generators")),
+        labeled(
+            text_input(&t!("generators-placeholder"), generators)
+                .on_input(Msg::GeneratorsChanged)
+                .width(Length::Fixed(110.0)),
+            t!("generators-desc"),
+        ),
+        text(t!("cost-model")),
+        labeled(
+            text_input(&costs.to_string(), cost_input)
+                .on_input(Msg::CostModelChanged)
+                .width(Length::Fill),
+            t!("cost-model-desc"),
+        ),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)]
+    .spacing(4);
+    if let Some(alg) = solution {
+        if alg.is_empty() {
+            return col.push(text(t!("solve-solved"))).into();
+        }
+        col = col.push(text(t!("solve-result",
+            n = alg.len(),
+            cost = format!("{:.1}", costs.alg(alg)),
+            alg = alg.to_string())));
+        let hinted = regrip(alg, costs);
+        col = col.push(text(if hinted.rotations() == 0 {
+            t!("regrip-none", cost = format!("{:.1}", hinted.plain_cost))
+        } else {
+            t!("regrip-hint",
+                alg = hinted.to_string(),
+                from = format!("{:.1}", hinted.plain_cost),
+                to = format!("{:.1}", hinted.cost))
+        }));
+    }
+    col.into()
+}
+
+/// "Solve & watch" (or "Stop" while it plays) and the pace of its turns.
+pub fn build_watch_row(watching: bool, pace: Pace) -> Element<'static, Msg> {
+    let start: Element<'static, Msg> = if watching {
+        button(text(t!("watch-stop"))).on_press(Msg::StopWatching).into()
+    } else {
+        labeled(button(text(t!("watch-solve"))).on_press(Msg::WatchSolve), t!("watch-solve-desc"))
+    };
+    row![
+        start,
+        text(t!("watch-pace")),
+        labeled(pick_list(&Pace::ALL[..], Some(pace), Msg::WatchPaceChanged), t!("watch-pace-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)
+        .into()
+}
+
+/// Color of the move turning in the watched solve.
+const WATCH_CURRENT: Color = Color { r: 0.95, g: 0.6, b: 0.1, a: 1.0 };
+/// Size of the watched solve's move labels.
+const WATCH_TEXT: u16 = 28;
+
+/// The watched solve's moves made so far, over the views, the one turning
+/// last and in color.
+pub fn build_watch_strip(watch: &Watch) -> Element<'static, Msg> {
+    let shown = &watch.solution.0[..watch.shown];
+    let mut r = row![text(t!("watch-progress", n = watch.shown, total = watch.solution.len())).size(16)]
+        .spacing(10)
+        .align_items(Alignment::Center);
+    for (i, m) in shown.iter().enumerate() {
+        let label = text(m.to_string()).size(WATCH_TEXT);
+        r = r.push(if i + 1 == shown.len() { label.style(WATCH_CURRENT) } else { label });
+    }
+    r.into()
+}
+
+/// Pauses listed, and drawn, at most; the rest only count toward the
+/// cases.
+const SHOWN_PAUSES: usize = 8;
+/// Height of the grid of paused cubes.
+const PAUSES_H: f32 = 180.0;
+
+/// Pause analysis of the session's drill solves: the threshold and a Find
+/// button, then the longest pauses, each with the cube as it stood, and the
+/// cases the pauses came on, the costliest first.
+pub fn build_pauses_panel<'a>(
+    threshold: Threshold,
+    pauses: Option<&'a [Pause]>,
+    view: ViewParams,
+    settings: RenderSettings,
+) -> Element<'a, Msg> {
+    let secs = |d: Duration| fmt_secs(d.as_millis() as u64);
+    let mut controls = row![
+        labeled(button(text(t!("pauses-find"))).on_press(Msg::FindPauses), t!("pauses-find-desc")),
+        text(t!("pause-threshold")),
+        labeled(pick_list(&Threshold::ALL[..], Some(threshold), Msg::PauseThresholdChanged), t!("pause-threshold-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let Some(pauses) = pauses else { return controls.into() };
+    controls = controls.push(button(text(t!("pauses-close"))).on_press(Msg::ClosePauses));
+    if pauses.is_empty() {
+        return column![controls, text(t!("pauses-none"))].spacing(4).into();
+    }
+
+    let shown = &pauses[..pauses.len().min(SHOWN_PAUSES)];
+    let grid = Canvas::new(CubeGrid { cells: shown.iter().map(|p| (&p.state, view)).collect(), settings })
+        .width(Length::FillPortion(1))
+        .height(Length::Fixed(PAUSES_H));
+    let mut list = column![].spacing(2).width(Length::FillPortion(2));
+    for (i, p) in shown.iter().enumerate() {
+        list = list.push(text(t!("pause-line",
+            n = i + 1,
+            solve = p.solve + 1,
+            before = p.before,
+            time = secs(p.length),
+            case = p.case.map_or_else(|| t!("case-solved"), |c| c.to_string()))).size(14));
+    }
+    let mut cases = column![text(t!("pauses-by-case"))].spacing(2);
+    for s in by_case(pauses) {
+        cases = cases.push(text(t!("pause-case-line",
+            case = s.case.map_or_else(|| t!("case-solved"), |c| c.to_string()),
+            n = s.count,
+            total = secs(s.total),
+            mean = secs(s.mean()),
+            longest = secs(s.longest))).size(14));
+    }
+    column![controls, row![grid, list].spacing(16).align_items(Alignment::Center), cases]
+        .spacing(6)
+        .into()
+}
+
+/// Neutrality drill controls, the report and solve sheet exports, and
+/// per-color averages; the slowest color is shown in red.
+pub fn build_trainer_panel(
+    target: Option<Col>,
+    stats: &NeutralityStats,
+    sheet_solutions: bool,
+    classroom: Interval,
+) -> Element<'static, Msg> {
+    let controls = match target {
+        Some(c) => row![
+            button(text(t!("drill-stop"))).on_press(Msg::StopDrill),
+            text(t!("drill-target", color = color_name(c))),
+        ],
+        None => row![
+            labeled(button(text(t!("drill-start"))).on_press(Msg::StartDrill), t!("drill-start-desc")),
+        ]
+            .push_maybe(export_button(t!("report-export"), Msg::ExportReport(report::default_path()), t!("report-export-desc")))
+            .push_maybe(export_button(
+                t!("sheet-export"),
+                Msg::ExportSolveSheet(solve_sheet::default_path()),
+                t!("sheet-export-desc"),
+            ))
+            .push(labeled(
+                checkbox(t!("sheet-solutions"), sheet_solutions).on_toggle(Msg::ToggleSheetSolutions),
+                t!("sheet-solutions-desc"),
+            ))
+            .push(labeled(button(text(t!("round-new"))).on_press(Msg::NewRound), t!("round-new-desc")))
+            .push(labeled(button(text(t!("classroom"))).on_press(Msg::StartClassroom), t!("classroom-desc")))
+            .push(pick_list(&Interval::ALL[..], Some(classroom), Msg::ClassroomIntervalChanged)),
+    }
+        .spacing(8)
+        .align_items(Alignment::Center);
+
+    let slowest = stats.slowest();
+    let mut averages = row![].spacing(14);
+    for c in Col::ALL {
+        let Some(avg) = stats.average_ms(c) else { continue };
+        let mut line = t!("drill-stat", color = color_name(c), time = fmt_secs(avg), n = stats.count(c));
+        if slowest == Some(c) {
+            line = format!("{line} — {}", t!("drill-slowest"));
+            averages = averages.push(text(line).style(WARN_COLOR));
+        } else {
+            averages = averages.push(text(line));
+        }
+    }
+    if Col::ALL.iter().all(|&c| stats.average_ms(c).is_none()) {
+        averages = averages.push(text(t!("drill-stats-empty")));
+    }
+    column![controls, averages].spacing(4).into()
+}
+
+/// Solve timer for a physical cube: the button for the next phase, the
+/// inspection countdown or the time on the clock, the session mean, the
+/// session's inspection rules, and the global hotkey when this build has it
+/// (with the reason, if its key couldn't be grabbed).
+pub fn build_timer_row(
+    timer: &SolveTimer,
+    hotkey: HotkeySettings,
+    hotkey_error: Option<&str>,
+    rules: InspectionRules,
+) -> Element<'static, Msg> {
+    let label = if timer.running() {
+        t!("timer-stop")
+    } else if timer.inspection().is_some() {
+        t!("timer-start-solve")
+    } else {
+        t!("timer-start")
+    };
+    let secs = |d: Duration| fmt_secs(d.as_millis() as u64);
+    let clock = match (timer.inspection(), timer.elapsed(), timer.last()) {
+        (Some(i), _, _) => i.left().to_string(),
+        (_, Some(d), _) => secs(d),
+        (_, _, Some(attempt)) => attempt.describe(),
+        _ => secs(Duration::ZERO),
+    };
+    let mut r = row![
+        labeled(button(text(label)).on_press(Msg::ToggleTimer), t!("timer-desc")),
+        text(clock).size(22),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if let Some((mean, n)) = timer.mean() {
+        r = r.push(text(t!("timer-mean", time = secs(mean), n = n)));
+    }
+    r = r.push(labeled(
+        pick_list(&InspectionMode::ALL[..], Some(rules.mode), Msg::InspectionModeChanged),
+        t!("inspection-mode-desc"),
+    ));
+    if rules.enforced() {
+        r = r.push(labeled(
+            pick_list(&InspectionSecs::ALL[..], Some(rules.secs), Msg::InspectionSecsChanged),
+            t!("inspection-secs-desc"),
+        ));
+    }
+    if hotkey::AVAILABLE {
+        r = r
+            .push(labeled(
+                checkbox(t!("hotkey"), hotkey.enabled).on_toggle(Msg::ToggleHotkey),
+                t!("hotkey-desc"),
+            ))
+            .push(pick_list(&HotKey::ALL[..], Some(hotkey.key), Msg::HotkeyChanged));
+        if let Some(e) = hotkey_error {
+            r = r.push(text(e.to_string()).style(WARN_COLOR));
+        }
+    }
+    r.into()
+}
+
+/// Width of the A/B trainer's algorithm fields.
+const AB_INPUT_W: f32 = 200.0;
+
+/// A/B trainer: a case and two algorithms for it, then while the test runs
+/// the trial under way, and the means so far with whether the gap between
+/// them is more than noise.
+pub fn build_ab_panel<'a>(
+    case: Option<&'static NamedAlg>,
+    inputs: &'a [String; 2],
+    trials: Trials,
+    test: Option<&'a AbTest>,
+) -> Element<'a, Msg> {
+    let controls: Element<'a, Msg> = match test {
+        Some(test) if !test.finished() => row![
+            text(t!("ab-running",
+                case = test.case.name,
+                n = test.done() + 1,
+                total = test.total(),
+                side = test.side.to_string(),
+                alg = test.alg(test.side).to_string())),
+            labeled(button(text(t!("ab-redo"))).on_press(Msg::RedoAbTrial), t!("ab-redo-desc")),
+            button(text(t!("ab-stop"))).on_press(Msg::StopAbTest),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center)
+            .into(),
+        _ => {
+            let mut r = row![
+                text(t!("ab-title")),
+                pick_list(ab_test::cases(), case, Msg::AbCaseChanged).placeholder(t!("ab-case")),
+            ]
+                .spacing(8)
+                .align_items(Alignment::Center);
+            for (side, input) in [Side::A, Side::B].into_iter().zip(inputs) {
+                r = r.push(text_input(&t!("ab-alg-placeholder", side = side.to_string()), input)
+                    .on_input(move |s| Msg::AbAlgChanged(side, s))
+                    .width(Length::Fixed(AB_INPUT_W)));
+            }
+            r = r
+                .push(labeled(pick_list(&Trials::ALL[..], Some(trials), Msg::AbTrialsChanged), t!("ab-trials-desc")))
+                .push(labeled(button(text(t!("ab-start"))).on_press(Msg::StartAbTest), t!("ab-start-desc")));
+            if test.is_some() {
+                r = r.push(button(text(t!("ab-close"))).on_press(Msg::StopAbTest));
+            }
+            r.into()
+        }
+    };
+    let Some(test) = test.filter(|t| t.done() > 0) else { return controls };
+
+    let mut means = row![].spacing(14);
+    for side in [Side::A, Side::B] {
+        let times = &test.times[side as usize];
+        if times.is_empty() {
+            continue;
+        }
+        let mean = times.iter().sum::<Duration>() / times.len() as u32;
+        means = means.push(text(t!("ab-mean", side = side.to_string(), time = fmt_secs(mean.as_millis() as u64), n = times.len())));
+    }
+    let verdict = match test.result() {
+        None => text(t!("ab-verdict-waiting")),
+        Some(w) if w.significant() => {
+            let (faster, gap) = if w.mean_a < w.mean_b { (Side::A, w.mean_b - w.mean_a) } else { (Side::B, w.mean_a - w.mean_b) };
+            text(t!("ab-verdict-faster",
+                side = faster.to_string(),
+                gap = fmt_secs((gap * 1000.0) as u64),
+                p = format!("{:.3}", w.p))).style(AB_FASTER)
+        }
+        Some(w) => text(t!("ab-verdict-unclear", p = format!("{:.2}", w.p))),
+    };
+    column![controls, means, verdict].spacing(4).into()
+}
+
+/// The A/B verdict when one algorithm is really faster.
+const AB_FASTER: Color = Color { r: 0.2, g: 0.7, b: 0.3, a: 1.0 };
+
+/// Share of all turns above which a B or D face is flagged: those turns
+/// need a regrip or a reach, so leaning on them costs time.
+const SLOW_FACE_SHARE: f32 = 0.2;
+/// Size of the move usage heatmap.
+const HEATMAP_W: f32 = 270.0;
+const HEATMAP_H: f32 = 200.0;
+
+/// Move usage over the profile's solves: which face is turned most, a
+/// warning when that is a slow one, and on request the heatmap of every
+/// move.
+pub fn build_usage_panel(usage: &MoveUsage, shown: bool) -> Element<'static, Msg> {
+    let toggle = if shown { t!("usage-hide") } else { t!("usage-show") };
+    let mut controls = row![
+        labeled(button(text(toggle)).on_press(Msg::ToggleMoveUsage), t!("usage-show-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let Some((face, share)) = usage.busiest() else {
+        return controls.push(text(t!("usage-empty"))).into();
+    };
+    controls = controls
+        .push(text(t!("usage-summary",
+            solves = usage.solves,
+            turns = usage.total(),
+            face = format!("{face:?}"),
+            share = format!("{:.0}", share * 100.0))))
+        .push(labeled(button(text(t!("usage-reset-button"))).on_press(Msg::ResetMoveUsage), t!("usage-reset-desc")));
+    let mut panel = column![controls].spacing(4);
+    for slow in [FaceId::B, FaceId::D] {
+        let share = usage.face_total(slow) as f32 / usage.total() as f32;
+        if share > SLOW_FACE_SHARE {
+            panel = panel.push(text(t!("usage-slow-face",
+                face = format!("{slow:?}"),
+                share = format!("{:.0}", share * 100.0))).style(WARN_COLOR));
+        }
+    }
+    if shown {
+        panel = panel.push(Canvas::new(MoveHeatmap { counts: usage.counts })
+            .width(Length::Fixed(HEATMAP_W))
+            .height(Length::Fixed(HEATMAP_H)));
+    }
+    panel.into()
+}
+
+/// Profile switcher: the profiles on this computer, a field to add one, and
+/// backing up or restoring the active one.
+pub fn build_profile_row<'a>(
+    all: &'a [Profile],
+    current: &Profile,
+    input: &'a str,
+    confirming_restore: bool,
+) -> Element<'a, Msg> {
+    let mut add = button(text(t!("profile-add")));
+    if Profile::new(input).is_some() {
+        add = add.on_press(Msg::CreateProfile);
+    }
+    let mut r = row![
+        text(t!("profile")),
+        labeled(pick_list(all, Some(current.clone()), Msg::ProfileSelected), t!("profile-desc")),
+        text_input(&t!("profile-new-hint"), input)
+            .on_input(Msg::ProfileInputChanged)
+            .on_submit(Msg::CreateProfile)
+            .width(Length::Fixed(120.0)),
+        labeled(add, t!("profile-add-desc")),
+        labeled(button(text(t!("backup-export"))).on_press(Msg::ExportBackup(backup::default_path())), t!("backup-export-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    r = if confirming_restore {
+        r.push(button(text(t!("backup-confirm-restore"))).on_press(Msg::ConfirmRestore))
+            .push(button(text(t!("cancel"))).on_press(Msg::CancelRestore))
+    } else {
+        r.push(labeled(
+            button(text(t!("backup-restore"))).on_press(Msg::RestoreBackup(backup::default_path())),
+            t!("backup-restore-desc"),
+        ))
+    };
+    r.into()
+}
+
+/// Calendar cell width, and the colors of days solved and missed.
+const DAY_CELL: f32 = 16.0;
+const SOLVED_DAY: Color = Color { r: 0.2, g: 0.7, b: 0.3, a: 1.0 };
+const MISSED_DAY: Color = Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+
+/// Daily challenge: start or drop today's scramble, the streak and times,
+/// and a calendar of the last weeks with the days solved filled in.
+pub fn build_daily_panel(log: &DailyLog, running: bool, today: Day) -> Element<'static, Msg> {
+    let start = if running {
+        button(text(t!("daily-stop"))).on_press(Msg::StopDaily).into()
+    } else {
+        labeled(button(text(t!("daily-start"))).on_press(Msg::StartDaily), t!("daily-start-desc"))
+    };
+    let mut head = row![start, text(t!("daily-streak", n = log.streak(today)))]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if let Some(ms) = log.time(today) {
+        head = head.push(text(t!("daily-today", time = fmt_secs(ms))));
+    }
+    if let Some(ms) = log.best() {
+        head = head.push(text(t!("daily-best", time = fmt_secs(ms))));
+    }
+
+    let mut weeks = column![].spacing(2);
+    for week in DailyLog::calendar(today) {
+        let mut r = row![].spacing(2);
+        for day in week {
+            r = r.push(match day {
+                None => Space::with_width(DAY_CELL).into(),
+                Some(d) => match log.time(d) {
+                    Some(ms) => labeled(
+                        text("■").style(SOLVED_DAY).width(DAY_CELL),
+                        t!("daily-day-solved", day = d.to_string(), time = fmt_secs(ms)),
+                    ),
+                    None => labeled(text("□").style(MISSED_DAY).width(DAY_CELL), d.to_string()),
+                },
+            });
+        }
+        weeks = weeks.push(r);
+    }
+    row![head, weeks].spacing(16).align_items(Alignment::Center).into()
+}
+
+/// Leaderboard: the opt-in, where times go and under what name, and the
+/// fastest times of the day once fetched.
+pub fn build_leaderboard_panel<'a>(
+    settings: &'a LeaderboardSettings,
+    queued: usize,
+    board: Option<&'a Board>,
+    syncing: bool,
+) -> Element<'a, Msg> {
+    let mut r = row![
+        labeled(
+            checkbox(t!("leaderboard-opt-in"), settings.opted_in).on_toggle(Msg::LeaderboardOptIn),
+            t!("leaderboard-opt-in-desc"),
+        ),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if !settings.opted_in {
+        return r.into();
+    }
+    let mut refresh = button(text(t!("leaderboard-refresh")));
+    if !syncing {
+        refresh = refresh.on_press(Msg::SyncLeaderboard);
+    }
+    r = r
+        .push(labeled(
+            text_input(&t!("leaderboard-endpoint-hint"), &settings.endpoint)
+                .on_input(Msg::LeaderboardEndpointChanged)
+                .width(Length::Fixed(240.0)),
+            t!("leaderboard-endpoint-desc"),
+        ))
+        .push(labeled(
+            text_input(&t!("leaderboard-name-hint"), &settings.name)
+                .on_input(Msg::LeaderboardNameChanged)
+                .width(Length::Fixed(140.0)),
+            t!("leaderboard-name-desc"),
+        ))
+        .push(refresh);
+    if queued > 0 {
+        r = r.push(text(t!("leaderboard-queued", n = queued)));
+    }
+    let Some(board) = board else { return r.into() };
+    let mut times = column![text(t!("leaderboard-title", day = board.day.to_string()))].spacing(2);
+    if board.entries.is_empty() {
+        times = times.push(text(t!("leaderboard-empty")).size(14));
+    }
+    for (i, e) in board.entries.iter().enumerate() {
+        times = times.push(text(t!("leaderboard-entry", rank = i + 1, name = e.name.clone(), time = fmt_secs(e.millis))).size(14));
+    }
+    column![r, times].spacing(4).into()
+}
+
+/// OBS trigger: on/off, where OBS listens, its password, and what it does
+/// when a timed solve ends, with a button to try it now.
+pub fn build_obs_panel(settings: &ObsSettings) -> Element<'_, Msg> {
+    let mut r = row![
+        labeled(checkbox(t!("obs-enabled"), settings.enabled).on_toggle(Msg::ObsEnabled), t!("obs-enabled-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if !settings.enabled {
+        return r.into();
+    }
+    r = r
+        .push(labeled(
+            text_input(&t!("obs-host-hint"), &settings.host)
+                .on_input(Msg::ObsHostChanged)
+                .width(Length::Fixed(140.0)),
+            t!("obs-host-desc"),
+        ))
+        .push(
+            text_input(&t!("obs-port-hint"), &settings.port.to_string())
+                .on_input(Msg::ObsPortChanged)
+                .width(Length::Fixed(64.0)),
+        )
+        .push(labeled(
+            text_input(&t!("obs-password-hint"), &settings.password)
+                .on_input(Msg::ObsPasswordChanged)
+                .secure(true)
+                .width(Length::Fixed(140.0)),
+            t!("obs-password-desc"),
+        ))
+        .push(pick_list(&ObsAction::ALL[..], Some(settings.action), Msg::ObsActionChanged));
+    if settings.action == ObsAction::SwitchScene {
+        r = r.push(
+            text_input(&t!("obs-scene-hint"), &settings.scene)
+                .on_input(Msg::ObsSceneChanged)
+                .width(Length::Fixed(140.0)),
+        );
+    }
+    r.push(labeled(button(text(t!("obs-test"))).on_press(Msg::TestObs), t!("obs-test-desc"))).into()
+}
+
+/// Coach mode: drawing on the cube view, the tool and ink, whether marks
+/// stay with their step, and annotated snapshots.
+pub fn build_annotate_panel(notes: &Annotations, has_marks: bool) -> Element<'static, Msg> {
+    let mut r = row![
+        labeled(checkbox(t!("annotate"), notes.on).on_toggle(Msg::ToggleAnnotate), t!("annotate-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if notes.on {
+        r = r
+            .push(pick_list(&Tool::ALL[..], Some(notes.tool), Msg::AnnotateToolChanged))
+            .push(pick_list(&Ink::ALL[..], Some(notes.ink), Msg::AnnotateInkChanged));
+    }
+    r = r
+        .push(labeled(checkbox(t!("annotate-keep"), notes.keep).on_toggle(Msg::ToggleKeepMarks), t!("annotate-keep-desc")))
+        .push(button(text(t!("annotate-undo"))).on_press_maybe(has_marks.then_some(Msg::UndoMark)))
+        .push(button(text(t!("annotate-clear"))).on_press_maybe(has_marks.then_some(Msg::ClearMarks)))
+        .push_maybe(export_button(
+            t!("annotate-snapshot"),
+            Msg::ExportPng(annotate::snapshot_path()),
+            t!("annotate-snapshot-desc"),
+        ));
+    r.into()
+}
+
+/// Piece picker for tracking, where the piece is now, and (with the trail
+/// on) the slot it reached after each move, most recent last.
+pub fn build_tracking_panel(
+    tracked: Option<Piece>,
+    location: Option<(u8, u8)>,
+    show_trail: bool,
+    trail: &[Stop],
+) -> Element<'static, Msg> {
+    let mut r = row![
+        text(t!("track")),
+        labeled(
+            pick_list(&Piece::ALL[..], tracked, Msg::TrackPiece).placeholder(t!("track-placeholder")),
+            t!("track-desc"),
+        ),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if tracked.is_some() {
+        r = r
+            .push(button(text(t!("track-stop"))).on_press(Msg::StopTracking))
+            .push(labeled(checkbox(t!("track-trail"), show_trail).on_toggle(Msg::ToggleTrail), t!("track-trail-desc")));
+    }
+    if let Some((slot, twist)) = location {
+        let mark = match twist { 1 => "-", 2 => "+", _ => "" };
+        r = r.push(text(t!("track-at", slot = format!("{}{mark}", CORNER_NAMES[slot as usize]))));
+    }
+    if !show_trail || tracked.is_none() {
+        return r.into();
+    }
+    let line = if trail.is_empty() {
+        t!("track-trail-empty")
+    } else {
+        let skip = trail.len().saturating_sub(SHOWN_TRAIL);
+        let stops: Vec<String> = trail[skip..].iter().map(|s| s.to_string()).collect();
+        let more = if skip > 0 { "…, " } else { "" };
+        t!("track-trail-list", stops = format!("{more}{}", stops.join(", ")))
+    };
+    column![r, text(line)].spacing(4).into()
+}
+
+/// Random-walk demo: start/stop, rate, the stop-when-solved experiment, and
+/// the live turn counter.
+pub fn build_random_walk_panel(walk: &RandomWalk) -> Element<'static, Msg> {
+    let toggle = if walk.running { t!("random-walk-stop") } else { t!("random-walk-start") };
+    let mut r = row![
+        labeled(button(text(toggle)).on_press(Msg::ToggleRandomWalk), t!("random-walk-desc")),
+        slider(RATE_RANGE, walk.rate, Msg::RandomWalkRateChanged).width(Length::Fixed(200.0)),
+        text(t!("random-walk-rate", rate = walk.rate)),
+        labeled(
+            checkbox(t!("random-walk-until-solved"), walk.stop_when_solved).on_toggle(Msg::RandomWalkStopWhenSolved),
+            t!("random-walk-until-solved-desc", states = STATE_COUNT),
+        ),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if walk.running || walk.moves > 0 {
+        r = r.push(text(t!("random-walk-moves", n = walk.moves)));
+    }
+    r.into()
+}
+
+/// Notation drill: quiz and time limit, the prompt while running, per-quiz
+/// accuracy, the most-missed moves and the key layout.
+pub fn build_notation_panel(
+    drill: Option<&NotationDrill>,
+    quiz: Quiz,
+    limit_ms: u16,
+    stats: &NotationStats,
+) -> Element<'static, Msg> {
+    let toggle = match drill {
+        Some(_) => button(text(t!("notation-stop"))).on_press(Msg::StopNotation),
+        None => button(text(t!("notation-start"))).on_press(Msg::StartNotation),
+    };
+    let mut controls = row![
+        labeled(toggle, t!("notation-desc")),
+        pick_list(&Quiz::ALL[..], Some(quiz), Msg::NotationQuizChanged),
+        slider(LIMIT_RANGE, limit_ms, Msg::NotationLimitChanged).step(100u16).width(Length::Fixed(160.0)),
+        text(t!("notation-limit", secs = format!("{:.1}", f32::from(limit_ms) / 1000.0))),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    match drill {
+        Some(d) if d.quiz == Quiz::Keys => controls = controls.push(text(d.target.to_string()).size(32)),
+        Some(_) => controls = controls.push(text(t!("notation-prompt-read"))),
+        None => {}
+    }
+
+    let mut col = column![controls].spacing(4);
+    for q in Quiz::ALL {
+        let s = stats.quiz(q);
+        col = col.push(text(match (s.accuracy(), s.average_ms()) {
+            (Some(acc), avg) => t!("notation-stats",
+                quiz = q.to_string(),
+                accuracy = format!("{acc:.0}"),
+                n = s.attempts(),
+                avg = avg.map_or("–".to_string(), |ms| ms.to_string()),
+                timed_out = s.timed_out),
+            (None, _) => t!("notation-stats-none", quiz = q.to_string()),
+        }).size(13));
+    }
+    let missed = stats.most_missed(5);
+    if !missed.is_empty() {
+        let moves: Vec<String> = missed.iter().map(|(m, n)| format!("{m} ({}) ×{n}", keys_for(*m))).collect();
+        col = col.push(text(t!("notation-missed", moves = moves.join(", "))).size(13));
+    }
+    let keys: Vec<String> = KEYMAP.iter().map(|(k, m)| format!("{} {m}", k.to_ascii_uppercase())).collect();
+    col.push(text(t!("notation-keymap", keys = keys.join(" · "))).size(12)).into()
+}
+
+/// Metronome controls (tempo, optional click) and the beat drill with its
+/// running score.
+pub fn build_metronome_panel(m: &Metronome, can_click: bool) -> Element<'static, Msg> {
+    let toggle = if m.running { t!("metronome-stop") } else { t!("metronome-start") };
+    let mut r = row![
+        labeled(button(text(toggle)).on_press(Msg::ToggleMetronome), t!("metronome-desc")),
+        slider(BPM_RANGE, m.bpm, Msg::MetronomeBpmChanged).width(Length::Fixed(200.0)),
+        text(t!("metronome-bpm", bpm = m.bpm)),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    if can_click {
+        r = r.push(checkbox(t!("metronome-click"), m.click).on_toggle(Msg::MetronomeClickToggled));
+    }
+    r = match m.drill {
+        Some(score) => r
+            .push(button(text(t!("beat-drill-stop"))).on_press(Msg::StopBeatDrill))
+            .push(text(t!("beat-drill-live",
+                n = score.turns(),
+                accuracy = format!("{:.0}", score.accuracy(m.period()))))),
+        None => r.push(labeled(
+            button(text(t!("beat-drill-start"))).on_press(Msg::StartBeatDrill),
+            t!("beat-drill-desc"),
+        )),
+    };
+    r.into()
+}
+
+/// Reduced motion and the frame-rate cap, then the renderer when the build
+/// has more than one.
+pub fn build_motion_row(motion: MotionSettings, backend: Backend) -> Element<'static, Msg> {
+    let backends = Backend::available();
+    let backend: Element<'static, Msg> = if backends.len() > 1 {
+        labeled(
+            row![text(t!("renderer")), pick_list(backends, Some(backend), Msg::BackendChanged)]
+                .spacing(8)
+                .align_items(Alignment::Center),
+            t!("renderer-desc"),
+        )
+    } else {
+        Space::with_width(0).into()
+    };
+    row![
+        labeled(
+            checkbox(t!("reduced-motion"), motion.reduced).on_toggle(Msg::ToggleReducedMotion),
+            t!("reduced-motion-desc"),
+        ),
+        text(t!("fps-cap")),
+        pick_list(&FpsCap::ALL[..], Some(motion.fps_cap), Msg::FpsCapChanged),
+        backend,
+    ]
+        .spacing(12)
         .align_items(Alignment::Center)
         .into()
-}
\ No newline at end of file
+}
+
+/// Sound effect settings: click style, then volume and mute per cue.
+pub fn build_sound_panel(sound: SoundSettings) -> Element<'static, Msg> {
+    let mut col = column![row![
+        text(t!("sound")),
+        text(t!("click-sound")),
+        pick_list(&ClickSound::ALL[..], Some(sound.click), Msg::SoundClickChanged),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center)]
+    .spacing(4);
+    for cue in Cue::ALL {
+        let s = sound.cue(cue);
+        col = col.push(row![
+            checkbox(cue.to_string(), !s.muted).on_toggle(move |on| Msg::SoundMuteToggled(cue, on)),
+            slider(0.0..=1.0, s.volume, move |v| Msg::SoundVolumeChanged(cue, v)).step(0.05),
+            text(format!("{:.0}%", s.volume * 100.0)),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center));
+    }
+    col.into()
+}
+
+/// Controller bindings: each input with its command and a remove button,
+/// then a row adding one (the input typed, or learned from the next press).
+pub fn build_controllers_panel<'a>(
+    bindings: &'a Bindings,
+    input: &'a str,
+    command: &'a str,
+    learning: bool,
+) -> Element<'a, Msg> {
+    let mut col = column![text(t!("controllers"))].spacing(4);
+    if !controllers::SUPPORTED {
+        col = col.push(text(t!("controllers-unsupported")).size(14));
+    } else if !controllers::AVAILABLE {
+        col = col.push(text(t!("controllers-unavailable")).size(14));
+    }
+    for (i, c) in bindings.iter() {
+        col = col.push(row![
+            text(i.to_string()).width(Length::Fixed(80.0)),
+            text(c.as_str()).width(Length::Fill),
+            button(text(t!("binding-remove"))).on_press(Msg::RemoveBinding(*i)),
+        ]
+            .spacing(8)
+            .align_items(Alignment::Center));
+    }
+    let learn = if learning { t!("binding-learn-cancel") } else { t!("binding-learn") };
+    col.push(row![
+        labeled(
+            text_input(&t!("binding-input-placeholder"), input)
+                .on_input(Msg::BindingInputChanged)
+                .width(Length::Fixed(120.0)),
+            t!("binding-input-desc"),
+        ),
+        labeled(button(text(learn)).on_press(Msg::LearnBinding), t!("binding-learn-desc")),
+        labeled(
+            text_input(&t!("binding-command-placeholder"), command)
+                .on_input(Msg::BindingCommandChanged)
+                .on_submit(Msg::AddBinding)
+                .width(Length::Fill),
+            t!("binding-command-desc"),
+        ),
+        button(text(t!("binding-add"))).on_press(Msg::AddBinding),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center))
+    .into()
+}
+
+/// Game controller settings: the button turning each face, the triggers
+/// (or buttons) making turns prime or double, how the sticks orbit, and why
+/// no pad can be read, if none can.
+pub fn build_gamepad_panel(settings: GamepadSettings, error: Option<&str>) -> Element<'static, Msg> {
+    let mut faces = row![text(t!("gamepad"))].spacing(8).align_items(Alignment::Center);
+    for (i, face) in gamepad::FACES.iter().enumerate() {
+        faces = faces.push(text(format!("{face:?}"))).push(
+            pick_list(&PadButton::ALL[..], Some(settings.faces[i]), move |b| Msg::GamepadFaceChanged(i, b))
+                .width(Length::Fixed(110.0)),
+        );
+    }
+    if let Some(e) = error {
+        faces = faces.push(text(e.to_string()).style(WARN_COLOR));
+    }
+    let modifiers = row![
+        text(t!("gamepad-prime")),
+        pick_list(&PadButton::ALL[..], Some(settings.prime), Msg::GamepadPrimeChanged),
+        text(t!("gamepad-double")),
+        pick_list(&PadButton::ALL[..], Some(settings.double), Msg::GamepadDoubleChanged),
+        labeled(text(t!("gamepad-speed", speed = format!("{:.0}", settings.speed))), t!("gamepad-speed-desc")),
+        slider(gamepad::SPEED_MIN..=gamepad::SPEED_MAX, settings.speed, Msg::GamepadSpeedChanged).step(10.0).width(Length::Fixed(140.0)),
+        checkbox(t!("gamepad-invert"), settings.invert_y).on_toggle(Msg::GamepadInvertToggled),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    column![faces, modifiers].spacing(4).into()
+}
+
+/// Warning red, used for the slowest drill color and sticker mismatches.
+const WARN_COLOR: Color = Color { r: 0.85, g: 0.25, b: 0.2, a: 1.0 };
+
+/// Custom scramble field, physical-cube verification field, and the list of
+/// mismatched stickers from the last check.
+pub fn build_custom_scramble_panel(
+    scramble: &str,
+    kpuzzle: &str,
+    facelets: &str,
+    result: Option<&[Mismatch]>,
+) -> Element<'static, Msg> {
+    let scramble_row = row![
+        text(t!("custom-scramble")),
+        text_input(&t!("custom-scramble-placeholder"), scramble)
+            .on_input(Msg::CustomScrambleChanged)
+            .on_submit(Msg::ApplyCustomScramble)
+            .width(Length::Fill),
+        labeled(button(text(t!("apply"))).on_press(Msg::ApplyCustomScramble), t!("custom-scramble-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let import_row = row![
+        text(t!("kpuzzle")),
+        text_input(&t!("kpuzzle-placeholder"), kpuzzle)
+            .on_input(Msg::KPuzzleInputChanged)
+            .on_submit(Msg::ImportKPuzzle)
+            .width(Length::Fill),
+        labeled(button(text(t!("import"))).on_press(Msg::ImportKPuzzle), t!("kpuzzle-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+    let verify_row = row![
+        text(t!("verify")),
+        text_input(&t!("verify-placeholder"), facelets)
+            .on_input(Msg::VerifyInputChanged)
+            .on_submit(Msg::VerifyState)
+            .width(Length::Fill),
+        labeled(button(text(t!("verify"))).on_press(Msg::VerifyState), t!("verify-desc")),
+    ]
+        .spacing(8)
+        .align_items(Alignment::Center);
+
+    let mut col = column![scramble_row, import_row, verify_row].spacing(6);
+    match result {
+        Some([]) => col = col.push(text(t!("verify-ok"))),
+        Some(diff) => {
+            col = col.push(text(t!("verify-mismatch", n = diff.len())));
+            for m in diff {
+                col = col.push(text(t!(
+                    "verify-sticker",
+                    face = format!("{:?}", m.face),
+                    row = m.row + 1,
+                    col = m.col + 1,
+                    expected = color_name(m.expected),
+                    found = color_name(m.found),
+                )).style(WARN_COLOR));
+            }
+        }
+        None => {}
+    }
+    col.into()
+}